@@ -13,7 +13,7 @@ use modone_contract::types::{
 
 use crate::memory::ModbusMemory;
 use crate::policy::{ModbusAddressSpace, ModbusMappingPolicy, ModbusMappingRule};
-use crate::types::{ChangeSource, MemoryError};
+use crate::types::{ChangeSource, MemoryError, MemoryMapSettings, MemoryType, ModbusWriteGuard};
 
 // ProtocolAdapter / DirtyPublishWindow 는 modone-contract 가 소유. 재노출하여
 // codec 소비자가 `modbus_codec::{ProtocolAdapter, DirtyPublishWindow}` 로 쓰게 한다.
@@ -46,19 +46,26 @@ fn window_intersects_rule(window: &DirtyPublishWindow, rule: &ModbusMappingRule)
 pub struct ModbusAdapter {
     canonical_memory: Arc<RwLock<CanonicalMemory>>,
     modbus_memory: Arc<ModbusMemory>,
-    policy: RwLock<ModbusMappingPolicy>,
+    policy: Arc<RwLock<ModbusMappingPolicy>>,
 }
 
 impl ModbusAdapter {
+    /// Create the adapter and install its write guard on `modbus_memory`, so
+    /// client writes the canonical side would reject get an exception reply.
     pub fn new(
         canonical_memory: Arc<RwLock<CanonicalMemory>>,
         modbus_memory: Arc<ModbusMemory>,
         policy: ModbusMappingPolicy,
     ) -> Self {
+        let policy = Arc::new(RwLock::new(policy));
+        modbus_memory.set_write_guard(Some(Arc::new(CanonicalWriteGuard {
+            canonical_memory: Arc::clone(&canonical_memory),
+            policy: Arc::clone(&policy),
+        })));
         Self {
             canonical_memory,
             modbus_memory,
-            policy: RwLock::new(policy),
+            policy,
         }
     }
 
@@ -218,7 +225,8 @@ impl ModbusAdapter {
             return Ok(());
         }
 
-        let mut canonical = self.canonical_memory.write();
+        // FC16 다중 레지스터 쓰기(REAL 등)가 반쯤 반영된 상태로 보이지 않도록 한 배치로 적용한다.
+        let mut writes = Vec::with_capacity(external_writes.len());
         for address in external_writes.iter().copied() {
             let Some(relative) = address.checked_sub(modbus_start) else {
                 continue;
@@ -229,14 +237,18 @@ impl ModbusAdapter {
 
             let value = self.modbus_memory.read_holding_registers(address, 1)?;
             if let Some(word) = value.first() {
-                canonical.write(
+                writes.push((
                     CanonicalAddress::new(rule.canonical_area, relative as u32),
                     CanonicalValue::U16(*word),
-                    CanonicalWriteSource::ExternalProtocol,
-                )?;
+                ));
             }
         }
 
+        if !writes.is_empty() {
+            self.canonical_memory
+                .write()
+                .write_batch(writes, CanonicalWriteSource::ExternalProtocol)?;
+        }
         Ok(())
     }
 
@@ -334,41 +346,106 @@ impl ModbusAdapter {
     }
 
     fn resolve_bit_window(&self, rule: &ModbusMappingRule) -> Option<(u16, u16)> {
-        let config = self.modbus_memory.config();
-        let (start, space_count) = match rule.address_space {
-            ModbusAddressSpace::Coil => (config.coil_start, config.coil_count),
-            ModbusAddressSpace::DiscreteInput => {
-                (config.discrete_input_start, config.discrete_input_count)
+        match rule.address_space {
+            ModbusAddressSpace::Coil | ModbusAddressSpace::DiscreteInput => {
+                let area_size = self.canonical_memory.read().area_size(rule.canonical_area);
+                resolve_rule_window(&self.modbus_memory.config(), rule, area_size)
             }
-            ModbusAddressSpace::HoldingRegister | ModbusAddressSpace::InputRegister => return None,
-        };
-
-        let count = clamp_rule_count(space_count, rule.offset, rule.count, rule.canonical_area);
-        if count == 0 {
-            return None;
+            ModbusAddressSpace::HoldingRegister | ModbusAddressSpace::InputRegister => None,
         }
-
-        Some((start.saturating_add(rule.offset), count))
     }
 
     fn resolve_word_window(&self, rule: &ModbusMappingRule) -> Option<(u16, u16)> {
-        let config = self.modbus_memory.config();
-        let (start, space_count) = match rule.address_space {
-            ModbusAddressSpace::HoldingRegister => {
-                (config.holding_register_start, config.holding_register_count)
-            }
-            ModbusAddressSpace::InputRegister => {
-                (config.input_register_start, config.input_register_count)
+        match rule.address_space {
+            ModbusAddressSpace::HoldingRegister | ModbusAddressSpace::InputRegister => {
+                let area_size = self.canonical_memory.read().area_size(rule.canonical_area);
+                resolve_rule_window(&self.modbus_memory.config(), rule, area_size)
             }
-            ModbusAddressSpace::Coil | ModbusAddressSpace::DiscreteInput => return None,
-        };
+            ModbusAddressSpace::Coil | ModbusAddressSpace::DiscreteInput => None,
+        }
+    }
+}
 
-        let count = clamp_rule_count(space_count, rule.offset, rule.count, rule.canonical_area);
-        if count == 0 {
-            return None;
+/// Modbus window `(start, count)` a rule occupies under `config`, clamped to
+/// the allocated size of its canonical area
+fn resolve_rule_window(
+    config: &MemoryMapSettings,
+    rule: &ModbusMappingRule,
+    area_size: usize,
+) -> Option<(u16, u16)> {
+    let (start, space_count) = match rule.address_space {
+        ModbusAddressSpace::Coil => (config.coil_start, config.coil_count),
+        ModbusAddressSpace::DiscreteInput => {
+            (config.discrete_input_start, config.discrete_input_count)
+        }
+        ModbusAddressSpace::HoldingRegister => {
+            (config.holding_register_start, config.holding_register_count)
         }
+        ModbusAddressSpace::InputRegister => {
+            (config.input_register_start, config.input_register_count)
+        }
+    };
 
-        Some((start.saturating_add(rule.offset), count))
+    let count = clamp_rule_count(space_count, rule.offset, rule.count, area_size);
+    if count == 0 {
+        return None;
+    }
+
+    Some((start.saturating_add(rule.offset), count))
+}
+
+/// 외부 쓰기를 매핑 규칙으로 canonical 주소에 대응시켜 접근·쓰기 보호를 미리
+/// 검사한다. 어떤 규칙에도 걸리지 않는 주소는 canonical 에 반영되지 않으므로 통과.
+struct CanonicalWriteGuard {
+    canonical_memory: Arc<RwLock<CanonicalMemory>>,
+    policy: Arc<RwLock<ModbusMappingPolicy>>,
+}
+
+impl ModbusWriteGuard for CanonicalWriteGuard {
+    fn check_write(
+        &self,
+        config: &MemoryMapSettings,
+        memory_type: MemoryType,
+        start: u16,
+        count: u16,
+    ) -> Result<(), MemoryError> {
+        let space = match memory_type {
+            MemoryType::Coil => ModbusAddressSpace::Coil,
+            MemoryType::HoldingRegister => ModbusAddressSpace::HoldingRegister,
+            MemoryType::DiscreteInput | MemoryType::InputRegister => return Ok(()),
+        };
+        let policy = self.policy.read();
+        let canonical = self.canonical_memory.read();
+
+        for address in (start..).take(count as usize) {
+            for rule in policy
+                .rules
+                .iter()
+                .filter(|rule| rule.address_space == space)
+            {
+                let area_size = canonical.area_size(rule.canonical_area);
+                let Some((window_start, window_count)) =
+                    resolve_rule_window(config, rule, area_size)
+                else {
+                    continue;
+                };
+                let Some(relative) = address.checked_sub(window_start) else {
+                    continue;
+                };
+                if relative >= window_count {
+                    continue;
+                }
+                let target = CanonicalAddress::new(rule.canonical_area, relative as u32);
+                match canonical.check_write(target, CanonicalWriteSource::ExternalProtocol) {
+                    Ok(()) => {}
+                    Err(CanonicalMemoryError::WriteProtected { .. }) => {
+                        return Err(MemoryError::WriteProtected { address });
+                    }
+                    Err(_) => return Err(MemoryError::WriteNotAllowed { address }),
+                }
+            }
+        }
+        Ok(())
     }
 }
 
@@ -393,19 +470,14 @@ impl ProtocolAdapter for ModbusAdapter {
     }
 }
 
-fn clamp_rule_count(
-    space_count: u16,
-    offset: u16,
-    requested: u16,
-    canonical_area: CanonicalAreaKind,
-) -> u16 {
+fn clamp_rule_count(space_count: u16, offset: u16, requested: u16, area_size: usize) -> u16 {
     if offset >= space_count {
         return 0;
     }
 
     requested
         .min(space_count - offset)
-        .min(canonical_area.default_size() as u16)
+        .min(area_size.min(u16::MAX as usize) as u16)
 }
 
 #[cfg(test)]
@@ -502,6 +574,61 @@ mod tests {
         );
     }
 
+    #[test]
+    fn rejects_write_protected_client_writes_with_exception() {
+        use modone_contract::{ProtectionAction, WriteProtectionPolicy, WriteProtectionRule};
+
+        let canonical = Arc::new(RwLock::new(CanonicalMemory::new()));
+        canonical
+            .write()
+            .set_write_protection(WriteProtectionPolicy {
+                rules: vec![
+                    WriteProtectionRule {
+                        area: CanonicalAreaKind::DataWord,
+                        start_index: 0,
+                        end_index: None,
+                        sources: vec![CanonicalWriteSource::ExternalProtocol],
+                        action: ProtectionAction::Deny,
+                    },
+                    WriteProtectionRule {
+                        area: CanonicalAreaKind::DataWord,
+                        start_index: 10,
+                        end_index: Some(19),
+                        sources: vec![CanonicalWriteSource::ExternalProtocol],
+                        action: ProtectionAction::Allow,
+                    },
+                ],
+            });
+        let modbus_memory = Arc::new(ModbusMemory::new(&MemoryMapSettings::default()));
+        let _adapter = ModbusAdapter::new(
+            Arc::clone(&canonical),
+            Arc::clone(&modbus_memory),
+            test_policy(),
+        );
+
+        // FC06 to D5 is protected, FC10 into D10-D11 is allowed
+        let response = crate::pdu::process_request(&modbus_memory, 0x06, &[0x06, 0, 5, 0, 1]);
+        assert_eq!(response, vec![0x86, 0x04]);
+        assert_eq!(modbus_memory.read_holding_registers(5, 1).unwrap(), vec![0]);
+
+        let request = [0x10, 0, 10, 0, 2, 4, 0, 1, 0, 2];
+        assert_eq!(
+            crate::pdu::process_request(&modbus_memory, 0x10, &request),
+            vec![0x10, 0, 10, 0, 2]
+        );
+
+        // A multi-write straddling the protected range is rejected as a whole
+        let request = [0x10, 0, 19, 0, 2, 4, 0, 1, 0, 2];
+        assert_eq!(
+            crate::pdu::process_request(&modbus_memory, 0x10, &request),
+            vec![0x90, 0x04]
+        );
+        assert_eq!(
+            modbus_memory.read_holding_registers(19, 1).unwrap(),
+            vec![0]
+        );
+    }
+
     #[test]
     fn publishes_discrete_inputs_and_input_registers_with_offsets() {
         let canonical = Arc::new(RwLock::new(CanonicalMemory::new()));
@@ -0,0 +1,831 @@
+//! Modbus 마스터(클라이언트) 코덱 — 실 장비 미러링용 요청 생성·응답 해석
+//!
+//! 서버 쪽 `pdu::process_request`의 반대 방향이다. 폴 그룹은 장비의 코일/레지스터
+//! 구간을 읽어 canonical 영역에 `ExternalProtocol` 출처로 반영하고, 쓰기 그룹은
+//! canonical 구간이 바뀌었을 때만 장비로 되쓴다. 소켓은 native 셸이 담당한다.
+
+use std::sync::Arc;
+
+use modone_contract::memory::{CanonicalMemory, CanonicalMemoryError};
+use modone_contract::{CanonicalAddress, CanonicalAreaKind, CanonicalValue, CanonicalWriteSource};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::pdu::{pack_bits, unpack_bits};
+
+/// MBAP header length (transaction id, protocol id, length, unit id)
+pub const MBAP_HEADER_SIZE: usize = 7;
+
+/// Modbus master errors
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("Invalid {kind} group {index}: {message}")]
+    InvalidGroup {
+        kind: &'static str,
+        index: usize,
+        message: String,
+    },
+
+    #[error("Device returned exception 0x{code:02X} for function 0x{function:02X}")]
+    Exception { function: u8, code: u8 },
+
+    #[error("Invalid response: {0}")]
+    InvalidResponse(String),
+
+    #[error("Canonical memory error: {0}")]
+    Memory(#[from] CanonicalMemoryError),
+}
+
+pub type ClientResult<T> = Result<T, ClientError>;
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Read function codes usable in poll groups
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadFunction {
+    /// 0x01
+    Coils,
+    /// 0x02
+    DiscreteInputs,
+    /// 0x03
+    HoldingRegisters,
+    /// 0x04
+    InputRegisters,
+}
+
+impl ReadFunction {
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::Coils => 0x01,
+            Self::DiscreteInputs => 0x02,
+            Self::HoldingRegisters => 0x03,
+            Self::InputRegisters => 0x04,
+        }
+    }
+
+    pub fn is_bit(&self) -> bool {
+        matches!(self, Self::Coils | Self::DiscreteInputs)
+    }
+
+    fn max_count(&self) -> u16 {
+        if self.is_bit() {
+            2000
+        } else {
+            125
+        }
+    }
+
+    fn table(&self) -> DeviceTable {
+        match self {
+            Self::Coils => DeviceTable::Coils,
+            Self::DiscreteInputs => DeviceTable::DiscreteInputs,
+            Self::HoldingRegisters => DeviceTable::HoldingRegisters,
+            Self::InputRegisters => DeviceTable::InputRegisters,
+        }
+    }
+}
+
+/// Write function codes usable in write groups
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WriteFunction {
+    /// 0x05, one request per changed coil
+    SingleCoil,
+    /// 0x06, one request per changed register
+    SingleRegister,
+    /// 0x0F
+    MultipleCoils,
+    /// 0x10
+    MultipleRegisters,
+}
+
+impl WriteFunction {
+    pub fn code(&self) -> u8 {
+        match self {
+            Self::SingleCoil => 0x05,
+            Self::SingleRegister => 0x06,
+            Self::MultipleCoils => 0x0F,
+            Self::MultipleRegisters => 0x10,
+        }
+    }
+
+    pub fn is_bit(&self) -> bool {
+        matches!(self, Self::SingleCoil | Self::MultipleCoils)
+    }
+
+    fn max_count(&self) -> u16 {
+        match self {
+            Self::MultipleCoils => 1968,
+            Self::MultipleRegisters => 123,
+            // single 함수는 셀마다 요청을 나누므로 구간 길이 제한이 없다
+            Self::SingleCoil | Self::SingleRegister => u16::MAX,
+        }
+    }
+
+    fn table(&self) -> DeviceTable {
+        if self.is_bit() {
+            DeviceTable::Coils
+        } else {
+            DeviceTable::HoldingRegisters
+        }
+    }
+}
+
+/// Device data table a function code addresses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DeviceTable {
+    Coils,
+    DiscreteInputs,
+    HoldingRegisters,
+    InputRegisters,
+}
+
+/// Device range read periodically into a canonical area.
+///
+/// Polled values are written with source `ExternalProtocol`, so a
+/// `WriteProtectionPolicy` rule that denies that source on the target range
+/// rejects the poll and stops the mirroring.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollGroup {
+    pub function: ReadFunction,
+    /// First device address (0-based)
+    pub address: u16,
+    pub count: u16,
+    pub area: CanonicalAreaKind,
+    pub start_index: u32,
+    #[serde(default = "default_poll_interval_ms")]
+    pub interval_ms: u32,
+}
+
+/// Canonical range written back to the device when it changes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WriteGroup {
+    pub function: WriteFunction,
+    pub address: u16,
+    pub count: u16,
+    pub area: CanonicalAreaKind,
+    pub start_index: u32,
+}
+
+/// Modbus TCP master settings for one device
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModbusMasterConfig {
+    pub host: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+    #[serde(default = "default_unit_id")]
+    pub unit_id: u8,
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u32,
+    #[serde(default)]
+    pub poll_groups: Vec<PollGroup>,
+    #[serde(default)]
+    pub write_groups: Vec<WriteGroup>,
+}
+
+fn default_poll_interval_ms() -> u32 {
+    100
+}
+
+fn default_port() -> u16 {
+    502
+}
+
+fn default_unit_id() -> u8 {
+    1
+}
+
+fn default_timeout_ms() -> u32 {
+    1000
+}
+
+impl ModbusMasterConfig {
+    /// Check function/area pairing, request size limits and that no poll
+    /// group shares device or canonical addresses with another group
+    pub fn validate(&self) -> ClientResult<()> {
+        let check = |kind: &'static str,
+                     index: usize,
+                     is_bit: bool,
+                     address: u16,
+                     count: u16,
+                     max: u16,
+                     area: CanonicalAreaKind| {
+            let invalid = |message: String| ClientError::InvalidGroup {
+                kind,
+                index,
+                message,
+            };
+            if count == 0 || count > max {
+                return Err(invalid(format!("count must be 1..={}", max)));
+            }
+            // 장비 주소 공간(0..=65535)을 넘어가는 구간은 주소가 감싸여 엉뚱한 곳을 읽고 쓴다
+            if u32::from(address) + u32::from(count) > 0x1_0000 {
+                return Err(invalid(format!(
+                    "address {} + count {} exceeds the device address space",
+                    address, count
+                )));
+            }
+            if is_bit != area.is_bit_area() {
+                return Err(invalid(format!(
+                    "{} function cannot map onto {:?}",
+                    if is_bit { "bit" } else { "register" },
+                    area
+                )));
+            }
+            Ok(())
+        };
+
+        for (index, group) in self.poll_groups.iter().enumerate() {
+            check(
+                "poll",
+                index,
+                group.function.is_bit(),
+                group.address,
+                group.count,
+                group.function.max_count(),
+                group.area,
+            )?;
+            if !group
+                .area
+                .default_access()
+                .allows_write(CanonicalWriteSource::ExternalProtocol)
+            {
+                return Err(ClientError::InvalidGroup {
+                    kind: "poll",
+                    index,
+                    message: format!("{:?} is not writable from a device", group.area),
+                });
+            }
+        }
+        for (index, group) in self.write_groups.iter().enumerate() {
+            check(
+                "write",
+                index,
+                group.function.is_bit(),
+                group.address,
+                group.count,
+                group.function.max_count(),
+                group.area,
+            )?;
+        }
+
+        // 폴 결과가 다른 그룹의 장비/canonical 구간을 덮으면 값이 서로 되쓰며 싸운다
+        let spans = self
+            .poll_groups
+            .iter()
+            .enumerate()
+            .map(|(index, group)| GroupSpan {
+                kind: "poll",
+                index,
+                table: group.function.table(),
+                address: group.address,
+                area: group.area,
+                start_index: group.start_index,
+                count: group.count,
+            })
+            .chain(
+                self.write_groups
+                    .iter()
+                    .enumerate()
+                    .map(|(index, group)| GroupSpan {
+                        kind: "write",
+                        index,
+                        table: group.function.table(),
+                        address: group.address,
+                        area: group.area,
+                        start_index: group.start_index,
+                        count: group.count,
+                    }),
+            )
+            .collect::<Vec<_>>();
+        for (position, span) in spans.iter().enumerate() {
+            for other in &spans[position + 1..] {
+                if span.kind == "write" && other.kind == "write" {
+                    continue;
+                }
+                if span.overlaps_device(other) || span.overlaps_canonical(other) {
+                    return Err(ClientError::InvalidGroup {
+                        kind: other.kind,
+                        index: other.index,
+                        message: format!("addresses overlap {} group {}", span.kind, span.index),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Device and canonical range covered by one poll or write group
+struct GroupSpan {
+    kind: &'static str,
+    index: usize,
+    table: DeviceTable,
+    address: u16,
+    area: CanonicalAreaKind,
+    start_index: u32,
+    count: u16,
+}
+
+impl GroupSpan {
+    fn overlaps_device(&self, other: &Self) -> bool {
+        self.table == other.table
+            && ranges_overlap(
+                self.address as u32,
+                other.address as u32,
+                self.count,
+                other.count,
+            )
+    }
+
+    fn overlaps_canonical(&self, other: &Self) -> bool {
+        self.area == other.area
+            && ranges_overlap(self.start_index, other.start_index, self.count, other.count)
+    }
+}
+
+fn ranges_overlap(start: u32, other_start: u32, count: u16, other_count: u16) -> bool {
+    start < other_start + other_count as u32 && other_start < start + count as u32
+}
+
+// ============================================================================
+// PDU / MBAP framing
+// ============================================================================
+
+/// Build a read request PDU
+pub fn read_request(function: ReadFunction, address: u16, count: u16) -> Vec<u8> {
+    let mut pdu = vec![function.code()];
+    pdu.extend_from_slice(&address.to_be_bytes());
+    pdu.extend_from_slice(&count.to_be_bytes());
+    pdu
+}
+
+/// Build the write request PDUs for a group. Single-cell functions only
+/// send the cells that differ from `previous`.
+pub fn write_requests(
+    group: &WriteGroup,
+    values: &[CanonicalValue],
+    previous: Option<&[CanonicalValue]>,
+) -> Vec<Vec<u8>> {
+    let changed =
+        |offset: usize| previous.map_or(true, |prev| prev.get(offset) != values.get(offset));
+
+    match group.function {
+        WriteFunction::SingleCoil | WriteFunction::SingleRegister => values
+            .iter()
+            .enumerate()
+            .filter(|(offset, _)| changed(*offset))
+            .filter_map(|(offset, value)| {
+                // validate 가 구간을 막지만 주소가 감싸이지 않도록 범위를 다시 확인한다
+                let address = u16::try_from(offset)
+                    .ok()
+                    .and_then(|offset| group.address.checked_add(offset))?;
+                let mut pdu = vec![group.function.code()];
+                pdu.extend_from_slice(&address.to_be_bytes());
+                let raw = match value {
+                    CanonicalValue::Bool(true) => 0xFF00,
+                    CanonicalValue::Bool(false) => 0x0000,
+                    CanonicalValue::U16(word) => *word,
+                };
+                pdu.extend_from_slice(&raw.to_be_bytes());
+                Some(pdu)
+            })
+            .collect(),
+        WriteFunction::MultipleCoils => {
+            let bits: Vec<bool> = values
+                .iter()
+                .map(|value| matches!(value, CanonicalValue::Bool(true)))
+                .collect();
+            let packed = pack_bits(&bits);
+            let mut pdu = vec![group.function.code()];
+            pdu.extend_from_slice(&group.address.to_be_bytes());
+            pdu.extend_from_slice(&(bits.len() as u16).to_be_bytes());
+            pdu.push(packed.len() as u8);
+            pdu.extend(packed);
+            vec![pdu]
+        }
+        WriteFunction::MultipleRegisters => {
+            let mut pdu = vec![group.function.code()];
+            pdu.extend_from_slice(&group.address.to_be_bytes());
+            pdu.extend_from_slice(&(values.len() as u16).to_be_bytes());
+            pdu.push((values.len() * 2) as u8);
+            for value in values {
+                let raw = match value {
+                    CanonicalValue::U16(word) => *word,
+                    CanonicalValue::Bool(bit) => *bit as u16,
+                };
+                pdu.extend_from_slice(&raw.to_be_bytes());
+            }
+            vec![pdu]
+        }
+    }
+}
+
+fn check_exception(function: u8, pdu: &[u8]) -> ClientResult<()> {
+    match pdu {
+        [code, exception, ..] if *code == function | 0x80 => Err(ClientError::Exception {
+            function,
+            code: *exception,
+        }),
+        [code, ..] if *code == function => Ok(()),
+        [] => Err(ClientError::InvalidResponse("empty PDU".to_string())),
+        [code, ..] => Err(ClientError::InvalidResponse(format!(
+            "function 0x{:02X} does not match request 0x{:02X}",
+            code, function
+        ))),
+    }
+}
+
+/// Parse a read response PDU into canonical values
+pub fn parse_read_response(
+    function: ReadFunction,
+    count: u16,
+    pdu: &[u8],
+) -> ClientResult<Vec<CanonicalValue>> {
+    check_exception(function.code(), pdu)?;
+    let byte_count = *pdu
+        .get(1)
+        .ok_or_else(|| ClientError::InvalidResponse("missing byte count".to_string()))?
+        as usize;
+    let data = pdu
+        .get(2..2 + byte_count)
+        .ok_or_else(|| ClientError::InvalidResponse("truncated data".to_string()))?;
+
+    if function.is_bit() {
+        if byte_count < (count as usize).div_ceil(8) {
+            return Err(ClientError::InvalidResponse(
+                "too few coil bytes".to_string(),
+            ));
+        }
+        Ok(unpack_bits(data, count as usize)
+            .into_iter()
+            .map(CanonicalValue::Bool)
+            .collect())
+    } else {
+        if byte_count < count as usize * 2 {
+            return Err(ClientError::InvalidResponse(
+                "too few register bytes".to_string(),
+            ));
+        }
+        Ok(data
+            .chunks_exact(2)
+            .take(count as usize)
+            .map(|pair| CanonicalValue::U16(u16::from_be_bytes([pair[0], pair[1]])))
+            .collect())
+    }
+}
+
+/// Check a write response PDU
+pub fn check_write_response(request: &[u8], pdu: &[u8]) -> ClientResult<()> {
+    let function = *request
+        .first()
+        .ok_or_else(|| ClientError::InvalidResponse("empty request".to_string()))?;
+    check_exception(function, pdu)?;
+    // 0x05/0x06/0x0F/0x10 모두 주소(+값/개수) 4바이트를 그대로 되돌려준다
+    if pdu.get(1..5) != request.get(1..5) {
+        return Err(ClientError::InvalidResponse(
+            "write response does not echo the request".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Wrap a PDU in an MBAP header
+pub fn encode_mbap(transaction_id: u16, unit_id: u8, pdu: &[u8]) -> Vec<u8> {
+    let mut frame = Vec::with_capacity(MBAP_HEADER_SIZE + pdu.len());
+    frame.extend_from_slice(&transaction_id.to_be_bytes());
+    frame.extend_from_slice(&0u16.to_be_bytes());
+    frame.extend_from_slice(&((pdu.len() + 1) as u16).to_be_bytes());
+    frame.push(unit_id);
+    frame.extend_from_slice(pdu);
+    frame
+}
+
+/// Decode an MBAP header into `(transaction_id, unit_id, pdu_len)`
+pub fn decode_mbap_header(header: &[u8; MBAP_HEADER_SIZE]) -> ClientResult<(u16, u8, usize)> {
+    let transaction_id = u16::from_be_bytes([header[0], header[1]]);
+    let protocol_id = u16::from_be_bytes([header[2], header[3]]);
+    let length = u16::from_be_bytes([header[4], header[5]]) as usize;
+    if protocol_id != 0 || !(2..=254).contains(&length) {
+        return Err(ClientError::InvalidResponse(format!(
+            "bad MBAP header (protocol {}, length {})",
+            protocol_id, length
+        )));
+    }
+    Ok((transaction_id, header[6], length - 1))
+}
+
+// ============================================================================
+// Mirror
+// ============================================================================
+
+/// Write requests for one group, to be committed once the device accepted them
+#[derive(Debug, Clone)]
+pub struct PendingWrite {
+    pub group: usize,
+    pub requests: Vec<Vec<u8>>,
+    values: Vec<CanonicalValue>,
+}
+
+/// Applies poll results to canonical memory and tracks outputs to write back
+pub struct ModbusMirror {
+    config: ModbusMasterConfig,
+    memory: Arc<RwLock<CanonicalMemory>>,
+    last_written: Vec<Option<Vec<CanonicalValue>>>,
+}
+
+impl ModbusMirror {
+    pub fn new(
+        config: ModbusMasterConfig,
+        memory: Arc<RwLock<CanonicalMemory>>,
+    ) -> ClientResult<Self> {
+        config.validate()?;
+        Ok(Self {
+            last_written: vec![None; config.write_groups.len()],
+            config,
+            memory,
+        })
+    }
+
+    pub fn config(&self) -> &ModbusMasterConfig {
+        &self.config
+    }
+
+    /// Request PDU for a poll group
+    pub fn poll_request(&self, group: usize) -> Vec<u8> {
+        let group = &self.config.poll_groups[group];
+        read_request(group.function, group.address, group.count)
+    }
+
+    /// Apply a poll response to canonical memory as an `ExternalProtocol`
+    /// write; fails if the memory's write protection denies that source.
+    pub fn apply_poll(&self, group: usize, pdu: &[u8]) -> ClientResult<()> {
+        let group = &self.config.poll_groups[group];
+        let values = parse_read_response(group.function, group.count, pdu)?;
+        let writes = values
+            .into_iter()
+            .enumerate()
+            .map(|(offset, value)| {
+                (
+                    CanonicalAddress::new(group.area, group.start_index + offset as u32),
+                    value,
+                )
+            })
+            .collect();
+        self.memory
+            .write()
+            .write_batch(writes, CanonicalWriteSource::ExternalProtocol)?;
+        Ok(())
+    }
+
+    /// Write requests for groups whose canonical range changed since the last
+    /// accepted write. The first call only records the current values so that
+    /// connecting never overwrites the device with the mirror's initial state.
+    pub fn pending_writes(&mut self) -> ClientResult<Vec<PendingWrite>> {
+        let memory = self.memory.read();
+        let mut pending = Vec::new();
+        for (index, group) in self.config.write_groups.iter().enumerate() {
+            let values = memory.read_range(group.area, group.start_index, group.count as usize)?;
+            match self.last_written[index].as_deref() {
+                None => self.last_written[index] = Some(values),
+                Some(previous) if previous == values.as_slice() => {}
+                Some(previous) => pending.push(PendingWrite {
+                    group: index,
+                    requests: write_requests(group, &values, Some(previous)),
+                    values,
+                }),
+            }
+        }
+        Ok(pending)
+    }
+
+    /// Record that the device accepted a pending write
+    pub fn commit(&mut self, write: PendingWrite) {
+        self.last_written[write.group] = Some(write.values);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::ModbusMemory;
+    use crate::pdu::process_request;
+    use crate::types::MemoryMapSettings;
+
+    fn config() -> ModbusMasterConfig {
+        ModbusMasterConfig {
+            host: "127.0.0.1".to_string(),
+            port: 502,
+            unit_id: 1,
+            timeout_ms: 1000,
+            poll_groups: vec![
+                PollGroup {
+                    function: ReadFunction::HoldingRegisters,
+                    address: 10,
+                    count: 3,
+                    area: CanonicalAreaKind::DataWord,
+                    start_index: 100,
+                    interval_ms: 100,
+                },
+                PollGroup {
+                    function: ReadFunction::DiscreteInputs,
+                    address: 0,
+                    count: 10,
+                    area: CanonicalAreaKind::InputBit,
+                    start_index: 0,
+                    interval_ms: 100,
+                },
+            ],
+            write_groups: vec![
+                WriteGroup {
+                    function: WriteFunction::MultipleCoils,
+                    address: 0,
+                    count: 8,
+                    area: CanonicalAreaKind::OutputBit,
+                    start_index: 0,
+                },
+                WriteGroup {
+                    function: WriteFunction::SingleRegister,
+                    address: 50,
+                    count: 4,
+                    area: CanonicalAreaKind::DataWord,
+                    start_index: 200,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_mismatched_groups() {
+        assert!(config().validate().is_ok());
+
+        let mut bad = config();
+        bad.poll_groups[0].area = CanonicalAreaKind::InputBit;
+        assert!(bad.validate().is_err());
+
+        let mut bad = config();
+        bad.poll_groups[0].count = 126;
+        assert!(bad.validate().is_err());
+
+        let mut bad = config();
+        bad.poll_groups[0].area = CanonicalAreaKind::TimerValueWord;
+        assert!(bad.validate().is_err());
+
+        // 장비 주소 공간 끝을 넘는 구간: 65534 + 3
+        let mut bad = config();
+        bad.poll_groups[0].address = 65534;
+        assert!(bad.validate().is_err());
+
+        let mut bad = config();
+        bad.write_groups[1].address = 65533;
+        assert!(matches!(
+            bad.validate(),
+            Err(ClientError::InvalidGroup {
+                kind: "write",
+                index: 1,
+                ..
+            })
+        ));
+
+        // 마지막 주소까지 딱 맞는 구간은 허용
+        let mut ok = config();
+        ok.write_groups[1].address = 65532;
+        assert!(ok.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_overlapping_groups() {
+        // 장비 주소 겹침: 폴 HR 10..13 과 쓰기 HR 12..
+        let mut bad = config();
+        bad.write_groups[1].address = 12;
+        assert!(matches!(
+            bad.validate(),
+            Err(ClientError::InvalidGroup {
+                kind: "write",
+                index: 1,
+                ..
+            })
+        ));
+
+        // canonical 겹침: 폴 D100..103 과 쓰기 D102..
+        let mut bad = config();
+        bad.write_groups[1].start_index = 102;
+        assert!(bad.validate().is_err());
+
+        // 폴끼리 같은 canonical 구간을 채워도 거부
+        let mut bad = config();
+        bad.poll_groups[1] = PollGroup {
+            function: ReadFunction::InputRegisters,
+            address: 10,
+            count: 2,
+            area: CanonicalAreaKind::DataWord,
+            start_index: 99,
+            interval_ms: 100,
+        };
+        assert!(bad.validate().is_err());
+
+        // 다른 테이블의 같은 주소는 허용 (입력 레지스터 10 vs 보유 레지스터 10)
+        let mut ok = config();
+        ok.poll_groups[1] = PollGroup {
+            function: ReadFunction::InputRegisters,
+            address: 10,
+            count: 2,
+            area: CanonicalAreaKind::DataWord,
+            start_index: 300,
+            interval_ms: 100,
+        };
+        assert!(ok.validate().is_ok());
+    }
+
+    #[test]
+    fn test_poll_against_server_pdu() {
+        let device = ModbusMemory::new(&MemoryMapSettings::default());
+        device.write_holding_register(11, 1234).unwrap();
+        device.write_discrete_input(3, true).unwrap();
+
+        let memory = Arc::new(RwLock::new(CanonicalMemory::new()));
+        let mirror = ModbusMirror::new(config(), Arc::clone(&memory)).unwrap();
+        for group in 0..2 {
+            let request = mirror.poll_request(group);
+            let response = process_request(&device, request[0], &request);
+            mirror.apply_poll(group, &response).unwrap();
+        }
+
+        let memory = memory.read();
+        assert_eq!(
+            memory
+                .read(CanonicalAddress::new(CanonicalAreaKind::DataWord, 101))
+                .unwrap(),
+            CanonicalValue::U16(1234)
+        );
+        assert_eq!(
+            memory
+                .read(CanonicalAddress::new(CanonicalAreaKind::InputBit, 3))
+                .unwrap(),
+            CanonicalValue::Bool(true)
+        );
+    }
+
+    #[test]
+    fn test_pending_writes_only_send_changes() {
+        let device = ModbusMemory::new(&MemoryMapSettings::default());
+        let memory = Arc::new(RwLock::new(CanonicalMemory::new()));
+        let mut mirror = ModbusMirror::new(config(), Arc::clone(&memory)).unwrap();
+
+        // 첫 호출은 기준값만 기록한다
+        assert!(mirror.pending_writes().unwrap().is_empty());
+
+        memory
+            .write()
+            .write_batch(
+                vec![
+                    (
+                        CanonicalAddress::new(CanonicalAreaKind::OutputBit, 2),
+                        CanonicalValue::Bool(true),
+                    ),
+                    (
+                        CanonicalAddress::new(CanonicalAreaKind::DataWord, 202),
+                        CanonicalValue::U16(77),
+                    ),
+                ],
+                CanonicalWriteSource::Test,
+            )
+            .unwrap();
+
+        let pending = mirror.pending_writes().unwrap();
+        assert_eq!(pending.len(), 2);
+        assert_eq!(pending[1].requests.len(), 1);
+        for write in pending {
+            for request in &write.requests {
+                let response = process_request(&device, request[0], request);
+                check_write_response(request, &response).unwrap();
+            }
+            mirror.commit(write);
+        }
+        assert_eq!(device.read_coils(2, 1).unwrap(), vec![true]);
+        assert_eq!(device.read_holding_registers(52, 1).unwrap(), vec![77]);
+        assert!(mirror.pending_writes().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_mbap_round_trip_and_exceptions() {
+        let frame = encode_mbap(7, 3, &[0x03, 0x00, 0x01, 0x00, 0x02]);
+        let header: [u8; MBAP_HEADER_SIZE] = frame[..MBAP_HEADER_SIZE].try_into().unwrap();
+        assert_eq!(decode_mbap_header(&header).unwrap(), (7, 3, 5));
+
+        assert!(matches!(
+            parse_read_response(ReadFunction::HoldingRegisters, 2, &[0x83, 0x02]),
+            Err(ClientError::Exception {
+                function: 0x03,
+                code: 0x02
+            })
+        ));
+    }
+}
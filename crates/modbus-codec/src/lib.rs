@@ -4,6 +4,7 @@
 //! 이 크레이트는 `modone-contract` 만 의존하며 wasm32 로 컴파일된다.
 
 pub mod adapter;
+pub mod client;
 pub mod memory;
 pub mod pdu;
 pub mod policy;
@@ -12,11 +13,15 @@ pub mod types;
 pub use adapter::{
     DirtyPublishWindow, ModbusAdapter, ModbusAdapterError, ModbusAdapterResult, ProtocolAdapter,
 };
+pub use client::{
+    ClientError, ClientResult, ModbusMasterConfig, ModbusMirror, PendingWrite, PollGroup,
+    ReadFunction, WriteFunction, WriteGroup,
+};
 pub use memory::ModbusMemory;
 pub use policy::{
     ModbusAddressSpace, ModbusMappingPolicy, ModbusMappingRule, ModbusMappingSource,
 };
 pub use types::{
     ChangeSource, MemoryBatchChangeEvent, MemoryChangeEvent, MemoryError, MemoryEventSink,
-    MemoryMapSettings, MemoryType,
+    MemoryMapSettings, MemoryType, ModbusWriteGuard,
 };
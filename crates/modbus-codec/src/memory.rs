@@ -10,7 +10,7 @@ use std::sync::Arc;
 
 use crate::types::{
     ChangeSource, MemoryBatchChangeEvent, MemoryChangeEvent, MemoryError, MemoryEventSink,
-    MemoryMapSettings, MemoryType,
+    MemoryMapSettings, MemoryType, ModbusWriteGuard,
 };
 
 /// Thread-safe Modbus memory storage
@@ -28,6 +28,8 @@ pub struct ModbusMemory {
     config: RwLock<MemoryMapSettings>,
     /// 변경 이벤트 송출 싱크 (native 셸이 주입; 없으면 송출 안 함)
     event_sink: RwLock<Option<Arc<dyn MemoryEventSink>>>,
+    /// 외부 쓰기 사전 검사 (어댑터가 주입; 없으면 검사 안 함)
+    write_guard: RwLock<Option<Arc<dyn ModbusWriteGuard>>>,
     /// Buffer for batching change events
     change_buffer: RwLock<Vec<MemoryChangeEvent>>,
     /// Whether we're in batch mode
@@ -50,6 +52,7 @@ impl ModbusMemory {
             input_registers: RwLock::new(vec![0u16; config.input_register_count as usize]),
             config: RwLock::new(config.clone()),
             event_sink: RwLock::new(None),
+            write_guard: RwLock::new(None),
             change_buffer: RwLock::new(Vec::new()),
             batch_mode: RwLock::new(false),
             external_coil_writes: RwLock::new(HashSet::new()),
@@ -87,6 +90,28 @@ impl ModbusMemory {
         *self.event_sink.write() = Some(sink);
     }
 
+    /// 외부 쓰기 가드를 주입한다. `None` 이면 검사하지 않는다.
+    pub fn set_write_guard(&self, guard: Option<Arc<dyn ModbusWriteGuard>>) {
+        *self.write_guard.write() = guard;
+    }
+
+    fn check_external_write(
+        &self,
+        config: &MemoryMapSettings,
+        memory_type: MemoryType,
+        start: u16,
+        count: usize,
+        source: ChangeSource,
+    ) -> Result<(), MemoryError> {
+        if !matches!(source, ChangeSource::External) {
+            return Ok(());
+        }
+        match self.write_guard.read().as_ref() {
+            Some(guard) => guard.check_write(config, memory_type, start, count as u16),
+            None => Ok(()),
+        }
+    }
+
     /// Start batch mode - changes will be buffered until end_batch is called
     pub fn start_batch(&self) {
         *self.batch_mode.write() = true;
@@ -149,6 +174,7 @@ impl ModbusMemory {
     ) -> Result<(), MemoryError> {
         let config = self.config();
         let index = self.resolve_address(address, config.coil_start, config.coil_count)?;
+        self.check_external_write(&config, MemoryType::Coil, address, 1, source)?;
 
         let old_value = {
             let coils = self.coils.read();
@@ -195,6 +221,7 @@ impl ModbusMemory {
             config.coil_start,
             config.coil_count,
         )?;
+        self.check_external_write(&config, MemoryType::Coil, start, values.len(), source)?;
 
         // Read old values first
         let old_values: Vec<bool> = {
@@ -363,6 +390,7 @@ impl ModbusMemory {
             config.holding_register_start,
             config.holding_register_count,
         )?;
+        self.check_external_write(&config, MemoryType::HoldingRegister, address, 1, source)?;
 
         let old_value = {
             let registers = self.holding_registers.read();
@@ -408,6 +436,13 @@ impl ModbusMemory {
             config.holding_register_start,
             config.holding_register_count,
         )?;
+        self.check_external_write(
+            &config,
+            MemoryType::HoldingRegister,
+            start,
+            values.len(),
+            source,
+        )?;
 
         let old_values: Vec<u16> = {
             let registers = self.holding_registers.read();
@@ -107,7 +107,7 @@ pub fn process_request(memory: &ModbusMemory, function_code: u8, pdu: &[u8]) ->
                     // Echo the request
                     pdu.to_vec()
                 }
-                Err(_) => exception_response(function_code, 0x02),
+                Err(err) => exception_response(function_code, err.exception_code()),
             }
         }
 
@@ -124,7 +124,7 @@ pub fn process_request(memory: &ModbusMemory, function_code: u8, pdu: &[u8]) ->
                     // Echo the request
                     pdu.to_vec()
                 }
-                Err(_) => exception_response(function_code, 0x02),
+                Err(err) => exception_response(function_code, err.exception_code()),
             }
         }
 
@@ -147,7 +147,7 @@ pub fn process_request(memory: &ModbusMemory, function_code: u8, pdu: &[u8]) ->
                 Ok(_) => {
                     vec![function_code, pdu[1], pdu[2], pdu[3], pdu[4]]
                 }
-                Err(_) => exception_response(function_code, 0x02),
+                Err(err) => exception_response(function_code, err.exception_code()),
             }
         }
 
@@ -176,7 +176,7 @@ pub fn process_request(memory: &ModbusMemory, function_code: u8, pdu: &[u8]) ->
                 Ok(_) => {
                     vec![function_code, pdu[1], pdu[2], pdu[3], pdu[4]]
                 }
-                Err(_) => exception_response(function_code, 0x02),
+                Err(err) => exception_response(function_code, err.exception_code()),
             }
         }
 
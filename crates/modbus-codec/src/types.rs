@@ -31,6 +31,24 @@ pub enum MemoryError {
 
     #[error("CSV parse error at line {line}: {message}")]
     CsvParseError { line: usize, message: String },
+
+    #[error("Address {address} is not writable")]
+    WriteNotAllowed { address: u16 },
+
+    #[error("Address {address} is write-protected")]
+    WriteProtected { address: u16 },
+}
+
+impl MemoryError {
+    /// Modbus exception code reported to the client: 0x04 (Server Device
+    /// Failure) for project write protection, 0x02 (Illegal Data Address)
+    /// for everything else.
+    pub fn exception_code(&self) -> u8 {
+        match self {
+            MemoryError::WriteProtected { .. } => 0x04,
+            _ => 0x02,
+        }
+    }
 }
 
 /// Modbus 메모리 맵 크기 설정.
@@ -230,6 +248,20 @@ pub trait MemoryEventSink: Send + Sync {
     fn emit_batch(&self, event: &MemoryBatchChangeEvent);
 }
 
+/// 외부 클라이언트 쓰기를 메모리 반영 전에 검사하는 가드.
+///
+/// 어댑터가 매핑 정책과 canonical 쓰기 보호를 근거로 구현해 주입한다. 거부된
+/// 요청은 메모리에 반영되지 않고 PDU 예외 응답으로 돌아간다.
+pub trait ModbusWriteGuard: Send + Sync {
+    fn check_write(
+        &self,
+        config: &MemoryMapSettings,
+        memory_type: MemoryType,
+        start: u16,
+        count: u16,
+    ) -> Result<(), MemoryError>;
+}
+
 impl<T: MemoryEventSink + ?Sized> MemoryEventSink for Arc<T> {
     fn emit_change(&self, event: &MemoryChangeEvent) {
         (**self).emit_change(event);
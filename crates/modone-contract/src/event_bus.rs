@@ -1,30 +1,293 @@
-use tokio::sync::broadcast;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, RwLock, Weak};
+use std::time::Duration;
 
-use super::types::CanonicalMemoryEvent;
+use tokio::sync::{broadcast, Notify};
+
+use super::link::CanonicalRange;
+use super::types::{
+    CanonicalAddress, CanonicalAreaKind, CanonicalMemoryChange, CanonicalMemoryEvent,
+};
 
 const DEFAULT_EVENT_BUS_CAPACITY: usize = 1024;
 
 /// Broadcast bus used by protocol adapters and runtime observers.
+///
+/// `subscribe` gives the raw ordered stream (lags when the consumer is slow);
+/// `subscribe_filtered` gives a per-subscriber mailbox that keeps only the
+/// latest change per address and never lags.
 #[derive(Clone, Debug)]
 pub struct CanonicalMemoryBus {
     tx: broadcast::Sender<CanonicalMemoryEvent>,
+    mailboxes: Arc<MailboxRegistry>,
 }
 
 impl CanonicalMemoryBus {
     pub fn new(capacity: usize) -> Self {
         let (tx, _) = broadcast::channel(capacity.max(1));
-        Self { tx }
+        Self {
+            tx,
+            mailboxes: Arc::new(MailboxRegistry::default()),
+        }
     }
 
     pub fn subscribe(&self) -> broadcast::Receiver<CanonicalMemoryEvent> {
         self.tx.subscribe()
     }
 
+    /// Subscribe to the changes matching `options.filter`, coalesced per address.
+    pub fn subscribe_filtered(&self, options: SubscriptionOptions) -> FilteredSubscription {
+        let mailbox = Arc::new(Mailbox {
+            filter: RwLock::new(options.filter),
+            pending: Mutex::new(PendingChanges::default()),
+            notify: Notify::new(),
+            closed: AtomicBool::new(false),
+        });
+        self.mailboxes
+            .entries
+            .lock()
+            .expect("mailbox registry poisoned")
+            .push(Arc::downgrade(&mailbox));
+
+        FilteredSubscription {
+            mailbox,
+            coalesce: options.coalesce,
+            #[cfg(feature = "bus-coalesce")]
+            last_delivery: None,
+        }
+    }
+
     pub(crate) fn emit(&self, event: CanonicalMemoryEvent) {
+        self.mailboxes.deliver(&event);
         let _ = self.tx.send(event);
     }
 }
 
+/// Which changes a filtered subscription receives. Address filters match on
+/// area and index, so a word change also reaches subscribers of its bits.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum BusFilter {
+    #[default]
+    All,
+    Areas(HashSet<CanonicalAreaKind>),
+    Ranges(Vec<CanonicalRange>),
+    /// Resolved addresses of a tag set.
+    Addresses(HashSet<(CanonicalAreaKind, u32)>),
+}
+
+impl BusFilter {
+    /// Address filter from concrete addresses (e.g. the bindings of watched tags).
+    pub fn addresses(addresses: impl IntoIterator<Item = CanonicalAddress>) -> Self {
+        Self::Addresses(
+            addresses
+                .into_iter()
+                .map(|address| (address.area, address.index))
+                .collect(),
+        )
+    }
+
+    pub fn matches(&self, address: &CanonicalAddress) -> bool {
+        match self {
+            Self::All => true,
+            Self::Areas(areas) => areas.contains(&address.area),
+            Self::Ranges(ranges) => ranges.iter().any(|range| {
+                range.area == address.area
+                    && range.start_index <= address.index
+                    && address.index <= range.end_index
+            }),
+            Self::Addresses(addresses) => addresses.contains(&(address.area, address.index)),
+        }
+    }
+}
+
+/// Options for [`CanonicalMemoryBus::subscribe_filtered`].
+#[derive(Debug, Clone, Default)]
+pub struct SubscriptionOptions {
+    pub filter: BusFilter,
+    /// Minimum spacing between deliveries; changes in between are merged.
+    /// Ignored without the `bus-coalesce` feature.
+    pub coalesce: Option<Duration>,
+}
+
+/// Receiving end of a filtered subscription. Dropping it unsubscribes.
+#[derive(Debug)]
+pub struct FilteredSubscription {
+    mailbox: Arc<Mailbox>,
+    coalesce: Option<Duration>,
+    #[cfg(feature = "bus-coalesce")]
+    last_delivery: Option<tokio::time::Instant>,
+}
+
+impl FilteredSubscription {
+    /// Wait for matching changes and take them, one entry per address with the
+    /// first old value and the latest new value. Returns `None` once the bus is
+    /// gone. Cancel-safe: nothing is taken until the future completes.
+    pub async fn recv(&mut self) -> Option<Vec<CanonicalMemoryChange>> {
+        loop {
+            if self.mailbox.is_empty() {
+                if self.mailbox.closed.load(Ordering::Acquire) {
+                    return None;
+                }
+                self.mailbox.notify.notified().await;
+                continue;
+            }
+
+            #[cfg(feature = "bus-coalesce")]
+            if let (Some(interval), Some(last)) = (self.coalesce, self.last_delivery) {
+                tokio::time::sleep_until(last + interval).await;
+            }
+
+            let changes = self.mailbox.take();
+            if !changes.is_empty() {
+                #[cfg(feature = "bus-coalesce")]
+                {
+                    self.last_delivery = Some(tokio::time::Instant::now());
+                }
+                return Some(changes);
+            }
+        }
+    }
+
+    /// Take pending changes without waiting or honouring the interval.
+    pub fn try_recv(&mut self) -> Vec<CanonicalMemoryChange> {
+        self.mailbox.take()
+    }
+
+    pub fn coalesce(&self) -> Option<Duration> {
+        self.coalesce
+    }
+
+    /// Handle for changing the filter while the subscription is owned by a task.
+    pub fn filter_handle(&self) -> SubscriptionFilterHandle {
+        SubscriptionFilterHandle(Arc::clone(&self.mailbox))
+    }
+}
+
+/// Replaces the filter of a live [`FilteredSubscription`].
+#[derive(Debug, Clone)]
+pub struct SubscriptionFilterHandle(Arc<Mailbox>);
+
+impl SubscriptionFilterHandle {
+    /// Swap the filter; pending changes the new filter rejects are dropped.
+    pub fn set_filter(&self, filter: BusFilter) {
+        // push() 와 같은 순서(filter → pending)로 잠근다
+        let mut current = self.0.filter.write().expect("mailbox filter poisoned");
+        let mut pending = self.0.pending.lock().expect("mailbox poisoned");
+        pending.retain(|change| filter.matches(&change.address));
+        *current = filter;
+    }
+}
+
+#[derive(Debug, Default)]
+struct MailboxRegistry {
+    entries: Mutex<Vec<Weak<Mailbox>>>,
+}
+
+impl MailboxRegistry {
+    fn deliver(&self, event: &CanonicalMemoryEvent) {
+        let mut entries = self.entries.lock().expect("mailbox registry poisoned");
+        entries.retain(|entry| match entry.upgrade() {
+            Some(mailbox) => {
+                mailbox.push(event);
+                true
+            }
+            None => false,
+        });
+    }
+}
+
+impl Drop for MailboxRegistry {
+    fn drop(&mut self) {
+        let entries = self.entries.get_mut().expect("mailbox registry poisoned");
+        for mailbox in entries.iter().filter_map(Weak::upgrade) {
+            mailbox.closed.store(true, Ordering::Release);
+            mailbox.notify.notify_one();
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Mailbox {
+    filter: RwLock<BusFilter>,
+    pending: Mutex<PendingChanges>,
+    notify: Notify,
+    closed: AtomicBool,
+}
+
+impl Mailbox {
+    fn push(&self, event: &CanonicalMemoryEvent) {
+        let filter = self.filter.read().expect("mailbox filter poisoned");
+        let changes = match event {
+            CanonicalMemoryEvent::Single(change) => std::slice::from_ref(change),
+            CanonicalMemoryEvent::Batch(batch) => batch.changes.as_slice(),
+        };
+
+        let mut pending = self.pending.lock().expect("mailbox poisoned");
+        let mut merged = false;
+        for change in changes
+            .iter()
+            .filter(|change| filter.matches(&change.address))
+        {
+            pending.merge(change);
+            merged = true;
+        }
+        drop(pending);
+
+        if merged {
+            self.notify.notify_one();
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pending
+            .lock()
+            .expect("mailbox poisoned")
+            .changes
+            .is_empty()
+    }
+
+    fn take(&self) -> Vec<CanonicalMemoryChange> {
+        let mut pending = self.pending.lock().expect("mailbox poisoned");
+        pending.positions.clear();
+        std::mem::take(&mut pending.changes)
+    }
+}
+
+/// Latest change per address, in order of first change.
+#[derive(Debug, Default)]
+struct PendingChanges {
+    changes: Vec<CanonicalMemoryChange>,
+    positions: HashMap<CanonicalAddress, usize>,
+}
+
+impl PendingChanges {
+    fn merge(&mut self, change: &CanonicalMemoryChange) {
+        match self.positions.get(&change.address) {
+            Some(&position) => {
+                let slot = &mut self.changes[position];
+                let old_value = slot.old_value;
+                *slot = change.clone();
+                slot.old_value = old_value;
+            }
+            None => {
+                self.positions.insert(change.address, self.changes.len());
+                self.changes.push(change.clone());
+            }
+        }
+    }
+
+    fn retain(&mut self, keep: impl Fn(&CanonicalMemoryChange) -> bool) {
+        self.changes.retain(|change| keep(change));
+        self.positions = self
+            .changes
+            .iter()
+            .enumerate()
+            .map(|(position, change)| (change.address, position))
+            .collect();
+    }
+}
+
 impl Default for CanonicalMemoryBus {
     fn default() -> Self {
         Self::new(DEFAULT_EVENT_BUS_CAPACITY)
@@ -72,4 +335,79 @@ mod tests {
         assert!(matches!(first, CanonicalMemoryEvent::Single(_)));
         assert!(matches!(second, CanonicalMemoryEvent::Batch(_)));
     }
+
+    fn word_change(index: u32, old: u16, new: u16) -> CanonicalMemoryEvent {
+        CanonicalMemoryEvent::Single(CanonicalMemoryChange {
+            address: CanonicalAddress::new(CanonicalAreaKind::DataWord, index),
+            old_value: Some(CanonicalValue::U16(old)),
+            new_value: CanonicalValue::U16(new),
+            source: CanonicalWriteSource::Simulation,
+            timestamp: "2026-03-12T00:00:00Z".to_string(),
+            batch_id: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn filtered_subscription_keeps_latest_value_per_address() {
+        // 용량 1 — raw 구독자는 lag 되지만 필터 구독은 잃지 않는다
+        let bus = CanonicalMemoryBus::new(1);
+        let mut sub = bus.subscribe_filtered(SubscriptionOptions {
+            filter: BusFilter::Ranges(vec![CanonicalRange {
+                area: CanonicalAreaKind::DataWord,
+                start_index: 0,
+                end_index: 9,
+            }]),
+            coalesce: None,
+        });
+
+        for value in 1..=100 {
+            bus.emit(word_change(3, value - 1, value));
+        }
+        bus.emit(word_change(5, 0, 1));
+        bus.emit(word_change(50, 0, 1));
+
+        let changes = sub.recv().await.expect("changes");
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].address.index, 3);
+        assert_eq!(changes[0].old_value, Some(CanonicalValue::U16(0)));
+        assert_eq!(changes[0].new_value, CanonicalValue::U16(100));
+        assert_eq!(changes[1].address.index, 5);
+        assert!(sub.try_recv().is_empty());
+
+        sub.filter_handle()
+            .set_filter(BusFilter::addresses([CanonicalAddress::with_bit_index(
+                CanonicalAreaKind::DataWord,
+                50,
+                2,
+            )]));
+        bus.emit(word_change(3, 100, 101));
+        bus.emit(word_change(50, 1, 2));
+        let changes = sub.recv().await.expect("changes");
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].address.index, 50);
+
+        drop(bus);
+        assert_eq!(sub.recv().await, None);
+    }
+
+    #[cfg(feature = "bus-coalesce")]
+    #[tokio::test]
+    async fn filtered_subscription_spaces_deliveries_by_coalesce_interval() {
+        let bus = CanonicalMemoryBus::default();
+        let interval = Duration::from_millis(30);
+        let mut sub = bus.subscribe_filtered(SubscriptionOptions {
+            filter: BusFilter::All,
+            coalesce: Some(interval),
+        });
+
+        bus.emit(word_change(1, 0, 1));
+        sub.recv().await.expect("first delivery");
+        let delivered = std::time::Instant::now();
+
+        bus.emit(word_change(1, 1, 2));
+        bus.emit(word_change(2, 0, 1));
+        let changes = sub.recv().await.expect("second delivery");
+        assert!(delivered.elapsed() >= interval);
+        assert_eq!(changes.len(), 2);
+    }
 }
@@ -12,14 +12,21 @@ pub mod event_bus;
 pub mod field_link;
 pub mod link;
 pub mod memory;
+pub mod protection;
+pub mod typed;
 pub mod types;
 
 pub use adapter::{DirtyPublishWindow, ProtocolAdapter};
 pub use cpu::{CpuHealth, CpuId, CpuKind};
-pub use event_bus::CanonicalMemoryBus;
+pub use event_bus::{
+    BusFilter, CanonicalMemoryBus, FilteredSubscription, SubscriptionFilterHandle,
+    SubscriptionOptions,
+};
 pub use field_link::FieldLink;
 pub use link::{CanonicalRange, LinkEndpoint, LinkSyncMode, MemoryLink};
 pub use memory::{CanonicalMemory, CanonicalMemoryError, CanonicalMemorySnapshot};
+pub use protection::{ProtectionAction, WriteProtectionPolicy, WriteProtectionRule};
+pub use typed::{TypedDataType, TypedValue, WordOrder};
 pub use types::{
     CanonicalAccess, CanonicalAddress, CanonicalAreaKind, CanonicalMemoryBatchChange,
     CanonicalMemoryChange, CanonicalMemoryEvent, CanonicalValue, CanonicalWriteSource,
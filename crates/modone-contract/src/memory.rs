@@ -4,6 +4,8 @@ use thiserror::Error;
 
 use super::{
     event_bus::CanonicalMemoryBus,
+    protection::WriteProtectionPolicy,
+    typed::{decode_words, encode_words, TypedDataType, TypedValue, WordOrder},
     types::{
         CanonicalAccess, CanonicalAddress, CanonicalAreaKind, CanonicalMemoryBatchChange,
         CanonicalMemoryChange, CanonicalMemoryEvent, CanonicalValue, CanonicalWriteSource,
@@ -47,18 +49,32 @@ pub enum CanonicalMemoryError {
         area: CanonicalAreaKind,
         write_source: CanonicalWriteSource,
     },
+    #[error("write to {address:?} from source {write_source:?} is blocked by write protection")]
+    WriteProtected {
+        address: CanonicalAddress,
+        write_source: CanonicalWriteSource,
+    },
     #[error("snapshot size mismatch for area {area:?}: expected={expected}, actual={actual}")]
     SnapshotSizeMismatch {
         area: CanonicalAreaKind,
         expected: usize,
         actual: usize,
     },
+    #[error("typed access requires a word address in a word area: {area:?}")]
+    NotWordAddress { area: CanonicalAreaKind },
+    #[error("value does not match data type {data_type}")]
+    TypedValueMismatch { data_type: &'static str },
+    #[error("insufficient words: expected={expected}, actual={actual}")]
+    InsufficientWords { expected: usize, actual: usize },
+    #[error("string of {length} bytes exceeds capacity {capacity}")]
+    StringTooLong { length: usize, capacity: usize },
 }
 
 #[derive(Debug, Clone)]
 pub struct CanonicalMemory {
     descriptors: BTreeMap<CanonicalAreaKind, AreaDescriptor>,
     storage: BTreeMap<CanonicalAreaKind, AreaStorage>,
+    protection: WriteProtectionPolicy,
     bus: CanonicalMemoryBus,
 }
 
@@ -86,6 +102,7 @@ impl CanonicalMemory {
         Self {
             descriptors,
             storage,
+            protection: WriteProtectionPolicy::default(),
             bus: CanonicalMemoryBus::default(),
         }
     }
@@ -94,6 +111,47 @@ impl CanonicalMemory {
         &self.bus
     }
 
+    /// Allocated size of `area` (points for bit areas, words for word areas).
+    pub fn area_size(&self, area: CanonicalAreaKind) -> usize {
+        self.descriptor(area).size
+    }
+
+    /// Reallocate `area` to `size` elements for the selected CPU model.
+    /// Values below the new size are kept; the tail is zero-filled.
+    pub fn resize_area(&mut self, area: CanonicalAreaKind, size: usize) {
+        if let Some(descriptor) = self.descriptors.get_mut(&area) {
+            descriptor.size = size;
+        }
+        match self
+            .storage
+            .get_mut(&area)
+            .expect("canonical area storage must exist")
+        {
+            AreaStorage::Bit(values) => values.resize(size, false),
+            AreaStorage::Word(values) => values.resize(size, 0),
+        }
+    }
+
+    pub fn write_protection(&self) -> &WriteProtectionPolicy {
+        &self.protection
+    }
+
+    /// Replace the project write-protection policy
+    pub fn set_write_protection(&mut self, policy: WriteProtectionPolicy) {
+        self.protection = policy;
+    }
+
+    /// Check whether `source` may write `address` without writing. Protocol
+    /// front-ends use this to reject a client request up front.
+    pub fn check_write(
+        &self,
+        address: CanonicalAddress,
+        source: CanonicalWriteSource,
+    ) -> Result<(), CanonicalMemoryError> {
+        self.ensure_index(address.area, address.index)?;
+        self.ensure_write_allowed(address, source)
+    }
+
     pub fn read(&self, address: CanonicalAddress) -> Result<CanonicalValue, CanonicalMemoryError> {
         let index = self.ensure_index(address.area, address.index)?;
 
@@ -150,11 +208,17 @@ impl CanonicalMemory {
         Ok(())
     }
 
+    /// Apply several writes as one batch event. All writes are validated first,
+    /// so a rejected write leaves memory untouched.
     pub fn write_batch(
         &mut self,
         writes: Vec<(CanonicalAddress, CanonicalValue)>,
         source: CanonicalWriteSource,
     ) -> Result<(), CanonicalMemoryError> {
+        for (address, value) in &writes {
+            self.validate_write(*address, *value, source)?;
+        }
+
         let timestamp = Self::timestamp();
         let batch_id = crate::clock::new_batch_id();
         let mut changes = Vec::new();
@@ -180,6 +244,59 @@ impl CanonicalMemory {
         Ok(())
     }
 
+    /// Read a multi-word value starting at a word address
+    pub fn read_typed(
+        &self,
+        address: CanonicalAddress,
+        data_type: TypedDataType,
+        order: WordOrder,
+    ) -> Result<TypedValue, CanonicalMemoryError> {
+        let words = self
+            .typed_addresses(address, data_type)?
+            .map(|word_address| match self.read(word_address)? {
+                CanonicalValue::U16(word) => Ok(word),
+                CanonicalValue::Bool(_) => {
+                    Err(CanonicalMemoryError::TypeMismatch { area: address.area })
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        decode_words(data_type, order, &words)
+    }
+
+    /// Write a multi-word value atomically (one batch, all-or-nothing)
+    pub fn write_typed(
+        &mut self,
+        address: CanonicalAddress,
+        data_type: TypedDataType,
+        value: &TypedValue,
+        order: WordOrder,
+        source: CanonicalWriteSource,
+    ) -> Result<(), CanonicalMemoryError> {
+        let words = encode_words(data_type, order, value)?;
+        let writes = self
+            .typed_addresses(address, data_type)?
+            .zip(words)
+            .map(|(word_address, word)| (word_address, CanonicalValue::U16(word)))
+            .collect();
+        self.write_batch(writes, source)
+    }
+
+    fn typed_addresses(
+        &self,
+        address: CanonicalAddress,
+        data_type: TypedDataType,
+    ) -> Result<impl Iterator<Item = CanonicalAddress>, CanonicalMemoryError> {
+        if !address.area.is_word_area() || address.bit_index.is_some() {
+            return Err(CanonicalMemoryError::NotWordAddress { area: address.area });
+        }
+        let count = data_type.word_count() as u32;
+        if count > 0 {
+            self.ensure_index(address.area, address.index + count - 1)?;
+        }
+        Ok((0..count)
+            .map(move |offset| CanonicalAddress::new(address.area, address.index + offset)))
+    }
+
     pub fn clear_all(&mut self) {
         for area in CanonicalAreaKind::ALL {
             self.reset_area(area);
@@ -287,8 +404,7 @@ impl CanonicalMemory {
         batch_id: Option<String>,
     ) -> Result<Option<CanonicalMemoryChange>, CanonicalMemoryError> {
         let index = self.ensure_index(address.area, address.index)?;
-        let access = self.descriptor(address.area).access;
-        self.ensure_write_allowed(address.area, access, source)?;
+        self.ensure_write_allowed(address, source)?;
 
         let timestamp = Self::timestamp();
 
@@ -374,6 +490,29 @@ impl CanonicalMemory {
         Ok(Some(change))
     }
 
+    /// write_internal과 같은 검사를 값 변경 없이 수행한다.
+    fn validate_write(
+        &self,
+        address: CanonicalAddress,
+        value: CanonicalValue,
+        source: CanonicalWriteSource,
+    ) -> Result<(), CanonicalMemoryError> {
+        self.ensure_index(address.area, address.index)?;
+        self.ensure_write_allowed(address, source)?;
+
+        match (address.area.is_bit_area(), address.bit_index, value) {
+            (true, Some(_), _) => {
+                Err(CanonicalMemoryError::BitIndexOnBitArea { area: address.area })
+            }
+            (true, None, CanonicalValue::Bool(_)) => Ok(()),
+            (false, Some(bit_index), CanonicalValue::Bool(_)) => {
+                Self::ensure_word_bit_index(bit_index)
+            }
+            (false, None, CanonicalValue::U16(_)) => Ok(()),
+            _ => Err(CanonicalMemoryError::TypeMismatch { area: address.area }),
+        }
+    }
+
     fn reset_area(&mut self, area: CanonicalAreaKind) {
         match self
             .storage
@@ -406,18 +545,22 @@ impl CanonicalMemory {
 
     fn ensure_write_allowed(
         &self,
-        area: CanonicalAreaKind,
-        access: CanonicalAccess,
+        address: CanonicalAddress,
         source: CanonicalWriteSource,
     ) -> Result<(), CanonicalMemoryError> {
-        if access.allows_write(source) {
-            Ok(())
-        } else {
-            Err(CanonicalMemoryError::WriteNotAllowed {
-                area,
+        if !self.descriptor(address.area).access.allows_write(source) {
+            return Err(CanonicalMemoryError::WriteNotAllowed {
+                area: address.area,
                 write_source: source,
-            })
+            });
         }
+        if !self.protection.allows(&address, source) {
+            return Err(CanonicalMemoryError::WriteProtected {
+                address,
+                write_source: source,
+            });
+        }
+        Ok(())
     }
 
     fn descriptor(&self, area: CanonicalAreaKind) -> &AreaDescriptor {
@@ -580,6 +723,37 @@ mod tests {
         assert_eq!(memory.read(address), Ok(CanonicalValue::U16(1234)));
     }
 
+    #[test]
+    fn resize_area_keeps_values_within_new_size() {
+        let mut memory = CanonicalMemory::new();
+        let kept = CanonicalAddress::new(CanonicalAreaKind::DataWord, 10);
+        memory
+            .write(
+                kept,
+                CanonicalValue::U16(42),
+                CanonicalWriteSource::Simulation,
+            )
+            .expect("write should succeed");
+
+        memory.resize_area(CanonicalAreaKind::DataWord, 8000);
+        assert_eq!(memory.area_size(CanonicalAreaKind::DataWord), 8000);
+        assert_eq!(memory.read(kept), Ok(CanonicalValue::U16(42)));
+        assert!(matches!(
+            memory.read(CanonicalAddress::new(CanonicalAreaKind::DataWord, 8000)),
+            Err(CanonicalMemoryError::AddressOutOfRange { size: 8000, .. })
+        ));
+
+        memory.resize_area(CanonicalAreaKind::DataWord, 32768);
+        assert_eq!(
+            memory.read(CanonicalAddress::new(CanonicalAreaKind::DataWord, 32767)),
+            Ok(CanonicalValue::U16(0))
+        );
+        assert_eq!(
+            memory.snapshot().areas[&CanonicalAreaKind::DataWord].len(),
+            32768
+        );
+    }
+
     #[tokio::test]
     async fn emits_single_and_batch_events_in_order() {
         let mut memory = CanonicalMemory::new();
@@ -618,4 +792,120 @@ mod tests {
             CanonicalMemoryEvent::Single(_) => panic!("expected batch event"),
         }
     }
+
+    #[test]
+    fn rejected_batch_leaves_memory_untouched() {
+        let mut memory = CanonicalMemory::new();
+        let size = CanonicalAreaKind::DataWord.default_size() as u32;
+
+        let result = memory.write_batch(
+            vec![
+                (
+                    CanonicalAddress::new(CanonicalAreaKind::DataWord, 0),
+                    CanonicalValue::U16(1),
+                ),
+                (
+                    CanonicalAddress::new(CanonicalAreaKind::DataWord, size),
+                    CanonicalValue::U16(2),
+                ),
+            ],
+            CanonicalWriteSource::Simulation,
+        );
+
+        assert!(matches!(
+            result,
+            Err(CanonicalMemoryError::AddressOutOfRange { .. })
+        ));
+        assert_eq!(
+            memory.read(CanonicalAddress::new(CanonicalAreaKind::DataWord, 0)),
+            Ok(CanonicalValue::U16(0))
+        );
+    }
+
+    #[test]
+    fn write_protection_rejects_matching_sources() {
+        use crate::protection::{ProtectionAction, WriteProtectionRule};
+
+        let mut memory = CanonicalMemory::new();
+        memory.set_write_protection(WriteProtectionPolicy {
+            rules: vec![WriteProtectionRule {
+                area: CanonicalAreaKind::OutputBit,
+                start_index: 0,
+                end_index: None,
+                sources: vec![CanonicalWriteSource::ExternalProtocol],
+                action: ProtectionAction::Deny,
+            }],
+        });
+        let address = CanonicalAddress::new(CanonicalAreaKind::OutputBit, 3);
+
+        assert_eq!(
+            memory.write(
+                address,
+                CanonicalValue::Bool(true),
+                CanonicalWriteSource::ExternalProtocol,
+            ),
+            Err(CanonicalMemoryError::WriteProtected {
+                address,
+                write_source: CanonicalWriteSource::ExternalProtocol,
+            })
+        );
+        assert!(memory
+            .check_write(address, CanonicalWriteSource::ExternalProtocol)
+            .is_err());
+        memory
+            .write(
+                address,
+                CanonicalValue::Bool(true),
+                CanonicalWriteSource::Simulation,
+            )
+            .expect("simulation writes are not restricted");
+    }
+
+    #[tokio::test]
+    async fn typed_write_is_a_single_batch() {
+        let mut memory = CanonicalMemory::new();
+        let mut rx = memory.bus().subscribe();
+        let address = CanonicalAddress::new(CanonicalAreaKind::DataWord, 100);
+
+        memory
+            .write_typed(
+                address,
+                TypedDataType::Real,
+                &TypedValue::Real(123.456),
+                WordOrder::HighWordFirst,
+                CanonicalWriteSource::Simulation,
+            )
+            .expect("typed write should succeed");
+
+        assert_eq!(
+            memory.read_typed(address, TypedDataType::Real, WordOrder::HighWordFirst),
+            Ok(TypedValue::Real(123.456))
+        );
+        match rx.recv().await.expect("batch event") {
+            CanonicalMemoryEvent::Batch(batch) => assert_eq!(batch.changes.len(), 2),
+            CanonicalMemoryEvent::Single(_) => panic!("expected batch event"),
+        }
+
+        let size = CanonicalAreaKind::DataWord.default_size() as u32;
+        let last = CanonicalAddress::new(CanonicalAreaKind::DataWord, size - 1);
+        assert!(matches!(
+            memory.write_typed(
+                last,
+                TypedDataType::LReal,
+                &TypedValue::LReal(1.0),
+                WordOrder::LowWordFirst,
+                CanonicalWriteSource::Simulation,
+            ),
+            Err(CanonicalMemoryError::AddressOutOfRange { .. })
+        ));
+        assert_eq!(memory.read(last), Ok(CanonicalValue::U16(0)));
+        assert!(matches!(
+            memory.read_typed(
+                CanonicalAddress::new(CanonicalAreaKind::InternalBit, 0),
+                TypedDataType::Int32,
+                WordOrder::LowWordFirst,
+            ),
+            Err(CanonicalMemoryError::NotWordAddress { .. })
+        ));
+    }
 }
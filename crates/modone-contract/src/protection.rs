@@ -0,0 +1,100 @@
+//! 쓰기 보호 정책 — 영역·구간·쓰기 출처별로 프로젝트가 정하는 쓰기 허용 규칙
+//!
+//! `CanonicalAccess` 기본값(영역 단위 고정) 위에 얹히며 기본 접근을 좁히기만
+//! 한다. ReadOnly/InternalOnly 영역을 외부 출처에 열 수는 없다. 한 주소에 여러
+//! 규칙이 걸리면 목록에서 뒤에 오는 규칙이 이긴다(넓은 Deny 뒤에 좁은 Allow).
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::{CanonicalAddress, CanonicalAreaKind, CanonicalWriteSource};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ProtectionAction {
+    Allow,
+    Deny,
+}
+
+/// 한 영역 구간 `[start_index, end_index]` 에 대한 출처별 규칙.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteProtectionRule {
+    pub area: CanonicalAreaKind,
+    #[serde(default)]
+    pub start_index: u32,
+    /// 생략하면 영역 끝까지.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_index: Option<u32>,
+    /// 규칙이 적용되는 쓰기 출처.
+    pub sources: Vec<CanonicalWriteSource>,
+    pub action: ProtectionAction,
+}
+
+impl WriteProtectionRule {
+    /// Whether the rule covers this write
+    pub fn matches(&self, address: &CanonicalAddress, source: CanonicalWriteSource) -> bool {
+        address.area == self.area
+            && address.index >= self.start_index
+            && !matches!(self.end_index, Some(end) if address.index > end)
+            && self.sources.contains(&source)
+    }
+}
+
+/// 프로젝트 단위 쓰기 보호 정책. 비어 있으면 영역 기본 접근만 적용된다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WriteProtectionPolicy {
+    #[serde(default)]
+    pub rules: Vec<WriteProtectionRule>,
+}
+
+impl WriteProtectionPolicy {
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Whether the policy lets `source` write `address`; the last matching
+    /// rule decides and unmatched writes are allowed.
+    pub fn allows(&self, address: &CanonicalAddress, source: CanonicalWriteSource) -> bool {
+        !matches!(
+            self.rules.iter().rev().find(|rule| rule.matches(address, source)),
+            Some(rule) if rule.action == ProtectionAction::Deny
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn last_matching_rule_wins() {
+        let policy = WriteProtectionPolicy {
+            rules: vec![
+                WriteProtectionRule {
+                    area: CanonicalAreaKind::DataWord,
+                    start_index: 0,
+                    end_index: None,
+                    sources: vec![CanonicalWriteSource::ExternalProtocol],
+                    action: ProtectionAction::Deny,
+                },
+                WriteProtectionRule {
+                    area: CanonicalAreaKind::DataWord,
+                    start_index: 1000,
+                    end_index: Some(1999),
+                    sources: vec![CanonicalWriteSource::ExternalProtocol],
+                    action: ProtectionAction::Allow,
+                },
+            ],
+        };
+        let external = CanonicalWriteSource::ExternalProtocol;
+        let word = |index| CanonicalAddress::new(CanonicalAreaKind::DataWord, index);
+
+        assert!(!policy.allows(&word(999), external));
+        assert!(policy.allows(&word(1000), external));
+        assert!(policy.allows(&word(1999), external));
+        assert!(!policy.allows(&word(2000), external));
+        assert!(policy.allows(&word(0), CanonicalWriteSource::Simulation));
+        assert!(policy.allows(
+            &CanonicalAddress::new(CanonicalAreaKind::OutputBit, 0),
+            external
+        ));
+    }
+}
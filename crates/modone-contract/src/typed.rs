@@ -0,0 +1,261 @@
+//! 다중 워드 타입 뷰 (INT32/UINT32/REAL/LREAL/STRING)
+//!
+//! canonical 셀은 Bool/U16뿐이라 OPC UA 매핑·디버거·시나리오가 각자 다중 워드
+//! 디코딩을 구현해 왔다. 이 모듈은 워드 순서를 명시한 인코딩/디코딩을 한곳에
+//! 모으고, [`CanonicalMemory::read_typed`](crate::CanonicalMemory::read_typed)/
+//! [`write_typed`](crate::CanonicalMemory::write_typed)가 이를 사용한다.
+//! 다중 워드 쓰기는 하나의 배치로 원자적으로 적용된다.
+
+use serde::{Deserialize, Serialize};
+
+use super::memory::CanonicalMemoryError;
+
+/// Order of the words of a multi-word value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WordOrder {
+    /// Lower address holds the low word (LS, MELSEC)
+    #[default]
+    LowWordFirst,
+    /// Lower address holds the high word (S7, Modbus big-endian)
+    HighWordFirst,
+}
+
+/// Multi-word data type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TypedDataType {
+    #[serde(rename = "INT32")]
+    Int32,
+    #[serde(rename = "UINT32")]
+    UInt32,
+    #[serde(rename = "REAL")]
+    Real,
+    #[serde(rename = "LREAL")]
+    LReal,
+    /// Byte string of `length` characters, two per word, NUL padded
+    #[serde(rename = "STRING")]
+    String { length: u16 },
+}
+
+impl TypedDataType {
+    /// Number of consecutive words the type occupies
+    pub fn word_count(&self) -> usize {
+        match self {
+            TypedDataType::Int32 | TypedDataType::UInt32 | TypedDataType::Real => 2,
+            TypedDataType::LReal => 4,
+            TypedDataType::String { length } => (*length as usize).div_ceil(2),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            TypedDataType::Int32 => "INT32",
+            TypedDataType::UInt32 => "UINT32",
+            TypedDataType::Real => "REAL",
+            TypedDataType::LReal => "LREAL",
+            TypedDataType::String { .. } => "STRING",
+        }
+    }
+}
+
+/// Decoded multi-word value
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value")]
+pub enum TypedValue {
+    #[serde(rename = "INT32")]
+    Int32(i32),
+    #[serde(rename = "UINT32")]
+    UInt32(u32),
+    #[serde(rename = "REAL")]
+    Real(f32),
+    #[serde(rename = "LREAL")]
+    LReal(f64),
+    #[serde(rename = "STRING")]
+    String(String),
+}
+
+/// Decode words (lowest address first) into a typed value.
+///
+/// Strings stop at the first NUL. With `LowWordFirst` the low byte of each word
+/// holds the first character; with `HighWordFirst` the high byte does.
+pub fn decode_words(
+    data_type: TypedDataType,
+    order: WordOrder,
+    words: &[u16],
+) -> Result<TypedValue, CanonicalMemoryError> {
+    let count = data_type.word_count();
+    if words.len() < count {
+        return Err(CanonicalMemoryError::InsufficientWords {
+            expected: count,
+            actual: words.len(),
+        });
+    }
+
+    Ok(match data_type {
+        TypedDataType::Int32 => TypedValue::Int32(combine(&words[..2], order) as i32),
+        TypedDataType::UInt32 => TypedValue::UInt32(combine(&words[..2], order) as u32),
+        TypedDataType::Real => TypedValue::Real(f32::from_bits(combine(&words[..2], order) as u32)),
+        TypedDataType::LReal => TypedValue::LReal(f64::from_bits(combine(&words[..4], order))),
+        TypedDataType::String { length } => {
+            let bytes: Vec<u8> = words[..count]
+                .iter()
+                .flat_map(|word| string_bytes(*word, order))
+                .take(length as usize)
+                .take_while(|byte| *byte != 0)
+                .collect();
+            TypedValue::String(String::from_utf8_lossy(&bytes).into_owned())
+        }
+    })
+}
+
+/// Encode a typed value into words (lowest address first)
+pub fn encode_words(
+    data_type: TypedDataType,
+    order: WordOrder,
+    value: &TypedValue,
+) -> Result<Vec<u16>, CanonicalMemoryError> {
+    let mismatch = || CanonicalMemoryError::TypedValueMismatch {
+        data_type: data_type.name(),
+    };
+
+    Ok(match (data_type, value) {
+        (TypedDataType::Int32, TypedValue::Int32(v)) => split(*v as u32 as u64, 2, order),
+        (TypedDataType::UInt32, TypedValue::UInt32(v)) => split(*v as u64, 2, order),
+        (TypedDataType::Real, TypedValue::Real(v)) => split(v.to_bits() as u64, 2, order),
+        (TypedDataType::LReal, TypedValue::LReal(v)) => split(v.to_bits(), 4, order),
+        (TypedDataType::String { length }, TypedValue::String(text)) => {
+            let bytes = text.as_bytes();
+            if bytes.len() > length as usize {
+                return Err(CanonicalMemoryError::StringTooLong {
+                    length: bytes.len(),
+                    capacity: length as usize,
+                });
+            }
+            let mut padded = bytes.to_vec();
+            padded.resize(data_type.word_count() * 2, 0);
+            padded
+                .chunks(2)
+                .map(|pair| match order {
+                    WordOrder::LowWordFirst => u16::from_le_bytes([pair[0], pair[1]]),
+                    WordOrder::HighWordFirst => u16::from_be_bytes([pair[0], pair[1]]),
+                })
+                .collect()
+        }
+        _ => return Err(mismatch()),
+    })
+}
+
+/// 워드를 하위 워드부터 정렬해 하나의 정수로 합친다.
+fn combine(words: &[u16], order: WordOrder) -> u64 {
+    let fold = |acc: u64, word: &u16| (acc << 16) | *word as u64;
+    match order {
+        WordOrder::LowWordFirst => words.iter().rev().fold(0, fold),
+        WordOrder::HighWordFirst => words.iter().fold(0, fold),
+    }
+}
+
+fn split(raw: u64, count: usize, order: WordOrder) -> Vec<u16> {
+    let mut words: Vec<u16> = (0..count).map(|i| (raw >> (16 * i)) as u16).collect();
+    if order == WordOrder::HighWordFirst {
+        words.reverse();
+    }
+    words
+}
+
+fn string_bytes(word: u16, order: WordOrder) -> [u8; 2] {
+    match order {
+        WordOrder::LowWordFirst => word.to_le_bytes(),
+        WordOrder::HighWordFirst => word.to_be_bytes(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_numeric_types_in_both_orders() {
+        let cases = [
+            (TypedDataType::Int32, TypedValue::Int32(-123_456)),
+            (TypedDataType::UInt32, TypedValue::UInt32(0xDEAD_BEEF)),
+            (TypedDataType::Real, TypedValue::Real(12.5)),
+            (TypedDataType::LReal, TypedValue::LReal(-0.1)),
+        ];
+        for order in [WordOrder::LowWordFirst, WordOrder::HighWordFirst] {
+            for (data_type, value) in &cases {
+                let words = encode_words(*data_type, order, value).unwrap();
+                assert_eq!(words.len(), data_type.word_count());
+                assert_eq!(decode_words(*data_type, order, &words).unwrap(), *value);
+            }
+        }
+    }
+
+    #[test]
+    fn applies_word_order() {
+        let words = encode_words(
+            TypedDataType::UInt32,
+            WordOrder::LowWordFirst,
+            &TypedValue::UInt32(0x1234_5678),
+        )
+        .unwrap();
+        assert_eq!(words, vec![0x5678, 0x1234]);
+
+        let words = encode_words(
+            TypedDataType::UInt32,
+            WordOrder::HighWordFirst,
+            &TypedValue::UInt32(0x1234_5678),
+        )
+        .unwrap();
+        assert_eq!(words, vec![0x1234, 0x5678]);
+    }
+
+    #[test]
+    fn encodes_strings_with_padding_and_capacity() {
+        let data_type = TypedDataType::String { length: 5 };
+        let words = encode_words(
+            data_type,
+            WordOrder::LowWordFirst,
+            &TypedValue::String("AB".to_string()),
+        )
+        .unwrap();
+        assert_eq!(words, vec![0x4241, 0x0000, 0x0000]);
+        assert_eq!(
+            decode_words(data_type, WordOrder::LowWordFirst, &words).unwrap(),
+            TypedValue::String("AB".to_string())
+        );
+
+        let words = encode_words(
+            data_type,
+            WordOrder::HighWordFirst,
+            &TypedValue::String("AB".to_string()),
+        )
+        .unwrap();
+        assert_eq!(words[0], 0x4142);
+
+        assert_eq!(
+            encode_words(
+                data_type,
+                WordOrder::LowWordFirst,
+                &TypedValue::String("TOOLONG".to_string()),
+            ),
+            Err(CanonicalMemoryError::StringTooLong {
+                length: 7,
+                capacity: 5
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_mismatched_values() {
+        assert_eq!(
+            encode_words(
+                TypedDataType::Real,
+                WordOrder::LowWordFirst,
+                &TypedValue::Int32(1)
+            ),
+            Err(CanonicalMemoryError::TypedValueMismatch { data_type: "REAL" })
+        );
+        assert!(decode_words(TypedDataType::LReal, WordOrder::LowWordFirst, &[0, 0]).is_err());
+    }
+}
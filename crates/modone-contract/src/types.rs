@@ -135,6 +135,9 @@ pub enum CanonicalWriteSource {
     /// inter-CPU 메모리 링크의 복사. 접근 판정상 외부 취급(InternalOnly 영역 쓰기
     /// 불가). 감사·구분을 위해 ExternalProtocol 과 별도 변형으로 둔다.
     CpuLink,
+    /// 센서 고장 주입(stuck-at/채터링/노이즈/지연). 감사 로그에서 실제 입력 변화와
+    /// 구분하기 위해 Simulation 과 별도 변형으로 둔다.
+    FaultInjection,
     SnapshotRestore,
     Migration,
     Test,
@@ -147,6 +150,7 @@ impl CanonicalWriteSource {
             self,
             Self::InternalRuntime
                 | Self::Simulation
+                | Self::FaultInjection
                 | Self::SnapshotRestore
                 | Self::Migration
                 | Self::Test
@@ -44,10 +44,11 @@ impl OpcUaAdapter {
             return Ok(());
         }
 
-        let mut mem = self.canonical_memory.write();
-        for w in writes {
-            mem.write(w.address, w.value, CanonicalWriteSource::ExternalProtocol)?;
-        }
+        // 다중 워드 노드(Float/Double) 쓰기는 한 배치로 원자적으로 반영한다.
+        self.canonical_memory.write().write_batch(
+            writes.into_iter().map(|w| (w.address, w.value)).collect(),
+            CanonicalWriteSource::ExternalProtocol,
+        )?;
         Ok(())
     }
 
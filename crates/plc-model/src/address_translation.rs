@@ -0,0 +1,268 @@
+// 벤더 간 주소 변환 — 한 프로파일 기준으로 작성된 주소를 다른 프로파일 표기로 옮긴다.
+//
+// 먼저 사용자 매핑 표(`AddressMappingTable`)의 패밀리/범위 규칙을 보고, 맞는 규칙이
+// 없으면 canonical 주소를 거친다(원본 파싱 → canonical → 대상 우선 별칭). 대상
+// 프로파일에 별칭이 없거나 범위를 벗어나는 피연산자는 오류 사유와 함께 돌려주고,
+// 호출자(래더/태그 목록 변환)가 `UnmappableOperand` 로 보고서에 모은다.
+
+use modone_contract::CanonicalAddress;
+use serde::{Deserialize, Serialize};
+
+use crate::profile::{VendorAddress, VendorProfile};
+
+/// One user-editable row of a mapping table: `count` addresses of
+/// `source_family` starting at `source_start` go to `target_family` from
+/// `target_start`. Indexes are in each vendor's own notation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressMappingRule {
+    pub source_family: String,
+    pub target_family: String,
+    #[serde(default)]
+    pub source_start: u32,
+    #[serde(default)]
+    pub target_start: u32,
+    /// Unbounded when omitted.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub count: Option<u32>,
+}
+
+impl AddressMappingRule {
+    pub fn family(source_family: &str, target_family: &str) -> Self {
+        Self {
+            source_family: source_family.to_string(),
+            target_family: target_family.to_string(),
+            source_start: 0,
+            target_start: 0,
+            count: None,
+        }
+    }
+
+    fn apply(&self, address: &VendorAddress) -> Option<VendorAddress> {
+        if !self.source_family.eq_ignore_ascii_case(&address.family)
+            || address.index < self.source_start
+        {
+            return None;
+        }
+        let offset = address.index - self.source_start;
+        if self.count.is_some_and(|count| offset >= count) {
+            return None;
+        }
+        Some(VendorAddress {
+            family: self.target_family.to_ascii_uppercase(),
+            index: self.target_start.checked_add(offset)?,
+            ..address.clone()
+        })
+    }
+}
+
+/// Rules tried in order before the canonical fallback.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AddressMappingTable {
+    #[serde(default)]
+    pub rules: Vec<AddressMappingRule>,
+}
+
+/// Operand that could not be rewritten for the target profile.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnmappableOperand {
+    /// Where the operand was found (network/field, tag id, ...).
+    pub location: String,
+    pub operand: String,
+    pub reason: String,
+}
+
+/// Outcome of translating a program or tag list.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TranslationReport {
+    /// Operands rewritten for the target profile.
+    pub translated: usize,
+    /// Operands left as they were; the translated output still contains them.
+    pub unmappable: Vec<UnmappableOperand>,
+}
+
+impl TranslationReport {
+    pub fn is_complete(&self) -> bool {
+        self.unmappable.is_empty()
+    }
+
+    /// Translate `operand` and count the result; unmappable operands are
+    /// recorded and returned unchanged.
+    pub fn translate(
+        &mut self,
+        translator: &AddressTranslator<'_>,
+        location: impl Into<String>,
+        operand: &str,
+    ) -> String {
+        match translator.translate(operand) {
+            Ok(translated) => {
+                self.translated += 1;
+                translated
+            }
+            Err(reason) => {
+                self.unmappable.push(UnmappableOperand {
+                    location: location.into(),
+                    operand: operand.to_string(),
+                    reason,
+                });
+                operand.to_string()
+            }
+        }
+    }
+}
+
+/// Rewrites addresses written for `source` into `target` notation.
+pub struct AddressTranslator<'a> {
+    source: &'a dyn VendorProfile,
+    target: &'a dyn VendorProfile,
+    table: &'a AddressMappingTable,
+}
+
+impl<'a> AddressTranslator<'a> {
+    pub fn new(
+        source: &'a dyn VendorProfile,
+        target: &'a dyn VendorProfile,
+        table: &'a AddressMappingTable,
+    ) -> Self {
+        Self {
+            source,
+            target,
+            table,
+        }
+    }
+
+    /// Target notation of a source address.
+    pub fn translate(&self, operand: &str) -> Result<String, String> {
+        let address = self
+            .source
+            .parse_address(operand)
+            .map_err(|e| format!("not a {} address: {e}", self.source.display_name()))?;
+        let target = self.map(&address)?;
+        self.target
+            .validate_address(&target)
+            .map_err(|e| e.to_string())?;
+        self.target
+            .format_address(&target)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Target canonical address of a source canonical address, for tag lists
+    /// that store canonical addresses rather than vendor text.
+    pub fn translate_canonical(
+        &self,
+        canonical: &CanonicalAddress,
+    ) -> Result<CanonicalAddress, String> {
+        let Some(alias) = self.source.preferred_alias(canonical) else {
+            // 원본 표기가 없는 주소는 canonical 그대로 대상에 있어야 한다
+            return match self.target.preferred_alias(canonical) {
+                Some(_) => Ok(*canonical),
+                None => Err(self.no_alias(canonical)),
+            };
+        };
+        let target = self.map(&alias)?;
+        self.target.to_canonical(&target).map_err(|e| e.to_string())
+    }
+
+    fn map(&self, address: &VendorAddress) -> Result<VendorAddress, String> {
+        if let Some(mapped) = self.table.rules.iter().find_map(|rule| rule.apply(address)) {
+            return Ok(mapped);
+        }
+        let canonical = self
+            .source
+            .to_canonical(address)
+            .map_err(|e| e.to_string())?;
+        self.target
+            .preferred_alias(&canonical)
+            .ok_or_else(|| self.no_alias(&canonical))
+    }
+
+    fn no_alias(&self, canonical: &CanonicalAddress) -> String {
+        format!(
+            "{} has no address for {:?} {}; add a mapping rule",
+            self.target.display_name(),
+            canonical.area,
+            canonical.index
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::PlcHardwareTopology;
+    use crate::profiles::{LsProfile, MelsecFxQProfile};
+    use modone_contract::CanonicalAreaKind;
+
+    fn ls() -> LsProfile {
+        LsProfile::new("XGK".to_string(), PlcHardwareTopology::default())
+    }
+
+    fn melsec(model: &str) -> MelsecFxQProfile {
+        MelsecFxQProfile::new(model.to_string(), PlcHardwareTopology::default())
+    }
+
+    #[test]
+    fn translates_through_canonical_addresses() {
+        let (source, target) = (ls(), melsec("FX5U"));
+        let table = AddressMappingTable::default();
+        let translator = AddressTranslator::new(&source, &target, &table);
+
+        assert_eq!(translator.translate("M0010").unwrap(), "M10");
+        assert_eq!(translator.translate("D0100.3").unwrap(), "D100.3");
+        // 캐노니컬 출력 20 → FX 8진 Y24
+        assert_eq!(translator.translate("P00020").unwrap(), "Y24");
+        assert!(translator
+            .translate("TD10")
+            .unwrap_err()
+            .contains("mapping rule"));
+        assert!(translator.translate("Q12").is_err());
+
+        // 역방향도 같은 경로
+        let back = AddressTranslator::new(&target, &source, &table);
+        assert_eq!(back.translate("M16").unwrap(), "M0016");
+    }
+
+    #[test]
+    fn mapping_rules_take_precedence() {
+        let (source, target) = (ls(), melsec("Q06UDV"));
+        let table = AddressMappingTable {
+            rules: vec![
+                AddressMappingRule {
+                    source_family: "P".to_string(),
+                    target_family: "X".to_string(),
+                    source_start: 0,
+                    target_start: 0x100,
+                    count: Some(16),
+                },
+                AddressMappingRule::family("TD", "D"),
+            ],
+        };
+        let translator = AddressTranslator::new(&source, &target, &table);
+
+        assert_eq!(translator.translate("P0015").unwrap(), "X10F");
+        // 범위 밖은 canonical 경로(출력 Y, 16진)
+        assert_eq!(translator.translate("P0016").unwrap(), "Y10");
+        assert_eq!(translator.translate("TD10").unwrap(), "D10");
+
+        let canonical = CanonicalAddress::new(CanonicalAreaKind::TimerValueWord, 7);
+        assert_eq!(
+            translator.translate_canonical(&canonical).unwrap(),
+            CanonicalAddress::new(CanonicalAreaKind::DataWord, 7)
+        );
+    }
+
+    #[test]
+    fn report_collects_unmappable_operands() {
+        let (source, target) = (ls(), melsec("FX3U"));
+        let table = AddressMappingTable::default();
+        let translator = AddressTranslator::new(&source, &target, &table);
+
+        let mut report = TranslationReport::default();
+        assert_eq!(report.translate(&translator, "network 0", "M0001"), "M1");
+        // FX3U 의 D 는 8000 점이라 범위를 벗어난다
+        assert_eq!(report.translate(&translator, "network 1", "D9000"), "D9000");
+        assert_eq!(report.translated, 1);
+        assert_eq!(report.unmappable.len(), 1);
+        assert_eq!(report.unmappable[0].location, "network 1");
+        assert!(!report.is_complete());
+    }
+}
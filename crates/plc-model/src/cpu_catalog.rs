@@ -0,0 +1,499 @@
+// CPU 모델 카탈로그 — 벤더 프로파일별로 지원 명령 계열, 최대 프로그램 스텝,
+// 기본 스캔/워치독 값과 내장 포트를 표로 선언한다.
+//
+// 모델은 memory_layout.rs 와 같은 시리즈 접두어로 찾는다(대소문자 무시). 디바이스
+// 범위는 같은 모델의 내장 메모리 레이아웃을 그대로 쓴다. 프로젝트의 `PlcSettings.model`
+// 검증, 새 프로젝트의 기본값, 래더 프로그램의 미지원 명령 경고가 이 표를 공유한다.
+
+use serde::Serialize;
+
+use crate::hardware::{PlcManufacturer, PlcSettings};
+use crate::memory_layout::{builtin_memory_layout, PlcMemoryLayout};
+use crate::profile::{VendorProfileError, VendorProfileId};
+
+/// Instruction family a CPU model may or may not execute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlcInstructionFamily {
+    /// NO/NC contacts
+    Contact,
+    /// Rising/falling edge contacts
+    EdgeContact,
+    /// Output coils
+    Coil,
+    /// Set/reset coils
+    SetReset,
+    /// On/off-delay timers on timer devices
+    Timer,
+    /// Retentive (accumulating) timers
+    RetentiveTimer,
+    /// Up or down counters on counter devices
+    Counter,
+    /// Up/down (reversible) counters
+    UpDownCounter,
+    Compare,
+    /// Integer add/sub/mul/div/mod
+    Arithmetic,
+    Move,
+    FloatingPoint,
+    HighSpeedCounter,
+    Positioning,
+    Pid,
+}
+
+impl PlcInstructionFamily {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Contact => "contact",
+            Self::EdgeContact => "edge contact",
+            Self::Coil => "coil",
+            Self::SetReset => "set/reset",
+            Self::Timer => "timer",
+            Self::RetentiveTimer => "retentive timer",
+            Self::Counter => "counter",
+            Self::UpDownCounter => "up/down counter",
+            Self::Compare => "compare",
+            Self::Arithmetic => "arithmetic",
+            Self::Move => "move",
+            Self::FloatingPoint => "floating point",
+            Self::HighSpeedCounter => "high-speed counter",
+            Self::Positioning => "positioning",
+            Self::Pid => "PID",
+        }
+    }
+}
+
+/// Communication port built into the CPU module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlcPortKind {
+    Usb,
+    Ethernet,
+    Rs232c,
+    Rs422,
+    Rs485,
+    EtherCat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PlcBuiltinPort {
+    pub kind: PlcPortKind,
+    /// Protocols the port speaks out of the box.
+    pub protocols: &'static [&'static str],
+}
+
+/// Catalogue entry for one CPU series.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PlcCpuModel {
+    pub profile_id: VendorProfileId,
+    /// Representative model name shown in pickers.
+    pub name: &'static str,
+    /// Case-insensitive model name prefixes; more specific entries come first.
+    pub model_prefixes: &'static [&'static str],
+    pub instruction_families: &'static [PlcInstructionFamily],
+    /// Program capacity in steps; `None` for CPUs sized by memory, not steps.
+    pub max_program_steps: Option<u32>,
+    pub default_scan_time_ms: u32,
+    pub default_watchdog_ms: u32,
+    pub builtin_ports: &'static [PlcBuiltinPort],
+}
+
+impl PlcCpuModel {
+    pub fn matches(&self, model: &str) -> bool {
+        let model = model.trim().to_ascii_uppercase();
+        self.model_prefixes
+            .iter()
+            .any(|prefix| model.starts_with(prefix))
+    }
+
+    pub fn supports(&self, family: PlcInstructionFamily) -> bool {
+        self.instruction_families.contains(&family)
+    }
+
+    /// Device ranges of this series (the built-in memory layout).
+    pub fn memory_layout(&self) -> PlcMemoryLayout {
+        builtin_memory_layout(self.profile_id, self.model_prefixes[0])
+    }
+
+    pub fn info(&'static self) -> PlcCpuModelInfo {
+        PlcCpuModelInfo {
+            model: self,
+            memory_layout: self.memory_layout(),
+        }
+    }
+}
+
+/// Catalogue entry together with its device ranges, for the UI.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlcCpuModelInfo {
+    #[serde(flatten)]
+    pub model: &'static PlcCpuModel,
+    pub memory_layout: PlcMemoryLayout,
+}
+
+const fn port(kind: PlcPortKind, protocols: &'static [&'static str]) -> PlcBuiltinPort {
+    PlcBuiltinPort { kind, protocols }
+}
+
+use PlcInstructionFamily::*;
+
+const BASIC_FLOAT: &[PlcInstructionFamily] = &[
+    Contact,
+    EdgeContact,
+    Coil,
+    SetReset,
+    Timer,
+    RetentiveTimer,
+    Counter,
+    UpDownCounter,
+    Compare,
+    Arithmetic,
+    Move,
+    FloatingPoint,
+    Pid,
+];
+
+// 내장 고속 카운터·펄스 출력이 있는 소형 CPU
+const COMPACT_MOTION: &[PlcInstructionFamily] = &[
+    Contact,
+    EdgeContact,
+    Coil,
+    SetReset,
+    Timer,
+    RetentiveTimer,
+    Counter,
+    UpDownCounter,
+    Compare,
+    Arithmetic,
+    Move,
+    FloatingPoint,
+    HighSpeedCounter,
+    Positioning,
+    Pid,
+];
+
+const LOADER_PORTS: &[PlcBuiltinPort] = &[
+    port(PlcPortKind::Usb, &["loader"]),
+    port(PlcPortKind::Rs232c, &["loader"]),
+];
+
+const BUILTIN_CPU_MODELS: &[PlcCpuModel] = &[
+    PlcCpuModel {
+        profile_id: VendorProfileId::LsXg5000,
+        name: "XGK-CPUUN",
+        model_prefixes: &["XGK"],
+        instruction_families: BASIC_FLOAT,
+        max_program_steps: Some(128 * 1024),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 200,
+        builtin_ports: LOADER_PORTS,
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::LsXg5000,
+        name: "XGI-CPUUN",
+        model_prefixes: &["XGI"],
+        instruction_families: BASIC_FLOAT,
+        max_program_steps: Some(128 * 1024),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 200,
+        builtin_ports: LOADER_PORTS,
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::LsXg5000,
+        name: "XGR-CPUH",
+        model_prefixes: &["XGR"],
+        instruction_families: BASIC_FLOAT,
+        max_program_steps: Some(128 * 1024),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 200,
+        builtin_ports: &[port(PlcPortKind::Usb, &["loader"])],
+    },
+    // XGB 고성능형: 내장 고속 카운터·위치결정
+    PlcCpuModel {
+        profile_id: VendorProfileId::LsXg5000,
+        name: "XBC-DN32H",
+        model_prefixes: &["XBC"],
+        instruction_families: COMPACT_MOTION,
+        max_program_steps: Some(15 * 1024),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 200,
+        builtin_ports: &[
+            port(PlcPortKind::Rs232c, &["loader", "cnet"]),
+            port(PlcPortKind::Rs485, &["cnet", "modbus-rtu"]),
+        ],
+    },
+    // XGB 경제형: 실수 연산·위치결정 없음
+    PlcCpuModel {
+        profile_id: VendorProfileId::LsXg5000,
+        name: "XBM-DN32S",
+        model_prefixes: &["XBM"],
+        instruction_families: &[
+            Contact,
+            EdgeContact,
+            Coil,
+            SetReset,
+            Timer,
+            RetentiveTimer,
+            Counter,
+            UpDownCounter,
+            Compare,
+            Arithmetic,
+            Move,
+            HighSpeedCounter,
+        ],
+        max_program_steps: Some(4 * 1024),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 200,
+        builtin_ports: &[
+            port(PlcPortKind::Rs232c, &["loader", "cnet"]),
+            port(PlcPortKind::Rs485, &["cnet", "modbus-rtu"]),
+        ],
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::MelsecFxQCommon,
+        name: "FX3U-32M",
+        model_prefixes: &["FX3U"],
+        instruction_families: COMPACT_MOTION,
+        max_program_steps: Some(64_000),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 200,
+        builtin_ports: &[port(PlcPortKind::Rs422, &["loader"])],
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::MelsecFxQCommon,
+        name: "FX5U-32M",
+        model_prefixes: &["FX5U"],
+        instruction_families: COMPACT_MOTION,
+        max_program_steps: Some(64_000),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 200,
+        builtin_ports: &[
+            port(PlcPortKind::Ethernet, &["slmp", "modbus-tcp"]),
+            port(PlcPortKind::Rs485, &["mc", "modbus-rtu"]),
+        ],
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::MelsecFxQCommon,
+        name: "Q06UDVCPU",
+        model_prefixes: &["Q"],
+        instruction_families: BASIC_FLOAT,
+        max_program_steps: Some(260_000),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 200,
+        builtin_ports: &[
+            port(PlcPortKind::Usb, &["loader"]),
+            port(PlcPortKind::Ethernet, &["slmp", "mc"]),
+        ],
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::MelsecFxQCommon,
+        name: "R08CPU",
+        model_prefixes: &["R"],
+        instruction_families: BASIC_FLOAT,
+        max_program_steps: Some(1_200_000),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 200,
+        builtin_ports: &[
+            port(PlcPortKind::Usb, &["loader"]),
+            port(PlcPortKind::Ethernet, &["slmp", "mc"]),
+        ],
+    },
+    // S7-1200 은 S5 타이머/카운터 디바이스가 없다(IEC 인스턴스만)
+    PlcCpuModel {
+        profile_id: VendorProfileId::SiemensS7,
+        name: "CPU 1214C",
+        model_prefixes: &["S7-12", "CPU 12"],
+        instruction_families: &[
+            Contact,
+            EdgeContact,
+            Coil,
+            SetReset,
+            Compare,
+            Arithmetic,
+            Move,
+            FloatingPoint,
+            HighSpeedCounter,
+            Positioning,
+            Pid,
+        ],
+        max_program_steps: None,
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 150,
+        builtin_ports: &[port(PlcPortKind::Ethernet, &["profinet", "s7comm"])],
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::SiemensS7,
+        name: "CPU 1516-3 PN/DP",
+        model_prefixes: &["S7-15", "CPU 15"],
+        instruction_families: BASIC_FLOAT,
+        max_program_steps: None,
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 150,
+        builtin_ports: &[port(
+            PlcPortKind::Ethernet,
+            &["profinet", "s7comm", "opc-ua"],
+        )],
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::OmronSysmac,
+        name: "CP1H-X40DT-D",
+        model_prefixes: &["CP"],
+        instruction_families: COMPACT_MOTION,
+        max_program_steps: Some(20_000),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 1000,
+        builtin_ports: &[port(PlcPortKind::Usb, &["loader"])],
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::OmronSysmac,
+        name: "CJ2M-CPU33",
+        model_prefixes: &["CJ"],
+        instruction_families: BASIC_FLOAT,
+        max_program_steps: Some(60_000),
+        default_scan_time_ms: 10,
+        default_watchdog_ms: 1000,
+        builtin_ports: &[
+            port(PlcPortKind::Usb, &["loader"]),
+            port(PlcPortKind::Ethernet, &["fins", "ethernet-ip"]),
+        ],
+    },
+    PlcCpuModel {
+        profile_id: VendorProfileId::OmronSysmac,
+        name: "NJ501-1300",
+        model_prefixes: &["NJ"],
+        instruction_families: &[
+            Contact,
+            EdgeContact,
+            Coil,
+            SetReset,
+            Timer,
+            RetentiveTimer,
+            Counter,
+            UpDownCounter,
+            Compare,
+            Arithmetic,
+            Move,
+            FloatingPoint,
+            Positioning,
+            Pid,
+        ],
+        max_program_steps: None,
+        default_scan_time_ms: 1,
+        default_watchdog_ms: 1000,
+        builtin_ports: &[
+            port(PlcPortKind::Usb, &["loader"]),
+            port(PlcPortKind::Ethernet, &["ethernet-ip", "fins"]),
+            port(PlcPortKind::EtherCat, &["ethercat"]),
+        ],
+    },
+];
+
+/// CPU series catalogued for `profile_id`.
+pub fn cpu_models(profile_id: VendorProfileId) -> impl Iterator<Item = &'static PlcCpuModel> {
+    BUILTIN_CPU_MODELS
+        .iter()
+        .filter(move |model| model.profile_id == profile_id)
+}
+
+/// Catalogue entry whose prefix matches `model`.
+pub fn find_cpu_model(profile_id: VendorProfileId, model: &str) -> Option<&'static PlcCpuModel> {
+    cpu_models(profile_id).find(|entry| entry.matches(model))
+}
+
+/// Catalogue entry of the project's CPU. An empty model means "unspecified"
+/// and resolves to `None`; any other unknown model is rejected.
+pub fn resolve_cpu_model(
+    settings: &PlcSettings,
+) -> Result<Option<&'static PlcCpuModel>, VendorProfileError> {
+    if settings.model.trim().is_empty() {
+        return Ok(None);
+    }
+    let profile_id = VendorProfileId::for_manufacturer(settings.manufacturer);
+    find_cpu_model(profile_id, &settings.model)
+        .map(Some)
+        .ok_or_else(|| VendorProfileError::UnsupportedModel {
+            manufacturer: settings.manufacturer.to_string(),
+            model: settings.model.clone(),
+        })
+}
+
+impl PlcSettings {
+    /// Settings for a new project, with the scan time taken from the CPU
+    /// catalogue when the model is known.
+    pub fn for_model(manufacturer: PlcManufacturer, model: impl Into<String>) -> Self {
+        let model = model.into();
+        let profile_id = VendorProfileId::for_manufacturer(manufacturer);
+        let scan_time_ms = find_cpu_model(profile_id, &model)
+            .map(|entry| entry.default_scan_time_ms)
+            .unwrap_or(PlcSettings::default().scan_time_ms);
+        Self {
+            manufacturer,
+            model,
+            scan_time_ms,
+            ..PlcSettings::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use modone_contract::CanonicalAreaKind;
+
+    #[test]
+    fn finds_models_by_series_prefix() {
+        let fx = find_cpu_model(VendorProfileId::MelsecFxQCommon, "fx5u-32mt/es").unwrap();
+        assert_eq!(fx.name, "FX5U-32M");
+        assert!(fx.supports(PlcInstructionFamily::Positioning));
+        assert_eq!(
+            fx.memory_layout().size(CanonicalAreaKind::DataWord),
+            8000 + 2048 + crate::profiles::MELSEC_BUFFER_SECTION
+        );
+
+        let s7 = find_cpu_model(VendorProfileId::SiemensS7, "CPU 1212C").unwrap();
+        assert!(!s7.supports(PlcInstructionFamily::Timer));
+        assert!(find_cpu_model(VendorProfileId::SiemensS7, "XGK-CPUU").is_none());
+
+        // 카탈로그의 모든 항목은 메모리 레이아웃 표에도 있어야 한다
+        for entry in BUILTIN_CPU_MODELS {
+            assert_ne!(
+                entry.memory_layout(),
+                PlcMemoryLayout::default(),
+                "{}",
+                entry.name
+            );
+        }
+    }
+
+    #[test]
+    fn validates_project_model_and_picks_defaults() {
+        let settings = |manufacturer, model: &str| PlcSettings {
+            manufacturer,
+            model: model.to_string(),
+            ..PlcSettings::default()
+        };
+        assert_eq!(
+            resolve_cpu_model(&settings(PlcManufacturer::LS, "")),
+            Ok(None)
+        );
+        assert_eq!(
+            resolve_cpu_model(&settings(PlcManufacturer::LS, "XGK-CPUH"))
+                .unwrap()
+                .map(|entry| entry.name),
+            Some("XGK-CPUUN")
+        );
+        assert!(matches!(
+            resolve_cpu_model(&settings(PlcManufacturer::LS, "FX3U")),
+            Err(VendorProfileError::UnsupportedModel { .. })
+        ));
+
+        assert_eq!(
+            PlcSettings::for_model(PlcManufacturer::Omron, "NJ501").scan_time_ms,
+            1
+        );
+        assert_eq!(
+            PlcSettings::for_model(PlcManufacturer::Omron, "").scan_time_ms,
+            PlcSettings::default().scan_time_ms
+        );
+    }
+}
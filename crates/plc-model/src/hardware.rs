@@ -1,13 +1,18 @@
 // 모든 벤더 프로파일이 공유하는 PLC 하드웨어 모델 타입 (rack/module/주소창)
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
+use modone_contract::CanonicalAreaKind;
+
 /// PLC manufacturer enumeration
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PlcManufacturer {
     LS,
     Mitsubishi,
     Siemens,
+    Omron,
 }
 
 impl Default for PlcManufacturer {
@@ -22,6 +27,7 @@ impl std::fmt::Display for PlcManufacturer {
             PlcManufacturer::LS => write!(f, "LS"),
             PlcManufacturer::Mitsubishi => write!(f, "Mitsubishi"),
             PlcManufacturer::Siemens => write!(f, "Siemens"),
+            PlcManufacturer::Omron => write!(f, "Omron"),
         }
     }
 }
@@ -34,6 +40,7 @@ impl std::str::FromStr for PlcManufacturer {
             "ls" => Ok(PlcManufacturer::LS),
             "mitsubishi" => Ok(PlcManufacturer::Mitsubishi),
             "siemens" => Ok(PlcManufacturer::Siemens),
+            "omron" => Ok(PlcManufacturer::Omron),
             _ => Err(format!("Unknown PLC manufacturer: {}", s)),
         }
     }
@@ -54,6 +61,17 @@ pub struct PlcSettings {
     /// Hardware topology for rack/base/module oriented PLC families.
     #[serde(default)]
     pub hardware_topology: PlcHardwareTopology,
+
+    /// Per-area device size overrides on top of the model's built-in layout.
+    /// On MELSEC the size is the area total, so extended devices carved after
+    /// a base device (B after M, W after D) move when the area is resized.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub memory_sizes: BTreeMap<CanonicalAreaKind, u32>,
+
+    /// Vendor device section overrides (S7 `M`, `DB1` sizes in bytes; MELSEC
+    /// `B`/`W`/`SW`... points and `G` or `U3E0` buffer words per unit).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub memory_sections: BTreeMap<String, u32>,
 }
 
 impl Default for PlcSettings {
@@ -63,6 +81,8 @@ impl Default for PlcSettings {
             model: String::new(),
             scan_time_ms: 10,
             hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: BTreeMap::new(),
+            memory_sections: BTreeMap::new(),
         }
     }
 }
@@ -73,6 +93,24 @@ pub struct PlcHardwareTopology {
     /// Rack/base definitions in installation order.
     #[serde(default)]
     pub racks: Vec<PlcRackTopology>,
+    /// How module address windows are assigned.
+    #[serde(default)]
+    pub io_allocation: PlcIoAllocationMode,
+    /// I/O points taken by an empty slot under sequential allocation (default 16).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub empty_slot_points: Option<u16>,
+}
+
+/// I/O address assignment mode for the hardware topology.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlcIoAllocationMode {
+    /// Address windows are typed by hand.
+    #[default]
+    Manual,
+    /// Every slot owns a fixed 64-point block (LS fixed allocation, XGI/XGR).
+    FixedSlot,
+    /// Slots are numbered consecutively by occupied points (LS dynamic, MELSEC).
+    Sequential,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -129,6 +167,8 @@ pub enum PlcModuleKind {
     AnalogIo,
     Communication,
     Special,
+    /// Free/reserved slot; `point_count` is the number of I/O points kept for it.
+    Empty,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
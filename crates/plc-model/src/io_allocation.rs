@@ -0,0 +1,445 @@
+// 하드웨어 topology(rack/slot/모듈)로부터 I/O 주소창을 자동 할당한다.
+//
+// 벤더별 규칙은 각 프로파일의 `VendorProfile::allocate_io` 가 맡고, 여기에는 공통
+// 진입점(`allocate_io_windows`)과 주소창 겹침 검사, 프로파일들이 공유하는 슬롯
+// 순회/점수 계산 도우미만 둔다. 원격 베이스는 네트워크 파라미터로 할당하므로 자동
+// 할당에서 제외하고 손으로 적은 주소창을 그대로 둔다.
+
+use serde::{Deserialize, Serialize};
+
+use crate::hardware::{
+    PlcAddressWindow, PlcHardwareModule, PlcHardwareTopology, PlcIoAllocationMode, PlcIoDirection,
+    PlcModuleKind, PlcRackKind, PlcSettings,
+};
+use crate::profile::{resolve_vendor_profile, VendorProfile, VendorProfileError};
+
+/// Points taken by an empty slot when the topology does not say otherwise.
+pub const DEFAULT_EMPTY_SLOT_POINTS: u16 = 16;
+/// I/O points an intelligent (analog/communication/special) module occupies.
+pub(crate) const INTELLIGENT_MODULE_POINTS: u32 = 16;
+
+/// One problem found while assigning or checking I/O address windows.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IoAllocationConflict {
+    /// Rack index, or `None` for topology-wide problems.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rack_index: Option<usize>,
+    /// Module index within the rack.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub module_index: Option<usize>,
+    pub message: String,
+}
+
+impl IoAllocationConflict {
+    pub(crate) fn topology(message: impl Into<String>) -> Self {
+        Self {
+            rack_index: None,
+            module_index: None,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn module(
+        rack_index: usize,
+        module_index: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            rack_index: Some(rack_index),
+            module_index: Some(module_index),
+            message: message.into(),
+        }
+    }
+}
+
+/// Topology with regenerated address windows plus the conflicts found.
+#[derive(Debug, Clone)]
+pub struct IoAllocation {
+    pub topology: PlcHardwareTopology,
+    pub conflicts: Vec<IoAllocationConflict>,
+}
+
+/// Regenerate the address windows of `settings.hardware_topology` with the
+/// vendor profile's allocation rules.
+///
+/// Manual topologies are returned unchanged; every mode is checked for
+/// overlapping windows.
+pub fn allocate_io_windows(settings: &PlcSettings) -> Result<IoAllocation, VendorProfileError> {
+    let profile = resolve_vendor_profile(settings)?;
+    let topology = &settings.hardware_topology;
+    let mut allocation = if topology.io_allocation == PlcIoAllocationMode::Manual {
+        IoAllocation {
+            topology: topology.clone(),
+            conflicts: Vec::new(),
+        }
+    } else {
+        profile.allocate_io(topology)
+    };
+
+    let overlaps = find_window_overlaps(&allocation.topology, profile.as_ref());
+    allocation.conflicts.extend(overlaps);
+    Ok(allocation)
+}
+
+/// Report windows in the same I/O number space whose ranges overlap.
+///
+/// One module may hold windows of different families at the same numbers
+/// (a MELSEC intelligent module's X/Y), so those are not compared.
+pub fn find_window_overlaps(
+    topology: &PlcHardwareTopology,
+    profile: &dyn VendorProfile,
+) -> Vec<IoAllocationConflict> {
+    let mut windows: Vec<(String, u32, u32, usize, usize, String)> = Vec::new();
+    for (rack_index, rack) in topology.racks.iter().enumerate() {
+        for (module_index, module) in rack.modules.iter().enumerate() {
+            for window in &module.address_windows {
+                let family = window.family.trim().to_ascii_uppercase();
+                windows.push((
+                    profile.io_number_space(&family),
+                    window.start,
+                    window.start.saturating_add(window.count),
+                    rack_index,
+                    module_index,
+                    family,
+                ));
+            }
+        }
+    }
+    windows.sort();
+
+    // 시작 번호 순으로 정렬했으므로 뒤 창이 앞 창의 끝보다 먼저 시작하면 겹친다.
+    // 이웃만 보면 긴 창 안에 든 뒤쪽 창을 놓치므로 모든 뒤 창과 비교한다.
+    let mut conflicts = Vec::new();
+    for (index, (space, _, end, rack_a, module_a, family)) in windows.iter().enumerate() {
+        for (next_space, next_start, _, rack_b, module_b, next_family) in &windows[index + 1..] {
+            if next_space != space || next_start >= end {
+                break;
+            }
+            if (rack_a, module_a) == (rack_b, module_b) && family != next_family {
+                continue;
+            }
+            conflicts.push(IoAllocationConflict::module(
+                *rack_b,
+                *module_b,
+                format!(
+                    "{next_family} window starting at {next_start} overlaps {family} window of rack {rack_a} module {module_a}"
+                ),
+            ));
+        }
+    }
+    conflicts
+}
+
+/// Conflict for profiles that have no automatic allocation rules.
+pub(crate) fn unsupported_allocation(
+    display_name: &str,
+    topology: &PlcHardwareTopology,
+) -> IoAllocation {
+    IoAllocation {
+        topology: topology.clone(),
+        conflicts: vec![IoAllocationConflict::topology(format!(
+            "{display_name} has no automatic I/O allocation; use manual address windows"
+        ))],
+    }
+}
+
+/// Whether the rack takes part in automatic allocation.
+pub(crate) fn is_allocated_rack(kind: PlcRackKind) -> bool {
+    kind != PlcRackKind::RemoteBase
+}
+
+/// Module indices of a rack in slot order.
+pub(crate) fn slot_order(modules: &[PlcHardwareModule]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..modules.len()).collect();
+    order.sort_by_key(|&index| modules[index].slot);
+    order
+}
+
+/// I/O points a module occupies before vendor rounding; `None` for power and
+/// CPU modules, which sit outside the I/O slot numbering.
+pub(crate) fn occupied_points(
+    module: &PlcHardwareModule,
+    empty_slot_points: u32,
+) -> Result<Option<u32>, String> {
+    match module.module_kind {
+        PlcModuleKind::Power | PlcModuleKind::Cpu => Ok(None),
+        PlcModuleKind::Empty => Ok(Some(
+            module.point_count.map_or(empty_slot_points, u32::from),
+        )),
+        PlcModuleKind::DigitalInput | PlcModuleKind::DigitalOutput | PlcModuleKind::DigitalIo => {
+            match module.point_count {
+                Some(points) if points > 0 => Ok(Some(u32::from(points))),
+                _ => Err(format!(
+                    "Slot {} needs a point count for automatic I/O allocation",
+                    module.slot
+                )),
+            }
+        }
+        _ => Ok(Some(INTELLIGENT_MODULE_POINTS)),
+    }
+}
+
+pub(crate) fn is_digital(kind: PlcModuleKind) -> bool {
+    matches!(
+        kind,
+        PlcModuleKind::DigitalInput | PlcModuleKind::DigitalOutput | PlcModuleKind::DigitalIo
+    )
+}
+
+/// Add `window` to the module unless it runs past the device range `limit`.
+pub(crate) fn place_window(
+    module: &mut PlcHardwareModule,
+    window: PlcAddressWindow,
+    limit: u32,
+    (rack_index, module_index): (usize, usize),
+    conflicts: &mut Vec<IoAllocationConflict>,
+) {
+    let end = window.start.saturating_add(window.count);
+    if end > limit {
+        conflicts.push(IoAllocationConflict::module(
+            rack_index,
+            module_index,
+            format!(
+                "Slot {} needs {} points up to {end} but the {} range ends at {limit}",
+                module.slot, window.count, window.family
+            ),
+        ));
+    } else {
+        module.address_windows.push(window);
+    }
+}
+
+/// `points` rounded up to the vendor's allocation unit.
+pub(crate) fn round_up(points: u32, unit: u32) -> u32 {
+    points.div_ceil(unit) * unit
+}
+
+/// Input/output point split of a digital module (mixed modules: inputs first).
+pub(crate) fn digital_split(kind: PlcModuleKind, points: u32) -> (u32, u32) {
+    match kind {
+        PlcModuleKind::DigitalInput => (points, 0),
+        PlcModuleKind::DigitalOutput => (0, points),
+        _ => (points - points / 2, points / 2),
+    }
+}
+
+pub(crate) fn window(
+    family: &str,
+    start: u32,
+    count: u32,
+    io_direction: Option<PlcIoDirection>,
+) -> PlcAddressWindow {
+    PlcAddressWindow {
+        family: family.to_string(),
+        start,
+        count,
+        io_direction,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::{PlcManufacturer, PlcRackTopology};
+
+    fn module(
+        slot: u16,
+        module_kind: PlcModuleKind,
+        point_count: Option<u16>,
+    ) -> PlcHardwareModule {
+        PlcHardwareModule {
+            slot,
+            module_kind,
+            model: String::new(),
+            point_count,
+            address_windows: Vec::new(),
+        }
+    }
+
+    fn settings(
+        manufacturer: PlcManufacturer,
+        model: &str,
+        io_allocation: PlcIoAllocationMode,
+        modules: Vec<PlcHardwareModule>,
+    ) -> PlcSettings {
+        PlcSettings {
+            manufacturer,
+            model: model.to_string(),
+            hardware_topology: PlcHardwareTopology {
+                racks: vec![PlcRackTopology {
+                    rack_id: "main".to_string(),
+                    rack_kind: PlcRackKind::MainBase,
+                    modules,
+                }],
+                io_allocation,
+                empty_slot_points: None,
+            },
+            ..PlcSettings::default()
+        }
+    }
+
+    fn windows(allocation: &IoAllocation, module_index: usize) -> Vec<(String, u32, u32)> {
+        allocation.topology.racks[0].modules[module_index]
+            .address_windows
+            .iter()
+            .map(|window| (window.family.clone(), window.start, window.count))
+            .collect()
+    }
+
+    #[test]
+    fn allocates_ls_fixed_and_dynamic_slots() {
+        let modules = vec![
+            module(0, PlcModuleKind::Cpu, None),
+            module(1, PlcModuleKind::DigitalInput, Some(32)),
+            module(3, PlcModuleKind::DigitalIo, Some(32)),
+        ];
+
+        let fixed = allocate_io_windows(&settings(
+            PlcManufacturer::LS,
+            "XGK-CPUH",
+            PlcIoAllocationMode::FixedSlot,
+            modules.clone(),
+        ))
+        .unwrap();
+        assert!(fixed.conflicts.is_empty());
+        assert!(windows(&fixed, 0).is_empty());
+        assert_eq!(windows(&fixed, 1), vec![("P".to_string(), 64, 32)]);
+        assert_eq!(
+            windows(&fixed, 2),
+            vec![("P".to_string(), 192, 16), ("P".to_string(), 208, 16)]
+        );
+        assert_eq!(
+            fixed.topology.racks[0].modules[2].address_windows[1].io_direction,
+            Some(PlcIoDirection::Output)
+        );
+
+        // 가변 할당: 빈 슬롯 2 는 16점
+        let dynamic = allocate_io_windows(&settings(
+            PlcManufacturer::LS,
+            "XGK-CPUH",
+            PlcIoAllocationMode::Sequential,
+            modules,
+        ))
+        .unwrap();
+        assert!(dynamic.conflicts.is_empty());
+        assert_eq!(windows(&dynamic, 1), vec![("P".to_string(), 0, 32)]);
+        assert_eq!(windows(&dynamic, 2)[0], ("P".to_string(), 48, 16));
+
+        let xgi = allocate_io_windows(&settings(
+            PlcManufacturer::LS,
+            "XGI-CPUUN",
+            PlcIoAllocationMode::Sequential,
+            vec![module(2, PlcModuleKind::DigitalOutput, Some(16))],
+        ))
+        .unwrap();
+        assert_eq!(windows(&xgi, 0), vec![("%QX".to_string(), 128, 16)]);
+
+        let too_wide = allocate_io_windows(&settings(
+            PlcManufacturer::LS,
+            "XGK-CPUH",
+            PlcIoAllocationMode::FixedSlot,
+            vec![module(0, PlcModuleKind::DigitalInput, Some(96))],
+        ))
+        .unwrap();
+        assert_eq!(too_wide.conflicts.len(), 1);
+    }
+
+    #[test]
+    fn allocates_melsec_hex_numbers_with_free_and_reserved_slots() {
+        let allocation = allocate_io_windows(&settings(
+            PlcManufacturer::Mitsubishi,
+            "Q03UDV",
+            PlcIoAllocationMode::Sequential,
+            vec![
+                module(0, PlcModuleKind::DigitalInput, Some(32)),
+                // 슬롯 1 은 비어 있어 16점
+                module(2, PlcModuleKind::DigitalOutput, Some(16)),
+                module(3, PlcModuleKind::Empty, Some(32)),
+                module(4, PlcModuleKind::AnalogInput, Some(4)),
+                module(5, PlcModuleKind::DigitalIo, Some(32)),
+            ],
+        ))
+        .unwrap();
+
+        assert!(allocation.conflicts.is_empty());
+        assert_eq!(windows(&allocation, 0), vec![("X".to_string(), 0x00, 32)]);
+        assert_eq!(windows(&allocation, 1), vec![("Y".to_string(), 0x30, 16)]);
+        assert!(windows(&allocation, 2).is_empty());
+        assert_eq!(
+            windows(&allocation, 3),
+            vec![("X".to_string(), 0x60, 16), ("Y".to_string(), 0x60, 16)]
+        );
+        assert_eq!(
+            windows(&allocation, 4),
+            vec![("X".to_string(), 0x70, 16), ("Y".to_string(), 0x80, 16)]
+        );
+
+        let fx = allocate_io_windows(&settings(
+            PlcManufacturer::Mitsubishi,
+            "FX5U-32MR",
+            PlcIoAllocationMode::Sequential,
+            vec![
+                module(0, PlcModuleKind::Cpu, Some(32)),
+                module(1, PlcModuleKind::DigitalInput, Some(8)),
+            ],
+        ))
+        .unwrap();
+        assert_eq!(
+            windows(&fx, 0),
+            vec![("X".to_string(), 0, 16), ("Y".to_string(), 0, 16)]
+        );
+        assert_eq!(windows(&fx, 1), vec![("X".to_string(), 16, 8)]);
+    }
+
+    #[test]
+    fn reports_overlapping_manual_windows_and_unsupported_profiles() {
+        let mut manual = settings(
+            PlcManufacturer::Mitsubishi,
+            "Q03UDV",
+            PlcIoAllocationMode::Manual,
+            vec![
+                module(0, PlcModuleKind::DigitalInput, Some(16)),
+                module(1, PlcModuleKind::DigitalInput, Some(16)),
+            ],
+        );
+        manual.hardware_topology.racks[0].modules[0].address_windows =
+            vec![window("X", 0, 32, Some(PlcIoDirection::Input))];
+        manual.hardware_topology.racks[0].modules[1].address_windows =
+            vec![window("x", 16, 16, Some(PlcIoDirection::Input))];
+
+        let allocation = allocate_io_windows(&manual).unwrap();
+        assert_eq!(allocation.conflicts.len(), 1);
+        assert_eq!(allocation.conflicts[0].module_index, Some(1));
+
+        // 긴 창 안에 든 창은 이웃이 아니어도 겹친다
+        manual.hardware_topology.racks[0].modules[0].address_windows =
+            vec![window("X", 0, 64, Some(PlcIoDirection::Input))];
+        manual.hardware_topology.racks[0].modules[1].address_windows = vec![
+            window("X", 16, 16, Some(PlcIoDirection::Input)),
+            window("X", 48, 16, Some(PlcIoDirection::Input)),
+        ];
+        let allocation = allocate_io_windows(&manual).unwrap();
+        assert_eq!(allocation.conflicts.len(), 2);
+
+        // Q 시리즈의 X 와 Y 는 한 I/O 번호 공간을 쓴다
+        manual.hardware_topology.racks[0].modules[0].address_windows =
+            vec![window("X", 0, 16, Some(PlcIoDirection::Input))];
+        manual.hardware_topology.racks[0].modules[1].address_windows =
+            vec![window("Y", 0, 16, Some(PlcIoDirection::Output))];
+        let allocation = allocate_io_windows(&manual).unwrap();
+        assert_eq!(allocation.conflicts.len(), 1);
+
+        // FX 는 X 와 Y 번호가 따로다
+        manual.model = "FX5U-32MR".to_string();
+        assert!(allocate_io_windows(&manual).unwrap().conflicts.is_empty());
+
+        let s7 = allocate_io_windows(&settings(
+            PlcManufacturer::Siemens,
+            "S7-1200",
+            PlcIoAllocationMode::Sequential,
+            vec![module(1, PlcModuleKind::DigitalInput, Some(16))],
+        ))
+        .unwrap();
+        assert_eq!(s7.conflicts[0].rack_index, None);
+    }
+}
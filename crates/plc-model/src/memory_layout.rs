@@ -0,0 +1,431 @@
+// CPU 모델별 디바이스 메모리 크기 — 벤더 프로파일이 선언하고, canonical 메모리
+// 할당과 컴파일 시 주소 범위 검사가 같은 표를 쓴다.
+//
+// 크기 단위는 canonical 영역 기준이다(비트 영역은 점수, 워드 영역은 워드 수).
+// 내장 카탈로그는 각 CPU 의 기본 디바이스 파라미터 설정값이며, 프로젝트의
+// `PlcSettings.memory_sizes` 로 영역별로 덮어쓸 수 있다(GX Works 디바이스 설정처럼).
+// 한 canonical 영역을 나눠 쓰는 벤더 디바이스(S7 의 M·DB 블록, MELSEC 의 B/W 등
+// 확장 구간과 U\G 버퍼)는 `sections` 에 디바이스 이름별 크기로 두고
+// `PlcSettings.memory_sections` 로 덮어쓴다.
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::hardware::PlcSettings;
+use crate::profile::VendorProfileId;
+use crate::profiles::{MELSEC_BUFFER_SECTION, MELSEC_BUFFER_WORDS};
+
+use modone_contract::CanonicalAreaKind;
+
+/// Per-area device allocation of one CPU model.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlcMemoryLayout {
+    /// Areas not listed fall back to [`CanonicalAreaKind::default_size`].
+    #[serde(default)]
+    pub sizes: BTreeMap<CanonicalAreaKind, u32>,
+    /// Sizes of vendor devices sharing one canonical area, keyed by device
+    /// name (S7 `M`, `DB1`) in the device's own unit.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sections: BTreeMap<String, u32>,
+}
+
+impl PlcMemoryLayout {
+    pub fn from_sizes(sizes: &[(CanonicalAreaKind, u32)]) -> Self {
+        Self {
+            sizes: sizes.iter().copied().collect(),
+            sections: BTreeMap::new(),
+        }
+    }
+
+    /// Add vendor device sections (see [`PlcMemoryLayout::sections`]).
+    pub fn with_sections(mut self, sections: &[(&str, u32)]) -> Self {
+        self.sections.extend(
+            sections
+                .iter()
+                .map(|(name, size)| (name.to_string(), *size)),
+        );
+        self
+    }
+
+    /// Size of the vendor device section `name`, if the layout declares it.
+    pub fn section(&self, name: &str) -> Option<u32> {
+        self.sections.get(name).copied()
+    }
+
+    /// Allocated size of `area`.
+    pub fn size(&self, area: CanonicalAreaKind) -> u32 {
+        self.sizes
+            .get(&area)
+            .copied()
+            .unwrap_or(area.default_size() as u32)
+    }
+
+    /// Highest valid index of `area`, used for compile-time range checks.
+    pub fn max_index(&self, area: CanonicalAreaKind) -> u32 {
+        self.size(area).saturating_sub(1)
+    }
+
+    /// Apply project overrides on top of the model defaults.
+    pub fn with_overrides(mut self, overrides: &BTreeMap<CanonicalAreaKind, u32>) -> Self {
+        self.sizes
+            .extend(overrides.iter().map(|(area, size)| (*area, *size)));
+        self
+    }
+
+    /// Apply project section overrides on top of the model defaults.
+    pub fn with_section_overrides(mut self, overrides: &BTreeMap<String, u32>) -> Self {
+        self.sections
+            .extend(overrides.iter().map(|(name, size)| (name.clone(), *size)));
+        self
+    }
+}
+
+struct ModelMemorySpec {
+    profile_id: VendorProfileId,
+    /// Case-insensitive model name prefix; more specific prefixes come first.
+    model_prefix: &'static str,
+    sizes: &'static [(CanonicalAreaKind, u32)],
+    sections: &'static [(&'static str, u32)],
+}
+
+const BUILTIN_MEMORY_LAYOUTS: &[ModelMemorySpec] = &[
+    // LS XGK-CPUU/H/S/A/E: D/R 32K, N 21504, Z 128, T/C 2048
+    ModelMemorySpec {
+        profile_id: VendorProfileId::LsXg5000,
+        model_prefix: "XGK",
+        sizes: &[
+            (CanonicalAreaKind::DataWord, 32768),
+            (CanonicalAreaKind::RetentiveWord, 32768),
+            (CanonicalAreaKind::SystemWord, 21504),
+            (CanonicalAreaKind::IndexWord, 128),
+            (CanonicalAreaKind::TimerDoneBit, 2048),
+            (CanonicalAreaKind::TimerValueWord, 2048),
+            (CanonicalAreaKind::CounterDoneBit, 2048),
+            (CanonicalAreaKind::CounterValueWord, 2048),
+        ],
+        sections: &[],
+    },
+    // LS XGI-CPUU/H/S, XGR: %I/%Q 8베이스 × 16슬롯 × 64점, %M 256KB
+    ModelMemorySpec {
+        profile_id: VendorProfileId::LsXg5000,
+        model_prefix: "XGI",
+        sizes: LS_IEC_SIZES,
+        sections: &[],
+    },
+    ModelMemorySpec {
+        profile_id: VendorProfileId::LsXg5000,
+        model_prefix: "XGR",
+        sizes: LS_IEC_SIZES,
+        sections: &[],
+    },
+    // LS XBC (XGB 표준/고성능형): D 10240, N 5120, Z 128, T/C 1024
+    ModelMemorySpec {
+        profile_id: VendorProfileId::LsXg5000,
+        model_prefix: "XBC",
+        sizes: &[
+            (CanonicalAreaKind::InternalBit, 4096),
+            (CanonicalAreaKind::DataWord, 10240),
+            (CanonicalAreaKind::SystemWord, 5120),
+            (CanonicalAreaKind::IndexWord, 128),
+            (CanonicalAreaKind::TimerDoneBit, 1024),
+            (CanonicalAreaKind::TimerValueWord, 1024),
+            (CanonicalAreaKind::CounterDoneBit, 1024),
+            (CanonicalAreaKind::CounterValueWord, 1024),
+        ],
+        sections: &[],
+    },
+    // LS XBM (XGB 경제형): D 5120, T/C 256
+    ModelMemorySpec {
+        profile_id: VendorProfileId::LsXg5000,
+        model_prefix: "XBM",
+        sizes: &[
+            (CanonicalAreaKind::InternalBit, 4096),
+            (CanonicalAreaKind::DataWord, 5120),
+            (CanonicalAreaKind::IndexWord, 128),
+            (CanonicalAreaKind::TimerDoneBit, 256),
+            (CanonicalAreaKind::TimerValueWord, 256),
+            (CanonicalAreaKind::CounterDoneBit, 256),
+            (CanonicalAreaKind::CounterValueWord, 256),
+        ],
+        sections: &[],
+    },
+    // MELSEC FX3U/FX3UC: X/Y 0-377(8진), M 7680, T 512, C 256, D 8000
+    ModelMemorySpec {
+        profile_id: VendorProfileId::MelsecFxQCommon,
+        model_prefix: "FX3U",
+        sizes: &[
+            (CanonicalAreaKind::InputBit, 256),
+            (CanonicalAreaKind::OutputBit, 256),
+            (CanonicalAreaKind::InternalBit, 7680),
+            (CanonicalAreaKind::TimerDoneBit, 512),
+            (CanonicalAreaKind::TimerValueWord, 512),
+            (CanonicalAreaKind::CounterDoneBit, 256),
+            (CanonicalAreaKind::CounterValueWord, 256),
+            (CanonicalAreaKind::DataWord, 8000),
+        ],
+        sections: &[],
+    },
+    // MELSEC iQ-F FX5U/FX5UC: X/Y 0-1777(8진), M/L 7680, T 512, C 256, D 8000,
+    // SM 10000, SD 12000, R 32768. 확장 구간(B/F/W/SW, LC, U\G)은 `sections` 에 두고
+    // 영역 크기는 구간을 포함한 합계다(profiles/melsec.rs 참조).
+    ModelMemorySpec {
+        profile_id: VendorProfileId::MelsecFxQCommon,
+        model_prefix: "FX5U",
+        sizes: &[
+            (CanonicalAreaKind::InputBit, 1024),
+            (CanonicalAreaKind::OutputBit, 1024),
+            (CanonicalAreaKind::InternalBit, 7680 + 2048),
+            (CanonicalAreaKind::RetentiveBit, 7680),
+            (CanonicalAreaKind::SpecialBit, 10000 + 2048),
+            (CanonicalAreaKind::TimerDoneBit, 512),
+            (CanonicalAreaKind::TimerValueWord, 512),
+            (CanonicalAreaKind::CounterDoneBit, 256 + 1024),
+            (CanonicalAreaKind::CounterValueWord, 256 + 1024),
+            (
+                CanonicalAreaKind::DataWord,
+                8000 + 2048 + MELSEC_BUFFER_SECTION,
+            ),
+            (CanonicalAreaKind::SystemWord, 12000 + 2048),
+            (CanonicalAreaKind::RetentiveWord, 32768),
+        ],
+        sections: &[
+            ("B", 2048),
+            ("F", 2048),
+            ("W", 2048),
+            ("SW", 2048),
+            ("LC", 1024),
+            ("G", MELSEC_BUFFER_WORDS),
+        ],
+    },
+    // MELSEC QnU: X/Y 0-1FFF(16진), M/L 8192, T 2048, C 1024, D 12288, SM/SD 2048,
+    // SB 2048, ZR 65536 + 확장 구간(B/W 8192, F/SW 2048, U\G)
+    ModelMemorySpec {
+        profile_id: VendorProfileId::MelsecFxQCommon,
+        model_prefix: "Q",
+        sizes: &[
+            (CanonicalAreaKind::InputBit, 8192),
+            (CanonicalAreaKind::OutputBit, 8192),
+            (CanonicalAreaKind::InternalBit, 8192 + 8192),
+            (CanonicalAreaKind::RetentiveBit, 8192),
+            (CanonicalAreaKind::SpecialBit, 2048 + 2048),
+            (CanonicalAreaKind::SystemBit, 2048),
+            (CanonicalAreaKind::TimerDoneBit, 2048),
+            (CanonicalAreaKind::TimerValueWord, 2048),
+            (CanonicalAreaKind::CounterDoneBit, 1024),
+            (CanonicalAreaKind::CounterValueWord, 1024),
+            (
+                CanonicalAreaKind::DataWord,
+                12288 + 8192 + MELSEC_BUFFER_SECTION,
+            ),
+            (CanonicalAreaKind::SystemWord, 2048 + 2048),
+            (CanonicalAreaKind::RetentiveWord, 65536),
+        ],
+        sections: MELSEC_Q_SECTIONS,
+    },
+    // MELSEC iQ-R: X/Y 0-FFF(16진), M/L 8192, T 1024, C 512, D 18432, SM/SD 4096,
+    // SB 2048, ZR 65536 + 확장 구간(B/W 8192, F/SW 2048, LT/LC 1024, U\G)
+    ModelMemorySpec {
+        profile_id: VendorProfileId::MelsecFxQCommon,
+        model_prefix: "R",
+        sizes: &[
+            (CanonicalAreaKind::InputBit, 4096),
+            (CanonicalAreaKind::OutputBit, 4096),
+            (CanonicalAreaKind::InternalBit, 8192 + 8192),
+            (CanonicalAreaKind::RetentiveBit, 8192),
+            (CanonicalAreaKind::SpecialBit, 4096 + 2048),
+            (CanonicalAreaKind::SystemBit, 2048),
+            (CanonicalAreaKind::TimerDoneBit, 1024 + 1024),
+            (CanonicalAreaKind::TimerValueWord, 1024 + 1024),
+            (CanonicalAreaKind::CounterDoneBit, 512 + 1024),
+            (CanonicalAreaKind::CounterValueWord, 512 + 1024),
+            (
+                CanonicalAreaKind::DataWord,
+                18432 + 8192 + MELSEC_BUFFER_SECTION,
+            ),
+            (CanonicalAreaKind::SystemWord, 4096 + 2048),
+            (CanonicalAreaKind::RetentiveWord, 65536),
+        ],
+        sections: MELSEC_IQR_SECTIONS,
+    },
+    // S7-1200: I/Q 1024 바이트, M 8192 바이트, 기본 DB1 16KB (S5 타이머/카운터 없음)
+    ModelMemorySpec {
+        profile_id: VendorProfileId::SiemensS7,
+        model_prefix: "S7-12",
+        sizes: S7_1200_SIZES,
+        sections: S7_1200_SECTIONS,
+    },
+    ModelMemorySpec {
+        profile_id: VendorProfileId::SiemensS7,
+        model_prefix: "CPU 12",
+        sizes: S7_1200_SIZES,
+        sections: S7_1200_SECTIONS,
+    },
+    // S7-1500: I/Q 32KB, M 16384 바이트, T/C 2048, 기본 DB1 64KB
+    ModelMemorySpec {
+        profile_id: VendorProfileId::SiemensS7,
+        model_prefix: "S7-15",
+        sizes: S7_1500_SIZES,
+        sections: S7_1500_SECTIONS,
+    },
+    ModelMemorySpec {
+        profile_id: VendorProfileId::SiemensS7,
+        model_prefix: "CPU 15",
+        sizes: S7_1500_SIZES,
+        sections: S7_1500_SECTIONS,
+    },
+    // Omron CP1: CIO 0-99 입력 / 100-199 출력, W 512ch, H 512ch, A 960ch, D 32K, T/C 4096
+    ModelMemorySpec {
+        profile_id: VendorProfileId::OmronSysmac,
+        model_prefix: "CP",
+        sizes: &[
+            (CanonicalAreaKind::InputBit, 200 * 16),
+            (CanonicalAreaKind::OutputBit, 200 * 16),
+            (CanonicalAreaKind::InternalBit, 512 * 16),
+            (CanonicalAreaKind::RetentiveBit, 512 * 16),
+            (CanonicalAreaKind::SystemBit, 960 * 16),
+            (CanonicalAreaKind::RetentiveWord, 32768),
+            (CanonicalAreaKind::TimerDoneBit, 4096),
+            (CanonicalAreaKind::TimerValueWord, 4096),
+            (CanonicalAreaKind::CounterDoneBit, 4096),
+            (CanonicalAreaKind::CounterValueWord, 4096),
+        ],
+        sections: &[],
+    },
+    // Omron CJ2/CJ1, NJ: CIO I/O 영역 0-159, W 512ch, H 1536ch, A 960ch, D 32K, T/C 4096
+    ModelMemorySpec {
+        profile_id: VendorProfileId::OmronSysmac,
+        model_prefix: "CJ",
+        sizes: OMRON_CJ_SIZES,
+        sections: &[],
+    },
+    ModelMemorySpec {
+        profile_id: VendorProfileId::OmronSysmac,
+        model_prefix: "NJ",
+        sizes: OMRON_CJ_SIZES,
+        sections: &[],
+    },
+];
+
+const OMRON_CJ_SIZES: &[(CanonicalAreaKind, u32)] = &[
+    (CanonicalAreaKind::InputBit, 160 * 16),
+    (CanonicalAreaKind::OutputBit, 160 * 16),
+    (CanonicalAreaKind::InternalBit, 512 * 16),
+    (CanonicalAreaKind::RetentiveBit, 1536 * 16),
+    (CanonicalAreaKind::SystemBit, 960 * 16),
+    (CanonicalAreaKind::RetentiveWord, 32768),
+    (CanonicalAreaKind::TimerDoneBit, 4096),
+    (CanonicalAreaKind::TimerValueWord, 4096),
+    (CanonicalAreaKind::CounterDoneBit, 4096),
+    (CanonicalAreaKind::CounterValueWord, 4096),
+];
+
+const LS_IEC_SIZES: &[(CanonicalAreaKind, u32)] = &[
+    (CanonicalAreaKind::InputBit, 8 * 16 * 64),
+    (CanonicalAreaKind::OutputBit, 8 * 16 * 64),
+    (CanonicalAreaKind::DataWord, 131072),
+    (CanonicalAreaKind::TimerDoneBit, 2048),
+    (CanonicalAreaKind::TimerValueWord, 2048),
+    (CanonicalAreaKind::CounterDoneBit, 2048),
+    (CanonicalAreaKind::CounterValueWord, 2048),
+];
+
+// S7 은 바이트 주소 체계라 비트 영역 크기는 바이트 수 × 8 이다. M 과 DB 블록은
+// DataWord 하나에 바이트 이미지로 이어 붙으므로(profiles/s7.rs) 섹션 크기는 바이트
+// 단위이고, DataWord 는 그 합계다.
+const S7_1200_SIZES: &[(CanonicalAreaKind, u32)] = &[
+    (CanonicalAreaKind::InputBit, 1024 * 8),
+    (CanonicalAreaKind::OutputBit, 1024 * 8),
+    (CanonicalAreaKind::DataWord, (8192 + 16384) / 2),
+];
+
+const S7_1200_SECTIONS: &[(&str, u32)] = &[("M", 8192), ("DB1", 16384)];
+
+const S7_1500_SIZES: &[(CanonicalAreaKind, u32)] = &[
+    (CanonicalAreaKind::InputBit, 32768 * 8),
+    (CanonicalAreaKind::OutputBit, 32768 * 8),
+    (CanonicalAreaKind::TimerDoneBit, 2048),
+    (CanonicalAreaKind::TimerValueWord, 2048),
+    (CanonicalAreaKind::CounterDoneBit, 2048),
+    (CanonicalAreaKind::CounterValueWord, 2048),
+    (CanonicalAreaKind::DataWord, (16384 + 65536) / 2),
+];
+
+const MELSEC_Q_SECTIONS: &[(&str, u32)] = &[
+    ("B", 8192),
+    ("F", 2048),
+    ("W", 8192),
+    ("SW", 2048),
+    ("G", MELSEC_BUFFER_WORDS),
+];
+
+const MELSEC_IQR_SECTIONS: &[(&str, u32)] = &[
+    ("B", 8192),
+    ("F", 2048),
+    ("W", 8192),
+    ("SW", 2048),
+    ("LT", 1024),
+    ("LC", 1024),
+    ("G", MELSEC_BUFFER_WORDS),
+];
+
+const S7_1500_SECTIONS: &[(&str, u32)] = &[("M", 16384), ("DB1", 65536)];
+
+/// Built-in layout for `model`; unknown models get the generic default sizes.
+pub fn builtin_memory_layout(profile_id: VendorProfileId, model: &str) -> PlcMemoryLayout {
+    let model = model.trim().to_ascii_uppercase();
+    BUILTIN_MEMORY_LAYOUTS
+        .iter()
+        .find(|spec| spec.profile_id == profile_id && model.starts_with(spec.model_prefix))
+        .map(|spec| PlcMemoryLayout::from_sizes(spec.sizes).with_sections(spec.sections))
+        .unwrap_or_default()
+}
+
+/// Layout for the project's CPU: the model's built-in sizes plus the
+/// `memory_sizes` and `memory_sections` overrides in `PlcSettings`.
+pub fn resolve_memory_layout(settings: &PlcSettings) -> PlcMemoryLayout {
+    let profile_id = VendorProfileId::for_manufacturer(settings.manufacturer);
+    let base = builtin_memory_layout(profile_id, &settings.model);
+    base.with_overrides(&settings.memory_sizes)
+        .with_section_overrides(&settings.memory_sections)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::PlcManufacturer;
+
+    #[test]
+    fn resolves_model_layout_with_project_overrides() {
+        let fx = builtin_memory_layout(VendorProfileId::MelsecFxQCommon, "fx3u-32m");
+        assert_eq!(fx.size(CanonicalAreaKind::DataWord), 8000);
+        assert_eq!(fx.max_index(CanonicalAreaKind::InputBit), 0o377);
+        // 표에 없는 영역은 기본 크기
+        assert_eq!(
+            fx.size(CanonicalAreaKind::SystemWord),
+            CanonicalAreaKind::SystemWord.default_size() as u32
+        );
+
+        // XGI/XGR은 IEC 표 사용
+        for model in ["XGI-CPUUN", "XGR-CPUH"] {
+            let iec = builtin_memory_layout(VendorProfileId::LsXg5000, model);
+            assert_eq!(iec, PlcMemoryLayout::from_sizes(LS_IEC_SIZES));
+            assert_ne!(iec, PlcMemoryLayout::default());
+        }
+
+        let unknown = builtin_memory_layout(VendorProfileId::LsXg5000, "");
+        assert_eq!(unknown, PlcMemoryLayout::default());
+
+        let mut settings = PlcSettings {
+            manufacturer: PlcManufacturer::LS,
+            model: "XGK-CPUUN".to_string(),
+            ..PlcSettings::default()
+        };
+        settings
+            .memory_sizes
+            .insert(CanonicalAreaKind::DataWord, 524288);
+        let layout = resolve_memory_layout(&settings);
+        assert_eq!(layout.size(CanonicalAreaKind::DataWord), 524288);
+        assert_eq!(layout.size(CanonicalAreaKind::SystemWord), 21504);
+    }
+}
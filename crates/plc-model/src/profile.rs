@@ -1,7 +1,11 @@
+use std::ops::Range;
+
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::hardware::{PlcHardwareTopology, PlcManufacturer, PlcSettings};
+use crate::io_allocation::{unsupported_allocation, IoAllocation};
+use crate::memory_layout::{resolve_memory_layout, PlcMemoryLayout};
 
 use modone_contract::{CanonicalAccess, CanonicalAddress, CanonicalAreaKind};
 
@@ -9,6 +13,8 @@ use modone_contract::{CanonicalAccess, CanonicalAddress, CanonicalAreaKind};
 pub enum VendorProfileId {
     LsXg5000,
     MelsecFxQCommon,
+    SiemensS7,
+    OmronSysmac,
 }
 
 impl VendorProfileId {
@@ -16,6 +22,18 @@ impl VendorProfileId {
         match self {
             Self::LsXg5000 => "ls-xg5000",
             Self::MelsecFxQCommon => "melsec-fx-q-common",
+            Self::SiemensS7 => "siemens-s7",
+            Self::OmronSysmac => "omron-sysmac",
+        }
+    }
+
+    /// Profile used for `manufacturer`'s PLCs.
+    pub fn for_manufacturer(manufacturer: PlcManufacturer) -> Self {
+        match manufacturer {
+            PlcManufacturer::LS => Self::LsXg5000,
+            PlcManufacturer::Mitsubishi => Self::MelsecFxQCommon,
+            PlcManufacturer::Siemens => Self::SiemensS7,
+            PlcManufacturer::Omron => Self::OmronSysmac,
         }
     }
 }
@@ -24,6 +42,8 @@ impl VendorProfileId {
 pub enum VendorDataKind {
     Bit,
     Word,
+    /// 32-bit value spanning canonical words; see [`VendorProfile::canonical_span`].
+    DoubleWord,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -72,6 +92,9 @@ pub struct VendorAddress {
     pub bit_index: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub index_register: Option<u8>,
+    /// Data block number for block-relative families (S7 `DB1.DBW0`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data_block: Option<u16>,
 }
 
 impl VendorAddress {
@@ -81,6 +104,7 @@ impl VendorAddress {
             index,
             bit_index: None,
             index_register: None,
+            data_block: None,
         }
     }
 
@@ -93,6 +117,11 @@ impl VendorAddress {
         self.index_register = Some(index_register);
         self
     }
+
+    pub fn with_data_block(mut self, data_block: u16) -> Self {
+        self.data_block = Some(data_block);
+        self
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -106,6 +135,77 @@ pub struct VendorAddressMetadata {
     pub number_base: VendorAddressNumberBase,
 }
 
+/// Bytes of a word area read or written together as one operand, for
+/// operands that are wider than a canonical word or straddle two of them
+/// (S7 `MW11`, `MD20`). Canonical word `n` holds image bytes `2n` and `2n + 1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CanonicalByteSpan {
+    pub area: CanonicalAreaKind,
+    pub byte_offset: u32,
+    /// 2 or 4 bytes.
+    pub len: u8,
+    /// Even bytes are the high byte of their word and the first byte is the
+    /// most significant (S7). Otherwise little-endian (LS `%MD`).
+    pub big_endian: bool,
+}
+
+impl CanonicalByteSpan {
+    /// Canonical word indices the span touches.
+    pub fn words(&self) -> Range<u32> {
+        let first = self.byte_offset / 2;
+        let last = (self.byte_offset + u32::from(self.len) - 1) / 2;
+        first..last + 1
+    }
+
+    /// Assemble the value from the words returned for [`Self::words`].
+    pub fn decode(&self, words: &[u16]) -> u32 {
+        (0..u32::from(self.len)).fold(0, |value, position| {
+            let byte = u32::from(self.byte_at(words, position));
+            if self.big_endian {
+                (value << 8) | byte
+            } else {
+                value | (byte << (8 * position))
+            }
+        })
+    }
+
+    /// Store the low `len` bytes of `value` into `words` (as from [`Self::words`]).
+    pub fn encode(&self, words: &mut [u16], value: u32) {
+        let len = u32::from(self.len);
+        for position in 0..len {
+            let shift = if self.big_endian {
+                8 * (len - 1 - position)
+            } else {
+                8 * position
+            };
+            let (word, high) = self.locate(position);
+            let byte = u16::from((value >> shift) as u8);
+            words[word] = if high {
+                (words[word] & 0x00FF) | (byte << 8)
+            } else {
+                (words[word] & 0xFF00) | byte
+            };
+        }
+    }
+
+    fn byte_at(&self, words: &[u16], position: u32) -> u8 {
+        let (word, high) = self.locate(position);
+        if high {
+            (words[word] >> 8) as u8
+        } else {
+            words[word] as u8
+        }
+    }
+
+    /// Word slot and half of the span's `position`-th byte.
+    fn locate(&self, position: u32) -> (usize, bool) {
+        let byte = self.byte_offset + position;
+        let word = (byte / 2 - self.byte_offset / 2) as usize;
+        let even = byte % 2 == 0;
+        (word, even == self.big_endian)
+    }
+}
+
 // Modbus 매핑 정책 타입은 `modbus-codec` 로 이전됨. `crate::plc_runtime::X` 및
 // `profile::X` 경로 호환을 위해 재노출한다. (정책의 `profile_id` 는 codec 에서
 // vendor enum 결합을 끊기 위해 `String` 라벨이다.)
@@ -159,6 +259,9 @@ pub trait VendorProfile: Send + Sync {
     fn manufacturer(&self) -> PlcManufacturer;
     fn model_hint(&self) -> Option<&str>;
     fn hardware_topology(&self) -> &PlcHardwareTopology;
+    /// Device sizes of the selected CPU model; bounds `validate_address` and
+    /// the canonical memory allocation.
+    fn memory_layout(&self) -> &PlcMemoryLayout;
     fn parse_address(&self, input: &str) -> Result<VendorAddress, VendorProfileError>;
     fn format_address(&self, address: &VendorAddress) -> Result<String, VendorProfileError>;
     fn validate_address(
@@ -168,6 +271,29 @@ pub trait VendorProfile: Send + Sync {
     fn to_canonical(&self, address: &VendorAddress)
         -> Result<CanonicalAddress, VendorProfileError>;
     fn canonical_aliases(&self, canonical: &CanonicalAddress) -> Vec<VendorAddress>;
+
+    /// Byte span of operands that do not map onto a single canonical word;
+    /// `to_canonical` then names the word holding the first byte. `None` for
+    /// bit and plain word operands.
+    fn canonical_span(
+        &self,
+        address: &VendorAddress,
+    ) -> Result<Option<CanonicalByteSpan>, VendorProfileError> {
+        self.validate_address(address)?;
+        Ok(None)
+    }
+
+    /// First bit of a word operand in a bit area (Omron channel `W10`); the
+    /// sixteen bits from there are read and written together, bit 0 first.
+    /// `None` for operands that map onto a single canonical cell.
+    fn canonical_bit_word(
+        &self,
+        address: &VendorAddress,
+    ) -> Result<Option<CanonicalAddress>, VendorProfileError> {
+        self.validate_address(address)?;
+        Ok(None)
+    }
+
     fn recommended_modbus_mapping_policy(&self) -> ModbusMappingPolicy;
     fn legacy_modbus_mapping_policy(&self) -> ModbusMappingPolicy;
     fn opcua_alias_policy(&self) -> OpcUaAliasPolicy;
@@ -175,23 +301,52 @@ pub trait VendorProfile: Send + Sync {
     fn preferred_alias(&self, canonical: &CanonicalAddress) -> Option<VendorAddress> {
         self.canonical_aliases(canonical).into_iter().next()
     }
+
+    /// I/O number space of an address-window family; windows in one space
+    /// must not overlap.
+    fn io_number_space(&self, family: &str) -> String {
+        family.to_string()
+    }
+
+    /// Assign module address windows from the rack/slot layout using the
+    /// vendor's rules. Profiles without such rules report a conflict.
+    fn allocate_io(&self, topology: &PlcHardwareTopology) -> IoAllocation {
+        unsupported_allocation(self.display_name(), topology)
+    }
 }
 
 pub fn resolve_vendor_profile(
     settings: &PlcSettings,
 ) -> Result<Box<dyn VendorProfile>, VendorProfileError> {
     match settings.manufacturer {
-        PlcManufacturer::LS => Ok(Box::new(crate::profiles::LsProfile::new(
-            settings.model.clone(),
-            settings.hardware_topology.clone(),
-        ))),
-        PlcManufacturer::Mitsubishi => Ok(Box::new(crate::profiles::MelsecFxQProfile::new(
-            settings.model.clone(),
-            settings.hardware_topology.clone(),
-        ))),
-        PlcManufacturer::Siemens => Err(VendorProfileError::UnsupportedManufacturer {
-            manufacturer: settings.manufacturer.to_string(),
-        }),
+        PlcManufacturer::LS => Ok(Box::new(
+            crate::profiles::LsProfile::new(
+                settings.model.clone(),
+                settings.hardware_topology.clone(),
+            )
+            .with_memory_layout(resolve_memory_layout(settings)),
+        )),
+        PlcManufacturer::Mitsubishi => Ok(Box::new(
+            crate::profiles::MelsecFxQProfile::new(
+                settings.model.clone(),
+                settings.hardware_topology.clone(),
+            )
+            .with_memory_layout(resolve_memory_layout(settings)),
+        )),
+        PlcManufacturer::Siemens => Ok(Box::new(
+            crate::profiles::S7Profile::new(
+                settings.model.clone(),
+                settings.hardware_topology.clone(),
+            )
+            .with_memory_layout(resolve_memory_layout(settings)),
+        )),
+        PlcManufacturer::Omron => Ok(Box::new(
+            crate::profiles::OmronProfile::new(
+                settings.model.clone(),
+                settings.hardware_topology.clone(),
+            )
+            .with_memory_layout(resolve_memory_layout(settings)),
+        )),
     }
 }
 
@@ -300,6 +455,8 @@ mod tests {
             model: "XGK".to_string(),
             scan_time_ms: 10,
             hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: Default::default(),
+            memory_sections: Default::default(),
         })
         .expect("ls profile should resolve");
         assert_eq!(ls.id(), VendorProfileId::LsXg5000);
@@ -309,24 +466,65 @@ mod tests {
             model: "FX5U".to_string(),
             scan_time_ms: 10,
             hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: Default::default(),
+            memory_sections: Default::default(),
         })
         .expect("melsec profile should resolve");
         assert_eq!(melsec.id(), VendorProfileId::MelsecFxQCommon);
     }
 
     #[test]
-    fn rejects_unsupported_manufacturer() {
-        let result = resolve_vendor_profile(&PlcSettings {
-            manufacturer: PlcManufacturer::Siemens,
-            model: "S7".to_string(),
-            scan_time_ms: 10,
-            hardware_topology: PlcHardwareTopology::default(),
-        });
+    fn bounds_addresses_by_model_memory_layout() {
+        let settings = |model: &str| PlcSettings {
+            manufacturer: PlcManufacturer::Mitsubishi,
+            model: model.to_string(),
+            ..PlcSettings::default()
+        };
 
+        let fx3u = resolve_vendor_profile(&settings("FX3U-32M")).unwrap();
+        assert!(fx3u.parse_address("D7999").is_ok());
         assert!(matches!(
-            result,
-            Err(VendorProfileError::UnsupportedManufacturer { .. })
+            fx3u.parse_address("D8000"),
+            Err(VendorProfileError::AddressOutOfRange {
+                max_index: 7999,
+                ..
+            })
         ));
+        assert!(fx3u.parse_address("X400").is_err());
+
+        let q = resolve_vendor_profile(&settings("Q03UDE")).unwrap();
+        assert!(q.parse_address("D12287").is_ok());
+        assert!(q.parse_address("X1FFF").is_ok());
+
+        let mut overridden = settings("FX3U-32M");
+        overridden
+            .memory_sizes
+            .insert(CanonicalAreaKind::DataWord, 10000);
+        let fx3u = resolve_vendor_profile(&overridden).unwrap();
+        assert!(fx3u.parse_address("D9999").is_ok());
+        assert_eq!(
+            fx3u.memory_layout().size(CanonicalAreaKind::DataWord),
+            10000
+        );
     }
 
+    #[test]
+    fn resolves_siemens_profile() {
+        let s7 = resolve_vendor_profile(&PlcSettings {
+            manufacturer: PlcManufacturer::Siemens,
+            model: "S7-1500".to_string(),
+            scan_time_ms: 10,
+            hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: Default::default(),
+            memory_sections: Default::default(),
+        })
+        .expect("siemens profile should resolve");
+
+        assert_eq!(s7.id(), VendorProfileId::SiemensS7);
+        assert_eq!(s7.memory_layout().section("M"), Some(16384));
+        assert_eq!(
+            s7.memory_layout().size(CanonicalAreaKind::DataWord),
+            (16384 + 65536) / 2
+        );
+    }
 }
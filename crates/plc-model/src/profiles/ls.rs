@@ -1,14 +1,31 @@
-use crate::hardware::{PlcHardwareTopology, PlcIoDirection, PlcManufacturer};
+use crate::hardware::{PlcHardwareTopology, PlcIoAllocationMode, PlcIoDirection, PlcManufacturer};
+use crate::io_allocation::{
+    digital_split, is_allocated_rack, is_digital, occupied_points, place_window, round_up,
+    slot_order, window, IoAllocation, IoAllocationConflict, DEFAULT_EMPTY_SLOT_POINTS,
+};
+use crate::memory_layout::{builtin_memory_layout, PlcMemoryLayout};
 
 use crate::profile::{
-    format_vendor_address, split_vendor_address, ModbusAddressSpace, ModbusMappingPolicy,
-    ModbusMappingRule, ModbusMappingSource, OpcUaAliasPolicy, VendorAddress, VendorAddressMetadata,
-    VendorAddressNumberBase, VendorDataKind, VendorProfile, VendorProfileError, VendorProfileId,
+    format_vendor_address, split_vendor_address, CanonicalByteSpan, ModbusAddressSpace,
+    ModbusMappingPolicy, ModbusMappingRule, ModbusMappingSource, OpcUaAliasPolicy, VendorAddress,
+    VendorAddressMetadata, VendorAddressNumberBase, VendorDataKind, VendorProfile,
+    VendorProfileError, VendorProfileId,
 };
 use modone_contract::{CanonicalAddress, CanonicalAreaKind};
 
 const LS_FAMILIES: [&str; 12] = ["TD", "CD", "P", "M", "K", "F", "T", "C", "D", "R", "Z", "N"];
 
+// XGI/XGR IEC 직접 변수. %IX/%QX 는 베이스.슬롯.접점 고정 할당(슬롯당 64점)이고,
+// %MX/%MW/%MD 는 같은 M 메모리를 비트/워드/더블워드로 본 것이라 모두 DataWord 위에
+// 겹쳐 놓는다(%MX16 = %MW1.0, %MD10 = %MW20~21).
+const LS_IEC_FAMILIES: [&str; 5] = ["%IX", "%QX", "%MX", "%MW", "%MD"];
+
+// XGK/XGB 디바이스 메모리. XGI/XGR 에는 없고 IEC 직접 변수로만 접근한다.
+// 타이머/카운터(T/C/TD/CD)와 플래그(F)는 명령 인스턴스용으로 남긴다.
+const LS_XGK_DEVICE_FAMILIES: [&str; 7] = ["P", "M", "K", "D", "R", "Z", "N"];
+const IEC_POINTS_PER_SLOT: u32 = 64;
+const IEC_SLOTS_PER_BASE: u32 = 16;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum LsIoTopology {
     LegacyUnifiedP,
@@ -23,7 +40,10 @@ enum LsIoTopology {
 pub struct LsProfile {
     model: String,
     io_topology: LsIoTopology,
+    /// XGI/XGR CPUs address memory with IEC direct variables.
+    iec_direct: bool,
     hardware_topology: PlcHardwareTopology,
+    memory_layout: PlcMemoryLayout,
 }
 
 impl LsProfile {
@@ -36,19 +56,33 @@ impl LsProfile {
                 input_end_exclusive: 20,
                 output_end_exclusive: 40,
             }
-        } else if lowered.starts_with("xgt") || lowered.starts_with("xgi") {
+        } else if lowered.starts_with("xgt")
+            || lowered.starts_with("xgi")
+            || lowered.starts_with("xgr")
+        {
             LsIoTopology::DynamicSlotP
         } else {
             LsIoTopology::LegacyUnifiedP
         };
+        let iec_direct = lowered.starts_with("xgi") || lowered.starts_with("xgr");
+
+        let memory_layout = builtin_memory_layout(VendorProfileId::LsXg5000, &model);
 
         Self {
             model,
             io_topology,
+            iec_direct,
             hardware_topology,
+            memory_layout,
         }
     }
 
+    /// Replace the model's built-in device sizes (project overrides applied).
+    pub fn with_memory_layout(mut self, memory_layout: PlcMemoryLayout) -> Self {
+        self.memory_layout = memory_layout;
+        self
+    }
+
     fn canonical_area_for_p(&self, index: u32) -> CanonicalAreaKind {
         for rack in &self.hardware_topology.racks {
             for module in &rack.modules {
@@ -120,6 +154,13 @@ impl LsProfile {
         family: &str,
         index: Option<u32>,
     ) -> Result<VendorAddressMetadata, VendorProfileError> {
+        if self.iec_direct && LS_XGK_DEVICE_FAMILIES.contains(&family) {
+            return Err(VendorProfileError::UnsupportedFamily {
+                profile_id: VendorProfileId::LsXg5000,
+                family: family.to_string(),
+            });
+        }
+
         let metadata = match family {
             "P" => VendorAddressMetadata {
                 canonical_area: self.canonical_area_for_p(index.unwrap_or_default()),
@@ -129,7 +170,9 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Bit,
                 supports_bit_index: false,
-                max_index: 2047,
+                max_index: self
+                    .memory_layout
+                    .max_index(self.canonical_area_for_p(index.unwrap_or_default())),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "M" => VendorAddressMetadata {
@@ -138,7 +181,7 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Bit,
                 supports_bit_index: false,
-                max_index: 8191,
+                max_index: self.memory_layout.max_index(CanonicalAreaKind::InternalBit),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "K" => VendorAddressMetadata {
@@ -147,7 +190,9 @@ impl LsProfile {
                 retained: true,
                 data_kind: VendorDataKind::Bit,
                 supports_bit_index: false,
-                max_index: 2047,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::RetentiveBit),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "F" => VendorAddressMetadata {
@@ -156,7 +201,7 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Bit,
                 supports_bit_index: false,
-                max_index: 2047,
+                max_index: self.memory_layout.max_index(CanonicalAreaKind::SpecialBit),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "T" => VendorAddressMetadata {
@@ -165,7 +210,9 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Bit,
                 supports_bit_index: false,
-                max_index: 2047,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::TimerDoneBit),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "C" => VendorAddressMetadata {
@@ -174,7 +221,9 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Bit,
                 supports_bit_index: false,
-                max_index: 2047,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::CounterDoneBit),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "D" => VendorAddressMetadata {
@@ -183,7 +232,7 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Word,
                 supports_bit_index: true,
-                max_index: 9999,
+                max_index: self.memory_layout.max_index(CanonicalAreaKind::DataWord),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "R" => VendorAddressMetadata {
@@ -192,7 +241,9 @@ impl LsProfile {
                 retained: true,
                 data_kind: VendorDataKind::Word,
                 supports_bit_index: true,
-                max_index: 9999,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::RetentiveWord),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "Z" => VendorAddressMetadata {
@@ -201,7 +252,7 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Word,
                 supports_bit_index: false,
-                max_index: 15,
+                max_index: self.memory_layout.max_index(CanonicalAreaKind::IndexWord),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "N" => VendorAddressMetadata {
@@ -210,7 +261,7 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Word,
                 supports_bit_index: false,
-                max_index: 8191,
+                max_index: self.memory_layout.max_index(CanonicalAreaKind::SystemWord),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "TD" => VendorAddressMetadata {
@@ -219,7 +270,9 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Word,
                 supports_bit_index: false,
-                max_index: 2047,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::TimerValueWord),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             "CD" => VendorAddressMetadata {
@@ -228,7 +281,54 @@ impl LsProfile {
                 retained: false,
                 data_kind: VendorDataKind::Word,
                 supports_bit_index: false,
-                max_index: 2047,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::CounterValueWord),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "%IX" | "%QX" if self.iec_direct => {
+                let area = if family == "%IX" {
+                    CanonicalAreaKind::InputBit
+                } else {
+                    CanonicalAreaKind::OutputBit
+                };
+                VendorAddressMetadata {
+                    canonical_area: area,
+                    access: area.default_access(),
+                    retained: false,
+                    data_kind: VendorDataKind::Bit,
+                    supports_bit_index: false,
+                    max_index: self.memory_layout.max_index(area),
+                    number_base: VendorAddressNumberBase::Decimal,
+                }
+            }
+            "%MX" if self.iec_direct => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::DataWord,
+                access: CanonicalAreaKind::DataWord.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: false,
+                max_index: (self.memory_layout.size(CanonicalAreaKind::DataWord) * 16)
+                    .saturating_sub(1),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "%MW" if self.iec_direct => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::DataWord,
+                access: CanonicalAreaKind::DataWord.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::Word,
+                supports_bit_index: true,
+                max_index: self.memory_layout.max_index(CanonicalAreaKind::DataWord),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "%MD" if self.iec_direct => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::DataWord,
+                access: CanonicalAreaKind::DataWord.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::DoubleWord,
+                supports_bit_index: false,
+                max_index: (self.memory_layout.size(CanonicalAreaKind::DataWord) / 2)
+                    .saturating_sub(1),
                 number_base: VendorAddressNumberBase::Decimal,
             },
             _ => {
@@ -241,6 +341,85 @@ impl LsProfile {
 
         Ok(metadata)
     }
+
+    /// Parse `%IX0.1.3`, `%MX20`, `%MW100.3` or `%MD10`.
+    fn parse_iec_address(&self, input: &str) -> Result<VendorAddress, VendorProfileError> {
+        let malformed = |reason: &str| VendorProfileError::MalformedAddress {
+            input: input.to_string(),
+            reason: reason.to_string(),
+        };
+        let trimmed = input.trim().to_uppercase();
+        let family = LS_IEC_FAMILIES
+            .iter()
+            .find(|candidate| trimmed.starts_with(**candidate))
+            .ok_or_else(|| malformed("missing or unsupported IEC direct-variable prefix"))?;
+        let rest = &trimmed[family.len()..];
+        if rest.is_empty() {
+            return Err(malformed("missing numeric address"));
+        }
+        let number_base = self.metadata_for(family, None)?.number_base;
+
+        let address = match *family {
+            "%IX" | "%QX" => {
+                let parts = rest
+                    .split('.')
+                    .map(|part| number_base.parse(part))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let [base, slot, point] = parts[..] else {
+                    return Err(malformed("expected base.slot.point"));
+                };
+                if slot >= IEC_SLOTS_PER_BASE || point >= IEC_POINTS_PER_SLOT {
+                    return Err(malformed("slot or point number is out of range"));
+                }
+                let index = (base * IEC_SLOTS_PER_BASE + slot) * IEC_POINTS_PER_SLOT + point;
+                VendorAddress::new(*family, index)
+            }
+            "%MW" => match rest.split_once('.') {
+                Some((word, bit)) => {
+                    let bit_index = bit
+                        .parse::<u8>()
+                        .map_err(|_| malformed("invalid bit-index suffix"))?;
+                    VendorAddress::new(*family, number_base.parse(word)?).with_bit_index(bit_index)
+                }
+                None => VendorAddress::new(*family, number_base.parse(rest)?),
+            },
+            _ => VendorAddress::new(*family, number_base.parse(rest)?),
+        };
+
+        self.validate_address(&address)?;
+        Ok(address)
+    }
+
+    fn format_iec_address(address: &VendorAddress) -> String {
+        match address.family.as_str() {
+            "%IX" | "%QX" => {
+                let slot_index = address.index / IEC_POINTS_PER_SLOT;
+                format!(
+                    "{}{}.{}.{}",
+                    address.family,
+                    slot_index / IEC_SLOTS_PER_BASE,
+                    slot_index % IEC_SLOTS_PER_BASE,
+                    address.index % IEC_POINTS_PER_SLOT
+                )
+            }
+            _ => format_vendor_address(address, VendorAddressNumberBase::Decimal, 1),
+        }
+    }
+
+    fn iec_alias(canonical: &CanonicalAddress) -> Option<VendorAddress> {
+        match (canonical.area, canonical.bit_index) {
+            (CanonicalAreaKind::InputBit, None) => Some(VendorAddress::new("%IX", canonical.index)),
+            (CanonicalAreaKind::OutputBit, None) => {
+                Some(VendorAddress::new("%QX", canonical.index))
+            }
+            (CanonicalAreaKind::DataWord, None) => Some(VendorAddress::new("%MW", canonical.index)),
+            (CanonicalAreaKind::DataWord, Some(bit_index)) => Some(VendorAddress::new(
+                "%MX",
+                canonical.index * 16 + u32::from(bit_index),
+            )),
+            _ => None,
+        }
+    }
 }
 
 impl VendorProfile for LsProfile {
@@ -260,6 +439,10 @@ impl VendorProfile for LsProfile {
         &self.hardware_topology
     }
 
+    fn memory_layout(&self) -> &PlcMemoryLayout {
+        &self.memory_layout
+    }
+
     fn model_hint(&self) -> Option<&str> {
         if self.model.is_empty() {
             None
@@ -269,6 +452,10 @@ impl VendorProfile for LsProfile {
     }
 
     fn parse_address(&self, input: &str) -> Result<VendorAddress, VendorProfileError> {
+        if input.trim_start().starts_with('%') {
+            return self.parse_iec_address(input);
+        }
+
         let (family, number_part, bit_index, index_register) =
             split_vendor_address(input, &LS_FAMILIES)?;
         let number_base = match family.as_str() {
@@ -299,6 +486,9 @@ impl VendorProfile for LsProfile {
 
     fn format_address(&self, address: &VendorAddress) -> Result<String, VendorProfileError> {
         let metadata = self.validate_address(address)?;
+        if address.family.starts_with('%') {
+            return Ok(Self::format_iec_address(address));
+        }
         Ok(format_vendor_address(address, metadata.number_base, 4))
     }
 
@@ -354,11 +544,39 @@ impl VendorProfile for LsProfile {
             });
         }
 
-        let mut canonical = CanonicalAddress::new(metadata.canonical_area, address.index);
-        canonical.bit_index = address.bit_index;
+        let canonical = match address.family.as_str() {
+            "%MX" => CanonicalAddress::with_bit_index(
+                metadata.canonical_area,
+                address.index / 16,
+                (address.index % 16) as u8,
+            ),
+            "%MD" => CanonicalAddress::new(metadata.canonical_area, address.index * 2),
+            _ => {
+                let mut canonical = CanonicalAddress::new(metadata.canonical_area, address.index);
+                canonical.bit_index = address.bit_index;
+                canonical
+            }
+        };
         Ok(canonical)
     }
 
+    fn canonical_span(
+        &self,
+        address: &VendorAddress,
+    ) -> Result<Option<CanonicalByteSpan>, VendorProfileError> {
+        let metadata = self.validate_address(address)?;
+        if address.family != "%MD" {
+            return Ok(None);
+        }
+        // %MD10 = %MW20(하위) + %MW21(상위)
+        Ok(Some(CanonicalByteSpan {
+            area: metadata.canonical_area,
+            byte_offset: address.index * 4,
+            len: 4,
+            big_endian: false,
+        }))
+    }
+
     fn canonical_aliases(&self, canonical: &CanonicalAddress) -> Vec<VendorAddress> {
         let family = match canonical.area {
             CanonicalAreaKind::InputBit => Some("P"),
@@ -379,14 +597,23 @@ impl VendorProfile for LsProfile {
             CanonicalAreaKind::OutputBit | CanonicalAreaKind::SystemBit => None,
         };
 
-        family
-            .map(|family| VendorAddress {
+        // XGI/XGR 은 IEC 직접 변수를 우선 별칭으로 둔다
+        let iec = self
+            .iec_direct
+            .then(|| Self::iec_alias(canonical))
+            .flatten();
+
+        let family =
+            family.filter(|family| !(self.iec_direct && LS_XGK_DEVICE_FAMILIES.contains(family)));
+
+        iec.into_iter()
+            .chain(family.map(|family| VendorAddress {
                 family: family.to_string(),
                 index: canonical.index,
                 bit_index: canonical.bit_index,
                 index_register: None,
-            })
-            .into_iter()
+                data_block: None,
+            }))
             .collect()
     }
 
@@ -515,6 +742,107 @@ impl VendorProfile for LsProfile {
             namespace_segment: "LS".to_string(),
         }
     }
+
+    // 고정 할당은 베이스당 16슬롯 × 64점 블록(XGI/XGR 의 %IX/%QX 는 항상 고정),
+    // 가변 할당은 슬롯 순서대로 16점 단위로 이어 붙이고 빈 슬롯도 점수를 차지한다.
+    fn allocate_io(&self, topology: &PlcHardwareTopology) -> IoAllocation {
+        let fixed = self.iec_direct || topology.io_allocation == PlcIoAllocationMode::FixedSlot;
+        let (input_family, output_family) = if self.iec_direct {
+            ("%IX", "%QX")
+        } else {
+            ("P", "P")
+        };
+        let empty_slot_points = round_up(
+            u32::from(
+                topology
+                    .empty_slot_points
+                    .unwrap_or(DEFAULT_EMPTY_SLOT_POINTS),
+            ),
+            16,
+        );
+        let input_limit = self.memory_layout.size(CanonicalAreaKind::InputBit);
+        let output_limit = self.memory_layout.size(CanonicalAreaKind::OutputBit);
+
+        let mut allocated = topology.clone();
+        let mut conflicts = Vec::new();
+        let mut cursor = 0u32;
+        let mut base = 0u32;
+
+        for (rack_index, rack) in allocated.racks.iter_mut().enumerate() {
+            if !is_allocated_rack(rack.rack_kind) {
+                continue;
+            }
+
+            let mut next_slot = 0u32;
+            for module_index in slot_order(&rack.modules) {
+                let module = &mut rack.modules[module_index];
+                let slot = u32::from(module.slot);
+                if !fixed {
+                    cursor += slot.saturating_sub(next_slot) * empty_slot_points;
+                }
+                next_slot = slot + 1;
+                module.address_windows.clear();
+
+                let points = match occupied_points(module, empty_slot_points) {
+                    Ok(Some(points)) => points,
+                    Ok(None) => continue,
+                    Err(message) => {
+                        conflicts.push(IoAllocationConflict::module(
+                            rack_index,
+                            module_index,
+                            message,
+                        ));
+                        continue;
+                    }
+                };
+
+                let start = if fixed {
+                    if slot >= IEC_SLOTS_PER_BASE || points > IEC_POINTS_PER_SLOT {
+                        conflicts.push(IoAllocationConflict::module(
+                            rack_index,
+                            module_index,
+                            format!(
+                                "Slot {slot} with {points} points does not fit the fixed \
+                                 {IEC_POINTS_PER_SLOT}-point slot layout"
+                            ),
+                        ));
+                        continue;
+                    }
+                    (base * IEC_SLOTS_PER_BASE + slot) * IEC_POINTS_PER_SLOT
+                } else {
+                    let start = cursor;
+                    cursor += round_up(points, 16);
+                    start
+                };
+
+                // 특수/통신 모듈은 점수만 차지하고 P 주소창은 만들지 않는다.
+                if !is_digital(module.module_kind) {
+                    continue;
+                }
+                let (inputs, outputs) = digital_split(module.module_kind, points);
+                let position = (rack_index, module_index);
+                if inputs > 0 {
+                    let input = window(input_family, start, inputs, Some(PlcIoDirection::Input));
+                    place_window(module, input, input_limit, position, &mut conflicts);
+                }
+                if outputs > 0 {
+                    let output = window(
+                        output_family,
+                        start + inputs,
+                        outputs,
+                        Some(PlcIoDirection::Output),
+                    );
+                    place_window(module, output, output_limit, position, &mut conflicts);
+                }
+            }
+            base += 1;
+        }
+
+        IoAllocation {
+            topology: allocated,
+            conflicts,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -608,6 +936,7 @@ mod tests {
                         }],
                     }],
                 }],
+                ..PlcHardwareTopology::default()
             },
         );
 
@@ -615,6 +944,76 @@ mod tests {
         assert_eq!(p.area, CanonicalAreaKind::OutputBit);
     }
 
+    #[test]
+    fn parses_xgi_iec_direct_variables() {
+        let profile = LsProfile::new("XGI-CPUUN".to_string(), PlcHardwareTopology::default());
+
+        let ix = profile.parse_address("%IX0.0.0").expect("%IX should parse");
+        assert_eq!(
+            profile.to_canonical(&ix).unwrap(),
+            CanonicalAddress::new(CanonicalAreaKind::InputBit, 0)
+        );
+
+        let qx = profile.parse_address("%qx0.1.3").expect("%QX should parse");
+        let canonical = profile.to_canonical(&qx).unwrap();
+        assert_eq!(canonical.index, 67);
+        assert_eq!(profile.format_address(&qx).unwrap(), "%QX0.1.3");
+        assert_eq!(profile.preferred_alias(&canonical), Some(qx));
+
+        // %MX20 과 %MW1.4 는 같은 비트
+        let mx = profile.parse_address("%MX20").expect("%MX should parse");
+        let mx_canonical = profile.to_canonical(&mx).unwrap();
+        assert_eq!(
+            mx_canonical,
+            CanonicalAddress::with_bit_index(CanonicalAreaKind::DataWord, 1, 4)
+        );
+        let mw_bit = profile.parse_address("%MW1.4").unwrap();
+        assert_eq!(profile.to_canonical(&mw_bit).unwrap(), mx_canonical);
+        assert_eq!(profile.preferred_alias(&mx_canonical), Some(mx));
+
+        let md = profile.parse_address("%MD10").expect("%MD should parse");
+        assert_eq!(profile.to_canonical(&md).unwrap().index, 20);
+        assert_eq!(profile.format_address(&md).unwrap(), "%MD10");
+
+        let mw = profile.parse_address("%MW100").unwrap();
+        let aliases = profile.canonical_aliases(&profile.to_canonical(&mw).unwrap());
+        assert_eq!(aliases[0], mw);
+
+        assert!(profile.parse_address("%IX0.16.0").is_err());
+        assert!(profile.parse_address("%IX0.0").is_err());
+
+        let xgk = LsProfile::new("XGK-CPUUN".to_string(), PlcHardwareTopology::default());
+        assert!(matches!(
+            xgk.parse_address("%MW100"),
+            Err(VendorProfileError::UnsupportedFamily { .. })
+        ));
+
+        // XGK 디바이스는 XGI/XGR 에서 쓸 수 없다
+        let xgr = LsProfile::new("XGR-CPUH".to_string(), PlcHardwareTopology::default());
+        for device in ["P0001", "M0010", "D0100"] {
+            assert!(matches!(
+                profile.parse_address(device),
+                Err(VendorProfileError::UnsupportedFamily { .. })
+            ));
+            assert!(xgr.parse_address(device).is_err());
+        }
+        assert!(profile.parse_address("T0001").is_ok());
+        assert_eq!(aliases, vec![mw]);
+    }
+
+    #[test]
+    fn spans_xgi_double_words_over_two_words() {
+        let profile = LsProfile::new("XGI-CPUUN".to_string(), PlcHardwareTopology::default());
+        let md = profile.parse_address("%MD10").unwrap();
+        let span = profile.canonical_span(&md).unwrap().expect("%MD spans");
+        assert_eq!(span.words(), 20..22);
+        // 하위 워드가 먼저
+        assert_eq!(span.decode(&[0x5678, 0x1234]), 0x1234_5678);
+
+        let mw = profile.parse_address("%MW10").unwrap();
+        assert_eq!(profile.canonical_span(&mw).unwrap(), None);
+    }
+
     #[test]
     fn rejects_out_of_range_ls_addresses() {
         let profile = LsProfile::new(String::new(), PlcHardwareTopology::default());
@@ -1,4 +1,21 @@
-use crate::hardware::{PlcHardwareTopology, PlcManufacturer};
+// MELSEC FX/Q/iQ-R 프로파일.
+//
+// 기본 디바이스(X/Y/M/L/T/C/D)는 각 canonical 영역의 0 번부터 놓인다. FX5·Q·iQ-R 의
+// 확장 디바이스는 별도 canonical 영역이 없으므로 같은 성격의 영역 뒤쪽에 구간으로
+// 붙는다(B→M 뒤, F→SM 뒤, W·U\G→D 뒤, SW→SD 뒤, LT/LC→T/C 뒤). 구간 크기는 메모리
+// 레이아웃의 `sections`(디바이스 이름별 점수, `G` 는 유닛당 워드 수, `U3E0` 처럼
+// 유닛 번호 키는 그 유닛만의 워드 수)에서 온다. 영역 크기는 확장 구간을 포함한
+// 합계이고, 기본 디바이스 점수는 합계에서 확장 구간을 뺀 값이다. 그래서 영역 크기를
+// 바꾸면 뒤에 붙은 확장 디바이스의 canonical 위치도 함께 움직인다.
+
+use crate::hardware::{
+    PlcHardwareTopology, PlcIoAllocationMode, PlcIoDirection, PlcManufacturer, PlcModuleKind,
+};
+use crate::io_allocation::{
+    digital_split, is_allocated_rack, is_digital, occupied_points, place_window, round_up,
+    slot_order, window, IoAllocation, IoAllocationConflict, DEFAULT_EMPTY_SLOT_POINTS,
+};
+use crate::memory_layout::{builtin_memory_layout, PlcMemoryLayout};
 
 use crate::profile::{
     format_vendor_address, split_vendor_address, ModbusAddressSpace, ModbusMappingPolicy,
@@ -7,105 +24,347 @@ use crate::profile::{
 };
 use modone_contract::{CanonicalAddress, CanonicalAreaKind};
 
-const MELSEC_FAMILIES: [&str; 7] = ["X", "Y", "M", "L", "T", "C", "D"];
+// 긴 접두어가 먼저 와야 한다(LT/LC 가 L 보다, SM/SD/SB/SW 가 단일 문자보다 앞)
+const MELSEC_FAMILIES: [&str; 18] = [
+    "SM", "SD", "SB", "SW", "ZR", "LT", "LC", "X", "Y", "M", "L", "B", "F", "T", "C", "D", "W", "R",
+];
+
+/// Extended-device points used when the memory layout has no such section.
+const MELSEC_LINK_POINTS: u32 = 2048;
+const MELSEC_LONG_POINTS: u32 = 1024;
+/// Default buffer-memory words per unit (`Un\G0` .. `Un\G1023`).
+pub(crate) const MELSEC_BUFFER_WORDS: u32 = 1024;
+/// I/O module units `U0`..`U3F` followed by CPU shared memory `U3E0`..`U3E3`.
+const MELSEC_MODULE_UNITS: u32 = 0x40;
+const MELSEC_CPU_UNIT_START: u32 = 0x3E0;
+const MELSEC_CPU_UNITS: u32 = 4;
+/// DataWord words taken by `U\G` buffer memory after D and W at the default unit size.
+pub(crate) const MELSEC_BUFFER_SECTION: u32 =
+    (MELSEC_MODULE_UNITS + MELSEC_CPU_UNITS) * MELSEC_BUFFER_WORDS;
+/// R addresses one 32K-word block; ZR addresses the file register serially.
+const MELSEC_R_BLOCK_WORDS: u32 = 32768;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MelsecSeries {
+    /// FX3U/FX3G: base devices only.
+    Fx3,
+    Fx5,
+    Q,
+    IqR,
+}
+
+impl MelsecSeries {
+    fn from_model(lowered: &str) -> Self {
+        if lowered.starts_with("fx5") {
+            Self::Fx5
+        } else if lowered.starts_with('r') {
+            Self::IqR
+        } else if lowered.contains('q') {
+            Self::Q
+        } else {
+            Self::Fx3
+        }
+    }
+
+    fn supports(&self, family: &str) -> bool {
+        match family {
+            "X" | "Y" | "M" | "L" | "T" | "C" | "D" => true,
+            "B" | "W" | "SB" | "SW" | "F" | "SM" | "SD" | "R" | "G" => *self != Self::Fx3,
+            "ZR" => matches!(self, Self::Q | Self::IqR),
+            "LC" => matches!(self, Self::Fx5 | Self::IqR),
+            "LT" => *self == Self::IqR,
+            _ => false,
+        }
+    }
+}
+
+/// One device family's slice of a canonical area.
+#[derive(Debug, Clone, Copy)]
+struct MelsecDevice {
+    family: &'static str,
+    area: CanonicalAreaKind,
+    base: u32,
+    points: u32,
+    number_base: VendorAddressNumberBase,
+    retained: bool,
+}
 
 #[derive(Debug, Clone)]
 pub struct MelsecFxQProfile {
     model: String,
+    series: MelsecSeries,
     io_number_base: VendorAddressNumberBase,
     hardware_topology: PlcHardwareTopology,
+    memory_layout: PlcMemoryLayout,
+    devices: Vec<MelsecDevice>,
+    /// `(offset, words)` of each unit slot within the `G` device.
+    buffer_units: Vec<(u32, u32)>,
 }
 
 impl MelsecFxQProfile {
     pub fn new(model: String, hardware_topology: PlcHardwareTopology) -> Self {
         let lowered = model.to_ascii_lowercase();
-        let io_number_base = if lowered.contains('q') {
+        let series = MelsecSeries::from_model(&lowered);
+        let io_number_base = if matches!(series, MelsecSeries::Q | MelsecSeries::IqR) {
             VendorAddressNumberBase::Hexadecimal
         } else {
             VendorAddressNumberBase::Octal
         };
 
-        Self {
+        let memory_layout = builtin_memory_layout(VendorProfileId::MelsecFxQCommon, &model);
+
+        let mut profile = Self {
             model,
+            series,
             io_number_base,
             hardware_topology,
+            memory_layout,
+            devices: Vec::new(),
+            buffer_units: Vec::new(),
+        };
+        profile.rebuild();
+        profile
+    }
+
+    /// Replace the model's built-in device sizes (project overrides applied).
+    pub fn with_memory_layout(mut self, memory_layout: PlcMemoryLayout) -> Self {
+        self.memory_layout = memory_layout;
+        self.rebuild();
+        self
+    }
+
+    fn rebuild(&mut self) {
+        self.buffer_units = self.build_buffer_units();
+        self.devices = self.build_devices();
+    }
+
+    /// Points of an extended-device section; 0 when the series lacks it.
+    fn section(&self, family: &str) -> u32 {
+        if !self.series.supports(family) {
+            return 0;
         }
+        self.memory_layout
+            .section(family)
+            .unwrap_or(if family == "LT" || family == "LC" {
+                MELSEC_LONG_POINTS
+            } else {
+                MELSEC_LINK_POINTS
+            })
+    }
+
+    fn build_buffer_units(&self) -> Vec<(u32, u32)> {
+        if !self.series.supports("G") {
+            return Vec::new();
+        }
+        let default_words = self
+            .memory_layout
+            .section("G")
+            .unwrap_or(MELSEC_BUFFER_WORDS);
+        let mut offset = 0;
+        (0..MELSEC_MODULE_UNITS + MELSEC_CPU_UNITS)
+            .map(|slot| {
+                let unit = format!("U{:X}", Self::buffer_unit_from_slot(slot));
+                let words = self.memory_layout.section(&unit).unwrap_or(default_words);
+                let entry = (offset, words);
+                offset += words;
+                entry
+            })
+            .collect()
+    }
+
+    fn build_devices(&self) -> Vec<MelsecDevice> {
+        use CanonicalAreaKind as Area;
+        use VendorAddressNumberBase::{Decimal, Hexadecimal};
+
+        let size = |area| self.memory_layout.size(area);
+        let section = |family| self.section(family);
+        let buffer_words = self
+            .buffer_units
+            .last()
+            .map_or(0, |(offset, words)| offset + words);
+
+        let m = size(Area::InternalBit).saturating_sub(section("B"));
+        let sm = size(Area::SpecialBit).saturating_sub(section("F"));
+        let t = size(Area::TimerDoneBit).saturating_sub(section("LT"));
+        let c = size(Area::CounterDoneBit).saturating_sub(section("LC"));
+        let d = size(Area::DataWord).saturating_sub(section("W") + buffer_words);
+        let sd = size(Area::SystemWord).saturating_sub(section("SW"));
+        let zr = size(Area::RetentiveWord);
+
+        let device = |family, area, base, points, number_base, retained| MelsecDevice {
+            family,
+            area,
+            base,
+            points,
+            number_base,
+            retained,
+        };
+
+        [
+            device(
+                "X",
+                Area::InputBit,
+                0,
+                size(Area::InputBit),
+                self.io_number_base,
+                false,
+            ),
+            device(
+                "Y",
+                Area::OutputBit,
+                0,
+                size(Area::OutputBit),
+                self.io_number_base,
+                false,
+            ),
+            device("M", Area::InternalBit, 0, m, Decimal, false),
+            device("B", Area::InternalBit, m, section("B"), Hexadecimal, false),
+            device(
+                "L",
+                Area::RetentiveBit,
+                0,
+                size(Area::RetentiveBit),
+                Decimal,
+                true,
+            ),
+            device("SM", Area::SpecialBit, 0, sm, Decimal, false),
+            device("F", Area::SpecialBit, sm, section("F"), Decimal, false),
+            device(
+                "SB",
+                Area::SystemBit,
+                0,
+                size(Area::SystemBit),
+                Hexadecimal,
+                false,
+            ),
+            device("T", Area::TimerDoneBit, 0, t, Decimal, false),
+            device("LT", Area::TimerDoneBit, t, section("LT"), Decimal, false),
+            device("C", Area::CounterDoneBit, 0, c, Decimal, false),
+            device("LC", Area::CounterDoneBit, c, section("LC"), Decimal, false),
+            device("D", Area::DataWord, 0, d, Decimal, false),
+            device("W", Area::DataWord, d, section("W"), Hexadecimal, false),
+            device(
+                "G",
+                Area::DataWord,
+                d + section("W"),
+                buffer_words,
+                Decimal,
+                false,
+            ),
+            device("SD", Area::SystemWord, 0, sd, Decimal, false),
+            device(
+                "SW",
+                Area::SystemWord,
+                sd,
+                section("SW"),
+                Hexadecimal,
+                false,
+            ),
+            device(
+                "R",
+                Area::RetentiveWord,
+                0,
+                zr.min(MELSEC_R_BLOCK_WORDS),
+                Decimal,
+                true,
+            ),
+            device("ZR", Area::RetentiveWord, 0, zr, Decimal, true),
+        ]
+        .into_iter()
+        .filter(|device| self.series.supports(device.family))
+        .collect()
+    }
+
+    fn device(&self, family: &str) -> Result<&MelsecDevice, VendorProfileError> {
+        self.devices
+            .iter()
+            .find(|device| device.family == family)
+            .ok_or_else(|| VendorProfileError::UnsupportedFamily {
+                profile_id: VendorProfileId::MelsecFxQCommon,
+                family: family.to_string(),
+            })
     }
 
     fn metadata_for(&self, family: &str) -> Result<VendorAddressMetadata, VendorProfileError> {
-        let metadata = match family {
-            "X" => VendorAddressMetadata {
-                canonical_area: CanonicalAreaKind::InputBit,
-                access: CanonicalAreaKind::InputBit.default_access(),
-                retained: false,
-                data_kind: VendorDataKind::Bit,
-                supports_bit_index: false,
-                max_index: 0x7FFF,
-                number_base: self.io_number_base,
-            },
-            "Y" => VendorAddressMetadata {
-                canonical_area: CanonicalAreaKind::OutputBit,
-                access: CanonicalAreaKind::OutputBit.default_access(),
-                retained: false,
-                data_kind: VendorDataKind::Bit,
-                supports_bit_index: false,
-                max_index: 0x7FFF,
-                number_base: self.io_number_base,
-            },
-            "M" => VendorAddressMetadata {
-                canonical_area: CanonicalAreaKind::InternalBit,
-                access: CanonicalAreaKind::InternalBit.default_access(),
-                retained: false,
-                data_kind: VendorDataKind::Bit,
-                supports_bit_index: false,
-                max_index: 8191,
-                number_base: VendorAddressNumberBase::Decimal,
-            },
-            "L" => VendorAddressMetadata {
-                canonical_area: CanonicalAreaKind::RetentiveBit,
-                access: CanonicalAreaKind::RetentiveBit.default_access(),
-                retained: true,
-                data_kind: VendorDataKind::Bit,
-                supports_bit_index: false,
-                max_index: 8191,
-                number_base: VendorAddressNumberBase::Decimal,
-            },
-            "T" => VendorAddressMetadata {
-                canonical_area: CanonicalAreaKind::TimerDoneBit,
-                access: CanonicalAreaKind::TimerDoneBit.default_access(),
-                retained: false,
-                data_kind: VendorDataKind::Bit,
-                supports_bit_index: false,
-                max_index: 2047,
-                number_base: VendorAddressNumberBase::Decimal,
-            },
-            "C" => VendorAddressMetadata {
-                canonical_area: CanonicalAreaKind::CounterDoneBit,
-                access: CanonicalAreaKind::CounterDoneBit.default_access(),
-                retained: false,
-                data_kind: VendorDataKind::Bit,
-                supports_bit_index: false,
-                max_index: 2047,
-                number_base: VendorAddressNumberBase::Decimal,
-            },
-            "D" => VendorAddressMetadata {
-                canonical_area: CanonicalAreaKind::DataWord,
-                access: CanonicalAreaKind::DataWord.default_access(),
-                retained: false,
-                data_kind: VendorDataKind::Word,
-                supports_bit_index: true,
-                max_index: 9999,
-                number_base: VendorAddressNumberBase::Decimal,
+        let device = self.device(family)?;
+        let word = device.area.is_word_area();
+        // G 의 인덱스는 유닛 안의 오프셋(유닛별 상한은 validate_address 에서 확인)
+        let points = if family == "G" {
+            self.buffer_units
+                .iter()
+                .map(|(_, words)| *words)
+                .max()
+                .unwrap_or(0)
+        } else {
+            device.points
+        };
+
+        Ok(VendorAddressMetadata {
+            canonical_area: device.area,
+            access: device.area.default_access(),
+            retained: device.retained,
+            data_kind: if word {
+                VendorDataKind::Word
+            } else {
+                VendorDataKind::Bit
             },
-            _ => {
-                return Err(VendorProfileError::UnsupportedFamily {
-                    profile_id: VendorProfileId::MelsecFxQCommon,
-                    family: family.to_string(),
-                });
-            }
+            supports_bit_index: word,
+            max_index: points.saturating_sub(1),
+            number_base: device.number_base,
+        })
+    }
+
+    /// Slot of unit `Un` within the buffer-memory section.
+    fn buffer_unit_slot(unit: u16) -> Option<u32> {
+        let unit = u32::from(unit);
+        if unit < MELSEC_MODULE_UNITS {
+            Some(unit)
+        } else if (MELSEC_CPU_UNIT_START..MELSEC_CPU_UNIT_START + MELSEC_CPU_UNITS).contains(&unit)
+        {
+            Some(MELSEC_MODULE_UNITS + unit - MELSEC_CPU_UNIT_START)
+        } else {
+            None
+        }
+    }
+
+    fn buffer_unit_from_slot(slot: u32) -> u16 {
+        if slot < MELSEC_MODULE_UNITS {
+            slot as u16
+        } else {
+            (MELSEC_CPU_UNIT_START + slot - MELSEC_MODULE_UNITS) as u16
+        }
+    }
+
+    /// Parse `U3E0\G100`; the unit number is the module's start I/O / 16 in hex.
+    fn parse_buffer_address(&self, input: &str) -> Result<VendorAddress, VendorProfileError> {
+        let malformed = |reason: &str| VendorProfileError::MalformedAddress {
+            input: input.to_string(),
+            reason: reason.to_string(),
         };
+        let trimmed = input.trim().to_uppercase();
+        let (unit, member) = trimmed
+            .split_once('\\')
+            .ok_or_else(|| malformed("expected `Un\\Gm`"))?;
+        let unit = unit
+            .strip_prefix('U')
+            .ok_or_else(|| malformed("missing `U` unit prefix"))?;
+        let unit = u16::from_str_radix(unit, 16).map_err(|_| malformed("invalid unit number"))?;
 
-        Ok(metadata)
+        let (family, number_part, bit_index, index_register) =
+            split_vendor_address(member, &["G"])?;
+        let metadata = self.metadata_for(&family)?;
+        let index = metadata.number_base.parse(&number_part)?;
+
+        let mut address = VendorAddress::new(family, index).with_data_block(unit);
+        if let Some(bit_index) = bit_index {
+            address = address.with_bit_index(bit_index);
+        }
+        if let Some(index_register) = index_register {
+            address = address.with_index_register(index_register);
+        }
+
+        self.validate_address(&address)?;
+        Ok(address)
     }
 }
 
@@ -126,6 +385,10 @@ impl VendorProfile for MelsecFxQProfile {
         &self.hardware_topology
     }
 
+    fn memory_layout(&self) -> &PlcMemoryLayout {
+        &self.memory_layout
+    }
+
     fn model_hint(&self) -> Option<&str> {
         if self.model.is_empty() {
             None
@@ -135,6 +398,10 @@ impl VendorProfile for MelsecFxQProfile {
     }
 
     fn parse_address(&self, input: &str) -> Result<VendorAddress, VendorProfileError> {
+        if input.contains('\\') {
+            return self.parse_buffer_address(input);
+        }
+
         let (family, number_part, bit_index, index_register) =
             split_vendor_address(input, &MELSEC_FAMILIES)?;
         let metadata = self.metadata_for(&family)?;
@@ -154,7 +421,11 @@ impl VendorProfile for MelsecFxQProfile {
 
     fn format_address(&self, address: &VendorAddress) -> Result<String, VendorProfileError> {
         let metadata = self.validate_address(address)?;
-        Ok(format_vendor_address(address, metadata.number_base, 1))
+        let member = format_vendor_address(address, metadata.number_base, 1);
+        Ok(match address.data_block {
+            Some(unit) => format!("U{unit:X}\\{member}"),
+            None => member,
+        })
     }
 
     fn validate_address(
@@ -163,12 +434,40 @@ impl VendorProfile for MelsecFxQProfile {
     ) -> Result<VendorAddressMetadata, VendorProfileError> {
         let metadata = self.metadata_for(&address.family)?;
 
-        if address.index > metadata.max_index {
+        match (address.family == "G", address.data_block) {
+            (true, None) => {
+                return Err(VendorProfileError::MalformedAddress {
+                    input: format!("G{}", address.index),
+                    reason: "buffer memory requires a `Un\\` unit prefix".to_string(),
+                });
+            }
+            (true, Some(unit)) if Self::buffer_unit_slot(unit).is_none() => {
+                return Err(VendorProfileError::AddressOutOfRange {
+                    profile_id: self.id(),
+                    family: "U".to_string(),
+                    index: u32::from(unit),
+                    max_index: MELSEC_CPU_UNIT_START + MELSEC_CPU_UNITS - 1,
+                });
+            }
+            (false, Some(_)) => {
+                return Err(VendorProfileError::MalformedAddress {
+                    input: format!("{}{}", address.family, address.index),
+                    reason: "unit prefix is only valid for buffer memory".to_string(),
+                });
+            }
+            _ => {}
+        }
+
+        let points = match address.data_block.and_then(Self::buffer_unit_slot) {
+            Some(slot) => self.buffer_units[slot as usize].1,
+            None => metadata.max_index + 1,
+        };
+        if address.index >= points {
             return Err(VendorProfileError::AddressOutOfRange {
                 profile_id: self.id(),
                 family: address.family.clone(),
                 index: address.index,
-                max_index: metadata.max_index,
+                max_index: points.saturating_sub(1),
             });
         }
 
@@ -199,38 +498,45 @@ impl VendorProfile for MelsecFxQProfile {
         address: &VendorAddress,
     ) -> Result<CanonicalAddress, VendorProfileError> {
         let metadata = self.validate_address(address)?;
-
-        let mut canonical = CanonicalAddress::new(metadata.canonical_area, address.index);
+        let device = self.device(&address.family)?;
+        let unit_offset = address
+            .data_block
+            .and_then(Self::buffer_unit_slot)
+            .map_or(0, |slot| self.buffer_units[slot as usize].0);
+
+        let mut canonical = CanonicalAddress::new(
+            metadata.canonical_area,
+            device.base + unit_offset + address.index,
+        );
         canonical.bit_index = address.bit_index;
         Ok(canonical)
     }
 
     fn canonical_aliases(&self, canonical: &CanonicalAddress) -> Vec<VendorAddress> {
-        let family = match canonical.area {
-            CanonicalAreaKind::InputBit => Some("X"),
-            CanonicalAreaKind::OutputBit => Some("Y"),
-            CanonicalAreaKind::InternalBit => Some("M"),
-            CanonicalAreaKind::RetentiveBit => Some("L"),
-            CanonicalAreaKind::TimerDoneBit => Some("T"),
-            CanonicalAreaKind::CounterDoneBit => Some("C"),
-            CanonicalAreaKind::DataWord => Some("D"),
-            CanonicalAreaKind::SpecialBit
-            | CanonicalAreaKind::RetentiveWord
-            | CanonicalAreaKind::IndexWord
-            | CanonicalAreaKind::TimerValueWord
-            | CanonicalAreaKind::CounterValueWord
-            | CanonicalAreaKind::SystemBit
-            | CanonicalAreaKind::SystemWord => None,
-        };
-
-        family
-            .map(|family| VendorAddress {
-                family: family.to_string(),
-                index: canonical.index,
-                bit_index: canonical.bit_index,
-                index_register: None,
+        self.devices
+            .iter()
+            .filter(|device| {
+                device.area == canonical.area
+                    && canonical.index >= device.base
+                    && canonical.index < device.base + device.points
+            })
+            .map(|device| {
+                let offset = canonical.index - device.base;
+                let mut alias = if device.family == "G" {
+                    // 유닛 크기가 0 인 슬롯은 건너뛴다
+                    let slot = self
+                        .buffer_units
+                        .iter()
+                        .rposition(|(start, words)| *words > 0 && *start <= offset)
+                        .unwrap_or(0);
+                    VendorAddress::new(device.family, offset - self.buffer_units[slot].0)
+                        .with_data_block(Self::buffer_unit_from_slot(slot as u32))
+                } else {
+                    VendorAddress::new(device.family, offset)
+                };
+                alias.bit_index = canonical.bit_index;
+                alias
             })
-            .into_iter()
             .collect()
     }
 
@@ -318,6 +624,13 @@ impl VendorProfile for MelsecFxQProfile {
                     offset: 0,
                     count: 10000,
                 },
+                ModbusMappingRule {
+                    family: "R".to_string(),
+                    canonical_area: CanonicalAreaKind::RetentiveWord,
+                    address_space: ModbusAddressSpace::HoldingRegister,
+                    offset: 10000,
+                    count: 10000,
+                },
             ],
         }
     }
@@ -328,6 +641,144 @@ impl VendorProfile for MelsecFxQProfile {
             namespace_segment: "MELSEC".to_string(),
         }
     }
+
+    // Q/iQ-R 의 I/O 번호는 슬롯마다 하나라 X 와 Y 가 같은 번호 공간을 쓴다
+    // (X0-F 와 Y0-F 는 같은 슬롯에만 있을 수 있다). FX 는 X, Y 번호가 따로다.
+    fn io_number_space(&self, family: &str) -> String {
+        match family {
+            "X" | "Y" if matches!(self.series, MelsecSeries::Q | MelsecSeries::IqR) => {
+                "X/Y".to_string()
+            }
+            _ => family.to_string(),
+        }
+    }
+
+    // Q/iQ-R: X·Y 가 하나의 16진 번호를 공유하며 슬롯 순서대로 16점 단위로 이어진다.
+    // 빈 슬롯은 empty_slot_points, `Empty` 모듈은 예약한 점수를 차지하고, 인텔리전트
+    // 모듈은 같은 번호의 X/Y 16점을 가진다. FX 는 슬롯 개념 없이 CPU 내장 I/O 뒤로
+    // X 와 Y 를 각각 8점 단위로 이어 붙인다.
+    fn allocate_io(&self, topology: &PlcHardwareTopology) -> IoAllocation {
+        let mut allocated = topology.clone();
+        let mut conflicts = Vec::new();
+        let input_limit = self.memory_layout.size(CanonicalAreaKind::InputBit);
+        let output_limit = self.memory_layout.size(CanonicalAreaKind::OutputBit);
+
+        let rack_based = matches!(self.series, MelsecSeries::Q | MelsecSeries::IqR);
+        if rack_based && topology.io_allocation == PlcIoAllocationMode::FixedSlot {
+            conflicts.push(IoAllocationConflict::topology(
+                "MELSEC Q/iQ-R assign I/O numbers sequentially; fixed-slot allocation is not available",
+            ));
+        }
+        let empty_slot_points = round_up(
+            u32::from(
+                topology
+                    .empty_slot_points
+                    .unwrap_or(DEFAULT_EMPTY_SLOT_POINTS),
+            ),
+            16,
+        );
+
+        let mut next_input = 0u32;
+        let mut next_output = 0u32;
+        for (rack_index, rack) in allocated.racks.iter_mut().enumerate() {
+            if !is_allocated_rack(rack.rack_kind) {
+                continue;
+            }
+
+            let mut next_slot = 0u32;
+            for module_index in slot_order(&rack.modules) {
+                let module = &mut rack.modules[module_index];
+                let position = (rack_index, module_index);
+                let slot = u32::from(module.slot);
+                module.address_windows.clear();
+
+                if !rack_based {
+                    let points = match module.module_kind {
+                        PlcModuleKind::Cpu => module.point_count.map(u32::from),
+                        kind if is_digital(kind) => match occupied_points(module, 0) {
+                            Ok(points) => points,
+                            Err(message) => {
+                                conflicts.push(IoAllocationConflict::module(
+                                    rack_index,
+                                    module_index,
+                                    message,
+                                ));
+                                None
+                            }
+                        },
+                        _ => None,
+                    };
+                    let Some(points) = points else {
+                        continue;
+                    };
+                    // CPU 내장 I/O 는 입력·출력을 반씩 가진다.
+                    let kind = if module.module_kind == PlcModuleKind::Cpu {
+                        PlcModuleKind::DigitalIo
+                    } else {
+                        module.module_kind
+                    };
+                    let (inputs, outputs) = digital_split(kind, points);
+                    if inputs > 0 {
+                        let input = window("X", next_input, inputs, Some(PlcIoDirection::Input));
+                        place_window(module, input, input_limit, position, &mut conflicts);
+                        next_input += round_up(inputs, 8);
+                    }
+                    if outputs > 0 {
+                        let output =
+                            window("Y", next_output, outputs, Some(PlcIoDirection::Output));
+                        place_window(module, output, output_limit, position, &mut conflicts);
+                        next_output += round_up(outputs, 8);
+                    }
+                    continue;
+                }
+
+                next_input += slot.saturating_sub(next_slot) * empty_slot_points;
+                next_slot = slot + 1;
+                let points = match occupied_points(module, empty_slot_points) {
+                    Ok(Some(points)) => round_up(points, 16),
+                    Ok(None) => continue,
+                    Err(message) => {
+                        conflicts.push(IoAllocationConflict::module(
+                            rack_index,
+                            module_index,
+                            message,
+                        ));
+                        continue;
+                    }
+                };
+                let start = next_input;
+                next_input += points;
+
+                match module.module_kind {
+                    PlcModuleKind::Empty => {}
+                    kind if is_digital(kind) => {
+                        let (inputs, outputs) =
+                            digital_split(kind, u32::from(module.point_count.unwrap_or_default()));
+                        if inputs > 0 {
+                            let input = window("X", start, inputs, Some(PlcIoDirection::Input));
+                            place_window(module, input, input_limit, position, &mut conflicts);
+                        }
+                        if outputs > 0 {
+                            let output =
+                                window("Y", start + inputs, outputs, Some(PlcIoDirection::Output));
+                            place_window(module, output, output_limit, position, &mut conflicts);
+                        }
+                    }
+                    _ => {
+                        let input = window("X", start, points, Some(PlcIoDirection::Input));
+                        place_window(module, input, input_limit, position, &mut conflicts);
+                        let output = window("Y", start, points, Some(PlcIoDirection::Output));
+                        place_window(module, output, output_limit, position, &mut conflicts);
+                    }
+                }
+            }
+        }
+
+        IoAllocation {
+            topology: allocated,
+            conflicts,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -363,6 +814,113 @@ mod tests {
         assert_eq!(profile.format_address(&y).unwrap(), "Y1F");
     }
 
+    #[test]
+    fn parses_extended_q_and_iq_r_devices() {
+        let q = MelsecFxQProfile::new("Q03UDE".to_string(), PlcHardwareTopology::default());
+
+        // B/W/SB/SW 는 16진, SM/SD/F/R/ZR 은 10진
+        let w = q.parse_address("W1F").expect("W should parse");
+        assert_eq!(w.index, 0x1F);
+        assert_eq!(q.format_address(&w).unwrap(), "W1F");
+        let w_canonical = q.to_canonical(&w).unwrap();
+        assert_eq!(w_canonical.area, CanonicalAreaKind::DataWord);
+        assert_eq!(w_canonical.index, 12288 + 0x1F);
+        assert_eq!(q.preferred_alias(&w_canonical), Some(w));
+
+        let b = q.parse_address("BFF").expect("B should parse");
+        assert_eq!(q.to_canonical(&b).unwrap().index, 8192 + 0xFF);
+        assert!(q.parse_address("SB7FF").is_ok());
+        assert!(q.parse_address("SW1A").is_ok());
+
+        let sd = q.parse_address("SD210").expect("SD should parse");
+        assert_eq!(
+            q.to_canonical(&sd).unwrap(),
+            CanonicalAddress::new(CanonicalAreaKind::SystemWord, 210)
+        );
+        let sm = q.parse_address("SM400").expect("SM should parse");
+        assert_eq!(
+            q.to_canonical(&sm).unwrap().area,
+            CanonicalAreaKind::SpecialBit
+        );
+        assert_eq!(
+            q.to_canonical(&q.parse_address("F10").unwrap())
+                .unwrap()
+                .index,
+            2048 + 10
+        );
+
+        // R 과 ZR 은 같은 파일 레지스터
+        let r = q.parse_address("R100").unwrap();
+        let zr = q.parse_address("ZR100").unwrap();
+        assert_eq!(q.to_canonical(&r).unwrap(), q.to_canonical(&zr).unwrap());
+        assert!(q.parse_address("R32768").is_err());
+        assert!(q.parse_address("ZR40000").is_ok());
+
+        let g = q
+            .parse_address("U3E0\\G100")
+            .expect("buffer memory should parse");
+        assert_eq!(g.data_block, Some(0x3E0));
+        assert_eq!(q.format_address(&g).unwrap(), "U3E0\\G100");
+        let g_canonical = q.to_canonical(&g).unwrap();
+        assert_eq!(q.preferred_alias(&g_canonical), Some(g));
+        assert!(q.parse_address("U3F0\\G0").is_err());
+        assert!(q.parse_address("LT0").is_err());
+
+        let r_cpu = MelsecFxQProfile::new("R04CPU".to_string(), PlcHardwareTopology::default());
+        let lt = r_cpu
+            .parse_address("LT10")
+            .expect("LT should parse on iQ-R");
+        assert_eq!(
+            r_cpu.to_canonical(&lt).unwrap(),
+            CanonicalAddress::new(CanonicalAreaKind::TimerDoneBit, 1024 + 10)
+        );
+        assert!(r_cpu.parse_address("LC0").is_ok());
+        assert_eq!(r_cpu.parse_address("X1F").unwrap().index, 0x1F);
+
+        let fx3 = MelsecFxQProfile::new("FX3U".to_string(), PlcHardwareTopology::default());
+        assert!(matches!(
+            fx3.parse_address("W0"),
+            Err(VendorProfileError::UnsupportedFamily { .. })
+        ));
+    }
+
+    #[test]
+    fn sizes_extended_devices_from_memory_layout_sections() {
+        let q = MelsecFxQProfile::new("Q03UDE".to_string(), PlcHardwareTopology::default());
+
+        // Q/iQ-R 기본 B/W 는 8K 점
+        assert_eq!(
+            q.to_canonical(&q.parse_address("B800").unwrap())
+                .unwrap()
+                .index,
+            8192 + 0x800
+        );
+        assert!(q.parse_address("W1000").is_ok());
+        assert!(q.parse_address("W1FFF").is_ok());
+        assert!(q.parse_address("W2000").is_err());
+
+        // 유닛별 버퍼 크기 — U1 만 4K 워드, 나머지는 G 기본값
+        let layout = q
+            .memory_layout()
+            .clone()
+            .with_sections(&[("G", 512), ("U1", 4096)]);
+        let q = q.with_memory_layout(layout);
+        assert!(q.parse_address("U1\\G4095").is_ok());
+        assert!(q.parse_address("U0\\G512").is_err());
+        let index = |input: &str| {
+            q.to_canonical(&q.parse_address(input).unwrap())
+                .unwrap()
+                .index
+        };
+        assert_eq!(index("U1\\G0") - index("U0\\G0"), 512);
+        assert_eq!(index("U2\\G0") - index("U1\\G0"), 4096);
+        let u2 = q.parse_address("U2\\G0").unwrap();
+        let u2_canonical = q.to_canonical(&u2).unwrap();
+        assert_eq!(q.preferred_alias(&u2_canonical), Some(u2));
+        let cpu = q.parse_address("U3E0\\G10").unwrap();
+        assert_eq!(q.preferred_alias(&q.to_canonical(&cpu).unwrap()), Some(cpu));
+    }
+
     #[test]
     fn rejects_unsupported_melsec_family() {
         let profile = MelsecFxQProfile::new(String::new(), PlcHardwareTopology::default());
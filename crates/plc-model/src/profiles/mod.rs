@@ -1,5 +1,10 @@
 mod ls;
 mod melsec;
+mod omron;
+mod s7;
 
 pub use ls::LsProfile;
 pub use melsec::MelsecFxQProfile;
+pub(crate) use melsec::{MELSEC_BUFFER_SECTION, MELSEC_BUFFER_WORDS};
+pub use omron::OmronProfile;
+pub use s7::S7Profile;
@@ -0,0 +1,555 @@
+// Omron CJ/CP/NJ 프로파일 — 채널(16비트 워드).비트 표기(`0.00`, `W10.05`, `D100`)를
+// canonical 영역으로 옮긴다.
+//
+// - CIO/W/H/A 는 비트 영역이며 canonical 인덱스는 `채널 × 16 + 비트` 다. 비트 번호
+//   없이 채널만 쓰면(`W10`, `100`) 그 채널의 16 비트를 한 워드로 다룬다.
+//   CIO 는 I/O 영역만 모델링한다(CP1 은 0-99 입력 / 100-199 출력 고정, CJ/NJ 는
+//   topology 의 `CIO` 주소창 방향을 따르고 없으면 LS P 와 같이 출력으로 본다).
+// - H(유지 릴레이)와 D(DM)는 전원이 꺼져도 값을 유지하므로 Retentive 영역에 놓는다.
+
+use crate::hardware::{PlcHardwareTopology, PlcIoDirection, PlcManufacturer};
+use crate::memory_layout::{builtin_memory_layout, PlcMemoryLayout};
+
+use crate::profile::{
+    split_vendor_address, ModbusAddressSpace, ModbusMappingPolicy, ModbusMappingRule,
+    ModbusMappingSource, OpcUaAliasPolicy, VendorAddress, VendorAddressMetadata,
+    VendorAddressNumberBase, VendorDataKind, VendorProfile, VendorProfileError, VendorProfileId,
+};
+use modone_contract::{CanonicalAddress, CanonicalAreaKind};
+
+const OMRON_FAMILIES: [&str; 7] = ["CIO", "W", "H", "A", "D", "T", "C"];
+
+/// CP1 built-in I/O: input channels 0-99, output channels 100-199.
+const CP_OUTPUT_START_CHANNEL: u32 = 100;
+
+#[derive(Debug, Clone)]
+pub struct OmronProfile {
+    model: String,
+    fixed_cp_io: bool,
+    hardware_topology: PlcHardwareTopology,
+    memory_layout: PlcMemoryLayout,
+}
+
+impl OmronProfile {
+    pub fn new(model: String, hardware_topology: PlcHardwareTopology) -> Self {
+        let fixed_cp_io = model.trim().to_ascii_lowercase().starts_with("cp");
+        let memory_layout = builtin_memory_layout(VendorProfileId::OmronSysmac, &model);
+
+        Self {
+            model,
+            fixed_cp_io,
+            hardware_topology,
+            memory_layout,
+        }
+    }
+
+    /// Replace the model's built-in device sizes (project overrides applied).
+    pub fn with_memory_layout(mut self, memory_layout: PlcMemoryLayout) -> Self {
+        self.memory_layout = memory_layout;
+        self
+    }
+
+    fn canonical_area_for_cio(&self, channel: u32) -> CanonicalAreaKind {
+        for rack in &self.hardware_topology.racks {
+            for module in &rack.modules {
+                for window in &module.address_windows {
+                    if !window.family.eq_ignore_ascii_case("CIO") {
+                        continue;
+                    }
+
+                    let end = window.start.saturating_add(window.count);
+                    if channel >= window.start && channel < end {
+                        return match window.io_direction {
+                            Some(PlcIoDirection::Input) => CanonicalAreaKind::InputBit,
+                            _ => CanonicalAreaKind::OutputBit,
+                        };
+                    }
+                }
+            }
+        }
+
+        if self.fixed_cp_io && channel < CP_OUTPUT_START_CHANNEL {
+            CanonicalAreaKind::InputBit
+        } else {
+            CanonicalAreaKind::OutputBit
+        }
+    }
+
+    fn metadata_for(
+        &self,
+        family: &str,
+        channel: Option<u32>,
+    ) -> Result<VendorAddressMetadata, VendorProfileError> {
+        let channel_max =
+            |area: CanonicalAreaKind| (self.memory_layout.size(area) / 16).saturating_sub(1);
+
+        let metadata = match family {
+            "CIO" => {
+                let area = self.canonical_area_for_cio(channel.unwrap_or_default());
+                VendorAddressMetadata {
+                    canonical_area: area,
+                    access: area.default_access(),
+                    retained: false,
+                    data_kind: VendorDataKind::Bit,
+                    supports_bit_index: true,
+                    max_index: channel_max(area),
+                    number_base: VendorAddressNumberBase::Decimal,
+                }
+            }
+            "W" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::InternalBit,
+                access: CanonicalAreaKind::InternalBit.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: true,
+                max_index: channel_max(CanonicalAreaKind::InternalBit),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "H" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::RetentiveBit,
+                access: CanonicalAreaKind::RetentiveBit.default_access(),
+                retained: true,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: true,
+                max_index: channel_max(CanonicalAreaKind::RetentiveBit),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "A" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::SystemBit,
+                access: CanonicalAreaKind::SystemBit.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: true,
+                max_index: channel_max(CanonicalAreaKind::SystemBit),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "D" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::RetentiveWord,
+                access: CanonicalAreaKind::RetentiveWord.default_access(),
+                retained: true,
+                data_kind: VendorDataKind::Word,
+                supports_bit_index: true,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::RetentiveWord),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "T" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::TimerDoneBit,
+                access: CanonicalAreaKind::TimerDoneBit.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: false,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::TimerDoneBit),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            // 카운터 현재값은 전원 차단 후에도 유지된다
+            "C" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::CounterDoneBit,
+                access: CanonicalAreaKind::CounterDoneBit.default_access(),
+                retained: true,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: false,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::CounterDoneBit),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            _ => {
+                return Err(VendorProfileError::UnsupportedFamily {
+                    profile_id: VendorProfileId::OmronSysmac,
+                    family: family.to_string(),
+                });
+            }
+        };
+
+        Ok(metadata)
+    }
+}
+
+/// Bit areas addressed as `channel.bit`, or as a whole channel word.
+fn is_channel_bit_family(family: &str) -> bool {
+    matches!(family, "CIO" | "W" | "H" | "A")
+}
+
+impl VendorProfile for OmronProfile {
+    fn id(&self) -> VendorProfileId {
+        VendorProfileId::OmronSysmac
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Omron CJ/CP/NJ Profile"
+    }
+
+    fn manufacturer(&self) -> PlcManufacturer {
+        PlcManufacturer::Omron
+    }
+
+    fn hardware_topology(&self) -> &PlcHardwareTopology {
+        &self.hardware_topology
+    }
+
+    fn memory_layout(&self) -> &PlcMemoryLayout {
+        &self.memory_layout
+    }
+
+    fn model_hint(&self) -> Option<&str> {
+        if self.model.is_empty() {
+            None
+        } else {
+            Some(&self.model)
+        }
+    }
+
+    fn parse_address(&self, input: &str) -> Result<VendorAddress, VendorProfileError> {
+        // 접두어 없는 `0.00` 과 띄어 쓴 `CIO 0.00` 은 CIO
+        let trimmed = input.trim();
+        let prefixed;
+        let input_with_family = if trimmed.starts_with(|c: char| c.is_ascii_digit()) {
+            prefixed = format!("CIO{trimmed}");
+            prefixed.as_str()
+        } else if trimmed
+            .get(..3)
+            .is_some_and(|family| family.eq_ignore_ascii_case("CIO"))
+        {
+            prefixed = format!("CIO{}", trimmed[3..].trim_start());
+            prefixed.as_str()
+        } else {
+            trimmed
+        };
+
+        let (family, number_part, bit_index, index_register) =
+            split_vendor_address(input_with_family, &OMRON_FAMILIES)?;
+        let number_base = self.metadata_for(&family, None)?.number_base;
+        let index = number_base.parse(&number_part)?;
+
+        let mut address = VendorAddress::new(family, index);
+        if let Some(bit_index) = bit_index {
+            address = address.with_bit_index(bit_index);
+        }
+        if let Some(index_register) = index_register {
+            address = address.with_index_register(index_register);
+        }
+
+        self.validate_address(&address)?;
+        Ok(address)
+    }
+
+    fn format_address(&self, address: &VendorAddress) -> Result<String, VendorProfileError> {
+        self.validate_address(address)?;
+
+        let family = if address.family == "CIO" {
+            ""
+        } else {
+            address.family.as_str()
+        };
+        Ok(match address.bit_index {
+            Some(bit_index) => format!("{family}{}.{bit_index:02}", address.index),
+            None => format!("{family}{}", address.index),
+        })
+    }
+
+    fn validate_address(
+        &self,
+        address: &VendorAddress,
+    ) -> Result<VendorAddressMetadata, VendorProfileError> {
+        let mut metadata = self.metadata_for(&address.family, Some(address.index))?;
+
+        if address.index > metadata.max_index {
+            return Err(VendorProfileError::AddressOutOfRange {
+                profile_id: self.id(),
+                family: address.family.clone(),
+                index: address.index,
+                max_index: metadata.max_index,
+            });
+        }
+
+        match address.bit_index {
+            Some(_) if !metadata.supports_bit_index => {
+                return Err(VendorProfileError::BitIndexNotSupported {
+                    profile_id: self.id(),
+                    family: address.family.clone(),
+                });
+            }
+            Some(bit_index) if bit_index >= 16 => {
+                return Err(VendorProfileError::BitIndexOutOfRange { bit_index });
+            }
+            None if is_channel_bit_family(&address.family) => {
+                metadata.data_kind = VendorDataKind::Word;
+            }
+            _ => {}
+        }
+
+        if address.index_register.is_some() {
+            return Err(VendorProfileError::IndexedAddressNotSupported {
+                profile_id: self.id(),
+                family: address.family.clone(),
+            });
+        }
+
+        Ok(metadata)
+    }
+
+    fn to_canonical(
+        &self,
+        address: &VendorAddress,
+    ) -> Result<CanonicalAddress, VendorProfileError> {
+        let metadata = self.validate_address(address)?;
+
+        if is_channel_bit_family(&address.family) {
+            let bit = u32::from(address.bit_index.unwrap_or_default());
+            return Ok(CanonicalAddress::new(
+                metadata.canonical_area,
+                address.index * 16 + bit,
+            ));
+        }
+
+        let mut canonical = CanonicalAddress::new(metadata.canonical_area, address.index);
+        canonical.bit_index = address.bit_index;
+        Ok(canonical)
+    }
+
+    fn canonical_bit_word(
+        &self,
+        address: &VendorAddress,
+    ) -> Result<Option<CanonicalAddress>, VendorProfileError> {
+        if is_channel_bit_family(&address.family) && address.bit_index.is_none() {
+            return self.to_canonical(address).map(Some);
+        }
+        self.validate_address(address)?;
+        Ok(None)
+    }
+
+    fn canonical_aliases(&self, canonical: &CanonicalAddress) -> Vec<VendorAddress> {
+        let channel_bit = |family: &str| {
+            canonical.bit_index.is_none().then(|| {
+                VendorAddress::new(family, canonical.index / 16)
+                    .with_bit_index((canonical.index % 16) as u8)
+            })
+        };
+
+        let alias = match canonical.area {
+            CanonicalAreaKind::InputBit | CanonicalAreaKind::OutputBit
+                if self.canonical_area_for_cio(canonical.index / 16) == canonical.area =>
+            {
+                channel_bit("CIO")
+            }
+            CanonicalAreaKind::InternalBit => channel_bit("W"),
+            CanonicalAreaKind::RetentiveBit => channel_bit("H"),
+            CanonicalAreaKind::SystemBit => channel_bit("A"),
+            CanonicalAreaKind::RetentiveWord => Some(VendorAddress {
+                family: "D".to_string(),
+                index: canonical.index,
+                bit_index: canonical.bit_index,
+                index_register: None,
+                data_block: None,
+            }),
+            CanonicalAreaKind::TimerDoneBit => Some(VendorAddress::new("T", canonical.index)),
+            CanonicalAreaKind::CounterDoneBit => Some(VendorAddress::new("C", canonical.index)),
+            _ => None,
+        };
+
+        alias.into_iter().collect()
+    }
+
+    fn recommended_modbus_mapping_policy(&self) -> ModbusMappingPolicy {
+        ModbusMappingPolicy {
+            profile_id: self.id().as_str().to_string(),
+            source: ModbusMappingSource::Recommended,
+            rules: vec![
+                ModbusMappingRule {
+                    family: "CIO".to_string(),
+                    canonical_area: CanonicalAreaKind::OutputBit,
+                    address_space: ModbusAddressSpace::Coil,
+                    offset: 0,
+                    count: 3200,
+                },
+                ModbusMappingRule {
+                    family: "CIO".to_string(),
+                    canonical_area: CanonicalAreaKind::InputBit,
+                    address_space: ModbusAddressSpace::DiscreteInput,
+                    offset: 0,
+                    count: 3200,
+                },
+                ModbusMappingRule {
+                    family: "D".to_string(),
+                    canonical_area: CanonicalAreaKind::RetentiveWord,
+                    address_space: ModbusAddressSpace::HoldingRegister,
+                    offset: 0,
+                    count: 10000,
+                },
+            ],
+        }
+    }
+
+    fn legacy_modbus_mapping_policy(&self) -> ModbusMappingPolicy {
+        ModbusMappingPolicy {
+            profile_id: self.id().as_str().to_string(),
+            source: ModbusMappingSource::LegacyWide,
+            rules: vec![
+                ModbusMappingRule {
+                    family: "CIO".to_string(),
+                    canonical_area: CanonicalAreaKind::InputBit,
+                    address_space: ModbusAddressSpace::DiscreteInput,
+                    offset: 0,
+                    count: 3200,
+                },
+                ModbusMappingRule {
+                    family: "CIO".to_string(),
+                    canonical_area: CanonicalAreaKind::OutputBit,
+                    address_space: ModbusAddressSpace::Coil,
+                    offset: 0,
+                    count: 3200,
+                },
+                ModbusMappingRule {
+                    family: "W".to_string(),
+                    canonical_area: CanonicalAreaKind::InternalBit,
+                    address_space: ModbusAddressSpace::Coil,
+                    offset: 3200,
+                    count: 8192,
+                },
+                ModbusMappingRule {
+                    family: "H".to_string(),
+                    canonical_area: CanonicalAreaKind::RetentiveBit,
+                    address_space: ModbusAddressSpace::Coil,
+                    offset: 11392,
+                    count: 8192,
+                },
+                ModbusMappingRule {
+                    family: "T".to_string(),
+                    canonical_area: CanonicalAreaKind::TimerDoneBit,
+                    address_space: ModbusAddressSpace::DiscreteInput,
+                    offset: 3200,
+                    count: 4096,
+                },
+                ModbusMappingRule {
+                    family: "C".to_string(),
+                    canonical_area: CanonicalAreaKind::CounterDoneBit,
+                    address_space: ModbusAddressSpace::DiscreteInput,
+                    offset: 7296,
+                    count: 4096,
+                },
+                ModbusMappingRule {
+                    family: "D".to_string(),
+                    canonical_area: CanonicalAreaKind::RetentiveWord,
+                    address_space: ModbusAddressSpace::HoldingRegister,
+                    offset: 0,
+                    count: 32768,
+                },
+            ],
+        }
+    }
+
+    fn opcua_alias_policy(&self) -> OpcUaAliasPolicy {
+        OpcUaAliasPolicy {
+            expose_vendor_aliases: true,
+            namespace_segment: "Omron".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_channel_bit_addresses() {
+        let profile = OmronProfile::new("CP1L-EM40".to_string(), PlcHardwareTopology::default());
+
+        let input = profile.parse_address("0.00").expect("CIO bit should parse");
+        assert_eq!(input.family, "CIO");
+        assert_eq!(profile.format_address(&input).unwrap(), "0.00");
+        assert_eq!(
+            profile.to_canonical(&input).unwrap(),
+            CanonicalAddress::new(CanonicalAreaKind::InputBit, 0)
+        );
+
+        let output = profile.parse_address("100.01").unwrap();
+        let canonical = profile.to_canonical(&output).unwrap();
+        assert_eq!(
+            canonical,
+            CanonicalAddress::new(CanonicalAreaKind::OutputBit, 1601)
+        );
+        assert_eq!(profile.preferred_alias(&canonical), Some(output));
+
+        let work = profile.parse_address("W10.05").expect("W bit should parse");
+        let canonical = profile.to_canonical(&work).unwrap();
+        assert_eq!(canonical.index, 165);
+        assert_eq!(
+            profile.format_address(&profile.preferred_alias(&canonical).unwrap()),
+            Ok("W10.05".to_string())
+        );
+    }
+
+    #[test]
+    fn maps_channel_words_onto_their_bits() {
+        let profile = OmronProfile::new("CP1L-EM40".to_string(), PlcHardwareTopology::default());
+
+        let work = profile
+            .parse_address("W10")
+            .expect("W channel should parse");
+        assert_eq!(
+            profile.validate_address(&work).unwrap().data_kind,
+            VendorDataKind::Word
+        );
+        assert_eq!(profile.format_address(&work).unwrap(), "W10");
+        assert_eq!(
+            profile.canonical_bit_word(&work).unwrap(),
+            Some(CanonicalAddress::new(CanonicalAreaKind::InternalBit, 160))
+        );
+
+        let output = profile
+            .parse_address("100")
+            .expect("CIO channel should parse");
+        assert_eq!(
+            profile.canonical_bit_word(&output).unwrap(),
+            Some(CanonicalAddress::new(CanonicalAreaKind::OutputBit, 1600))
+        );
+        assert_eq!(
+            profile.canonical_bit_word(&profile.parse_address("W10.05").unwrap()),
+            Ok(None)
+        );
+
+        let spaced = profile
+            .parse_address("CIO 100.01")
+            .expect("spaced CIO should parse");
+        assert_eq!(spaced, profile.parse_address("100.01").unwrap());
+        assert!(profile.parse_address("cio 0").is_ok());
+    }
+
+    #[test]
+    fn maps_retentive_omron_areas() {
+        let profile = OmronProfile::new("CJ2M-CPU31".to_string(), PlcHardwareTopology::default());
+
+        let d = profile.parse_address("D100").expect("D should parse");
+        let metadata = profile.validate_address(&d).unwrap();
+        assert!(metadata.retained);
+        assert_eq!(
+            profile.to_canonical(&d).unwrap(),
+            CanonicalAddress::new(CanonicalAreaKind::RetentiveWord, 100)
+        );
+
+        let h = profile.parse_address("H1535.15").expect("H should parse");
+        assert!(profile.validate_address(&h).unwrap().retained);
+
+        let w = profile.parse_address("W0.00").unwrap();
+        assert!(!profile.validate_address(&w).unwrap().retained);
+    }
+
+    #[test]
+    fn rejects_invalid_omron_addresses() {
+        let profile = OmronProfile::new("CJ2M-CPU31".to_string(), PlcHardwareTopology::default());
+
+        assert!(matches!(
+            profile.parse_address("W10.16"),
+            Err(VendorProfileError::BitIndexOutOfRange { bit_index: 16 })
+        ));
+        assert!(matches!(
+            profile.parse_address("160.00"),
+            Err(VendorProfileError::AddressOutOfRange { .. })
+        ));
+        assert!(profile.parse_address("T10.01").is_err());
+    }
+}
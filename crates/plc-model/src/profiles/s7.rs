@@ -0,0 +1,704 @@
+// Siemens S7-1200/1500 프로파일 — 바이트 주소 체계(I0.0, MW10, DB1.DBX0.1)를
+// canonical 영역으로 옮긴다.
+//
+// - I/Q 비트는 `바이트 × 8 + 비트` 로 InputBit/OutputBit 에 놓인다.
+// - M 과 데이터 블록은 DataWord 위의 한 바이트 이미지다: 앞쪽이 M 영역, 그 뒤로
+//   메모리 레이아웃의 `DB<n>` 섹션이 번호 순으로 워드 경계에 맞춰 이어진다.
+//   M10.0 은 MB10 의 bit 0 이고 MW9·MW10·MD10 과 겹친다(LS %MX/%MW/%MD 와 같은 방식).
+// - S7 은 빅엔디언이라 워드의 짝수 바이트가 상위 바이트다(DBX0.1 = 워드 0 의 bit 9).
+//   홀수 바이트에서 시작하는 워드와 더블워드는 `canonical_span` 으로 바이트 구간을 준다.
+
+use std::collections::BTreeMap;
+use std::ops::Range;
+
+use crate::hardware::{PlcHardwareTopology, PlcManufacturer};
+use crate::memory_layout::{builtin_memory_layout, PlcMemoryLayout};
+
+use crate::profile::{
+    format_vendor_address, split_vendor_address, CanonicalByteSpan, ModbusAddressSpace,
+    ModbusMappingPolicy, ModbusMappingRule, ModbusMappingSource, OpcUaAliasPolicy, VendorAddress,
+    VendorAddressMetadata, VendorAddressNumberBase, VendorDataKind, VendorProfile,
+    VendorProfileError, VendorProfileId,
+};
+use modone_contract::{CanonicalAddress, CanonicalAreaKind};
+
+const S7_FAMILIES: [&str; 7] = ["MW", "MD", "I", "Q", "M", "T", "C"];
+const S7_DB_FAMILIES: [&str; 3] = ["DBX", "DBW", "DBD"];
+
+/// Memory layout section holding the M area size in bytes.
+pub const S7_MERKER_SECTION: &str = "M";
+/// Prefix of the memory layout sections sizing data blocks (`DB1` = bytes of DB1).
+pub const S7_DB_SECTION_PREFIX: &str = "DB";
+
+#[derive(Debug, Clone)]
+pub struct S7Profile {
+    model: String,
+    hardware_topology: PlcHardwareTopology,
+    memory_layout: PlcMemoryLayout,
+    merker_bytes: u32,
+    /// Byte-image ranges of the configured data blocks.
+    data_blocks: BTreeMap<u16, Range<u32>>,
+}
+
+impl S7Profile {
+    pub fn new(model: String, hardware_topology: PlcHardwareTopology) -> Self {
+        let memory_layout = builtin_memory_layout(VendorProfileId::SiemensS7, &model);
+
+        Self {
+            model,
+            hardware_topology,
+            memory_layout: PlcMemoryLayout::default(),
+            merker_bytes: 0,
+            data_blocks: BTreeMap::new(),
+        }
+        .with_memory_layout(memory_layout)
+    }
+
+    /// Replace the model's built-in device sizes (project overrides applied).
+    /// DataWord grows to hold the M area and every configured data block.
+    pub fn with_memory_layout(mut self, mut memory_layout: PlcMemoryLayout) -> Self {
+        let merker_bytes = memory_layout
+            .section(S7_MERKER_SECTION)
+            .unwrap_or(CanonicalAreaKind::InternalBit.default_size() as u32 / 8);
+        let sizes: BTreeMap<u16, u32> = memory_layout
+            .sections
+            .iter()
+            .filter_map(|(name, size)| {
+                let number = name
+                    .strip_prefix(S7_DB_SECTION_PREFIX)?
+                    .parse::<u16>()
+                    .ok()?;
+                (number > 0).then_some((number, *size))
+            })
+            .collect();
+
+        // 블록마다 짝수 바이트에서 시작해 워드 경계를 맞춘다
+        let mut next = merker_bytes.next_multiple_of(2);
+        let data_blocks = sizes
+            .into_iter()
+            .map(|(number, size)| {
+                let range = next..next + size;
+                next += size.next_multiple_of(2);
+                (number, range)
+            })
+            .collect();
+
+        let data_words = memory_layout
+            .size(CanonicalAreaKind::DataWord)
+            .max(next / 2);
+        memory_layout
+            .sizes
+            .insert(CanonicalAreaKind::DataWord, data_words);
+
+        self.memory_layout = memory_layout;
+        self.merker_bytes = merker_bytes;
+        self.data_blocks = data_blocks;
+        self
+    }
+
+    /// Numbers of the data blocks declared in the memory layout.
+    pub fn data_blocks(&self) -> impl Iterator<Item = u16> + '_ {
+        self.data_blocks.keys().copied()
+    }
+
+    /// Canonical DataWord range of data block `number`.
+    pub fn data_block_range(&self, number: u16) -> Option<Range<u32>> {
+        self.data_blocks
+            .get(&number)
+            .map(|bytes| bytes.start / 2..bytes.end.div_ceil(2))
+    }
+
+    fn data_block_bytes(&self, number: Option<u16>) -> u32 {
+        number
+            .and_then(|number| self.data_blocks.get(&number))
+            .map_or(0, |bytes| bytes.end - bytes.start)
+    }
+
+    /// Offset of the address's first byte in the M/DB byte image.
+    fn image_offset(&self, address: &VendorAddress) -> u32 {
+        let base = address
+            .data_block
+            .and_then(|number| self.data_blocks.get(&number))
+            .map_or(0, |bytes| bytes.start);
+        base + address.index
+    }
+
+    fn metadata_for(
+        &self,
+        family: &str,
+        data_block: Option<u16>,
+    ) -> Result<VendorAddressMetadata, VendorProfileError> {
+        let byte_max =
+            |area: CanonicalAreaKind| (self.memory_layout.size(area) / 8).saturating_sub(1);
+        let image = |data_kind: VendorDataKind, bytes: u32| {
+            let width = match data_kind {
+                VendorDataKind::Bit => 1,
+                VendorDataKind::Word => 2,
+                VendorDataKind::DoubleWord => 4,
+            };
+            VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::DataWord,
+                access: CanonicalAreaKind::DataWord.default_access(),
+                retained: false,
+                data_kind,
+                supports_bit_index: data_kind == VendorDataKind::Bit,
+                max_index: bytes.saturating_sub(width),
+                number_base: VendorAddressNumberBase::Decimal,
+            }
+        };
+        let db_bytes = self.data_block_bytes(data_block);
+
+        let metadata = match family {
+            "I" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::InputBit,
+                access: CanonicalAreaKind::InputBit.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: true,
+                max_index: byte_max(CanonicalAreaKind::InputBit),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "Q" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::OutputBit,
+                access: CanonicalAreaKind::OutputBit.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: true,
+                max_index: byte_max(CanonicalAreaKind::OutputBit),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "M" => image(VendorDataKind::Bit, self.merker_bytes),
+            "MW" => image(VendorDataKind::Word, self.merker_bytes),
+            "MD" => image(VendorDataKind::DoubleWord, self.merker_bytes),
+            "T" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::TimerDoneBit,
+                access: CanonicalAreaKind::TimerDoneBit.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: false,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::TimerDoneBit),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "C" => VendorAddressMetadata {
+                canonical_area: CanonicalAreaKind::CounterDoneBit,
+                access: CanonicalAreaKind::CounterDoneBit.default_access(),
+                retained: false,
+                data_kind: VendorDataKind::Bit,
+                supports_bit_index: false,
+                max_index: self
+                    .memory_layout
+                    .max_index(CanonicalAreaKind::CounterDoneBit),
+                number_base: VendorAddressNumberBase::Decimal,
+            },
+            "DBX" => image(VendorDataKind::Bit, db_bytes),
+            "DBW" => image(VendorDataKind::Word, db_bytes),
+            "DBD" => image(VendorDataKind::DoubleWord, db_bytes),
+            _ => {
+                return Err(VendorProfileError::UnsupportedFamily {
+                    profile_id: VendorProfileId::SiemensS7,
+                    family: family.to_string(),
+                });
+            }
+        };
+
+        Ok(metadata)
+    }
+
+    /// M/DB alias of image byte `byte`: a bit when `bit` is given, else the word there.
+    fn image_alias(&self, byte: u32, bit: Option<u8>) -> Option<VendorAddress> {
+        let (data_block, offset) = if byte < self.merker_bytes {
+            (None, byte)
+        } else {
+            let (number, bytes) = self
+                .data_blocks
+                .iter()
+                .find(|(_, bytes)| bytes.contains(&byte))?;
+            (Some(*number), byte - bytes.start)
+        };
+
+        let family = match (data_block, bit) {
+            (None, Some(_)) => "M",
+            (None, None) => "MW",
+            (Some(_), Some(_)) => "DBX",
+            (Some(_), None) => "DBW",
+        };
+        let mut alias = VendorAddress::new(family, offset);
+        if let Some(bit) = bit {
+            alias = alias.with_bit_index(bit);
+        }
+        if let Some(data_block) = data_block {
+            alias = alias.with_data_block(data_block);
+        }
+        self.validate_address(&alias).is_ok().then_some(alias)
+    }
+}
+
+fn is_data_block_family(family: &str) -> bool {
+    S7_DB_FAMILIES.contains(&family)
+}
+
+/// Families addressed as `byte.bit`.
+fn is_byte_bit_family(family: &str) -> bool {
+    matches!(family, "I" | "Q" | "M" | "DBX")
+}
+
+/// DataWord bit holding bit `bit` of image byte `byte` (even bytes are high bytes).
+fn image_bit(byte: u32, bit: u32) -> CanonicalAddress {
+    let word_bit = if byte % 2 == 0 { bit + 8 } else { bit };
+    CanonicalAddress::with_bit_index(CanonicalAreaKind::DataWord, byte / 2, word_bit as u8)
+}
+
+fn malformed(address: &VendorAddress, reason: &str) -> VendorProfileError {
+    VendorProfileError::MalformedAddress {
+        input: format!("{}{}", address.family, address.index),
+        reason: reason.to_string(),
+    }
+}
+
+impl VendorProfile for S7Profile {
+    fn id(&self) -> VendorProfileId {
+        VendorProfileId::SiemensS7
+    }
+
+    fn display_name(&self) -> &'static str {
+        "Siemens S7-1200/1500 Profile"
+    }
+
+    fn manufacturer(&self) -> PlcManufacturer {
+        PlcManufacturer::Siemens
+    }
+
+    fn hardware_topology(&self) -> &PlcHardwareTopology {
+        &self.hardware_topology
+    }
+
+    fn memory_layout(&self) -> &PlcMemoryLayout {
+        &self.memory_layout
+    }
+
+    fn model_hint(&self) -> Option<&str> {
+        if self.model.is_empty() {
+            None
+        } else {
+            Some(&self.model)
+        }
+    }
+
+    fn parse_address(&self, input: &str) -> Result<VendorAddress, VendorProfileError> {
+        let trimmed = input.trim().to_uppercase();
+
+        // DB1.DBX0.1 — 블록 번호와 블록 내 주소를 나눠 파싱
+        let (data_block, member) = match trimmed
+            .strip_prefix("DB")
+            .and_then(|rest| rest.split_once('.'))
+        {
+            Some((number, member)) => {
+                let number =
+                    number
+                        .parse::<u16>()
+                        .map_err(|_| VendorProfileError::MalformedAddress {
+                            input: input.to_string(),
+                            reason: "invalid data block number".to_string(),
+                        })?;
+                (Some(number), member)
+            }
+            None => (None, trimmed.as_str()),
+        };
+
+        let families: &[&str] = if data_block.is_some() {
+            &S7_DB_FAMILIES
+        } else {
+            &S7_FAMILIES
+        };
+        let (family, number_part, bit_index, index_register) =
+            split_vendor_address(member, families)?;
+        let metadata = self.metadata_for(&family, data_block)?;
+        let index = metadata.number_base.parse(&number_part)?;
+
+        let mut address = VendorAddress::new(family, index);
+        if let Some(bit_index) = bit_index {
+            address = address.with_bit_index(bit_index);
+        }
+        if let Some(index_register) = index_register {
+            address = address.with_index_register(index_register);
+        }
+        if let Some(data_block) = data_block {
+            address = address.with_data_block(data_block);
+        }
+
+        self.validate_address(&address)?;
+        Ok(address)
+    }
+
+    fn format_address(&self, address: &VendorAddress) -> Result<String, VendorProfileError> {
+        let metadata = self.validate_address(address)?;
+        let member = format_vendor_address(address, metadata.number_base, 1);
+        Ok(match address.data_block {
+            Some(data_block) => format!("DB{data_block}.{member}"),
+            None => member,
+        })
+    }
+
+    fn validate_address(
+        &self,
+        address: &VendorAddress,
+    ) -> Result<VendorAddressMetadata, VendorProfileError> {
+        let metadata = self.metadata_for(&address.family, address.data_block)?;
+
+        match (is_data_block_family(&address.family), address.data_block) {
+            (true, None) => return Err(malformed(address, "data block number is required")),
+            (true, Some(data_block)) if !self.data_blocks.contains_key(&data_block) => {
+                return Err(malformed(
+                    address,
+                    &format!("DB{data_block} is not declared in the memory layout"),
+                ));
+            }
+            (false, Some(_)) => {
+                return Err(malformed(address, "data block number on a non-DB family"));
+            }
+            _ => {}
+        }
+
+        if address.index > metadata.max_index {
+            return Err(VendorProfileError::AddressOutOfRange {
+                profile_id: self.id(),
+                family: address.family.clone(),
+                index: address.index,
+                max_index: metadata.max_index,
+            });
+        }
+
+        if is_byte_bit_family(&address.family) {
+            match address.bit_index {
+                None => return Err(malformed(address, "bit address requires a `.bit` suffix")),
+                Some(bit_index) if bit_index >= 8 => {
+                    return Err(VendorProfileError::BitIndexOutOfRange { bit_index });
+                }
+                Some(_) => {}
+            }
+        } else if address.bit_index.is_some() {
+            return Err(VendorProfileError::BitIndexNotSupported {
+                profile_id: self.id(),
+                family: address.family.clone(),
+            });
+        }
+
+        if address.index_register.is_some() {
+            return Err(VendorProfileError::IndexedAddressNotSupported {
+                profile_id: self.id(),
+                family: address.family.clone(),
+            });
+        }
+
+        Ok(metadata)
+    }
+
+    fn to_canonical(
+        &self,
+        address: &VendorAddress,
+    ) -> Result<CanonicalAddress, VendorProfileError> {
+        let metadata = self.validate_address(address)?;
+        let bit = u32::from(address.bit_index.unwrap_or_default());
+
+        let canonical = match address.family.as_str() {
+            "I" | "Q" => CanonicalAddress::new(metadata.canonical_area, address.index * 8 + bit),
+            "M" | "DBX" => image_bit(self.image_offset(address), bit),
+            "MW" | "MD" | "DBW" | "DBD" => {
+                CanonicalAddress::new(metadata.canonical_area, self.image_offset(address) / 2)
+            }
+            _ => CanonicalAddress::new(metadata.canonical_area, address.index),
+        };
+
+        Ok(canonical)
+    }
+
+    fn canonical_span(
+        &self,
+        address: &VendorAddress,
+    ) -> Result<Option<CanonicalByteSpan>, VendorProfileError> {
+        let metadata = self.validate_address(address)?;
+        let len = match metadata.data_kind {
+            VendorDataKind::Bit => return Ok(None),
+            VendorDataKind::Word => 2,
+            VendorDataKind::DoubleWord => 4,
+        };
+
+        let byte_offset = self.image_offset(address);
+        if len == 2 && byte_offset % 2 == 0 {
+            return Ok(None);
+        }
+        Ok(Some(CanonicalByteSpan {
+            area: CanonicalAreaKind::DataWord,
+            byte_offset,
+            len,
+            big_endian: true,
+        }))
+    }
+
+    fn canonical_aliases(&self, canonical: &CanonicalAddress) -> Vec<VendorAddress> {
+        let alias = match canonical.area {
+            CanonicalAreaKind::InputBit | CanonicalAreaKind::OutputBit
+                if canonical.bit_index.is_none() =>
+            {
+                let family = match canonical.area {
+                    CanonicalAreaKind::InputBit => "I",
+                    _ => "Q",
+                };
+                Some(
+                    VendorAddress::new(family, canonical.index / 8)
+                        .with_bit_index((canonical.index % 8) as u8),
+                )
+            }
+            CanonicalAreaKind::TimerDoneBit => Some(VendorAddress::new("T", canonical.index)),
+            CanonicalAreaKind::CounterDoneBit => Some(VendorAddress::new("C", canonical.index)),
+            CanonicalAreaKind::DataWord => match canonical.bit_index {
+                // 상위 바이트(bit 8~15)가 짝수 바이트
+                Some(bit) if bit >= 8 => self.image_alias(canonical.index * 2, Some(bit - 8)),
+                Some(bit) => self.image_alias(canonical.index * 2 + 1, Some(bit)),
+                None => self.image_alias(canonical.index * 2, None),
+            },
+            _ => None,
+        };
+
+        alias.into_iter().collect()
+    }
+
+    fn recommended_modbus_mapping_policy(&self) -> ModbusMappingPolicy {
+        ModbusMappingPolicy {
+            profile_id: self.id().as_str().to_string(),
+            source: ModbusMappingSource::Recommended,
+            rules: vec![
+                ModbusMappingRule {
+                    family: "Q".to_string(),
+                    canonical_area: CanonicalAreaKind::OutputBit,
+                    address_space: ModbusAddressSpace::Coil,
+                    offset: 0,
+                    count: 1024,
+                },
+                ModbusMappingRule {
+                    family: "I".to_string(),
+                    canonical_area: CanonicalAreaKind::InputBit,
+                    address_space: ModbusAddressSpace::DiscreteInput,
+                    offset: 0,
+                    count: 1024,
+                },
+                // M 바이트 이미지 뒤로 DB 블록이 이어진다(M 비트는 이 워드들의 비트다)
+                ModbusMappingRule {
+                    family: "MW".to_string(),
+                    canonical_area: CanonicalAreaKind::DataWord,
+                    address_space: ModbusAddressSpace::HoldingRegister,
+                    offset: 0,
+                    count: 10000,
+                },
+            ],
+        }
+    }
+
+    fn legacy_modbus_mapping_policy(&self) -> ModbusMappingPolicy {
+        ModbusMappingPolicy {
+            profile_id: self.id().as_str().to_string(),
+            source: ModbusMappingSource::LegacyWide,
+            rules: vec![
+                ModbusMappingRule {
+                    family: "I".to_string(),
+                    canonical_area: CanonicalAreaKind::InputBit,
+                    address_space: ModbusAddressSpace::DiscreteInput,
+                    offset: 0,
+                    count: 2048,
+                },
+                ModbusMappingRule {
+                    family: "Q".to_string(),
+                    canonical_area: CanonicalAreaKind::OutputBit,
+                    address_space: ModbusAddressSpace::Coil,
+                    offset: 0,
+                    count: 2048,
+                },
+                ModbusMappingRule {
+                    family: "T".to_string(),
+                    canonical_area: CanonicalAreaKind::TimerDoneBit,
+                    address_space: ModbusAddressSpace::DiscreteInput,
+                    offset: 2048,
+                    count: 2048,
+                },
+                ModbusMappingRule {
+                    family: "C".to_string(),
+                    canonical_area: CanonicalAreaKind::CounterDoneBit,
+                    address_space: ModbusAddressSpace::DiscreteInput,
+                    offset: 4096,
+                    count: 2048,
+                },
+                ModbusMappingRule {
+                    family: "MW".to_string(),
+                    canonical_area: CanonicalAreaKind::DataWord,
+                    address_space: ModbusAddressSpace::HoldingRegister,
+                    offset: 0,
+                    count: 10000,
+                },
+            ],
+        }
+    }
+
+    fn opcua_alias_policy(&self) -> OpcUaAliasPolicy {
+        OpcUaAliasPolicy {
+            expose_vendor_aliases: true,
+            namespace_segment: "S7".to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s7_1200() -> S7Profile {
+        S7Profile::new("S7-1200".to_string(), PlcHardwareTopology::default())
+    }
+
+    #[test]
+    fn parses_byte_bit_and_merker_word_addresses() {
+        let profile = s7_1200();
+
+        let q = profile.parse_address("Q4.7").expect("Q bit should parse");
+        assert_eq!(profile.format_address(&q).unwrap(), "Q4.7");
+        assert_eq!(
+            profile.to_canonical(&q).unwrap(),
+            CanonicalAddress::new(CanonicalAreaKind::OutputBit, 39)
+        );
+
+        let mw = profile.parse_address("MW10").expect("MW should parse");
+        assert_eq!(
+            profile.to_canonical(&mw).unwrap(),
+            CanonicalAddress::new(CanonicalAreaKind::DataWord, 5)
+        );
+        assert_eq!(profile.canonical_span(&mw), Ok(None));
+        let md = profile.parse_address("md20").expect("MD should parse");
+        assert_eq!(
+            profile.validate_address(&md).unwrap().data_kind,
+            VendorDataKind::DoubleWord
+        );
+
+        let t = profile.parse_address("T5").expect("timer should parse");
+        assert_eq!(
+            profile.to_canonical(&t).unwrap().area,
+            CanonicalAreaKind::TimerDoneBit
+        );
+
+        let alias = profile.preferred_alias(&CanonicalAddress::new(CanonicalAreaKind::InputBit, 1));
+        assert_eq!(profile.format_address(&alias.unwrap()).unwrap(), "I0.1");
+    }
+
+    #[test]
+    fn overlays_merker_bits_words_and_double_words() {
+        let profile = s7_1200();
+
+        // M10.0 은 MB10 의 bit 0 — 짝수 바이트라 MW10 의 상위 바이트
+        let m = profile.parse_address("M10.0").unwrap();
+        let bit = profile.to_canonical(&m).unwrap();
+        assert_eq!(
+            bit,
+            CanonicalAddress::with_bit_index(CanonicalAreaKind::DataWord, 5, 8)
+        );
+        assert_eq!(profile.preferred_alias(&bit), Some(m));
+        let m11 = profile.parse_address("M11.7").unwrap();
+        assert_eq!(
+            profile.to_canonical(&m11).unwrap(),
+            CanonicalAddress::with_bit_index(CanonicalAreaKind::DataWord, 5, 7)
+        );
+
+        // MW9 는 MB9(워드 4 하위)와 MB10(워드 5 상위)에 걸친다
+        let mw9 = profile
+            .parse_address("MW9")
+            .expect("odd word offsets are legal");
+        let span = profile.canonical_span(&mw9).unwrap().unwrap();
+        assert_eq!(span.byte_offset, 9);
+        assert_eq!(span.words(), 4..6);
+        assert_eq!(span.decode(&[0x0012, 0x3400]), 0x1234);
+
+        let md10 = profile.parse_address("MD10").unwrap();
+        let span = profile.canonical_span(&md10).unwrap().unwrap();
+        assert_eq!(span.words(), 5..7);
+        let mut words = [0, 0];
+        span.encode(&mut words, 0x1234_5678);
+        assert_eq!(words, [0x1234, 0x5678]);
+        assert_eq!(span.decode(&words), 0x1234_5678);
+
+        let dbw3 = profile
+            .parse_address("DB1.DBW3")
+            .expect("odd DB offsets are legal");
+        assert_eq!(profile.format_address(&dbw3).unwrap(), "DB1.DBW3");
+        assert!(profile.parse_address("MW8191").is_err());
+        assert!(profile.parse_address("MD8189").is_err());
+    }
+
+    #[test]
+    fn maps_data_blocks_into_data_word_ranges() {
+        let profile = s7_1200();
+        // S7-1200: M 8192 바이트 → 4096 워드 뒤에서 DB1(16KB) 시작
+        assert_eq!(profile.data_block_range(1), Some(4096..12288));
+        assert_eq!(profile.data_blocks().collect::<Vec<_>>(), vec![1]);
+
+        let dbw = profile
+            .parse_address("DB1.DBW10")
+            .expect("DBW should parse");
+        let canonical = profile.to_canonical(&dbw).unwrap();
+        assert_eq!(canonical.index, 4096 + 5);
+        assert_eq!(
+            profile.format_address(&profile.preferred_alias(&canonical).unwrap()),
+            Ok("DB1.DBW10".to_string())
+        );
+
+        let dbx = profile
+            .parse_address("DB1.DBX0.1")
+            .expect("DBX should parse");
+        let canonical = profile.to_canonical(&dbx).unwrap();
+        assert_eq!(canonical.index, 4096);
+        assert_eq!(canonical.bit_index, Some(9));
+        assert_eq!(profile.preferred_alias(&canonical), Some(dbx));
+    }
+
+    #[test]
+    fn sizes_data_blocks_from_memory_layout_sections() {
+        let layout = builtin_memory_layout(VendorProfileId::SiemensS7, "S7-1200")
+            .with_sections(&[("DB100", 100), ("DB2", 9)]);
+        let profile = s7_1200().with_memory_layout(layout);
+
+        assert_eq!(profile.data_blocks().collect::<Vec<_>>(), vec![1, 2, 100]);
+        // 번호 순으로 워드 경계에 맞춰 잇는다: DB1 16KB, DB2 9 바이트(5 워드), DB100
+        assert_eq!(profile.data_block_range(2), Some(12288..12293));
+        assert_eq!(profile.data_block_range(100), Some(12293..12343));
+        assert_eq!(
+            profile.memory_layout().size(CanonicalAreaKind::DataWord),
+            12343
+        );
+
+        let dbw = profile.parse_address("DB100.DBW98").unwrap();
+        assert_eq!(profile.to_canonical(&dbw).unwrap().index, 12293 + 49);
+        assert!(matches!(
+            profile.parse_address("DB100.DBW99"),
+            Err(VendorProfileError::AddressOutOfRange { max_index: 98, .. })
+        ));
+        assert!(profile.parse_address("DB2.DBD5").is_ok());
+        assert!(profile.parse_address("DB2.DBD6").is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_s7_addresses() {
+        let profile = s7_1200();
+
+        assert!(matches!(
+            profile.parse_address("I0"),
+            Err(VendorProfileError::MalformedAddress { .. })
+        ));
+        assert!(matches!(
+            profile.parse_address("I0.8"),
+            Err(VendorProfileError::BitIndexOutOfRange { bit_index: 8 })
+        ));
+        assert!(matches!(
+            profile.parse_address("DB999.DBW0"),
+            Err(VendorProfileError::MalformedAddress { .. })
+        ));
+        assert!(profile.parse_address("DBW0").is_err());
+    }
+}
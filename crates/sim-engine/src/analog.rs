@@ -0,0 +1,796 @@
+//! 아날로그 I/O 모듈 시뮬레이션
+//!
+//! 아날로그 입력 모듈은 채널별 현장 값(공학 단위: mA/V)을 디지털 변환값으로 바꿔
+//! 모듈의 버퍼/매핑 워드에 쓰고, 아날로그 출력 모듈은 프로그램이 쓴 디지털 값을
+//! 공학 단위로 되돌려 보여 준다. 채널마다 신호 범위(4-20 mA, 0-10 V, ±10 V)와
+//! 디지털 분해능(0-16000, 0-27648 등), 필터를 설정하고, 범위 초과·단선 플래그를
+//! 상태 워드의 채널 비트로 낸다.
+//!
+//! 입력 채널의 현장 값은 [`AnalogModuleSimulator::set_engineering_value`]로 직접
+//! 주거나, 소스 워드를 지정해 캔버스·시나리오가 그 워드에 쓴 값을 스캔마다 읽게
+//! 한다. 호스트는 스캔이 끝날 때마다 [`AnalogModuleSimulator::apply`]를 고장
+//! 주입보다 먼저 호출하므로 변환값 위에 노이즈·스파이크를 얹을 수 있고, 프로그램은
+//! 다음 스캔부터 새 변환값을 읽는다. 모든 쓰기는 `CanonicalWriteSource::Simulation`
+//! 으로 남고, 이벤트 재생은 [`AnalogModuleSimulator::output_addresses`]로 모듈이
+//! 다시 만들 쓰기를 건너뛴다.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use modone_contract::{CanonicalAddress, CanonicalValue, CanonicalWriteSource};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::memory::{CanonicalRuntimeFacade, SimMemoryError};
+
+/// 4-20 mA 채널은 이 값 미만이면 단선으로 본다
+const WIRE_BREAK_THRESHOLD_MA: f64 = 2.0;
+/// 상태 워드 하나에 담을 수 있는 채널 수
+const MAX_CHANNELS: usize = 16;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Analog module simulation error types
+#[derive(Debug, Error)]
+pub enum AnalogError {
+    #[error("Analog module layout requires a word address: {0:?}")]
+    WordAddressRequired(CanonicalAddress),
+    #[error("Invalid analog module parameter: {0}")]
+    InvalidParameter(String),
+    #[error("Analog module not found: {0}")]
+    ModuleNotFound(String),
+    #[error("Analog module '{module}' has no channel {channel}")]
+    ChannelNotFound { module: String, channel: usize },
+    #[error("Analog module '{0}' is an output module")]
+    NotAnInput(String),
+    #[error(transparent)]
+    Memory(#[from] SimMemoryError),
+}
+
+pub type AnalogResult<T> = Result<T, AnalogError>;
+
+// ============================================================================
+// Configuration Types
+// ============================================================================
+
+/// Signal range of an analog channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AnalogSignalRange {
+    /// 4 to 20 mA current loop
+    Current4To20mA,
+    /// 0 to 10 V
+    Voltage0To10V,
+    /// -10 to +10 V
+    VoltageBipolar10V,
+}
+
+impl AnalogSignalRange {
+    /// Nominal engineering span (low, high)
+    pub fn span(&self) -> (f64, f64) {
+        match self {
+            Self::Current4To20mA => (4.0, 20.0),
+            Self::Voltage0To10V => (0.0, 10.0),
+            Self::VoltageBipolar10V => (-10.0, 10.0),
+        }
+    }
+
+    pub fn unit(&self) -> &'static str {
+        match self {
+            Self::Current4To20mA => "mA",
+            Self::Voltage0To10V | Self::VoltageBipolar10V => "V",
+        }
+    }
+
+    /// Only live-zero current loops can tell a cut wire from a zero signal
+    pub fn detects_wire_break(&self) -> bool {
+        matches!(self, Self::Current4To20mA)
+    }
+}
+
+/// Direction of an analog module
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AnalogDirection {
+    Input,
+    Output,
+}
+
+/// Input filtering applied before conversion
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum AnalogFilter {
+    #[default]
+    None,
+    /// First-order lag with the given time constant
+    #[serde(rename_all = "camelCase")]
+    FirstOrder { time_constant_ms: u64 },
+    /// Mean of the last N samples (one sample per scan)
+    #[serde(rename_all = "camelCase")]
+    MovingAverage { samples: usize },
+}
+
+/// Word that drives an input channel's engineering value
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalogSource {
+    pub address: CanonicalAddress,
+    /// Engineering units per count (e.g. 0.01 for a word holding mA × 100)
+    #[serde(default = "default_source_scale")]
+    pub scale: f64,
+    /// Interpret the word as i16
+    #[serde(default)]
+    pub signed: bool,
+}
+
+fn default_source_scale() -> f64 {
+    1.0
+}
+
+/// Per-channel configuration
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalogChannelConfig {
+    pub range: AnalogSignalRange,
+    /// Digital value at the low end of the range (0, or e.g. -27648 for ±10 V)
+    pub digital_min: i32,
+    /// Digital value at the high end of the range (e.g. 16000 or 27648)
+    pub digital_max: i32,
+    #[serde(default)]
+    pub filter: AnalogFilter,
+    #[serde(default)]
+    pub source: Option<AnalogSource>,
+}
+
+impl AnalogChannelConfig {
+    /// Channel with a symmetric digital range for bipolar signals
+    pub fn new(range: AnalogSignalRange, digital_max: i32) -> Self {
+        let digital_min = if range.span().0 < 0.0 {
+            -digital_max
+        } else {
+            0
+        };
+        Self {
+            range,
+            digital_min,
+            digital_max,
+            filter: AnalogFilter::None,
+            source: None,
+        }
+    }
+
+    fn validate(&self, channel: usize) -> AnalogResult<()> {
+        let word_range = i16::MIN as i32..=i16::MAX as i32;
+        if self.digital_min >= self.digital_max
+            || !word_range.contains(&self.digital_min)
+            || !word_range.contains(&self.digital_max)
+        {
+            return Err(AnalogError::InvalidParameter(format!(
+                "channel {channel}: digital range {}..{} must be increasing and fit a 16-bit word",
+                self.digital_min, self.digital_max
+            )));
+        }
+        if matches!(self.filter, AnalogFilter::MovingAverage { samples: 0 }) {
+            return Err(AnalogError::InvalidParameter(format!(
+                "channel {channel}: moving average needs at least one sample"
+            )));
+        }
+        if let Some(source) = &self.source {
+            if source.address.area.is_bit_area() || source.address.bit_index.is_some() {
+                return Err(AnalogError::WordAddressRequired(source.address));
+            }
+            if !source.scale.is_finite() {
+                return Err(AnalogError::InvalidParameter(format!(
+                    "channel {channel}: source scale must be finite"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    fn digital_for(&self, engineering: f64) -> i32 {
+        let (low, high) = self.range.span();
+        let ratio = (engineering - low) / (high - low);
+        let digital =
+            self.digital_min as f64 + ratio * (self.digital_max - self.digital_min) as f64;
+        // 범위 밖 값은 선형으로 연장하되 16비트 워드를 넘지 않게 자른다
+        digital.round().clamp(i16::MIN as f64, i16::MAX as f64) as i32
+    }
+
+    fn engineering_for(&self, digital: i32) -> f64 {
+        let (low, high) = self.range.span();
+        let ratio =
+            (digital - self.digital_min) as f64 / (self.digital_max - self.digital_min) as f64;
+        low + ratio * (high - low)
+    }
+
+    fn decode_word(&self, word: u16) -> i32 {
+        if self.digital_min < 0 {
+            word as i16 as i32
+        } else {
+            word as i32
+        }
+    }
+}
+
+/// Where a module's words live in canonical memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalogWordLayout {
+    /// Channel 0 value word; channel n lives at `values + n`
+    pub values: CanonicalAddress,
+    /// Out-of-range flags, bit n for channel n
+    #[serde(default)]
+    pub range_status: Option<CanonicalAddress>,
+    /// Wire-break flags, bit n for channel n
+    #[serde(default)]
+    pub wire_break_status: Option<CanonicalAddress>,
+}
+
+impl AnalogWordLayout {
+    /// Values followed by the range and wire-break status words
+    pub fn contiguous(values: CanonicalAddress, channels: usize) -> Self {
+        let status =
+            |offset: usize| CanonicalAddress::new(values.area, values.index + offset as u32);
+        Self {
+            values,
+            range_status: Some(status(channels)),
+            wire_break_status: Some(status(channels + 1)),
+        }
+    }
+
+    fn channel(&self, channel: usize) -> CanonicalAddress {
+        CanonicalAddress::new(self.values.area, self.values.index + channel as u32)
+    }
+
+    fn validate(&self) -> AnalogResult<()> {
+        for address in [Some(self.values), self.range_status, self.wire_break_status]
+            .into_iter()
+            .flatten()
+        {
+            if address.area.is_bit_area() || address.bit_index.is_some() {
+                return Err(AnalogError::WordAddressRequired(address));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Analog module definition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalogModuleConfig {
+    /// Module identifier, e.g. the rack/slot label
+    pub id: String,
+    pub direction: AnalogDirection,
+    pub layout: AnalogWordLayout,
+    pub channels: Vec<AnalogChannelConfig>,
+}
+
+impl AnalogModuleConfig {
+    fn validate(&self) -> AnalogResult<()> {
+        if self.id.trim().is_empty() {
+            return Err(AnalogError::InvalidParameter(
+                "module id must not be empty".to_string(),
+            ));
+        }
+        if self.channels.is_empty() || self.channels.len() > MAX_CHANNELS {
+            return Err(AnalogError::InvalidParameter(format!(
+                "module '{}' needs 1 to {MAX_CHANNELS} channels",
+                self.id
+            )));
+        }
+        self.layout.validate()?;
+        for (channel, config) in self.channels.iter().enumerate() {
+            config.validate(channel)?;
+        }
+        Ok(())
+    }
+}
+
+/// Channel state reported to the UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalogChannelState {
+    pub channel: usize,
+    pub range: AnalogSignalRange,
+    /// Filtered field value (inputs) or converted output value, in `unit`
+    pub engineering: f64,
+    pub unit: String,
+    pub digital: i32,
+    pub over_range: bool,
+    pub under_range: bool,
+    pub wire_break: bool,
+}
+
+/// Module state reported to the UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalogModuleInfo {
+    pub id: String,
+    pub direction: AnalogDirection,
+    pub layout: AnalogWordLayout,
+    pub channels: Vec<AnalogChannelState>,
+}
+
+// ============================================================================
+// Simulator
+// ============================================================================
+
+struct AnalogChannel {
+    config: AnalogChannelConfig,
+    /// Field value set directly or read from the source word
+    field: f64,
+    /// Value after filtering
+    filtered: Option<f64>,
+    samples: VecDeque<f64>,
+    last_sample_ms: Option<u64>,
+    /// Simulated cut cable
+    broken: bool,
+    digital: i32,
+    engineering: f64,
+    over_range: bool,
+    under_range: bool,
+    wire_break: bool,
+}
+
+impl AnalogChannel {
+    fn new(config: AnalogChannelConfig) -> Self {
+        let field = config.range.span().0;
+        Self {
+            config,
+            field,
+            filtered: None,
+            samples: VecDeque::new(),
+            last_sample_ms: None,
+            broken: false,
+            digital: config.digital_for(field),
+            engineering: field,
+            over_range: false,
+            under_range: false,
+            wire_break: false,
+        }
+    }
+
+    fn filter(&mut self, value: f64, now_ms: u64) -> f64 {
+        let filtered = match self.config.filter {
+            AnalogFilter::None => value,
+            AnalogFilter::FirstOrder { time_constant_ms } => match self.filtered {
+                Some(previous) if time_constant_ms > 0 => {
+                    let dt = now_ms.saturating_sub(self.last_sample_ms.unwrap_or(now_ms)) as f64;
+                    let alpha = dt / (time_constant_ms as f64 + dt);
+                    previous + alpha * (value - previous)
+                }
+                _ => value,
+            },
+            AnalogFilter::MovingAverage { samples } => {
+                self.samples.push_back(value);
+                while self.samples.len() > samples {
+                    self.samples.pop_front();
+                }
+                self.samples.iter().sum::<f64>() / self.samples.len() as f64
+            }
+        };
+        self.filtered = Some(filtered);
+        self.last_sample_ms = Some(now_ms);
+        filtered
+    }
+
+    /// 현장 값 → 디지털 변환값. 단선이면 마지막 변환값을 유지한다.
+    fn update_input(&mut self, now_ms: u64) {
+        let measured = if self.broken { 0.0 } else { self.field };
+        let value = self.filter(measured, now_ms);
+        let (low, high) = self.config.range.span();
+
+        self.engineering = value;
+        self.wire_break = self.config.range.detects_wire_break() && value < WIRE_BREAK_THRESHOLD_MA;
+        self.under_range = value < low;
+        self.over_range = value > high;
+        if !self.wire_break {
+            self.digital = self.config.digital_for(value);
+        }
+    }
+
+    /// 디지털 출력값 → 공학 값. 출력 모듈은 범위 밖 명령을 범위 끝으로 자른다.
+    fn update_output(&mut self, word: u16) {
+        let digital = self.config.decode_word(word);
+        let (low, high) = self.config.range.span();
+
+        self.digital = digital;
+        self.under_range = digital < self.config.digital_min;
+        self.over_range = digital > self.config.digital_max;
+        self.wire_break = self.broken && self.config.range.detects_wire_break();
+        self.engineering = if self.broken {
+            0.0
+        } else {
+            self.config.engineering_for(digital).clamp(low, high)
+        };
+    }
+
+    fn state(&self, channel: usize) -> AnalogChannelState {
+        AnalogChannelState {
+            channel,
+            range: self.config.range,
+            engineering: self.engineering,
+            unit: self.config.range.unit().to_string(),
+            digital: self.digital,
+            over_range: self.over_range,
+            under_range: self.under_range,
+            wire_break: self.wire_break,
+        }
+    }
+}
+
+struct AnalogModule {
+    config: AnalogModuleConfig,
+    channels: Vec<AnalogChannel>,
+}
+
+impl AnalogModule {
+    fn info(&self) -> AnalogModuleInfo {
+        AnalogModuleInfo {
+            id: self.config.id.clone(),
+            direction: self.config.direction,
+            layout: self.config.layout,
+            channels: self
+                .channels
+                .iter()
+                .enumerate()
+                .map(|(index, channel)| channel.state(index))
+                .collect(),
+        }
+    }
+
+    fn channel_mut(&mut self, channel: usize) -> AnalogResult<&mut AnalogChannel> {
+        let module = self.config.id.clone();
+        self.channels
+            .get_mut(channel)
+            .ok_or(AnalogError::ChannelNotFound { module, channel })
+    }
+
+    fn apply(&mut self, runtime: &CanonicalRuntimeFacade, now_ms: u64) -> AnalogResult<()> {
+        let layout = self.config.layout;
+        for (index, channel) in self.channels.iter_mut().enumerate() {
+            let address = layout.channel(index);
+            match self.config.direction {
+                AnalogDirection::Input => {
+                    if let Some(source) = channel.config.source {
+                        let word = runtime.read_word_value(source.address)?;
+                        let raw = if source.signed {
+                            word as i16 as f64
+                        } else {
+                            word as f64
+                        };
+                        channel.field = raw * source.scale;
+                    }
+                    channel.update_input(now_ms);
+                    write_if_changed(runtime, address, channel.digital as i16 as u16)?;
+                }
+                AnalogDirection::Output => {
+                    channel.update_output(runtime.read_word_value(address)?);
+                }
+            }
+        }
+
+        let flags = |flag: fn(&AnalogChannel) -> bool| {
+            self.channels
+                .iter()
+                .enumerate()
+                .filter(|(_, channel)| flag(channel))
+                .fold(0u16, |word, (index, _)| word | (1 << index))
+        };
+        if let Some(address) = layout.range_status {
+            let word = flags(|channel| channel.over_range || channel.under_range);
+            write_if_changed(runtime, address, word)?;
+        }
+        if let Some(address) = layout.wire_break_status {
+            write_if_changed(runtime, address, flags(|channel| channel.wire_break))?;
+        }
+        Ok(())
+    }
+}
+
+/// Simulates analog I/O modules on the canonical runtime
+#[derive(Default)]
+pub struct AnalogModuleSimulator {
+    modules: Mutex<BTreeMap<String, AnalogModule>>,
+}
+
+impl AnalogModuleSimulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a module, replacing any module with the same id
+    pub fn configure(&self, config: AnalogModuleConfig) -> AnalogResult<()> {
+        config.validate()?;
+        let channels = config
+            .channels
+            .iter()
+            .map(|channel| AnalogChannel::new(*channel))
+            .collect();
+        self.modules
+            .lock()
+            .insert(config.id.clone(), AnalogModule { config, channels });
+        Ok(())
+    }
+
+    pub fn remove(&self, id: &str) -> AnalogResult<()> {
+        self.modules
+            .lock()
+            .remove(id)
+            .map(|_| ())
+            .ok_or_else(|| AnalogError::ModuleNotFound(id.to_string()))
+    }
+
+    pub fn clear(&self) {
+        self.modules.lock().clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.modules.lock().is_empty()
+    }
+
+    /// Set the field value of an input channel in engineering units
+    pub fn set_engineering_value(&self, id: &str, channel: usize, value: f64) -> AnalogResult<()> {
+        if !value.is_finite() {
+            return Err(AnalogError::InvalidParameter(format!(
+                "engineering value must be finite: {value}"
+            )));
+        }
+        let mut modules = self.modules.lock();
+        let module = modules
+            .get_mut(id)
+            .ok_or_else(|| AnalogError::ModuleNotFound(id.to_string()))?;
+        if module.config.direction != AnalogDirection::Input {
+            return Err(AnalogError::NotAnInput(id.to_string()));
+        }
+        module.channel_mut(channel)?.field = value;
+        Ok(())
+    }
+
+    /// Simulate a cut (or repaired) cable on a channel
+    pub fn set_wire_break(&self, id: &str, channel: usize, broken: bool) -> AnalogResult<()> {
+        let mut modules = self.modules.lock();
+        let module = modules
+            .get_mut(id)
+            .ok_or_else(|| AnalogError::ModuleNotFound(id.to_string()))?;
+        module.channel_mut(channel)?.broken = broken;
+        Ok(())
+    }
+
+    pub fn list(&self) -> Vec<AnalogModuleInfo> {
+        self.modules
+            .lock()
+            .values()
+            .map(AnalogModule::info)
+            .collect()
+    }
+
+    /// Words the modules write themselves (input values and status words).
+    /// Event replay skips recorded writes to them.
+    pub fn output_addresses(&self) -> Vec<CanonicalAddress> {
+        let mut addresses = Vec::new();
+        for module in self.modules.lock().values() {
+            let layout = module.config.layout;
+            if module.config.direction == AnalogDirection::Input {
+                addresses.extend((0..module.channels.len()).map(|index| layout.channel(index)));
+            }
+            addresses.extend(layout.range_status);
+            addresses.extend(layout.wire_break_status);
+        }
+        addresses
+    }
+
+    /// Convert all modules. The host calls this after each completed scan,
+    /// before fault injection.
+    pub fn apply(&self, runtime: &CanonicalRuntimeFacade) -> AnalogResult<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        self.apply_at(runtime, modone_contract::clock::now_millis())
+    }
+
+    pub fn apply_at(&self, runtime: &CanonicalRuntimeFacade, now_ms: u64) -> AnalogResult<()> {
+        for module in self.modules.lock().values_mut() {
+            module.apply(runtime, now_ms)?;
+        }
+        Ok(())
+    }
+}
+
+fn write_if_changed(
+    runtime: &CanonicalRuntimeFacade,
+    address: CanonicalAddress,
+    word: u16,
+) -> AnalogResult<()> {
+    if runtime.read(address)? != CanonicalValue::U16(word) {
+        runtime.write(
+            address,
+            CanonicalValue::U16(word),
+            CanonicalWriteSource::Simulation,
+        )?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use modone_contract::CanonicalAreaKind;
+
+    fn word(index: u32) -> CanonicalAddress {
+        CanonicalAddress::new(CanonicalAreaKind::DataWord, index)
+    }
+
+    fn module(
+        direction: AnalogDirection,
+        channels: Vec<AnalogChannelConfig>,
+    ) -> AnalogModuleConfig {
+        AnalogModuleConfig {
+            id: "slot2".to_string(),
+            direction,
+            layout: AnalogWordLayout::contiguous(word(100), channels.len()),
+            channels,
+        }
+    }
+
+    #[test]
+    fn test_output_addresses_follow_direction() {
+        let sim = AnalogModuleSimulator::new();
+        let channel = AnalogChannelConfig::new(AnalogSignalRange::Voltage0To10V, 27648);
+        sim.configure(module(AnalogDirection::Input, vec![channel, channel]))
+            .unwrap();
+        assert_eq!(
+            sim.output_addresses(),
+            vec![word(100), word(101), word(102), word(103)]
+        );
+
+        // 출력 모듈의 채널 워드는 래더가 쓰므로 상태 워드만 모듈 몫이다
+        sim.configure(module(AnalogDirection::Output, vec![channel, channel]))
+            .unwrap();
+        assert_eq!(sim.output_addresses(), vec![word(102), word(103)]);
+    }
+
+    #[test]
+    fn test_input_converts_ranges_and_flags() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let sim = AnalogModuleSimulator::new();
+        sim.configure(module(
+            AnalogDirection::Input,
+            vec![
+                AnalogChannelConfig::new(AnalogSignalRange::Current4To20mA, 16000),
+                AnalogChannelConfig::new(AnalogSignalRange::VoltageBipolar10V, 27648),
+                AnalogChannelConfig::new(AnalogSignalRange::Voltage0To10V, 27648),
+            ],
+        ))
+        .unwrap();
+
+        sim.set_engineering_value("slot2", 0, 12.0).unwrap();
+        sim.set_engineering_value("slot2", 1, -5.0).unwrap();
+        sim.set_engineering_value("slot2", 2, 11.0).unwrap();
+        sim.apply_at(&runtime, 0).unwrap();
+
+        assert_eq!(runtime.read_word_value(word(100)).unwrap(), 8000);
+        assert_eq!(runtime.read_word_value(word(101)).unwrap() as i16, -13824);
+        assert_eq!(runtime.read_word_value(word(102)).unwrap(), 30413);
+        // 채널 2 범위 초과
+        assert_eq!(runtime.read_word_value(word(103)).unwrap(), 0b100);
+        assert_eq!(runtime.read_word_value(word(104)).unwrap(), 0);
+
+        // 단선: 플래그가 서고 마지막 변환값을 유지한다
+        sim.set_wire_break("slot2", 0, true).unwrap();
+        sim.apply_at(&runtime, 10).unwrap();
+        assert_eq!(runtime.read_word_value(word(100)).unwrap(), 8000);
+        assert_eq!(runtime.read_word_value(word(104)).unwrap(), 0b1);
+        assert_eq!(runtime.read_word_value(word(103)).unwrap(), 0b101);
+        let state = &sim.list()[0].channels[0];
+        assert!(state.wire_break && state.under_range);
+    }
+
+    #[test]
+    fn test_source_word_and_filters() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let sim = AnalogModuleSimulator::new();
+        let mut lagged = AnalogChannelConfig::new(AnalogSignalRange::Voltage0To10V, 10000);
+        lagged.filter = AnalogFilter::FirstOrder {
+            time_constant_ms: 100,
+        };
+        lagged.source = Some(AnalogSource {
+            address: word(0),
+            scale: 0.01,
+            signed: false,
+        });
+        let mut averaged = AnalogChannelConfig::new(AnalogSignalRange::Voltage0To10V, 10000);
+        averaged.filter = AnalogFilter::MovingAverage { samples: 2 };
+        sim.configure(module(AnalogDirection::Input, vec![lagged, averaged]))
+            .unwrap();
+
+        runtime
+            .write_word_value(word(0), 0, CanonicalWriteSource::Simulation)
+            .unwrap();
+        sim.apply_at(&runtime, 0).unwrap();
+
+        // 시나리오가 소스 워드에 8.00 V 를 쓴다
+        runtime
+            .write_word_value(word(0), 800, CanonicalWriteSource::Simulation)
+            .unwrap();
+        sim.set_engineering_value("slot2", 1, 4.0).unwrap();
+        sim.apply_at(&runtime, 100).unwrap();
+        assert_eq!(runtime.read_word_value(word(100)).unwrap(), 4000);
+        assert_eq!(runtime.read_word_value(word(101)).unwrap(), 2000);
+
+        sim.apply_at(&runtime, 200).unwrap();
+        assert_eq!(runtime.read_word_value(word(100)).unwrap(), 6000);
+        assert_eq!(runtime.read_word_value(word(101)).unwrap(), 4000);
+    }
+
+    #[test]
+    fn test_output_reports_engineering_value() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let sim = AnalogModuleSimulator::new();
+        sim.configure(module(
+            AnalogDirection::Output,
+            vec![AnalogChannelConfig::new(
+                AnalogSignalRange::Current4To20mA,
+                16000,
+            )],
+        ))
+        .unwrap();
+
+        runtime
+            .write_word_value(word(100), 4000, CanonicalWriteSource::Simulation)
+            .unwrap();
+        sim.apply_at(&runtime, 0).unwrap();
+        let state = &sim.list()[0].channels[0];
+        assert!((state.engineering - 8.0).abs() < 1e-9);
+        assert_eq!(state.unit, "mA");
+
+        runtime
+            .write_word_value(word(100), 20000, CanonicalWriteSource::Simulation)
+            .unwrap();
+        sim.apply_at(&runtime, 10).unwrap();
+        let state = &sim.list()[0].channels[0];
+        assert!(state.over_range);
+        assert!((state.engineering - 20.0).abs() < 1e-9);
+        assert_eq!(runtime.read_word_value(word(101)).unwrap(), 0b1);
+
+        assert!(matches!(
+            sim.set_engineering_value("slot2", 0, 5.0),
+            Err(AnalogError::NotAnInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_invalid_configuration() {
+        let sim = AnalogModuleSimulator::new();
+        let mut config = module(
+            AnalogDirection::Input,
+            vec![AnalogChannelConfig::new(
+                AnalogSignalRange::Voltage0To10V,
+                40000,
+            )],
+        );
+        assert!(matches!(
+            sim.configure(config.clone()),
+            Err(AnalogError::InvalidParameter(_))
+        ));
+
+        config.channels[0].digital_max = 16000;
+        config.layout.values = CanonicalAddress::new(CanonicalAreaKind::InputBit, 0);
+        assert!(matches!(
+            sim.configure(config),
+            Err(AnalogError::WordAddressRequired(_))
+        ));
+        assert!(matches!(
+            sim.set_engineering_value("missing", 0, 1.0),
+            Err(AnalogError::ModuleNotFound(_))
+        ));
+    }
+}
@@ -5,6 +5,7 @@
 //! counter state management with edge detection.
 
 use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::types::{CounterState, SimCounterType};
@@ -14,7 +15,8 @@ use super::types::{CounterState, SimCounterType};
 // ============================================================================
 
 /// Internal runtime state for a counter
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct CounterRuntime {
     /// Counter type (CTU, CTD, CTUD)
     counter_type: SimCounterType,
@@ -47,6 +49,12 @@ impl CounterRuntime {
     }
 }
 
+/// Opaque copy of all counter runtime states, used for scan checkpoints and snapshots
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CounterSnapshot {
+    counters: HashMap<u16, CounterRuntime>,
+}
+
 // ============================================================================
 // Counter Manager
 // ============================================================================
@@ -271,6 +279,18 @@ impl CounterManager {
     pub fn clear(&self) {
         self.counters.write().clear();
     }
+
+    /// Capture all counter runtime states
+    pub fn snapshot(&self) -> CounterSnapshot {
+        CounterSnapshot {
+            counters: self.counters.read().clone(),
+        }
+    }
+
+    /// Replace all counter runtime states with a captured snapshot
+    pub fn restore(&self, snapshot: &CounterSnapshot) {
+        *self.counters.write() = snapshot.counters.clone();
+    }
 }
 
 impl Default for CounterManager {
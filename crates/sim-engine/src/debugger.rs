@@ -2,7 +2,7 @@
 //!
 //! Provides debugging capabilities for the PLC simulation including:
 //! - Breakpoint management (network, device, condition, scan count)
-//! - Watch variable tracking
+//! - Watch variable tracking (bindings and expressions)
 //! - Step execution control (network-level and scan-level)
 
 use parking_lot::RwLock;
@@ -10,13 +10,15 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use modone_contract::CanonicalValue;
+use modone_contract::{CanonicalAddress, CanonicalValue};
+use plc_model::VendorProfile;
 
-use super::memory::CanonicalRuntimeFacade;
+use super::expression::Expression;
+use super::history::HistoryPosition;
+use super::memory::{CanonicalRuntimeFacade, SimMemoryError};
 use super::tag_registry::SharedTagRegistry;
-use super::types::{
-    Breakpoint, BreakpointType, RuntimeBinding, SimBitDeviceType, SimWordDeviceType, WatchVariable,
-};
+use super::types::{Breakpoint, BreakpointType, RuntimeBinding, WatchVariable};
+use super::watch::{export_history_csv, WatchDataType, WatchFormat, WordOrder};
 
 // ============================================================================
 // Types
@@ -38,9 +40,24 @@ impl Default for StepType {
     }
 }
 
+/// Step direction through the scan history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StepDirection {
+    /// Execute (or replay) the next scan
+    #[default]
+    Forward,
+    /// Restore the previous scan checkpoint
+    Backward,
+}
+
 /// Represents a breakpoint that was hit during execution
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(rename_all = "camelCase", rename_all_fields = "camelCase", tag = "type")]
+#[serde(
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase",
+    tag = "type"
+)]
 pub enum BreakpointHit {
     /// Network breakpoint was hit
     #[serde(rename = "network")]
@@ -85,6 +102,9 @@ pub struct StepResult {
     pub scan_count: u64,
     /// Breakpoint hit during step (if any)
     pub breakpoint_hit: Option<BreakpointHit>,
+    /// Position in the scan history after the step
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub history_position: Option<HistoryPosition>,
 }
 
 /// Debugger error types
@@ -129,8 +149,12 @@ pub type DebuggerResult<T> = Result<T, DebuggerError>;
 pub struct SimDebugger {
     /// Collection of breakpoints
     breakpoints: RwLock<Vec<Breakpoint>>,
+    /// Compiled condition expressions indexed by breakpoint ID
+    conditions: RwLock<HashMap<String, Expression>>,
     /// Watch variables indexed by address
     watches: RwLock<HashMap<RuntimeBinding, WatchVariable>>,
+    /// Expression watches indexed by expression source
+    expression_watches: RwLock<HashMap<String, (Expression, WatchVariable)>>,
     /// Whether step mode is enabled
     step_mode: AtomicBool,
     /// Current step type
@@ -153,7 +177,9 @@ impl SimDebugger {
     pub fn with_tag_registry(max_watch_history: usize, tag_registry: SharedTagRegistry) -> Self {
         Self {
             breakpoints: RwLock::new(Vec::new()),
+            conditions: RwLock::new(HashMap::new()),
             watches: RwLock::new(HashMap::new()),
+            expression_watches: RwLock::new(HashMap::new()),
             step_mode: AtomicBool::new(false),
             step_type: RwLock::new(StepType::default()),
             paused_at: RwLock::new(None),
@@ -175,10 +201,24 @@ impl SimDebugger {
     // ========================================================================
 
     /// Add a new breakpoint
-    pub fn add_breakpoint(&self, breakpoint: Breakpoint) -> String {
+    ///
+    /// Condition expressions are compiled against `profile` here, so an invalid
+    /// expression is rejected instead of silently never triggering.
+    pub fn add_breakpoint(
+        &self,
+        breakpoint: Breakpoint,
+        profile: &dyn VendorProfile,
+    ) -> DebuggerResult<String> {
         let id = breakpoint.id.clone();
+        if breakpoint.breakpoint_type == BreakpointType::Condition {
+            if let Some(ref condition) = breakpoint.condition {
+                let expression = Expression::compile(condition, profile, &self.tag_registry)
+                    .map_err(|e| DebuggerError::InvalidCondition(format!("{condition}: {e}")))?;
+                self.conditions.write().insert(id.clone(), expression);
+            }
+        }
         self.breakpoints.write().push(breakpoint);
-        id
+        Ok(id)
     }
 
     /// Remove a breakpoint by ID
@@ -189,6 +229,7 @@ impl SimDebugger {
             .position(|bp| bp.id == id)
             .ok_or_else(|| DebuggerError::BreakpointNotFound(id.to_string()))?;
         breakpoints.remove(idx);
+        self.conditions.write().remove(id);
         Ok(())
     }
 
@@ -211,6 +252,7 @@ impl SimDebugger {
     /// Clear all breakpoints
     pub fn clear_breakpoints(&self) {
         self.breakpoints.write().clear();
+        self.conditions.write().clear();
     }
 
     // ========================================================================
@@ -248,7 +290,7 @@ impl SimDebugger {
                 }
                 BreakpointType::Condition => {
                     if let Some(ref cond) = bp.condition {
-                        if self.evaluate_condition(cond, memory) {
+                        if self.evaluate_condition(&bp.id, memory) {
                             bp.hit_count += 1;
                             return Some(BreakpointHit::Condition {
                                 breakpoint_id: bp.id.clone(),
@@ -299,123 +341,17 @@ impl SimDebugger {
         None
     }
 
-    /// Evaluate a condition expression
+    /// Evaluate the compiled condition of a breakpoint.
     ///
-    /// Supports simple expressions like:
-    /// - "D0001 > 100"
-    /// - "M0000 == 1"
-    /// - "D0010 != 0"
-    fn evaluate_condition(&self, condition: &str, memory: &CanonicalRuntimeFacade) -> bool {
-        // Parse condition: DEVICE OP VALUE
-        let parts: Vec<&str> = condition.split_whitespace().collect();
-        if parts.len() != 3 {
-            return false;
-        }
-
-        let device_addr = parts[0];
-        let operator = parts[1];
-        let target_value: i32 = match parts[2].parse() {
-            Ok(v) => v,
-            Err(_) => return false,
-        };
-
-        // Read device value
-        let device_value = self.read_device_value(device_addr, memory);
-
-        // Compare
-        match operator {
-            "==" => device_value == target_value,
-            "!=" => device_value != target_value,
-            ">" => device_value > target_value,
-            "<" => device_value < target_value,
-            ">=" => device_value >= target_value,
-            "<=" => device_value <= target_value,
-            _ => false,
-        }
-    }
-
-    /// Parse a device address string into bit device type and address
-    fn parse_bit_device(address: &str) -> Option<(SimBitDeviceType, u16)> {
-        if address.len() < 2 {
-            return None;
-        }
-
-        let device_char = address.chars().next()?.to_ascii_uppercase();
-        let addr_str = &address[1..];
-        let addr: u16 = addr_str.parse().ok()?;
-
-        let device_type = match device_char {
-            'X' => SimBitDeviceType::X,
-            'Y' => SimBitDeviceType::Y,
-            'P' => SimBitDeviceType::P,
-            'M' => SimBitDeviceType::M,
-            'K' => SimBitDeviceType::K,
-            'F' => SimBitDeviceType::F,
-            'T' => SimBitDeviceType::T,
-            'C' => SimBitDeviceType::C,
-            _ => return None,
-        };
-
-        Some((device_type, addr))
-    }
-
-    /// Parse a device address string into word device type and address
-    fn parse_word_device(address: &str) -> Option<(SimWordDeviceType, u16)> {
-        if address.len() < 2 {
-            return None;
-        }
-
-        let upper = address.to_uppercase();
-
-        // Check for two-letter device types first (TD, CD)
-        if upper.len() >= 3 {
-            if upper.starts_with("TD") {
-                let addr: u16 = upper[2..].parse().ok()?;
-                return Some((SimWordDeviceType::Td, addr));
-            }
-            if upper.starts_with("CD") {
-                let addr: u16 = upper[2..].parse().ok()?;
-                return Some((SimWordDeviceType::Cd, addr));
-            }
-        }
-
-        let device_char = upper.chars().next()?;
-        let addr_str = &upper[1..];
-        let addr: u16 = addr_str.parse().ok()?;
-
-        let device_type = match device_char {
-            'D' => SimWordDeviceType::D,
-            'R' => SimWordDeviceType::R,
-            'Z' => SimWordDeviceType::Z,
-            'N' => SimWordDeviceType::N,
-            _ => return None,
-        };
-
-        Some((device_type, addr))
-    }
-
-    /// Read a device value from memory as i32
-    fn read_device_value(&self, address: &str, memory: &CanonicalRuntimeFacade) -> i32 {
-        // Parse address (e.g., "M0000", "D0001")
-        if address.is_empty() {
-            return 0;
-        }
-
-        // Try bit device first
-        if let Some((device_type, addr)) = Self::parse_bit_device(address) {
-            return if memory.read_bit(device_type, addr).unwrap_or(false) {
-                1
-            } else {
-                0
-            };
-        }
-
-        // Try word device
-        if let Some((device_type, addr)) = Self::parse_word_device(address) {
-            return memory.read_word(device_type, addr).unwrap_or(0) as i32;
-        }
-
-        0
+    /// 평가 오류(0으로 나누기, 삭제된 태그 등)는 트리거하지 않는다.
+    fn evaluate_condition(&self, breakpoint_id: &str, memory: &CanonicalRuntimeFacade) -> bool {
+        self.conditions
+            .write()
+            .get_mut(breakpoint_id)
+            .is_some_and(|expr| {
+                expr.evaluate_bool(memory, &self.tag_registry)
+                    .unwrap_or(false)
+            })
     }
 
     // ========================================================================
@@ -437,13 +373,29 @@ impl SimDebugger {
         display_address: String,
         memory: &CanonicalRuntimeFacade,
     ) {
-        let initial_value = self.read_binding_json_value(&binding, memory);
-        let watch = WatchVariable::new(
+        self.add_typed_watch_binding(binding, display_address, None, memory);
+    }
+
+    /// Add a watch that decodes its value with a data type/word order/scaling.
+    ///
+    /// Multi-word types read consecutive words starting at the bound address.
+    pub fn add_typed_watch_binding(
+        &self,
+        binding: RuntimeBinding,
+        display_address: String,
+        format: Option<WatchFormat>,
+        memory: &CanonicalRuntimeFacade,
+    ) {
+        let initial_value = self.read_watch_value(&binding, format.as_ref(), memory);
+        let mut watch = WatchVariable::new(
             binding.clone(),
             display_address,
             initial_value,
             self.max_watch_history,
         );
+        if let Some(format) = format {
+            watch = watch.with_format(format);
+        }
         self.watches.write().insert(binding, watch);
     }
 
@@ -456,38 +408,145 @@ impl SimDebugger {
     }
 
     pub fn remove_watch_binding(&self, binding: &RuntimeBinding) -> DebuggerResult<()> {
-        self.watches
+        if self.watches.write().remove(binding).is_some() {
+            return Ok(());
+        }
+        if let RuntimeBinding::Expression { source } = binding {
+            if self.expression_watches.write().remove(source).is_some() {
+                return Ok(());
+            }
+        }
+        Err(DebuggerError::WatchNotFound(format!("{binding:?}")))
+    }
+
+    /// Add a watch on an expression such as `int32(D100) / 10` or `M0 AND M1`.
+    ///
+    /// `word_order` overrides the profile's word order for 32-bit reads.
+    pub fn add_watch_expression(
+        &self,
+        source: &str,
+        word_order: Option<WordOrder>,
+        profile: &dyn VendorProfile,
+        memory: &CanonicalRuntimeFacade,
+    ) -> DebuggerResult<()> {
+        let mut expression = Expression::compile(source, profile, &self.tag_registry)
+            .map_err(|e| DebuggerError::InvalidCondition(format!("{source}: {e}")))?;
+        if let Some(word_order) = word_order {
+            expression = expression.with_word_order(word_order);
+        }
+        let key = expression.source().to_string();
+        let initial_value = expression
+            .evaluate(memory, &self.tag_registry)
+            .map(|value| value.to_json())
+            .unwrap_or(serde_json::Value::Null);
+        let mut watch = WatchVariable::new(
+            RuntimeBinding::expression(key.clone()),
+            key.clone(),
+            initial_value,
+            self.max_watch_history,
+        );
+        watch.expression = Some(key.clone());
+        self.expression_watches
             .write()
-            .remove(binding)
-            .map(|_| ())
-            .ok_or_else(|| DebuggerError::WatchNotFound(format!("{binding:?}")))
+            .insert(key, (expression, watch));
+        Ok(())
     }
 
     /// Get all watch variables
     pub fn get_watches(&self) -> Vec<WatchVariable> {
-        self.watches.read().values().cloned().collect()
+        let mut watches: Vec<_> = self.watches.read().values().cloned().collect();
+        watches.extend(
+            self.expression_watches
+                .read()
+                .values()
+                .map(|(_, watch)| watch.clone()),
+        );
+        watches
     }
 
     /// Update a single watch variable
     pub fn update_watch(&self, binding: &RuntimeBinding, memory: &CanonicalRuntimeFacade) {
         if let Some(watch) = self.watches.write().get_mut(binding) {
-            let new_value = self.read_binding_json_value(binding, memory);
+            let new_value = self.read_watch_value(binding, watch.format.as_ref(), memory);
             watch.update(new_value);
         }
     }
 
     /// Update all watch variables
     pub fn update_all_watches(&self, memory: &CanonicalRuntimeFacade) {
+        self.update_watches(memory, None);
+    }
+
+    /// Update all watch variables, tagging history entries with the scan number
+    pub fn update_all_watches_at_scan(&self, memory: &CanonicalRuntimeFacade, scan_count: u64) {
+        self.update_watches(memory, Some(scan_count));
+    }
+
+    fn update_watches(&self, memory: &CanonicalRuntimeFacade, scan_count: Option<u64>) {
         let mut watches = self.watches.write();
         for (binding, watch) in watches.iter_mut() {
-            let new_value = self.read_binding_json_value(binding, memory);
-            watch.update(new_value);
+            let new_value = self.read_watch_value(binding, watch.format.as_ref(), memory);
+            watch.update_at_scan(new_value, scan_count);
+        }
+
+        for (expression, watch) in self.expression_watches.write().values_mut() {
+            let new_value = expression
+                .evaluate(memory, &self.tag_registry)
+                .map(|value| value.to_json())
+                .unwrap_or(serde_json::Value::Null);
+            watch.update_at_scan(new_value, scan_count);
         }
     }
 
+    /// Export the history of all watches as CSV
+    pub fn export_watch_history_csv(&self) -> String {
+        export_history_csv(&self.get_watches())
+    }
+
     /// Clear all watch variables
     pub fn clear_watches(&self) {
         self.watches.write().clear();
+        self.expression_watches.write().clear();
+    }
+
+    fn read_watch_value(
+        &self,
+        binding: &RuntimeBinding,
+        format: Option<&WatchFormat>,
+        memory: &CanonicalRuntimeFacade,
+    ) -> serde_json::Value {
+        let Some(format) = format else {
+            return self.read_binding_json_value(binding, memory);
+        };
+        let address = match binding {
+            RuntimeBinding::Canonical { address } => *address,
+            RuntimeBinding::Tag { tag_id } => match self.tag_registry.resolve(tag_id) {
+                Ok(tag) => tag.canonical_address,
+                Err(_) => return serde_json::Value::Null,
+            },
+            RuntimeBinding::Expression { .. } => return serde_json::Value::Null,
+        };
+
+        if format.data_type == WatchDataType::Bool {
+            return memory
+                .read_bool(address)
+                .map(|value| serde_json::json!(value))
+                .unwrap_or(serde_json::Value::Null);
+        }
+
+        let words = (0..format.word_count() as u32)
+            .map(|offset| {
+                let word = CanonicalAddress::new(address.area, address.index + offset);
+                match memory.read(word)? {
+                    CanonicalValue::Bool(value) => Ok(value as u16),
+                    CanonicalValue::U16(value) => Ok(value),
+                }
+            })
+            .collect::<Result<Vec<u16>, SimMemoryError>>();
+        match words {
+            Ok(words) => format.decode_words(&words),
+            Err(_) => serde_json::Value::Null,
+        }
     }
 
     fn read_binding_json_value(
@@ -509,6 +568,7 @@ impl SimDebugger {
                 },
                 Err(_) => serde_json::Value::Null,
             },
+            RuntimeBinding::Expression { .. } => serde_json::Value::Null,
         }
     }
 
@@ -571,7 +631,9 @@ impl SimDebugger {
     /// Reset all debugger state
     pub fn reset(&self) {
         self.breakpoints.write().clear();
+        self.conditions.write().clear();
         self.watches.write().clear();
+        self.expression_watches.write().clear();
         self.step_mode.store(false, Ordering::SeqCst);
         *self.step_type.write() = StepType::default();
         *self.paused_at.write() = None;
@@ -585,6 +647,12 @@ impl SimDebugger {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::{SimBitDeviceType, SimWordDeviceType};
+    use plc_model::{LsProfile, PlcHardwareTopology};
+
+    fn test_profile() -> LsProfile {
+        LsProfile::new("XGK".to_string(), PlcHardwareTopology::default())
+    }
 
     #[test]
     fn test_debugger_new() {
@@ -603,7 +671,7 @@ mod tests {
         bp.network_id = Some(1);
         let id = bp.id.clone();
 
-        debugger.add_breakpoint(bp);
+        debugger.add_breakpoint(bp, &test_profile()).unwrap();
         assert_eq!(debugger.get_breakpoints().len(), 1);
 
         debugger.remove_breakpoint(&id).unwrap();
@@ -616,7 +684,7 @@ mod tests {
 
         let bp = Breakpoint::new(BreakpointType::Network);
         let id = bp.id.clone();
-        debugger.add_breakpoint(bp);
+        debugger.add_breakpoint(bp, &test_profile()).unwrap();
 
         // Initially enabled
         assert!(debugger.get_breakpoints()[0].enabled);
@@ -703,7 +771,9 @@ mod tests {
         let debugger = SimDebugger::new(100);
 
         // Add some state
-        debugger.add_breakpoint(Breakpoint::new(BreakpointType::Network));
+        debugger
+            .add_breakpoint(Breakpoint::new(BreakpointType::Network), &test_profile())
+            .unwrap();
         debugger.enable_step_mode(StepType::Scan);
         debugger.pause(BreakpointHit::ScanCount {
             breakpoint_id: "test".to_string(),
@@ -730,7 +800,7 @@ mod tests {
         bp.device_address = Some("M0000".to_string());
         bp.device_binding = Some(binding.clone());
         let id = bp.id.clone();
-        debugger.add_breakpoint(bp);
+        debugger.add_breakpoint(bp, &test_profile()).unwrap();
 
         // Check device change - should trigger when value changes
         let result = debugger.check_device_change(
@@ -760,7 +830,7 @@ mod tests {
         let mut bp = Breakpoint::new(BreakpointType::Device);
         bp.device_address = Some("M0000".to_string());
         bp.device_binding = Some(binding.clone());
-        debugger.add_breakpoint(bp);
+        debugger.add_breakpoint(bp, &test_profile()).unwrap();
 
         // Check device change with same value - should NOT trigger
         let result = debugger.check_device_change(
@@ -781,7 +851,7 @@ mod tests {
         let mut bp = Breakpoint::new(BreakpointType::Device);
         bp.device_address = Some("M0000".to_string());
         bp.device_binding = Some(binding.clone());
-        debugger.add_breakpoint(bp);
+        debugger.add_breakpoint(bp, &test_profile()).unwrap();
 
         // Check different device - should NOT trigger
         let result = debugger.check_device_change(
@@ -803,7 +873,7 @@ mod tests {
         bp.device_address = Some("M0000".to_string());
         bp.device_binding = Some(binding.clone());
         let id = bp.id.clone();
-        debugger.add_breakpoint(bp);
+        debugger.add_breakpoint(bp, &test_profile()).unwrap();
         debugger.set_breakpoint_enabled(&id, false).unwrap();
 
         // Check device change - should NOT trigger (disabled)
@@ -820,9 +890,15 @@ mod tests {
     fn test_clear_breakpoints() {
         let debugger = SimDebugger::new(100);
 
-        debugger.add_breakpoint(Breakpoint::new(BreakpointType::Network));
-        debugger.add_breakpoint(Breakpoint::new(BreakpointType::Device));
-        debugger.add_breakpoint(Breakpoint::new(BreakpointType::Condition));
+        debugger
+            .add_breakpoint(Breakpoint::new(BreakpointType::Network), &test_profile())
+            .unwrap();
+        debugger
+            .add_breakpoint(Breakpoint::new(BreakpointType::Device), &test_profile())
+            .unwrap();
+        debugger
+            .add_breakpoint(Breakpoint::new(BreakpointType::Condition), &test_profile())
+            .unwrap();
         assert_eq!(debugger.get_breakpoints().len(), 3);
 
         debugger.clear_breakpoints();
@@ -843,59 +919,110 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_bit_device() {
-        // Test valid bit devices
-        assert!(SimDebugger::parse_bit_device("X0000").is_some());
-        assert!(SimDebugger::parse_bit_device("Y0001").is_some());
-        assert!(SimDebugger::parse_bit_device("M0000").is_some());
-        assert!(SimDebugger::parse_bit_device("P0100").is_some());
-        assert!(SimDebugger::parse_bit_device("K0050").is_some());
-        assert!(SimDebugger::parse_bit_device("F0001").is_some());
-        assert!(SimDebugger::parse_bit_device("T0000").is_some());
-        assert!(SimDebugger::parse_bit_device("C0000").is_some());
-
-        // Test parsing correctness
-        let (device, addr) = SimDebugger::parse_bit_device("M0100").unwrap();
-        assert_eq!(device, SimBitDeviceType::M);
-        assert_eq!(addr, 100);
-
-        let (device, addr) = SimDebugger::parse_bit_device("Y0017").unwrap();
-        assert_eq!(device, SimBitDeviceType::Y);
-        assert_eq!(addr, 17);
-
-        // Test invalid - word device letter
-        assert!(SimDebugger::parse_bit_device("D0000").is_none());
-
-        // Test invalid - too short
-        assert!(SimDebugger::parse_bit_device("M").is_none());
-        assert!(SimDebugger::parse_bit_device("").is_none());
+    fn test_condition_breakpoint_triggers() {
+        let debugger = SimDebugger::new(100);
+        let memory = CanonicalRuntimeFacade::new();
+
+        let mut bp = Breakpoint::new(BreakpointType::Condition);
+        bp.condition = Some("D0001 > 100 AND rising(M0000)".to_string());
+        let id = debugger.add_breakpoint(bp, &test_profile()).unwrap();
+
+        memory.write_word(SimWordDeviceType::D, 1, 150).unwrap();
+        assert!(debugger.check_before_network(0, 1, &memory).is_none());
+
+        memory.write_bit(SimBitDeviceType::M, 0, true).unwrap();
+        match debugger.check_before_network(0, 1, &memory) {
+            Some(BreakpointHit::Condition { breakpoint_id, .. }) => assert_eq!(breakpoint_id, id),
+            other => panic!("Expected Condition breakpoint hit, got {other:?}"),
+        }
+
+        // 에지는 한 번만 트리거된다
+        assert!(debugger.check_before_network(1, 1, &memory).is_none());
+    }
+
+    #[test]
+    fn test_invalid_condition_rejected_on_add() {
+        let debugger = SimDebugger::new(100);
+
+        for condition in ["D0001 >", "NoSuchTag == 1", "M0000 AND (M0001"] {
+            let mut bp = Breakpoint::new(BreakpointType::Condition);
+            bp.condition = Some(condition.to_string());
+            let result = debugger.add_breakpoint(bp, &test_profile());
+            assert!(
+                matches!(result, Err(DebuggerError::InvalidCondition(_))),
+                "{condition} should be rejected"
+            );
+        }
+        assert!(debugger.get_breakpoints().is_empty());
+    }
+
+    #[test]
+    fn test_expression_watch() {
+        let debugger = SimDebugger::new(100);
+        let memory = CanonicalRuntimeFacade::new();
+
+        debugger
+            .add_watch_expression("D0010 * 2 + 1", None, &test_profile(), &memory)
+            .unwrap();
+        memory.write_word(SimWordDeviceType::D, 10, 20).unwrap();
+        debugger.update_all_watches(&memory);
+
+        let watches = debugger.get_watches();
+        assert_eq!(watches.len(), 1);
+        assert_eq!(watches[0].current_value, serde_json::json!(41));
+        assert_eq!(watches[0].expression.as_deref(), Some("D0010 * 2 + 1"));
+
+        assert!(debugger
+            .add_watch_expression("D0010 +", None, &test_profile(), &memory)
+            .is_err());
+
+        // 같은 텍스트의 태그 바인딩은 식 watch 와 구별된다
+        assert_eq!(
+            watches[0].binding,
+            RuntimeBinding::expression("D0010 * 2 + 1")
+        );
+        assert!(debugger
+            .remove_watch_binding(&RuntimeBinding::tag("D0010 * 2 + 1"))
+            .is_err());
+        debugger
+            .remove_watch_binding(&RuntimeBinding::expression("D0010 * 2 + 1"))
+            .unwrap();
+        assert!(debugger.get_watches().is_empty());
     }
 
     #[test]
-    fn test_parse_word_device() {
-        // Test valid word devices
-        assert!(SimDebugger::parse_word_device("D0000").is_some());
-        assert!(SimDebugger::parse_word_device("R0100").is_some());
-        assert!(SimDebugger::parse_word_device("Z0001").is_some());
-        assert!(SimDebugger::parse_word_device("N0050").is_some());
-        assert!(SimDebugger::parse_word_device("TD0000").is_some());
-        assert!(SimDebugger::parse_word_device("CD0000").is_some());
-
-        // Test parsing correctness for single-letter
-        let (device, addr) = SimDebugger::parse_word_device("D0100").unwrap();
-        assert_eq!(device, SimWordDeviceType::D);
-        assert_eq!(addr, 100);
-
-        // Test parsing correctness for two-letter
-        let (device, addr) = SimDebugger::parse_word_device("TD0050").unwrap();
-        assert_eq!(device, SimWordDeviceType::Td);
-        assert_eq!(addr, 50);
-
-        // Test invalid - bit device letter
-        assert!(SimDebugger::parse_word_device("M0000").is_none());
-
-        // Test invalid - too short
-        assert!(SimDebugger::parse_word_device("D").is_none());
+    fn test_typed_watch_spans_word_pair() {
+        let debugger = SimDebugger::new(100);
+        let memory = CanonicalRuntimeFacade::new();
+        let binding = RuntimeBinding::canonical(CanonicalAddress::new(
+            modone_contract::CanonicalAreaKind::DataWord,
+            100,
+        ));
+
+        debugger.add_typed_watch_binding(
+            binding,
+            "D100".to_string(),
+            Some(WatchFormat::new(WatchDataType::Real)),
+            &memory,
+        );
+        let bits = 37.5f32.to_bits();
+        memory
+            .write_word(SimWordDeviceType::D, 100, bits as u16)
+            .unwrap();
+        memory
+            .write_word(SimWordDeviceType::D, 101, (bits >> 16) as u16)
+            .unwrap();
+        debugger.update_all_watches_at_scan(&memory, 7);
+
+        let watch = &debugger.get_watches()[0];
+        assert_eq!(watch.current_value, serde_json::json!(37.5));
+        assert_eq!(watch.display_value.as_deref(), Some("37.5"));
+        assert_eq!(watch.history[0].scan_count, Some(7));
+
+        let csv = debugger.export_watch_history_csv();
+        assert!(csv
+            .lines()
+            .any(|line| line.ends_with(",7,D100,REAL,37.5,37.5")));
     }
 
     // ========================================================================
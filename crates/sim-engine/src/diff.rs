@@ -0,0 +1,209 @@
+//! 메모리 스냅샷 비교
+//!
+//! HMI 조작이나 레시피 다운로드가 PLC에서 정확히 무엇을 바꿨는지 찾기 위해 두
+//! `CanonicalMemorySnapshot`(또는 스냅샷과 라이브 메모리)을 비교해 변경된 주소를
+//! 영역별로 묶어 돌려준다. 각 변경에는 벤더 별칭과 태그 ID를 붙일 수 있다.
+
+use modone_contract::{
+    CanonicalAddress, CanonicalAreaKind, CanonicalMemorySnapshot, CanonicalValue,
+};
+use plc_model::VendorProfile;
+use serde::{Deserialize, Serialize};
+
+use super::tag_registry::TagRegistry;
+
+/// One changed cell
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryChange {
+    pub address: CanonicalAddress,
+    pub before: CanonicalValue,
+    pub after: CanonicalValue,
+    /// Preferred vendor alias (e.g. `D0100`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /// Tags bound to the cell, or to a changed bit of a word
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tag_ids: Vec<String>,
+}
+
+/// Changes within one area, in address order
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AreaDiff {
+    pub area: CanonicalAreaKind,
+    pub changes: Vec<MemoryChange>,
+}
+
+/// Result of comparing two memory images
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryDiff {
+    pub before_captured_at: String,
+    pub after_captured_at: String,
+    /// Areas with at least one change
+    pub areas: Vec<AreaDiff>,
+    pub total_changes: usize,
+}
+
+impl MemoryDiff {
+    pub fn is_empty(&self) -> bool {
+        self.total_changes == 0
+    }
+
+    /// Attach vendor aliases and tag ids to every change
+    pub fn annotate(&mut self, profile: &dyn VendorProfile, tags: &TagRegistry) {
+        for change in self
+            .areas
+            .iter_mut()
+            .flat_map(|area| area.changes.iter_mut())
+        {
+            change.alias = profile.preferred_alias(&change.address).map(|alias| {
+                profile
+                    .format_address(&alias)
+                    .unwrap_or_else(|_| format!("{}{}", alias.family, alias.index))
+            });
+
+            let mut tag_ids = tags.tags_for_address(&change.address);
+            if let (CanonicalValue::U16(before), CanonicalValue::U16(after)) =
+                (change.before, change.after)
+            {
+                let changed_bits = before ^ after;
+                for bit in (0..16u8).filter(|bit| changed_bits & (1 << bit) != 0) {
+                    tag_ids.extend(tags.tags_for_address(&CanonicalAddress::with_bit_index(
+                        change.address.area,
+                        change.address.index,
+                        bit,
+                    )));
+                }
+            }
+            tag_ids.sort();
+            tag_ids.dedup();
+            change.tag_ids = tag_ids;
+        }
+    }
+}
+
+/// Compare two memory images. Cells missing from one side (different area
+/// sizes) compare as zero.
+pub fn diff_memory(
+    before: &CanonicalMemorySnapshot,
+    after: &CanonicalMemorySnapshot,
+) -> MemoryDiff {
+    let mut areas = Vec::new();
+    for area in CanonicalAreaKind::ALL.iter() {
+        let old = before
+            .areas
+            .get(area)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let new = after.areas.get(area).map(Vec::as_slice).unwrap_or_default();
+        let zero = if area.is_bit_area() {
+            CanonicalValue::Bool(false)
+        } else {
+            CanonicalValue::U16(0)
+        };
+
+        let changes: Vec<MemoryChange> = (0..old.len().max(new.len()))
+            .filter_map(|index| {
+                let before = old.get(index).copied().unwrap_or(zero);
+                let after = new.get(index).copied().unwrap_or(zero);
+                (before != after).then(|| MemoryChange {
+                    address: CanonicalAddress::new(*area, index as u32),
+                    before,
+                    after,
+                    alias: None,
+                    tag_ids: Vec::new(),
+                })
+            })
+            .collect();
+
+        if !changes.is_empty() {
+            areas.push(AreaDiff {
+                area: *area,
+                changes,
+            });
+        }
+    }
+
+    MemoryDiff {
+        before_captured_at: before.captured_at.clone(),
+        after_captured_at: after.captured_at.clone(),
+        total_changes: areas.iter().map(|area| area.changes.len()).sum(),
+        areas,
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::memory::CanonicalRuntimeFacade;
+    use crate::types::{RegisterTagRequest, SimBitDeviceType, SimWordDeviceType};
+    use plc_model::{LsProfile, PlcHardwareTopology};
+
+    #[test]
+    fn test_diff_groups_changes_by_area() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let before = runtime.snapshot();
+
+        runtime.write_bit(SimBitDeviceType::M, 3, true).unwrap();
+        runtime.write_word(SimWordDeviceType::D, 100, 42).unwrap();
+        runtime.write_word(SimWordDeviceType::D, 5, 1).unwrap();
+
+        let diff = runtime.diff_against(&before);
+        assert_eq!(diff.total_changes, 3);
+        assert_eq!(diff.areas.len(), 2);
+
+        let words = diff
+            .areas
+            .iter()
+            .find(|area| area.area == CanonicalAreaKind::DataWord)
+            .unwrap();
+        let indices: Vec<u32> = words.changes.iter().map(|c| c.address.index).collect();
+        assert_eq!(indices, vec![5, 100]);
+        assert_eq!(words.changes[1].before, CanonicalValue::U16(0));
+        assert_eq!(words.changes[1].after, CanonicalValue::U16(42));
+
+        assert!(diff_memory(&before, &before).is_empty());
+    }
+
+    #[test]
+    fn test_annotate_adds_aliases_and_bit_tags() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let tags = TagRegistry::new();
+        tags.register_semantic(RegisterTagRequest {
+            tag_id: Some("pump.run".to_string()),
+            display_name: "Pump run".to_string(),
+            binding: None,
+            canonical_address: Some(CanonicalAddress::with_bit_index(
+                CanonicalAreaKind::DataWord,
+                100,
+                2,
+            )),
+            vendor_aliases: Vec::new(),
+            access: None,
+            description: None,
+            engineering_unit: None,
+            folder_path: None,
+        })
+        .unwrap();
+
+        let before = runtime.snapshot();
+        runtime
+            .write_word(SimWordDeviceType::D, 100, 0b100)
+            .unwrap();
+
+        let mut diff = runtime.diff_against(&before);
+        diff.annotate(
+            &LsProfile::new("XGK".to_string(), PlcHardwareTopology::default()),
+            &tags,
+        );
+        let change = &diff.areas[0].changes[0];
+        assert_eq!(change.alias.as_deref(), Some("D0100"));
+        assert_eq!(change.tag_ids, vec!["pump.run".to_string()]);
+    }
+}
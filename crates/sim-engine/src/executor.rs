@@ -3,16 +3,22 @@
 //! Evaluates ladder logic AST nodes for the OneSim simulation engine.
 //! Handles contacts, coils, blocks, timers, counters, comparisons, and math operations.
 
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use thiserror::Error;
 
 use modone_contract::CanonicalAddress;
-use plc_model::VendorProfile;
+use plc_model::{
+    CanonicalByteSpan, PlcCpuModel, PlcInstructionFamily, VendorAddress, VendorProfile,
+    VendorProfileError,
+};
 
 use super::counter::CounterManager;
+use super::fault::{FaultAction, FaultHandling, ScanFault};
+use super::history::ScanCheckpoint;
 use super::memory::{CanonicalRuntimeFacade, SimMemoryError};
 use super::timer::TimerManager;
 use super::types::{
@@ -34,6 +40,10 @@ pub enum ExecutionError {
     #[error("Invalid device address: {0}")]
     InvalidAddress(String),
 
+    /// Device address beyond the CPU model's device range (compile time)
+    #[error("Device address out of range: {address} (max index {max_index})")]
+    AddressOutOfRange { address: String, max_index: u32 },
+
     /// Division by zero
     #[error("Division by zero")]
     DivisionByZero,
@@ -51,7 +61,7 @@ pub type ExecutionResult<T> = Result<T, ExecutionError>;
 // ============================================================================
 
 /// Node types for ladder logic execution
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum NodeType {
     // Contacts
@@ -96,6 +106,35 @@ pub enum NodeType {
     MathMov,
 }
 
+impl NodeType {
+    /// Instruction family checked against the CPU catalogue; blocks have none.
+    pub fn instruction_family(self) -> Option<PlcInstructionFamily> {
+        match self {
+            NodeType::ContactNo | NodeType::ContactNc => Some(PlcInstructionFamily::Contact),
+            NodeType::ContactP | NodeType::ContactN => Some(PlcInstructionFamily::EdgeContact),
+            NodeType::CoilOut => Some(PlcInstructionFamily::Coil),
+            NodeType::CoilSet | NodeType::CoilRst => Some(PlcInstructionFamily::SetReset),
+            NodeType::BlockSeries | NodeType::BlockParallel => None,
+            NodeType::TimerTon | NodeType::TimerTof => Some(PlcInstructionFamily::Timer),
+            NodeType::TimerTmr => Some(PlcInstructionFamily::RetentiveTimer),
+            NodeType::CounterCtu | NodeType::CounterCtd => Some(PlcInstructionFamily::Counter),
+            NodeType::CounterCtud => Some(PlcInstructionFamily::UpDownCounter),
+            NodeType::CompareEq
+            | NodeType::CompareNe
+            | NodeType::CompareLt
+            | NodeType::CompareLe
+            | NodeType::CompareGt
+            | NodeType::CompareGe => Some(PlcInstructionFamily::Compare),
+            NodeType::MathAdd
+            | NodeType::MathSub
+            | NodeType::MathMul
+            | NodeType::MathDiv
+            | NodeType::MathMod => Some(PlcInstructionFamily::Arithmetic),
+            NodeType::MathMov => Some(PlcInstructionFamily::Move),
+        }
+    }
+}
+
 /// 출력(구동) 노드인지 여부. 코일/타이머/카운터/연산은 rung의 파워플로우 평가에
 /// 기여하지 않고, 파워플로우로 구동되는 출력이다. (접점/블록/비교는 입력)
 fn is_output_node(node_type: NodeType) -> bool {
@@ -144,6 +183,15 @@ impl StopWatch {
     fn elapsed_us(&self) -> u64 {
         0
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn elapsed_ns(&self) -> u64 {
+        self.0.elapsed().as_nanos() as u64
+    }
+    #[cfg(target_arch = "wasm32")]
+    fn elapsed_ns(&self) -> u64 {
+        0
+    }
 }
 
 /// Device address parsed from string
@@ -247,22 +295,29 @@ impl DeviceAddress {
 }
 
 /// Ladder node for execution
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct LadderNode {
     /// Node type
     pub node_type: NodeType,
     /// Device address (for contacts, coils, etc.)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub address: Option<String>,
     /// Child nodes (for block nodes)
     pub children: Vec<LadderNode>,
     /// Timer/counter preset value
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub preset: Option<u32>,
     /// Timer time base
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub time_base: Option<SimTimeBase>,
     /// Comparison/math operands
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub operand1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub operand2: Option<String>,
     /// Destination for math operations
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub destination: Option<String>,
 }
 
@@ -381,18 +436,21 @@ impl LadderNode {
 }
 
 /// Ladder network for execution
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct LadderNetwork {
     /// Network ID
     pub id: u32,
     /// Root nodes in this network
     pub nodes: Vec<LadderNode>,
     /// Comment (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
 }
 
 /// Ladder program for execution
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct LadderProgram {
     /// Program name
     pub name: String,
@@ -554,6 +612,24 @@ pub struct NetworkExecutionResult {
     pub success: bool,
     /// Error message if failed
     pub error: Option<String>,
+    /// 명령어 타입별 소요시간(나노초). 명령어 프로파일링이 켜져 있을 때만 채워진다.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub instruction_timings: Vec<InstructionTiming>,
+}
+
+/// 한 네트워크 실행 중 특정 명령어 타입이 소비한 시간 합계.
+///
+/// 블록(series/parallel)은 자식 시간을 중복 집계하지 않도록 제외하고, 말단
+/// 명령어(접점/코일/타이머/카운터/비교/연산)의 배타적 시간만 기록한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionTiming {
+    /// Instruction type
+    pub node_type: NodeType,
+    /// Number of evaluations in the network
+    pub count: u32,
+    /// Accumulated time in nanoseconds
+    pub elapsed_ns: u64,
 }
 
 /// Result of executing a full program
@@ -568,6 +644,12 @@ pub struct ProgramExecutionResult {
     pub success: bool,
     /// Error message if failed
     pub error: Option<String>,
+    /// Faults raised during the scan, in order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub faults: Vec<ScanFault>,
+    /// Whether a fault with the `Halt` action stopped the scan
+    #[serde(default)]
+    pub halted: bool,
 }
 
 // ============================================================================
@@ -588,6 +670,14 @@ pub struct ProgramExecutor {
     scan_count: RwLock<u64>,
     /// Previous states for positive/negative edge contacts.
     edge_state: RwLock<HashMap<CanonicalAddress, bool>>,
+    /// Whether per-instruction timing is collected
+    instruction_profiling: AtomicBool,
+    /// Instruction timings of the network currently executing
+    instruction_scratch: Mutex<Vec<InstructionTiming>>,
+    /// Runtime error policy
+    fault_handling: RwLock<FaultHandling>,
+    /// Faults raised during the current scan
+    scan_faults: Mutex<Vec<ScanFault>>,
 }
 
 pub fn compile_program(
@@ -606,6 +696,57 @@ pub fn compile_program(
     })
 }
 
+/// Instruction the selected CPU model cannot execute
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsupportedInstruction {
+    pub network_id: u32,
+    pub node_type: NodeType,
+    pub family: PlcInstructionFamily,
+    pub message: String,
+}
+
+/// Instructions in `program` outside the CPU model's instruction families.
+/// The program still compiles and runs; callers surface these as warnings.
+pub fn unsupported_instructions(
+    program: &LadderProgram,
+    model: &PlcCpuModel,
+) -> Vec<UnsupportedInstruction> {
+    fn visit(
+        node: &LadderNode,
+        network_id: u32,
+        model: &PlcCpuModel,
+        found: &mut Vec<UnsupportedInstruction>,
+    ) {
+        if let Some(family) = node.node_type.instruction_family() {
+            if !model.supports(family) {
+                found.push(UnsupportedInstruction {
+                    network_id,
+                    node_type: node.node_type,
+                    family,
+                    message: format!(
+                        "Network {network_id}: {} does not support {} instructions ({:?})",
+                        model.name,
+                        family.as_str(),
+                        node.node_type
+                    ),
+                });
+            }
+        }
+        for child in &node.children {
+            visit(child, network_id, model, found);
+        }
+    }
+
+    let mut found = Vec::new();
+    for network in &program.networks {
+        for node in &network.nodes {
+            visit(node, network.id, model, &mut found);
+        }
+    }
+    found
+}
+
 fn compile_network(
     network: &LadderNetwork,
     profile: &dyn VendorProfile,
@@ -638,7 +779,11 @@ fn compile_node(node: &LadderNode, profile: &dyn VendorProfile) -> ExecutionResu
         time_base: node.time_base,
         operand1: compile_operand(node.operand1.as_deref(), profile)?,
         operand2: compile_operand(node.operand2.as_deref(), profile)?,
-        destination: compile_optional_address(node.destination.as_deref(), profile)?,
+        destination: node
+            .destination
+            .as_deref()
+            .map(|destination| compile_memory_operand(destination, profile))
+            .transpose()?,
     })
 }
 
@@ -654,8 +799,27 @@ fn compile_operand(
         return Ok(Some(CompiledOperand::Constant(value)));
     }
 
-    let address = compile_address(operand, profile)?;
-    Ok(Some(CompiledOperand::Address(address)))
+    compile_memory_operand(operand, profile).map(Some)
+}
+
+/// Operand or destination in device memory. Double words and words that
+/// straddle two canonical words keep their byte span.
+fn compile_memory_operand(
+    address: &str,
+    profile: &dyn VendorProfile,
+) -> ExecutionResult<CompiledOperand> {
+    let vendor_address = parse_vendor_address(address, profile)?;
+    let invalid = |_| ExecutionError::InvalidAddress(address.to_string());
+    if let Some(span) = profile.canonical_span(&vendor_address).map_err(invalid)? {
+        return Ok(CompiledOperand::Span(span));
+    }
+    if let Some(first) = profile
+        .canonical_bit_word(&vendor_address)
+        .map_err(invalid)?
+    {
+        return Ok(CompiledOperand::BitWord(first));
+    }
+    to_canonical(address, &vendor_address, profile).map(CompiledOperand::Address)
 }
 
 fn compile_optional_address(
@@ -671,20 +835,50 @@ fn compile_address(
     address: &str,
     profile: &dyn VendorProfile,
 ) -> ExecutionResult<CanonicalAddress> {
-    let vendor_address = profile
-        .parse_address(address)
-        .map_err(|_| ExecutionError::InvalidAddress(address.to_string()))?;
+    let vendor_address = parse_vendor_address(address, profile)?;
+    // 여러 칸에 걸친 피연산자는 접점·코일처럼 한 칸을 가리키는 자리에 쓸 수 없다
+    if matches!(profile.canonical_span(&vendor_address), Ok(Some(_)))
+        || matches!(profile.canonical_bit_word(&vendor_address), Ok(Some(_)))
+    {
+        return Err(ExecutionError::InvalidAddress(address.to_string()));
+    }
+    to_canonical(address, &vendor_address, profile)
+}
 
+fn parse_vendor_address(
+    address: &str,
+    profile: &dyn VendorProfile,
+) -> ExecutionResult<VendorAddress> {
+    profile.parse_address(address).map_err(|err| match err {
+        VendorProfileError::AddressOutOfRange { max_index, .. } => {
+            ExecutionError::AddressOutOfRange {
+                address: address.to_string(),
+                max_index,
+            }
+        }
+        _ => ExecutionError::InvalidAddress(address.to_string()),
+    })
+}
+
+fn to_canonical(
+    address: &str,
+    vendor_address: &VendorAddress,
+    profile: &dyn VendorProfile,
+) -> ExecutionResult<CanonicalAddress> {
     profile
-        .to_canonical(&vendor_address)
+        .to_canonical(vendor_address)
         .map_err(|_| ExecutionError::InvalidAddress(address.to_string()))
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum CompiledOperand {
     Constant(i32),
     Address(CanonicalAddress),
+    /// Double word or unaligned word (S7 `MD20`, `MW11`)
+    Span(CanonicalByteSpan),
+    /// Sixteen bits of a bit area from this one (Omron channel `W10`)
+    BitWord(CanonicalAddress),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -704,7 +898,7 @@ pub struct CompiledNode {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub operand2: Option<CompiledOperand>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub destination: Option<CanonicalAddress>,
+    pub destination: Option<CompiledOperand>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -723,6 +917,46 @@ pub struct CompiledProgram {
     pub networks: Vec<CompiledNetwork>,
 }
 
+impl CompiledProgram {
+    /// Addresses written by coils and math/move destinations. Event replay
+    /// skips recorded writes to them since the program produces them again.
+    pub fn output_addresses(&self) -> Vec<CanonicalAddress> {
+        fn collect(node: &CompiledNode, addresses: &mut Vec<CanonicalAddress>) {
+            match node.node_type {
+                NodeType::CoilOut | NodeType::CoilSet | NodeType::CoilRst => {
+                    addresses.extend(node.address);
+                }
+                NodeType::MathAdd
+                | NodeType::MathSub
+                | NodeType::MathMul
+                | NodeType::MathDiv
+                | NodeType::MathMod
+                | NodeType::MathMov => match node.destination {
+                    Some(CompiledOperand::Address(dest)) => addresses.push(dest),
+                    Some(CompiledOperand::BitWord(first)) => addresses.extend(
+                        (0..16).map(|bit| CanonicalAddress::new(first.area, first.index + bit)),
+                    ),
+                    Some(CompiledOperand::Span(span)) => addresses.extend(
+                        span.words()
+                            .map(|index| CanonicalAddress::new(span.area, index)),
+                    ),
+                    Some(CompiledOperand::Constant(_)) | None => {}
+                },
+                _ => {}
+            }
+            for child in &node.children {
+                collect(child, addresses);
+            }
+        }
+
+        let mut addresses = Vec::new();
+        for node in self.networks.iter().flat_map(|network| &network.nodes) {
+            collect(node, &mut addresses);
+        }
+        addresses
+    }
+}
+
 impl ProgramExecutor {
     /// Create a new ProgramExecutor
     pub fn new(
@@ -736,23 +970,63 @@ impl ProgramExecutor {
             counter_mgr,
             scan_count: RwLock::new(0),
             edge_state: RwLock::new(HashMap::new()),
+            instruction_profiling: AtomicBool::new(false),
+            instruction_scratch: Mutex::new(Vec::new()),
+            fault_handling: RwLock::new(FaultHandling::default()),
+            scan_faults: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Set the runtime error policy
+    pub fn set_fault_handling(&self, handling: FaultHandling) {
+        *self.fault_handling.write() = handling;
+    }
+
+    /// Current runtime error policy
+    pub fn fault_handling(&self) -> FaultHandling {
+        self.fault_handling.read().clone()
+    }
+
+    /// 명령어 타입별 시간 측정을 켜거나 끈다. 말단 노드마다 시계를 읽으므로
+    /// 기본은 꺼져 있다.
+    pub fn set_instruction_profiling(&self, enabled: bool) {
+        self.instruction_profiling.store(enabled, Ordering::Relaxed);
+        if !enabled {
+            self.instruction_scratch.lock().clear();
         }
     }
 
+    /// Whether per-instruction timing is enabled
+    pub fn instruction_profiling(&self) -> bool {
+        self.instruction_profiling.load(Ordering::Relaxed)
+    }
+
     /// Execute a full ladder program
     pub fn execute_program(&self, program: &CompiledProgram) -> ProgramExecutionResult {
         let watch = StopWatch::start();
         let mut network_results = Vec::with_capacity(program.networks.len());
         let mut all_success = true;
         let mut first_error: Option<String> = None;
+        let mut halted = false;
 
         for network in &program.networks {
             let result = self.execute_network(network);
-            if !result.success && first_error.is_none() {
-                first_error = result.error.clone();
+            if !result.success {
                 all_success = false;
+                if first_error.is_none() {
+                    first_error = result.error.clone();
+                }
             }
             network_results.push(result);
+
+            halted = self
+                .scan_faults
+                .lock()
+                .last()
+                .is_some_and(|fault| fault.action == FaultAction::Halt);
+            if halted {
+                break;
+            }
         }
 
         // Increment scan count
@@ -761,50 +1035,90 @@ impl ProgramExecutor {
         ProgramExecutionResult {
             network_results,
             total_time_us: watch.elapsed_us(),
-            success: all_success,
+            success: all_success && !halted,
             error: first_error,
+            faults: std::mem::take(&mut *self.scan_faults.lock()),
+            halted,
         }
     }
 
     /// Execute a single network
     pub fn execute_network(&self, network: &CompiledNetwork) -> NetworkExecutionResult {
         let watch = StopWatch::start();
+        let (completed, error) = self.run_network_nodes(network);
 
+        NetworkExecutionResult {
+            network_id: network.id,
+            execution_time_us: watch.elapsed_us(),
+            success: completed,
+            error,
+            instruction_timings: self.take_instruction_timings(),
+        }
+    }
+
+    /// 네트워크의 렁을 차례로 실행한다. 오류는 fault 정책에 따라 처리하며,
+    /// 네트워크를 끝까지 실행했는지와 중단시킨 오류 메시지를 돌려준다.
+    /// `Continue` 로 넘긴 오류는 `scan_faults` 에만 남는다.
+    fn run_network_nodes(&self, network: &CompiledNetwork) -> (bool, Option<String>) {
         for node in &network.nodes {
-            // Evaluate input condition
-            let power_flow = match self.evaluate_node(node) {
-                Ok(result) => result,
-                Err(e) => {
-                    return NetworkExecutionResult {
-                        network_id: network.id,
-                        execution_time_us: watch.elapsed_us(),
-                        success: false,
-                        error: Some(e.to_string()),
-                    };
+            let outcome = self
+                .evaluate_node(node)
+                .and_then(|power_flow| self.execute_output(node, power_flow));
+            if let Err(err) = outcome {
+                let action = self.raise_fault(network.id, &err);
+                if action != FaultAction::Continue {
+                    return (false, Some(err.to_string()));
                 }
-            };
-
-            // Execute output if applicable
-            if let Err(e) = self.execute_output(node, power_flow) {
-                return NetworkExecutionResult {
-                    network_id: network.id,
-                    execution_time_us: watch.elapsed_us(),
-                    success: false,
-                    error: Some(e.to_string()),
-                };
             }
         }
+        (true, None)
+    }
 
-        NetworkExecutionResult {
-            network_id: network.id,
-            execution_time_us: watch.elapsed_us(),
-            success: true,
-            error: None,
+    /// Record a fault, set the error flag and diagnostic word, and return the action
+    fn raise_fault(&self, network_id: u32, error: &ExecutionError) -> FaultAction {
+        let handling = self.fault_handling.read();
+        let kind = error.fault_kind();
+        let action = handling.policy.action_for(kind);
+        let code = kind.diagnostic_code();
+
+        // 시스템 영역 기록 실패는 원래 오류를 가리지 않도록 무시한다.
+        if let Some(flag) = handling.error_flag {
+            let _ = self.runtime.write_bool(
+                flag,
+                true,
+                modone_contract::CanonicalWriteSource::InternalRuntime,
+            );
         }
+        if let Some(word) = handling.diagnostic_word {
+            let _ = self.runtime.write_word_value(
+                word,
+                code,
+                modone_contract::CanonicalWriteSource::InternalRuntime,
+            );
+        }
+
+        self.scan_faults.lock().push(ScanFault {
+            network_id,
+            kind,
+            code,
+            message: error.to_string(),
+            action,
+        });
+        action
     }
 
     /// Evaluate a ladder node and return power flow state
     pub fn evaluate_node(&self, node: &CompiledNode) -> ExecutionResult<bool> {
+        if self.should_time(node.node_type) && !is_output_node(node.node_type) {
+            let watch = StopWatch::start();
+            let result = self.evaluate_node_untimed(node);
+            self.record_instruction(node.node_type, watch.elapsed_ns());
+            return result;
+        }
+        self.evaluate_node_untimed(node)
+    }
+
+    fn evaluate_node_untimed(&self, node: &CompiledNode) -> ExecutionResult<bool> {
         match node.node_type {
             // Contacts
             NodeType::ContactNo => {
@@ -907,6 +1221,16 @@ impl ProgramExecutor {
 
     /// Execute output operations
     pub fn execute_output(&self, node: &CompiledNode, input: bool) -> ExecutionResult<()> {
+        if self.should_time(node.node_type) && is_output_node(node.node_type) {
+            let watch = StopWatch::start();
+            let result = self.execute_output_untimed(node, input);
+            self.record_instruction(node.node_type, watch.elapsed_ns());
+            return result;
+        }
+        self.execute_output_untimed(node, input)
+    }
+
+    fn execute_output_untimed(&self, node: &CompiledNode, input: bool) -> ExecutionResult<()> {
         match node.node_type {
             // Coils
             NodeType::CoilOut => {
@@ -1000,6 +1324,32 @@ impl ProgramExecutor {
     // Helper Methods
     // ========================================================================
 
+    fn should_time(&self, node_type: NodeType) -> bool {
+        !matches!(node_type, NodeType::BlockSeries | NodeType::BlockParallel)
+            && self.instruction_profiling.load(Ordering::Relaxed)
+    }
+
+    fn record_instruction(&self, node_type: NodeType, elapsed_ns: u64) {
+        let mut scratch = self.instruction_scratch.lock();
+        if let Some(entry) = scratch.iter_mut().find(|t| t.node_type == node_type) {
+            entry.count += 1;
+            entry.elapsed_ns += elapsed_ns;
+        } else {
+            scratch.push(InstructionTiming {
+                node_type,
+                count: 1,
+                elapsed_ns,
+            });
+        }
+    }
+
+    fn take_instruction_timings(&self) -> Vec<InstructionTiming> {
+        if !self.instruction_profiling.load(Ordering::Relaxed) {
+            return Vec::new();
+        }
+        std::mem::take(&mut *self.instruction_scratch.lock())
+    }
+
     fn require_address(
         &self,
         address: Option<CanonicalAddress>,
@@ -1047,9 +1397,65 @@ impl ProgramExecutor {
                     })
                 }
             }
+            CompiledOperand::BitWord(first) => {
+                Ok(self.runtime.read_bit_word(*first)? as i16 as i32)
+            }
+            CompiledOperand::Span(span) => {
+                let value = span.decode(&self.read_span_words(span)?);
+                Ok(match span.len {
+                    2 => value as u16 as i16 as i32,
+                    _ => value as i32,
+                })
+            }
         }
     }
 
+    fn read_span_words(&self, span: &CanonicalByteSpan) -> ExecutionResult<Vec<u16>> {
+        span.words()
+            .map(|index| {
+                self.runtime
+                    .read_word_value(CanonicalAddress::new(span.area, index))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    /// Store an instruction result; bit destinations are left unchanged.
+    fn write_result(&self, dest: &CompiledOperand, value: i32) -> ExecutionResult<()> {
+        match dest {
+            CompiledOperand::Address(dest)
+                if dest.area.is_word_area() && dest.bit_index.is_none() =>
+            {
+                self.runtime.write_word_value(
+                    *dest,
+                    value as u16,
+                    modone_contract::CanonicalWriteSource::Simulation,
+                )?;
+            }
+            CompiledOperand::BitWord(first) => {
+                self.runtime.write_bit_word(
+                    *first,
+                    value as u16,
+                    modone_contract::CanonicalWriteSource::Simulation,
+                )?;
+            }
+            CompiledOperand::Span(span) => {
+                // 걸친 워드의 나머지 바이트는 유지한다
+                let mut words = self.read_span_words(span)?;
+                span.encode(&mut words, value as u32);
+                for (index, word) in span.words().zip(words) {
+                    self.runtime.write_word_value(
+                        CanonicalAddress::new(span.area, index),
+                        word,
+                        modone_contract::CanonicalWriteSource::Simulation,
+                    )?;
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
     /// Compare two operands
     fn compare<F>(&self, node: &CompiledNode, op: F) -> ExecutionResult<bool>
     where
@@ -1176,15 +1582,7 @@ impl ProgramExecutor {
         let op2 = self.read_operand(op2)?;
         let result = op(op1, op2);
 
-        if dest.area.is_word_area() && dest.bit_index.is_none() {
-            self.runtime.write_word_value(
-                dest,
-                result as u16,
-                modone_contract::CanonicalWriteSource::Simulation,
-            )?;
-        }
-
-        Ok(())
+        self.write_result(&dest, result)
     }
 
     /// Execute division with zero check
@@ -1205,21 +1603,12 @@ impl ProgramExecutor {
         let op2 = self.read_operand(op2)?;
 
         if op2 == 0 {
-            // Division by zero - keep destination unchanged
-            return Ok(());
+            return self.division_by_zero();
         }
 
         let result = op1 / op2;
 
-        if dest.area.is_word_area() && dest.bit_index.is_none() {
-            self.runtime.write_word_value(
-                *dest,
-                result as u16,
-                modone_contract::CanonicalWriteSource::Simulation,
-            )?;
-        }
-
-        Ok(())
+        self.write_result(dest, result)
     }
 
     /// Execute modulo with zero check
@@ -1240,19 +1629,20 @@ impl ProgramExecutor {
         let op2 = self.read_operand(op2)?;
 
         if op2 == 0 {
-            return Ok(());
+            return self.division_by_zero();
         }
 
         let result = op1 % op2;
 
-        if dest.area.is_word_area() && dest.bit_index.is_none() {
-            self.runtime.write_word_value(
-                *dest,
-                result as u16,
-                modone_contract::CanonicalWriteSource::Simulation,
-            )?;
-        }
+        self.write_result(dest, result)
+    }
 
+    /// Destination stays unchanged. With a fault policy the error is reported
+    /// to it; without one the instruction is silently skipped.
+    fn division_by_zero(&self) -> ExecutionResult<()> {
+        if self.fault_handling.read().is_configured() {
+            return Err(ExecutionError::DivisionByZero);
+        }
         Ok(())
     }
 
@@ -1268,15 +1658,7 @@ impl ProgramExecutor {
 
         let value = self.read_operand(op1)?;
 
-        if dest.area.is_word_area() && dest.bit_index.is_none() {
-            self.runtime.write_word_value(
-                *dest,
-                value as u16,
-                modone_contract::CanonicalWriteSource::Simulation,
-            )?;
-        }
-
-        Ok(())
+        self.write_result(dest, value)
     }
 
     /// Get current scan count
@@ -1288,6 +1670,27 @@ impl ProgramExecutor {
     pub fn reset_scan_count(&self) {
         *self.scan_count.write() = 0;
     }
+
+    /// 메모리·타이머·카운터·에지 상태를 체크포인트로 캡처한다.
+    pub fn capture_checkpoint(&self, scan_count: u64) -> ScanCheckpoint {
+        ScanCheckpoint {
+            scan_count,
+            captured_at: modone_contract::clock::now_rfc3339(),
+            memory: self.runtime.snapshot(),
+            timers: self.timer_mgr.snapshot(),
+            counters: self.counter_mgr.snapshot(),
+            edges: self.edge_state.read().clone(),
+        }
+    }
+
+    /// 체크포인트 시점의 상태로 되돌린다. 메모리 복원은 bus 이벤트를 내지 않는다.
+    pub fn restore_checkpoint(&self, checkpoint: &ScanCheckpoint) -> ExecutionResult<()> {
+        self.runtime.restore_snapshot(&checkpoint.memory)?;
+        self.timer_mgr.restore(&checkpoint.timers);
+        self.counter_mgr.restore(&checkpoint.counters);
+        *self.edge_state.write() = checkpoint.edges.clone();
+        Ok(())
+    }
 }
 
 impl Default for ProgramExecutor {
@@ -1417,6 +1820,25 @@ mod tests {
         assert!(!evaluate(&executor, &node).unwrap());
     }
 
+    #[test]
+    fn test_compile_rejects_address_beyond_model_range() {
+        let xbm = LsProfile::new("XBM-DN16S".to_string(), PlcHardwareTopology::default());
+
+        let node = LadderNode::contact(NodeType::ContactNo, "D5119.0");
+        assert!(compile_node(&node, &xbm).is_ok());
+
+        let node = LadderNode::contact(NodeType::ContactNo, "D5120.0");
+        assert!(matches!(
+            compile_node(&node, &xbm),
+            Err(ExecutionError::AddressOutOfRange {
+                max_index: 5119,
+                ..
+            })
+        ));
+        // 같은 주소라도 XGK 는 D32767 까지 허용
+        assert!(compile_node(&node, &test_profile()).is_ok());
+    }
+
     #[test]
     fn test_contact_n_falling_edge() {
         let (executor, memory, _, _) = create_executor();
@@ -1582,12 +2004,119 @@ mod tests {
         memory.write_word(SimWordDeviceType::D, 2, 999).unwrap();
 
         let node = LadderNode::math(NodeType::MathDiv, "D0", "D1", "D2");
-        // Should not panic, just keep destination unchanged
+        // Without a fault policy the destination stays unchanged and no fault is raised
         execute_output(&executor, &node, true).unwrap();
+        assert_eq!(memory.read_word(SimWordDeviceType::D, 2).unwrap(), 999);
+
+        let node = LadderNode::math(NodeType::MathMod, "D0", "D1", "D2");
+        execute_output(&executor, &node, true).unwrap();
+        assert_eq!(memory.read_word(SimWordDeviceType::D, 2).unwrap(), 999);
 
+        // With a policy it is reported; the destination still stays unchanged
+        let policy = crate::fault::FaultPolicy {
+            action: FaultAction::Continue,
+            ..Default::default()
+        };
+        executor.set_fault_handling(policy.resolve(&test_profile()).unwrap());
+        let node = LadderNode::math(NodeType::MathDiv, "D0", "D1", "D2");
+        assert!(matches!(
+            execute_output(&executor, &node, true),
+            Err(ExecutionError::DivisionByZero)
+        ));
         assert_eq!(memory.read_word(SimWordDeviceType::D, 2).unwrap(), 999);
     }
 
+    /// Network 0: DIV D0/D1 → D2 then SET M0. Network 1: SET M1.
+    fn faulting_program() -> LadderProgram {
+        let always_on = || LadderNode::contact(NodeType::ContactNc, "M99");
+        LadderProgram {
+            name: "Faults".to_string(),
+            networks: vec![
+                LadderNetwork {
+                    id: 0,
+                    nodes: vec![
+                        LadderNode::series(vec![
+                            always_on(),
+                            LadderNode::math(NodeType::MathDiv, "D0", "D1", "D2"),
+                        ]),
+                        LadderNode::series(vec![
+                            always_on(),
+                            LadderNode::coil(NodeType::CoilSet, "M0"),
+                        ]),
+                    ],
+                    comment: None,
+                },
+                LadderNetwork {
+                    id: 1,
+                    nodes: vec![LadderNode::series(vec![
+                        always_on(),
+                        LadderNode::coil(NodeType::CoilSet, "M1"),
+                    ])],
+                    comment: None,
+                },
+            ],
+        }
+    }
+
+    fn run_with_policy(
+        action: FaultAction,
+    ) -> (ProgramExecutionResult, Arc<CanonicalRuntimeFacade>) {
+        let (executor, memory, _, _) = create_executor();
+        let policy = crate::fault::FaultPolicy {
+            action,
+            error_flag: Some("M50".to_string()),
+            diagnostic_word: Some("D9000".to_string()),
+            ..Default::default()
+        };
+        executor.set_fault_handling(policy.resolve(&test_profile()).unwrap());
+        memory.write_word(SimWordDeviceType::D, 0, 10).unwrap();
+        let result = execute_program(&executor, &faulting_program());
+        (result, memory)
+    }
+
+    #[test]
+    fn test_fault_policy_continue() {
+        let (result, memory) = run_with_policy(FaultAction::Continue);
+
+        assert!(result.success);
+        assert!(result.error.is_none());
+        assert!(!result.halted);
+        assert_eq!(result.faults.len(), 1);
+        assert_eq!(
+            result.faults[0].kind,
+            crate::fault::FaultKind::DivisionByZero
+        );
+        assert!(memory.read_bit(SimBitDeviceType::M, 0).unwrap());
+        assert!(memory.read_bit(SimBitDeviceType::M, 1).unwrap());
+        assert!(memory.read_bit(SimBitDeviceType::M, 50).unwrap());
+        assert_eq!(
+            memory.read_word(SimWordDeviceType::D, 9000).unwrap(),
+            result.faults[0].code
+        );
+    }
+
+    #[test]
+    fn test_fault_policy_skip_network() {
+        let (result, memory) = run_with_policy(FaultAction::SkipNetwork);
+
+        assert!(!result.success);
+        assert!(result.error.is_some());
+        assert!(!result.network_results[0].success);
+        assert!(!memory.read_bit(SimBitDeviceType::M, 0).unwrap());
+        assert!(memory.read_bit(SimBitDeviceType::M, 1).unwrap());
+    }
+
+    #[test]
+    fn test_fault_policy_halt() {
+        let (result, memory) = run_with_policy(FaultAction::Halt);
+
+        assert!(result.halted);
+        assert!(!result.success);
+        assert_eq!(result.network_results.len(), 1);
+        assert!(!memory.read_bit(SimBitDeviceType::M, 1).unwrap());
+        assert_eq!(result.faults[0].action, FaultAction::Halt);
+    }
+
     #[test]
     fn test_math_mov() {
         let (executor, memory, _, _) = create_executor();
@@ -1602,6 +2131,103 @@ mod tests {
         assert_eq!(memory.read_word(SimWordDeviceType::D, 1).unwrap(), 42);
     }
 
+    #[test]
+    fn test_math_s7_double_and_unaligned_words() {
+        let (executor, memory, _, _) = create_executor();
+        let s7 = plc_model::S7Profile::new("S7-1200".to_string(), PlcHardwareTopology::default());
+        let word = |index| {
+            memory
+                .read_word_value(CanonicalAddress::new(
+                    modone_contract::CanonicalAreaKind::DataWord,
+                    index,
+                ))
+                .unwrap()
+        };
+
+        // MD10 = MB10..MB13, 상위 바이트부터
+        let mut mov = LadderNode::math(NodeType::MathMov, "305419896", "0", "MD10");
+        mov.operand2 = None;
+        let compiled = compile_node(&mov, &s7).unwrap();
+        executor.execute_output(&compiled, true).unwrap();
+        assert_eq!((word(5), word(6)), (0x1234, 0x5678));
+
+        // MW11 = MB11, MB12 — 두 워드에 걸친다
+        let add = LadderNode::math(NodeType::MathAdd, "MW11", "1", "MW20");
+        let compiled = compile_node(&add, &s7).unwrap();
+        executor.execute_output(&compiled, true).unwrap();
+        assert_eq!(word(10), 0x3457);
+
+        let contact = LadderNode::contact(NodeType::ContactNo, "MD10");
+        assert!(matches!(
+            compile_node(&contact, &s7),
+            Err(ExecutionError::InvalidAddress(_))
+        ));
+    }
+
+    #[test]
+    fn test_math_ls_iec_double_words() {
+        let (executor, memory, _, _) = create_executor();
+        let xgi =
+            plc_model::LsProfile::new("XGI-CPUUN".to_string(), PlcHardwareTopology::default());
+        let word = |index| {
+            memory
+                .read_word_value(CanonicalAddress::new(
+                    modone_contract::CanonicalAreaKind::DataWord,
+                    index,
+                ))
+                .unwrap()
+        };
+
+        // %MD10 = %MW20(하위) + %MW21(상위)
+        let mut mov = LadderNode::math(NodeType::MathMov, "305419896", "0", "%MD10");
+        mov.operand2 = None;
+        executor
+            .execute_output(&compile_node(&mov, &xgi).unwrap(), true)
+            .unwrap();
+        assert_eq!((word(20), word(21)), (0x5678, 0x1234));
+
+        let add = LadderNode::math(NodeType::MathAdd, "%MD10", "1", "%MD11");
+        executor
+            .execute_output(&compile_node(&add, &xgi).unwrap(), true)
+            .unwrap();
+        assert_eq!((word(22), word(23)), (0x5679, 0x1234));
+    }
+
+    #[test]
+    fn test_math_omron_channel_words() {
+        let (executor, memory, _, _) = create_executor();
+        let omron =
+            plc_model::OmronProfile::new("CJ2M-CPU31".to_string(), PlcHardwareTopology::default());
+        let bit = |index| {
+            memory
+                .read_bool(CanonicalAddress::new(
+                    modone_contract::CanonicalAreaKind::InternalBit,
+                    index,
+                ))
+                .unwrap()
+        };
+
+        // W10 = W10.00..W10.15
+        let mut mov = LadderNode::math(NodeType::MathMov, "5", "0", "W10");
+        mov.operand2 = None;
+        executor
+            .execute_output(&compile_node(&mov, &omron).unwrap(), true)
+            .unwrap();
+        assert!(bit(160) && !bit(161) && bit(162));
+
+        let add = LadderNode::math(NodeType::MathAdd, "W10", "1", "W11");
+        executor
+            .execute_output(&compile_node(&add, &omron).unwrap(), true)
+            .unwrap();
+        assert!(!bit(176) && bit(177) && bit(178));
+
+        let contact = LadderNode::contact(NodeType::ContactNo, "W10");
+        assert!(matches!(
+            compile_node(&contact, &omron),
+            Err(ExecutionError::InvalidAddress(_))
+        ));
+    }
+
     #[test]
     fn test_math_only_executes_on_true_input() {
         let (executor, memory, _, _) = create_executor();
@@ -1694,4 +2320,63 @@ mod tests {
         executor.reset_scan_count();
         assert_eq!(executor.scan_count(), 0);
     }
+
+    #[test]
+    fn test_instruction_profiling_collects_leaf_timings() {
+        let (executor, _, _, _) = create_executor();
+        let program = LadderProgram {
+            name: "Test".to_string(),
+            networks: vec![LadderNetwork {
+                id: 0,
+                nodes: vec![LadderNode::series(vec![
+                    LadderNode::contact(NodeType::ContactNo, "M0"),
+                    LadderNode::coil(NodeType::CoilOut, "P0"),
+                ])],
+                comment: None,
+            }],
+        };
+
+        let result = execute_program(&executor, &program);
+        assert!(result.network_results[0].instruction_timings.is_empty());
+
+        executor.set_instruction_profiling(true);
+        let result = execute_program(&executor, &program);
+        let timings = &result.network_results[0].instruction_timings;
+        let types: Vec<_> = timings.iter().map(|t| t.node_type).collect();
+        assert!(types.contains(&NodeType::ContactNo));
+        assert!(types.contains(&NodeType::CoilOut));
+        assert!(!types.contains(&NodeType::BlockSeries));
+        let coil = timings
+            .iter()
+            .find(|t| t.node_type == NodeType::CoilOut)
+            .unwrap();
+        assert_eq!(coil.count, 1);
+    }
+
+    #[test]
+    fn test_unsupported_instructions_for_cpu_model() {
+        let program = LadderProgram {
+            name: "Test".to_string(),
+            networks: vec![LadderNetwork {
+                id: 3,
+                nodes: vec![LadderNode::series(vec![
+                    LadderNode::contact(NodeType::ContactNo, "M0"),
+                    LadderNode::timer(NodeType::TimerTon, "T0", 10, SimTimeBase::Ms100),
+                    LadderNode::counter(NodeType::CounterCtu, "C0", 5),
+                ])],
+                comment: None,
+            }],
+        };
+
+        let s7_1200 =
+            plc_model::find_cpu_model(plc_model::VendorProfileId::SiemensS7, "S7-1214C").unwrap();
+        let found = unsupported_instructions(&program, s7_1200);
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].network_id, 3);
+        assert_eq!(found[0].family, PlcInstructionFamily::Timer);
+        assert_eq!(found[1].node_type, NodeType::CounterCtu);
+
+        let xgk = plc_model::find_cpu_model(plc_model::VendorProfileId::LsXg5000, "XGK").unwrap();
+        assert!(unsupported_instructions(&program, xgk).is_empty());
+    }
 }
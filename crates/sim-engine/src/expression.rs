@@ -0,0 +1,1061 @@
+//! 디버거 조건식 언어
+//!
+//! 조건 브레이크포인트와 식(expression) watch에 쓰는 작은 식 언어다.
+//! 추가 시점에 활성 `VendorProfile`로 컴파일해 잘못된 식을 즉시 거부하고,
+//! 평가는 canonical 메모리와 태그 레지스트리만 읽는다.
+//!
+//! 문법 (우선순위 낮은 순):
+//!
+//! ```text
+//! or      := and (("||" | OR) and)*
+//! and     := not (("&&" | AND) not)*
+//! not     := ("!" | NOT) not | cmp
+//! cmp     := sum (("==" | "=" | "!=" | "<>" | "<" | "<=" | ">" | ">=") sum)?
+//! sum     := term (("+" | "-") term)*
+//! term    := unary (("*" | "/" | "%") unary)*
+//! unary   := "-" unary | primary
+//! primary := NUMBER | TRUE | FALSE | "(" or ")" | STRING | IDENT | IDENT "(" args ")"
+//! ```
+//!
+//! - 식별자는 먼저 vendor 주소(`D100`, `M0.3`, `X0`)로, 실패하면 태그 id로 해석한다.
+//!   공백·특수문자가 있는 태그 id는 `"Line 1.Run"`처럼 따옴표로 쓴다.
+//! - 비트는 BOOL, 워드는 부호 있는 INT16으로 읽는다(실행기 피연산자와 동일). 비트
+//!   영역의 채널 워드(Omron `W10`)는 16 비트를 묶어 워드로 읽는다.
+//! - 타입 읽기: `int16`, `uint16`, `int32`, `uint32`, `real` — 32비트는 연속 2워드이고,
+//!   워드 순서는 프로파일 기본값(S7 은 상위 워드 먼저, 그 밖은 하위 워드 먼저)이며
+//!   `with_word_order`로 바꿀 수 있다.
+//! - 숫자로 시작하는 토큰도 점이 있으면 먼저 vendor 주소로 시도한다(Omron `0.00`,
+//!   `1.05`). 그래서 Omron 에서는 `2.50` 같은 소수가 CIO 비트가 되며, 실수 상수는
+//!   `2.5e0`처럼 지수를 붙여 쓴다.
+//! - 에지: `rising(x)`, `falling(x)` — 식 인스턴스마다 직전 평가값을 기억한다.
+
+use modone_contract::typed::decode_words;
+use modone_contract::{CanonicalAddress, CanonicalValue, TypedDataType, TypedValue, WordOrder};
+use plc_model::{PlcManufacturer, VendorProfile};
+use thiserror::Error;
+
+use super::memory::CanonicalRuntimeFacade;
+use super::tag_registry::TagRegistry;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Expression compile/evaluation errors
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ExpressionError {
+    /// Syntax error at a character position
+    #[error("syntax error at {position}: {message}")]
+    Syntax { position: usize, message: String },
+
+    /// Identifier is neither a vendor address nor a registered tag
+    #[error("unknown operand: {0}")]
+    UnknownOperand(String),
+
+    /// Unknown function name
+    #[error("unknown function: {0}")]
+    UnknownFunction(String),
+
+    /// Wrong number of function arguments
+    #[error("{function}() expects {expected} argument(s), found {found}")]
+    ArgumentCount {
+        function: String,
+        expected: usize,
+        found: usize,
+    },
+
+    /// Function argument has the wrong shape
+    #[error("{function}(): {message}")]
+    InvalidArgument { function: String, message: String },
+
+    /// Division or modulo by zero
+    #[error("division by zero")]
+    DivisionByZero,
+
+    /// Memory or tag read failure during evaluation
+    #[error("read failed: {0}")]
+    Read(String),
+}
+
+/// Result type for expression operations
+pub type ExpressionResult<T> = Result<T, ExpressionError>;
+
+// ============================================================================
+// Values
+// ============================================================================
+
+/// Evaluated expression value
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExprValue {
+    Bool(bool),
+    Int(i64),
+    Real(f64),
+}
+
+impl ExprValue {
+    /// Truthiness (non-zero numbers are true)
+    pub fn as_bool(self) -> bool {
+        match self {
+            ExprValue::Bool(b) => b,
+            ExprValue::Int(v) => v != 0,
+            ExprValue::Real(v) => v != 0.0,
+        }
+    }
+
+    fn as_f64(self) -> f64 {
+        match self {
+            ExprValue::Bool(b) => b as i64 as f64,
+            ExprValue::Int(v) => v as f64,
+            ExprValue::Real(v) => v,
+        }
+    }
+
+    fn as_i64(self) -> Option<i64> {
+        match self {
+            ExprValue::Bool(b) => Some(b as i64),
+            ExprValue::Int(v) => Some(v),
+            ExprValue::Real(_) => None,
+        }
+    }
+
+    /// JSON representation for watch display
+    pub fn to_json(self) -> serde_json::Value {
+        match self {
+            ExprValue::Bool(b) => serde_json::json!(b),
+            ExprValue::Int(v) => serde_json::json!(v),
+            ExprValue::Real(v) => serde_json::json!(v),
+        }
+    }
+}
+
+// ============================================================================
+// AST
+// ============================================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BinaryOp {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypedRead {
+    Int16,
+    UInt16,
+    Int32,
+    UInt32,
+    Real,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operand {
+    Address(CanonicalAddress),
+    /// Channel word over sixteen bits of a bit area (Omron `W10`)
+    BitWord(CanonicalAddress),
+    Tag(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node {
+    Literal(ExprValue),
+    Operand(Operand),
+    Typed(TypedRead, Operand),
+    Not(Box<Node>),
+    Neg(Box<Node>),
+    Binary(BinaryOp, Box<Node>, Box<Node>),
+    /// Edge detection; `slot` indexes `Expression::edge_state`
+    Edge {
+        rising: bool,
+        slot: usize,
+        inner: Box<Node>,
+    },
+}
+
+/// Compiled debugger expression
+#[derive(Debug, Clone)]
+pub struct Expression {
+    source: String,
+    root: Node,
+    edge_state: Vec<bool>,
+    word_order: WordOrder,
+}
+
+impl Expression {
+    /// Parse and resolve `source` against the vendor profile and tag registry.
+    pub fn compile(
+        source: &str,
+        profile: &dyn VendorProfile,
+        tags: &TagRegistry,
+    ) -> ExpressionResult<Self> {
+        let tokens = tokenize(source, profile)?;
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            profile,
+            tags,
+            edge_slots: 0,
+        };
+        let root = parser.parse_or()?;
+        if let Some(token) = parser.tokens.get(parser.pos) {
+            return Err(ExpressionError::Syntax {
+                position: token.position,
+                message: format!("unexpected '{}'", token.kind),
+            });
+        }
+        let edge_slots = parser.edge_slots;
+
+        Ok(Self {
+            source: source.trim().to_string(),
+            root,
+            edge_state: vec![false; edge_slots],
+            word_order: default_word_order(profile),
+        })
+    }
+
+    /// Override the word order of 32-bit reads
+    pub fn with_word_order(mut self, word_order: WordOrder) -> Self {
+        self.word_order = word_order;
+        self
+    }
+
+    /// Original expression text
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Evaluate the expression. Edge functions update their remembered state.
+    pub fn evaluate(
+        &mut self,
+        memory: &CanonicalRuntimeFacade,
+        tags: &TagRegistry,
+    ) -> ExpressionResult<ExprValue> {
+        let ctx = EvalContext {
+            memory,
+            tags,
+            word_order: self.word_order,
+        };
+        eval_node(&self.root, &ctx, &mut self.edge_state)
+    }
+
+    /// Evaluate and reduce to a boolean condition
+    pub fn evaluate_bool(
+        &mut self,
+        memory: &CanonicalRuntimeFacade,
+        tags: &TagRegistry,
+    ) -> ExpressionResult<bool> {
+        self.evaluate(memory, tags).map(ExprValue::as_bool)
+    }
+}
+
+// ============================================================================
+// Lexer
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Number(ExprValue),
+    Ident(String),
+    Str(String),
+    LParen,
+    RParen,
+    Comma,
+    Op(&'static str),
+}
+
+impl std::fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TokenKind::Number(ExprValue::Int(v)) => write!(f, "{}", v),
+            TokenKind::Number(ExprValue::Real(v)) => write!(f, "{}", v),
+            TokenKind::Number(ExprValue::Bool(v)) => write!(f, "{}", v),
+            TokenKind::Ident(s) => write!(f, "{}", s),
+            TokenKind::Str(s) => write!(f, "\"{}\"", s),
+            TokenKind::LParen => write!(f, "("),
+            TokenKind::RParen => write!(f, ")"),
+            TokenKind::Comma => write!(f, ","),
+            TokenKind::Op(op) => write!(f, "{}", op),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    position: usize,
+}
+
+const OPERATORS: [&str; 17] = [
+    "||", "&&", "==", "!=", "<>", "<=", ">=", "<", ">", "=", "!", "+", "-", "*", "/", "%", "~",
+];
+
+/// `%`는 IEC 직접 변수(`%MW100`)의 접두일 때만 식별자 시작이고, 아니면 나머지 연산자다.
+fn is_ident_start(c: char, next: Option<char>) -> bool {
+    c.is_ascii_alphabetic()
+        || c == '_'
+        || c == '$'
+        || (c == '%' && next.is_some_and(|n| n.is_ascii_alphabetic()))
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '%' | '$' | '\\' | '[' | ']' | '#')
+}
+
+/// S7 은 상위 워드가 낮은 주소에 오고, LS·MELSEC·Omron 은 하위 워드가 먼저다.
+fn default_word_order(profile: &dyn VendorProfile) -> WordOrder {
+    match profile.manufacturer() {
+        PlcManufacturer::Siemens => WordOrder::HighWordFirst,
+        _ => WordOrder::LowWordFirst,
+    }
+}
+
+fn tokenize(source: &str, profile: &dyn VendorProfile) -> ExpressionResult<Vec<Token>> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let kind = match c {
+            '(' => {
+                i += 1;
+                TokenKind::LParen
+            }
+            ')' => {
+                i += 1;
+                TokenKind::RParen
+            }
+            ',' => {
+                i += 1;
+                TokenKind::Comma
+            }
+            '"' | '\'' => {
+                let quote = c;
+                i += 1;
+                let body_start = i;
+                while i < chars.len() && chars[i] != quote {
+                    i += 1;
+                }
+                if i == chars.len() {
+                    return Err(ExpressionError::Syntax {
+                        position: start,
+                        message: "unterminated string".to_string(),
+                    });
+                }
+                let body: String = chars[body_start..i].iter().collect();
+                i += 1;
+                TokenKind::Str(body)
+            }
+            c if c.is_ascii_digit() => {
+                while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '.') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                if text.contains('.') && profile.parse_address(&text).is_ok() {
+                    TokenKind::Ident(text)
+                } else {
+                    TokenKind::Number(parse_number(&text).ok_or_else(|| {
+                        ExpressionError::Syntax {
+                            position: start,
+                            message: format!("invalid number '{}'", text),
+                        }
+                    })?)
+                }
+            }
+            c if is_ident_start(c, chars.get(i + 1).copied()) => {
+                while i < chars.len() && is_ident_char(chars[i]) {
+                    i += 1;
+                }
+                TokenKind::Ident(chars[start..i].iter().collect())
+            }
+            _ => {
+                let rest: String = chars[i..chars.len().min(i + 2)].iter().collect();
+                let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(**op)) else {
+                    return Err(ExpressionError::Syntax {
+                        position: start,
+                        message: format!("unexpected character '{}'", c),
+                    });
+                };
+                i += op.len();
+                TokenKind::Op(op)
+            }
+        };
+
+        tokens.push(Token {
+            kind,
+            position: start,
+        });
+    }
+
+    Ok(tokens)
+}
+
+fn parse_number(text: &str) -> Option<ExprValue> {
+    let lower = text.to_ascii_lowercase();
+    if let Some(hex) = lower.strip_prefix("0x") {
+        return i64::from_str_radix(hex, 16).ok().map(ExprValue::Int);
+    }
+    if let Some(hex) = lower.strip_suffix('h') {
+        return i64::from_str_radix(hex, 16).ok().map(ExprValue::Int);
+    }
+    if let Ok(value) = lower.parse::<i64>() {
+        return Some(ExprValue::Int(value));
+    }
+    lower.parse::<f64>().ok().map(ExprValue::Real)
+}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+struct Parser<'a> {
+    tokens: Vec<Token>,
+    pos: usize,
+    profile: &'a dyn VendorProfile,
+    tags: &'a TagRegistry,
+    edge_slots: usize,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.pos).map(|t| &t.kind)
+    }
+
+    fn position(&self) -> usize {
+        self.tokens
+            .get(self.pos)
+            .map(|t| t.position)
+            .or_else(|| self.tokens.last().map(|t| t.position + 1))
+            .unwrap_or(0)
+    }
+
+    fn error<T>(&self, message: impl Into<String>) -> ExpressionResult<T> {
+        Err(ExpressionError::Syntax {
+            position: self.position(),
+            message: message.into(),
+        })
+    }
+
+    /// 다음 토큰이 연산자 `ops` 중 하나이거나 키워드 `keyword`이면 소비한다.
+    fn eat_op(&mut self, ops: &[&'static str], keyword: Option<&str>) -> Option<&'static str> {
+        let matched = match self.peek()? {
+            TokenKind::Op(op) if ops.contains(op) => Some(*op),
+            TokenKind::Ident(word) if keyword.is_some_and(|k| word.eq_ignore_ascii_case(k)) => {
+                ops.first().copied()
+            }
+            _ => None,
+        };
+        if matched.is_some() {
+            self.pos += 1;
+        }
+        matched
+    }
+
+    fn expect(&mut self, kind: TokenKind) -> ExpressionResult<()> {
+        if self.peek() == Some(&kind) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            self.error(format!("expected '{}'", kind))
+        }
+    }
+
+    fn parse_or(&mut self) -> ExpressionResult<Node> {
+        let mut node = self.parse_and()?;
+        while self.eat_op(&["||"], Some("OR")).is_some() {
+            let rhs = self.parse_and()?;
+            node = Node::Binary(BinaryOp::Or, Box::new(node), Box::new(rhs));
+        }
+        Ok(node)
+    }
+
+    fn parse_and(&mut self) -> ExpressionResult<Node> {
+        let mut node = self.parse_not()?;
+        while self.eat_op(&["&&"], Some("AND")).is_some() {
+            let rhs = self.parse_not()?;
+            node = Node::Binary(BinaryOp::And, Box::new(node), Box::new(rhs));
+        }
+        Ok(node)
+    }
+
+    fn parse_not(&mut self) -> ExpressionResult<Node> {
+        if self.eat_op(&["!", "~"], Some("NOT")).is_some() {
+            return Ok(Node::Not(Box::new(self.parse_not()?)));
+        }
+        self.parse_cmp()
+    }
+
+    fn parse_cmp(&mut self) -> ExpressionResult<Node> {
+        let lhs = self.parse_sum()?;
+        let Some(op) = self.eat_op(&["==", "=", "!=", "<>", "<=", ">=", "<", ">"], None) else {
+            return Ok(lhs);
+        };
+        let op = match op {
+            "==" | "=" => BinaryOp::Eq,
+            "!=" | "<>" => BinaryOp::Ne,
+            "<=" => BinaryOp::Le,
+            ">=" => BinaryOp::Ge,
+            "<" => BinaryOp::Lt,
+            _ => BinaryOp::Gt,
+        };
+        let rhs = self.parse_sum()?;
+        Ok(Node::Binary(op, Box::new(lhs), Box::new(rhs)))
+    }
+
+    fn parse_sum(&mut self) -> ExpressionResult<Node> {
+        let mut node = self.parse_term()?;
+        while let Some(op) = self.eat_op(&["+", "-"], None) {
+            let op = if op == "+" {
+                BinaryOp::Add
+            } else {
+                BinaryOp::Sub
+            };
+            let rhs = self.parse_term()?;
+            node = Node::Binary(op, Box::new(node), Box::new(rhs));
+        }
+        Ok(node)
+    }
+
+    fn parse_term(&mut self) -> ExpressionResult<Node> {
+        let mut node = self.parse_unary()?;
+        while let Some(op) = self.eat_op(&["*", "/", "%"], None) {
+            let op = match op {
+                "*" => BinaryOp::Mul,
+                "/" => BinaryOp::Div,
+                _ => BinaryOp::Mod,
+            };
+            let rhs = self.parse_unary()?;
+            node = Node::Binary(op, Box::new(node), Box::new(rhs));
+        }
+        Ok(node)
+    }
+
+    fn parse_unary(&mut self) -> ExpressionResult<Node> {
+        if self.eat_op(&["-"], None).is_some() {
+            return Ok(Node::Neg(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+
+    fn parse_primary(&mut self) -> ExpressionResult<Node> {
+        let Some(kind) = self.peek().cloned() else {
+            return self.error("unexpected end of expression");
+        };
+        self.pos += 1;
+
+        match kind {
+            TokenKind::Number(value) => Ok(Node::Literal(value)),
+            TokenKind::LParen => {
+                let node = self.parse_or()?;
+                self.expect(TokenKind::RParen)?;
+                Ok(node)
+            }
+            TokenKind::Str(tag_id) => Ok(Node::Operand(self.resolve_tag(&tag_id)?)),
+            TokenKind::Ident(word) => {
+                if word.eq_ignore_ascii_case("TRUE") {
+                    return Ok(Node::Literal(ExprValue::Bool(true)));
+                }
+                if word.eq_ignore_ascii_case("FALSE") {
+                    return Ok(Node::Literal(ExprValue::Bool(false)));
+                }
+                if self.peek() == Some(&TokenKind::LParen) {
+                    self.pos += 1;
+                    return self.parse_call(&word);
+                }
+                Ok(Node::Operand(self.resolve_operand(&word)?))
+            }
+            other => {
+                self.pos -= 1;
+                self.error(format!("unexpected '{}'", other))
+            }
+        }
+    }
+
+    fn parse_call(&mut self, name: &str) -> ExpressionResult<Node> {
+        let mut args = Vec::new();
+        if self.peek() != Some(&TokenKind::RParen) {
+            loop {
+                args.push(self.parse_or()?);
+                if self.peek() == Some(&TokenKind::Comma) {
+                    self.pos += 1;
+                    continue;
+                }
+                break;
+            }
+        }
+        self.expect(TokenKind::RParen)?;
+
+        let function = name.to_ascii_lowercase();
+        if args.len() != 1 {
+            return Err(ExpressionError::ArgumentCount {
+                function,
+                expected: 1,
+                found: args.len(),
+            });
+        }
+        let arg = args.pop().expect("one argument");
+
+        let typed = match function.as_str() {
+            "rising" | "falling" => {
+                let slot = self.edge_slots;
+                self.edge_slots += 1;
+                return Ok(Node::Edge {
+                    rising: function == "rising",
+                    slot,
+                    inner: Box::new(arg),
+                });
+            }
+            "int16" => TypedRead::Int16,
+            "uint16" | "word" => TypedRead::UInt16,
+            "int32" | "dint" => TypedRead::Int32,
+            "uint32" | "udint" | "dword" => TypedRead::UInt32,
+            "real" | "float" => TypedRead::Real,
+            _ => return Err(ExpressionError::UnknownFunction(name.to_string())),
+        };
+
+        let Node::Operand(operand) = arg else {
+            return Err(ExpressionError::InvalidArgument {
+                function,
+                message: "argument must be a word address or tag".to_string(),
+            });
+        };
+        if let Operand::Address(address) = &operand {
+            if !address.area.is_word_area() || address.bit_index.is_some() {
+                return Err(ExpressionError::InvalidArgument {
+                    function,
+                    message: "argument must be a word address".to_string(),
+                });
+            }
+        }
+        Ok(Node::Typed(typed, operand))
+    }
+
+    fn resolve_operand(&self, word: &str) -> ExpressionResult<Operand> {
+        if let Ok(vendor) = self.profile.parse_address(word) {
+            if let Ok(Some(first)) = self.profile.canonical_bit_word(&vendor) {
+                return Ok(Operand::BitWord(first));
+            }
+            if let Ok(address) = self.profile.to_canonical(&vendor) {
+                return Ok(Operand::Address(address));
+            }
+        }
+        self.resolve_tag(word)
+    }
+
+    fn resolve_tag(&self, tag_id: &str) -> ExpressionResult<Operand> {
+        self.tags
+            .resolve(tag_id)
+            .map(|_| Operand::Tag(tag_id.to_string()))
+            .map_err(|_| ExpressionError::UnknownOperand(tag_id.to_string()))
+    }
+}
+
+// ============================================================================
+// Evaluation
+// ============================================================================
+
+struct EvalContext<'a> {
+    memory: &'a CanonicalRuntimeFacade,
+    tags: &'a TagRegistry,
+    word_order: WordOrder,
+}
+
+impl EvalContext<'_> {
+    fn address(&self, operand: &Operand) -> ExpressionResult<CanonicalAddress> {
+        match operand {
+            Operand::Address(address) | Operand::BitWord(address) => Ok(*address),
+            Operand::Tag(tag_id) => self
+                .tags
+                .resolve(tag_id)
+                .map(|tag| tag.canonical_address)
+                .map_err(|e| ExpressionError::Read(e.to_string())),
+        }
+    }
+
+    fn read(&self, address: CanonicalAddress) -> ExpressionResult<CanonicalValue> {
+        self.memory
+            .read(address)
+            .map_err(|e| ExpressionError::Read(e.to_string()))
+    }
+
+    /// `offset`-th word of a word operand; channel words step by sixteen bits
+    fn read_word(&self, operand: &Operand, offset: u32) -> ExpressionResult<u16> {
+        let address = self.address(operand)?;
+        match operand {
+            Operand::BitWord(_) => self.memory.read_bit_word(CanonicalAddress::new(
+                address.area,
+                address.index + offset * 16,
+            )),
+            _ => self.memory.read_word_value(CanonicalAddress {
+                index: address.index + offset,
+                ..address
+            }),
+        }
+        .map_err(|e| ExpressionError::Read(e.to_string()))
+    }
+}
+
+fn eval_node(
+    node: &Node,
+    ctx: &EvalContext<'_>,
+    edge_state: &mut [bool],
+) -> ExpressionResult<ExprValue> {
+    match node {
+        Node::Literal(value) => Ok(*value),
+        Node::Operand(operand @ Operand::BitWord(_)) => {
+            Ok(ExprValue::Int(ctx.read_word(operand, 0)? as i16 as i64))
+        }
+        Node::Operand(operand) => {
+            let address = ctx.address(operand)?;
+            Ok(match ctx.read(address)? {
+                CanonicalValue::Bool(b) => ExprValue::Bool(b),
+                CanonicalValue::U16(w) => ExprValue::Int(w as i16 as i64),
+            })
+        }
+        Node::Typed(kind, operand) => {
+            let first = ctx.read_word(operand, 0)?;
+            let data_type = match kind {
+                TypedRead::Int16 => return Ok(ExprValue::Int(first as i16 as i64)),
+                TypedRead::UInt16 => return Ok(ExprValue::Int(first as i64)),
+                TypedRead::Int32 => TypedDataType::Int32,
+                TypedRead::UInt32 => TypedDataType::UInt32,
+                TypedRead::Real => TypedDataType::Real,
+            };
+            let second = ctx.read_word(operand, 1)?;
+            let value = decode_words(data_type, ctx.word_order, &[first, second])
+                .map_err(|e| ExpressionError::Read(e.to_string()))?;
+            Ok(match value {
+                TypedValue::Int32(v) => ExprValue::Int(v as i64),
+                TypedValue::UInt32(v) => ExprValue::Int(v as i64),
+                TypedValue::Real(v) => ExprValue::Real(v as f64),
+                TypedValue::LReal(v) => ExprValue::Real(v),
+                TypedValue::String(_) => {
+                    return Err(ExpressionError::Read("unexpected string value".to_string()))
+                }
+            })
+        }
+        Node::Not(inner) => Ok(ExprValue::Bool(
+            !eval_node(inner, ctx, edge_state)?.as_bool(),
+        )),
+        Node::Neg(inner) => Ok(match eval_node(inner, ctx, edge_state)? {
+            ExprValue::Real(v) => ExprValue::Real(-v),
+            other => ExprValue::Int(other.as_i64().unwrap_or_default().wrapping_neg()),
+        }),
+        Node::Edge {
+            rising,
+            slot,
+            inner,
+        } => {
+            let current = eval_node(inner, ctx, edge_state)?.as_bool();
+            let previous = std::mem::replace(&mut edge_state[*slot], current);
+            Ok(ExprValue::Bool(if *rising {
+                current && !previous
+            } else {
+                !current && previous
+            }))
+        }
+        Node::Binary(op, lhs, rhs) => {
+            // 논리 연산은 단락 평가하지 않는다 — 우변의 에지 함수도 매번 상태를 갱신해야 한다.
+            let a = eval_node(lhs, ctx, edge_state)?;
+            let b = eval_node(rhs, ctx, edge_state)?;
+            eval_binary(*op, a, b)
+        }
+    }
+}
+
+fn eval_binary(op: BinaryOp, a: ExprValue, b: ExprValue) -> ExpressionResult<ExprValue> {
+    let integers = a.as_i64().zip(b.as_i64());
+
+    Ok(match op {
+        BinaryOp::Or => ExprValue::Bool(a.as_bool() || b.as_bool()),
+        BinaryOp::And => ExprValue::Bool(a.as_bool() && b.as_bool()),
+        BinaryOp::Eq => ExprValue::Bool(match integers {
+            Some((x, y)) => x == y,
+            None => a.as_f64() == b.as_f64(),
+        }),
+        BinaryOp::Ne => ExprValue::Bool(match integers {
+            Some((x, y)) => x != y,
+            None => a.as_f64() != b.as_f64(),
+        }),
+        BinaryOp::Lt => ExprValue::Bool(a.as_f64() < b.as_f64()),
+        BinaryOp::Le => ExprValue::Bool(a.as_f64() <= b.as_f64()),
+        BinaryOp::Gt => ExprValue::Bool(a.as_f64() > b.as_f64()),
+        BinaryOp::Ge => ExprValue::Bool(a.as_f64() >= b.as_f64()),
+        BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod => {
+            match integers {
+                Some((x, y)) => ExprValue::Int(match op {
+                    BinaryOp::Add => x.wrapping_add(y),
+                    BinaryOp::Sub => x.wrapping_sub(y),
+                    BinaryOp::Mul => x.wrapping_mul(y),
+                    BinaryOp::Div => x.checked_div(y).ok_or(ExpressionError::DivisionByZero)?,
+                    _ => x.checked_rem(y).ok_or(ExpressionError::DivisionByZero)?,
+                }),
+                None => {
+                    let (x, y) = (a.as_f64(), b.as_f64());
+                    if matches!(op, BinaryOp::Div | BinaryOp::Mod) && y == 0.0 {
+                        return Err(ExpressionError::DivisionByZero);
+                    }
+                    ExprValue::Real(match op {
+                        BinaryOp::Add => x + y,
+                        BinaryOp::Sub => x - y,
+                        BinaryOp::Mul => x * y,
+                        BinaryOp::Div => x / y,
+                        _ => x % y,
+                    })
+                }
+            }
+        }
+    })
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RegisterTagRequest, SimBitDeviceType, SimWordDeviceType};
+    use modone_contract::CanonicalAreaKind;
+    use plc_model::{LsProfile, OmronProfile, PlcHardwareTopology, S7Profile};
+
+    fn profile() -> LsProfile {
+        LsProfile::new("XGK".to_string(), PlcHardwareTopology::default())
+    }
+
+    fn compile(source: &str, tags: &TagRegistry) -> ExpressionResult<Expression> {
+        Expression::compile(source, &profile(), tags)
+    }
+
+    fn eval(source: &str, memory: &CanonicalRuntimeFacade) -> ExprValue {
+        let tags = TagRegistry::new();
+        compile(source, &tags)
+            .unwrap()
+            .evaluate(memory, &tags)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_simple_comparison() {
+        let memory = CanonicalRuntimeFacade::new();
+        memory.write_word(SimWordDeviceType::D, 1, 150).unwrap();
+
+        assert_eq!(eval("D0001 > 100", &memory), ExprValue::Bool(true));
+        assert_eq!(eval("D0001 == 150", &memory), ExprValue::Bool(true));
+        assert_eq!(eval("D0001 != 150", &memory), ExprValue::Bool(false));
+        assert_eq!(eval("M0000 == 1", &memory), ExprValue::Bool(false));
+    }
+
+    #[test]
+    fn test_logic_and_precedence() {
+        let memory = CanonicalRuntimeFacade::new();
+        memory.write_bit(SimBitDeviceType::M, 0, true).unwrap();
+        memory.write_word(SimWordDeviceType::D, 0, 7).unwrap();
+
+        assert_eq!(eval("M0 AND NOT M1", &memory), ExprValue::Bool(true));
+        assert_eq!(eval("M1 || D0 = 7 && M0", &memory), ExprValue::Bool(true));
+        assert_eq!(eval("!(M0 and D0 > 10)", &memory), ExprValue::Bool(true));
+        assert_eq!(eval("(D0 + 3) * 2 - D0 % 4", &memory), ExprValue::Int(17));
+        assert_eq!(eval("-D0 / 2", &memory), ExprValue::Int(-3));
+        assert_eq!(eval("D0 / 2.0", &memory), ExprValue::Real(3.5));
+        assert_eq!(eval("0x10 + 0Ah", &memory), ExprValue::Int(26));
+    }
+
+    #[test]
+    fn test_word_reads_are_signed() {
+        let memory = CanonicalRuntimeFacade::new();
+        memory.write_word(SimWordDeviceType::D, 0, 0xFFFF).unwrap();
+
+        assert_eq!(eval("D0", &memory), ExprValue::Int(-1));
+        assert_eq!(eval("uint16(D0)", &memory), ExprValue::Int(65535));
+    }
+
+    #[test]
+    fn test_typed_double_word_reads() {
+        let memory = CanonicalRuntimeFacade::new();
+        let value: i32 = -100_000;
+        memory
+            .write_word(SimWordDeviceType::D, 10, value as u32 as u16)
+            .unwrap();
+        memory
+            .write_word(SimWordDeviceType::D, 11, ((value as u32) >> 16) as u16)
+            .unwrap();
+        assert_eq!(eval("int32(D10)", &memory), ExprValue::Int(-100_000));
+        assert_eq!(
+            eval("uint32(D10)", &memory),
+            ExprValue::Int(value as u32 as i64)
+        );
+
+        let bits = 12.5f32.to_bits();
+        memory
+            .write_word(SimWordDeviceType::D, 20, bits as u16)
+            .unwrap();
+        memory
+            .write_word(SimWordDeviceType::D, 21, (bits >> 16) as u16)
+            .unwrap();
+        assert_eq!(eval("real(D20) > 12.4", &memory), ExprValue::Bool(true));
+        assert_eq!(eval("real(D20)", &memory), ExprValue::Real(12.5));
+    }
+
+    #[test]
+    fn test_omron_channel_words() {
+        let memory = CanonicalRuntimeFacade::new();
+        let omron = OmronProfile::new("CJ2M-CPU31".to_string(), PlcHardwareTopology::default());
+        let tags = TagRegistry::new();
+        for bit in [0, 2, 16] {
+            memory
+                .write_bool(
+                    CanonicalAddress::new(CanonicalAreaKind::InternalBit, 160 + bit),
+                    true,
+                    modone_contract::CanonicalWriteSource::Simulation,
+                )
+                .unwrap();
+        }
+
+        let mut word = Expression::compile("W10 == 5", &omron, &tags).unwrap();
+        assert!(word.evaluate_bool(&memory, &tags).unwrap());
+        let mut dword = Expression::compile("int32(W10)", &omron, &tags).unwrap();
+        assert_eq!(
+            dword.evaluate(&memory, &tags).unwrap(),
+            ExprValue::Int(0x1_0005)
+        );
+    }
+
+    #[test]
+    fn test_omron_dotted_addresses_and_s7_word_order() {
+        let memory = CanonicalRuntimeFacade::new();
+        let tags = TagRegistry::new();
+        let omron = OmronProfile::new("CP1L-EM40".to_string(), PlcHardwareTopology::default());
+        memory
+            .write_bool(
+                CanonicalAddress::new(CanonicalAreaKind::InputBit, 21),
+                true,
+                modone_contract::CanonicalWriteSource::Simulation,
+            )
+            .unwrap();
+        let mut bit = Expression::compile("1.05 AND NOT 0.00", &omron, &tags).unwrap();
+        assert!(bit.evaluate_bool(&memory, &tags).unwrap());
+        let mut real = Expression::compile("D0 + 2.5e0", &omron, &tags).unwrap();
+        assert_eq!(real.evaluate(&memory, &tags).unwrap(), ExprValue::Real(2.5));
+
+        // S7 은 상위 워드가 먼저
+        let s7 = S7Profile::new("S7-1200".to_string(), PlcHardwareTopology::default());
+        memory
+            .write_word_value(
+                CanonicalAddress::new(CanonicalAreaKind::DataWord, 0),
+                0x0001,
+                modone_contract::CanonicalWriteSource::Simulation,
+            )
+            .unwrap();
+        let mut dword = Expression::compile("uint32(MW0)", &s7, &tags).unwrap();
+        assert_eq!(
+            dword.evaluate(&memory, &tags).unwrap(),
+            ExprValue::Int(0x1_0000)
+        );
+        let mut swapped = dword.with_word_order(WordOrder::LowWordFirst);
+        assert_eq!(swapped.evaluate(&memory, &tags).unwrap(), ExprValue::Int(1));
+    }
+
+    #[test]
+    fn test_rising_and_falling_edges() {
+        let memory = CanonicalRuntimeFacade::new();
+        let tags = TagRegistry::new();
+        let mut rising = compile("rising(M5)", &tags).unwrap();
+        let mut falling = compile("falling(M5)", &tags).unwrap();
+
+        assert!(!rising.evaluate_bool(&memory, &tags).unwrap());
+        assert!(!falling.evaluate_bool(&memory, &tags).unwrap());
+
+        memory.write_bit(SimBitDeviceType::M, 5, true).unwrap();
+        assert!(rising.evaluate_bool(&memory, &tags).unwrap());
+        assert!(!rising.evaluate_bool(&memory, &tags).unwrap());
+        assert!(!falling.evaluate_bool(&memory, &tags).unwrap());
+
+        memory.write_bit(SimBitDeviceType::M, 5, false).unwrap();
+        assert!(falling.evaluate_bool(&memory, &tags).unwrap());
+    }
+
+    #[test]
+    fn test_tag_operands() {
+        let memory = CanonicalRuntimeFacade::new();
+        let tags = TagRegistry::new();
+        tags.register_semantic(RegisterTagRequest {
+            tag_id: Some("Line 1.Speed".to_string()),
+            display_name: "Speed".to_string(),
+            binding: None,
+            canonical_address: Some(CanonicalAddress::new(CanonicalAreaKind::DataWord, 40)),
+            vendor_aliases: Vec::new(),
+            access: None,
+            description: None,
+            engineering_unit: None,
+            folder_path: None,
+        })
+        .unwrap();
+        memory.write_word(SimWordDeviceType::D, 40, 300).unwrap();
+
+        let mut expr = compile("\"Line 1.Speed\" >= 300", &tags).unwrap();
+        assert!(expr.evaluate_bool(&memory, &tags).unwrap());
+    }
+
+    #[test]
+    fn test_invalid_expressions_rejected() {
+        let tags = TagRegistry::new();
+
+        assert!(matches!(
+            compile("D0 >", &tags),
+            Err(ExpressionError::Syntax { .. })
+        ));
+        assert!(matches!(
+            compile("(M0 AND M1", &tags),
+            Err(ExpressionError::Syntax { .. })
+        ));
+        assert!(matches!(
+            compile("NoSuchTag == 1", &tags),
+            Err(ExpressionError::UnknownOperand(_))
+        ));
+        assert!(matches!(
+            compile("bogus(M0)", &tags),
+            Err(ExpressionError::UnknownFunction(_))
+        ));
+        assert!(matches!(
+            compile("rising(M0, M1)", &tags),
+            Err(ExpressionError::ArgumentCount { .. })
+        ));
+        assert!(matches!(
+            compile("int32(M0)", &tags),
+            Err(ExpressionError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            compile("D0 @ 1", &tags),
+            Err(ExpressionError::Syntax { .. })
+        ));
+    }
+
+    #[test]
+    fn test_division_by_zero_is_evaluation_error() {
+        let memory = CanonicalRuntimeFacade::new();
+        let tags = TagRegistry::new();
+        let mut expr = compile("10 / D0", &tags).unwrap();
+        assert_eq!(
+            expr.evaluate(&memory, &tags),
+            Err(ExpressionError::DivisionByZero)
+        );
+    }
+}
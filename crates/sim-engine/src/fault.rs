@@ -0,0 +1,230 @@
+//! 런타임 오류 처리 정책 (fault policy)
+//!
+//! 실제 PLC는 연산 오류(0으로 나누기 등)가 나면 설정에 따라 에러 플래그만 세우고
+//! 계속 실행하거나, CPU를 ERROR 상태로 정지시키고 진단 코드를 시스템 워드에
+//! 남긴다. `FaultPolicy`는 프로젝트 단위 설정(벤더 주소 문자열)이고,
+//! `FaultPolicy::resolve`가 VendorProfile로 canonical 주소를 풀어 실행기용
+//! `FaultHandling`을 만든다.
+//!
+//! 진단 코드는 벤더 공통의 ModOne 자체 코드다([`FaultKind::diagnostic_code`]).
+
+use std::collections::BTreeMap;
+
+use modone_contract::CanonicalAddress;
+use plc_model::VendorProfile;
+use serde::{Deserialize, Serialize};
+
+use super::executor::ExecutionError;
+
+// ============================================================================
+// Policy Types
+// ============================================================================
+
+/// Reaction to a runtime error
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FaultAction {
+    /// Set the error flag/diagnostic word and continue with the next rung
+    Continue,
+    /// Set flags and skip the rest of the faulting network
+    #[default]
+    SkipNetwork,
+    /// Stop the CPU in the ERROR state
+    Halt,
+}
+
+/// Runtime error category
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FaultKind {
+    /// Division or modulo by zero
+    DivisionByZero,
+    /// Operand address missing or invalid
+    InvalidAddress,
+    /// Memory access rejected (out of range, read-only, ...)
+    MemoryAccess,
+    /// Instruction the simulator cannot execute
+    UnsupportedInstruction,
+}
+
+impl FaultKind {
+    /// ModOne diagnostic code written to the diagnostic word
+    pub fn diagnostic_code(self) -> u16 {
+        match self {
+            FaultKind::DivisionByZero => 0x0201,
+            FaultKind::InvalidAddress => 0x0301,
+            FaultKind::MemoryAccess => 0x0302,
+            FaultKind::UnsupportedInstruction => 0x0401,
+        }
+    }
+}
+
+impl ExecutionError {
+    /// Fault category of the error
+    pub fn fault_kind(&self) -> FaultKind {
+        match self {
+            ExecutionError::Memory(_) => FaultKind::MemoryAccess,
+            ExecutionError::InvalidAddress(_) | ExecutionError::AddressOutOfRange { .. } => {
+                FaultKind::InvalidAddress
+            }
+            ExecutionError::DivisionByZero => FaultKind::DivisionByZero,
+            ExecutionError::UnsupportedNodeType(_) => FaultKind::UnsupportedInstruction,
+        }
+    }
+}
+
+/// Per-project fault policy (persisted in the project config)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaultPolicy {
+    /// Action for faults without an override
+    #[serde(default)]
+    pub action: FaultAction,
+    /// Per-kind actions
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub overrides: BTreeMap<FaultKind, FaultAction>,
+    /// Bit set when any fault occurs, in vendor notation (e.g. `F110`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_flag: Option<String>,
+    /// Word receiving the diagnostic code of the latest fault (e.g. `D9000`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diagnostic_word: Option<String>,
+}
+
+impl FaultPolicy {
+    /// Action for the given fault kind
+    pub fn action_for(&self, kind: FaultKind) -> FaultAction {
+        self.overrides.get(&kind).copied().unwrap_or(self.action)
+    }
+
+    /// Resolve the flag/word addresses through the vendor profile
+    pub fn resolve(&self, profile: &dyn VendorProfile) -> Result<FaultHandling, String> {
+        let resolve = |address: &Option<String>| -> Result<Option<CanonicalAddress>, String> {
+            address
+                .as_deref()
+                .map(|address| {
+                    profile
+                        .parse_address(address)
+                        .and_then(|vendor| profile.to_canonical(&vendor))
+                        .map_err(|e| format!("Invalid fault policy address '{address}': {e}"))
+                })
+                .transpose()
+        };
+
+        let error_flag = resolve(&self.error_flag)?;
+        let diagnostic_word = resolve(&self.diagnostic_word)?;
+        if let Some(word) = diagnostic_word {
+            if !word.area.is_word_area() || word.bit_index.is_some() {
+                return Err(format!(
+                    "Fault diagnostic word must be a word address: {}",
+                    self.diagnostic_word.as_deref().unwrap_or_default()
+                ));
+            }
+        }
+
+        Ok(FaultHandling {
+            policy: self.clone(),
+            error_flag,
+            diagnostic_word,
+        })
+    }
+}
+
+/// Fault policy with resolved canonical addresses, used by the executor
+#[derive(Debug, Clone, Default)]
+pub struct FaultHandling {
+    pub policy: FaultPolicy,
+    pub error_flag: Option<CanonicalAddress>,
+    pub diagnostic_word: Option<CanonicalAddress>,
+}
+
+impl FaultHandling {
+    /// Whether the project set up a fault policy. Without one, division by zero
+    /// keeps the destination unchanged and raises no fault, as before policies.
+    pub fn is_configured(&self) -> bool {
+        self.policy != FaultPolicy::default()
+    }
+}
+
+/// A fault raised while executing a scan
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanFault {
+    /// Network in which the fault occurred
+    pub network_id: u32,
+    /// Fault category
+    pub kind: FaultKind,
+    /// Diagnostic code written to the diagnostic word
+    pub code: u16,
+    /// Error message
+    pub message: String,
+    /// Action taken
+    pub action: FaultAction,
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use modone_contract::CanonicalAreaKind;
+    use plc_model::{LsProfile, PlcHardwareTopology};
+
+    fn profile() -> LsProfile {
+        LsProfile::new("XGK".to_string(), PlcHardwareTopology::default())
+    }
+
+    #[test]
+    fn test_action_overrides() {
+        let mut policy = FaultPolicy::default();
+        policy
+            .overrides
+            .insert(FaultKind::DivisionByZero, FaultAction::Halt);
+
+        assert_eq!(
+            policy.action_for(FaultKind::DivisionByZero),
+            FaultAction::Halt
+        );
+        assert_eq!(
+            policy.action_for(FaultKind::MemoryAccess),
+            FaultAction::SkipNetwork
+        );
+    }
+
+    #[test]
+    fn test_resolve_addresses() {
+        let policy = FaultPolicy {
+            error_flag: Some("M100".to_string()),
+            diagnostic_word: Some("D9000".to_string()),
+            ..FaultPolicy::default()
+        };
+        let handling = policy.resolve(&profile()).unwrap();
+        assert_eq!(
+            handling.diagnostic_word,
+            Some(CanonicalAddress::new(CanonicalAreaKind::DataWord, 9000))
+        );
+        assert!(handling.error_flag.is_some());
+
+        let bad = FaultPolicy {
+            diagnostic_word: Some("M0".to_string()),
+            ..FaultPolicy::default()
+        };
+        assert!(bad.resolve(&profile()).is_err());
+    }
+
+    #[test]
+    fn test_policy_serialization() {
+        let policy: FaultPolicy = serde_json::from_str(
+            r#"{"action":"skipNetwork","overrides":{"divisionByZero":"halt"},"diagnosticWord":"D9000"}"#,
+        )
+        .unwrap();
+        assert_eq!(policy.action, FaultAction::SkipNetwork);
+        assert_eq!(
+            policy.action_for(FaultKind::DivisionByZero),
+            FaultAction::Halt
+        );
+        assert_eq!(policy.diagnostic_word.as_deref(), Some("D9000"));
+    }
+}
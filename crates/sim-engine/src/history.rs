@@ -0,0 +1,485 @@
+//! 스캔 히스토리 (타임트래블 디버깅)
+//!
+//! 스캔마다 전체 실행 상태(canonical 메모리 스냅샷, 타이머·카운터 런타임, 에지
+//! 접점 상태)를 체크포인트로 남겨 일정 개수만 순환 보관한다. 디버거는 이 기록을
+//! 따라 뒤로/앞으로 이동하거나 특정 주소가 마지막으로 바뀐 스캔으로 되돌아간다.
+//!
+//! 과거 시점에서 스캔을 새로 실행하면 그 이후 기록은 버려진다(분기).
+//!
+//! 체크포인트마다 메모리 전체를 복사하므로 기본값은 꺼져 있고, 디버거가
+//! `set_enabled` 로 켰을 때만 기록한다.
+
+use std::collections::{HashMap, VecDeque};
+
+use modone_contract::{CanonicalAddress, CanonicalMemorySnapshot, CanonicalValue};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+use super::counter::CounterSnapshot;
+use super::timer::TimerSnapshot;
+
+/// 켰을 때의 기본 보관 체크포인트 수. 기본 메모리 크기 기준 체크포인트 하나가 약 200KB다.
+pub const DEFAULT_HISTORY_DEPTH: usize = 100;
+
+// ============================================================================
+// Checkpoint
+// ============================================================================
+
+/// Full execution state captured at the end of one scan
+#[derive(Debug, Clone)]
+pub struct ScanCheckpoint {
+    pub(crate) scan_count: u64,
+    pub(crate) captured_at: String,
+    pub(crate) memory: CanonicalMemorySnapshot,
+    pub(crate) timers: TimerSnapshot,
+    pub(crate) counters: CounterSnapshot,
+    pub(crate) edges: HashMap<CanonicalAddress, bool>,
+}
+
+impl ScanCheckpoint {
+    /// Scan number the checkpoint was taken after
+    pub fn scan_count(&self) -> u64 {
+        self.scan_count
+    }
+
+    /// Capture timestamp (ISO 8601)
+    pub fn captured_at(&self) -> &str {
+        &self.captured_at
+    }
+
+    /// Canonical memory at the checkpoint
+    pub fn memory(&self) -> &CanonicalMemorySnapshot {
+        &self.memory
+    }
+
+    fn value_at(&self, address: CanonicalAddress) -> Option<CanonicalValue> {
+        let value = *self
+            .memory
+            .areas
+            .get(&address.area)?
+            .get(address.index as usize)?;
+        Some(match (value, address.bit_index) {
+            (CanonicalValue::U16(word), Some(bit)) => CanonicalValue::Bool(word & (1 << bit) != 0),
+            (value, _) => value,
+        })
+    }
+}
+
+/// Where the debugger currently sits in the history
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryPosition {
+    /// Scan number of the current checkpoint
+    pub scan_count: u64,
+    /// Index of the current checkpoint (0 = oldest)
+    pub index: usize,
+    /// Number of retained checkpoints
+    pub len: usize,
+    /// Whether the live state is the newest checkpoint
+    pub at_latest: bool,
+}
+
+/// Result of searching backwards for a value change
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValueChangeLocation {
+    /// Address that was searched
+    pub address: CanonicalAddress,
+    /// Scan in which the value changed
+    pub changed_in_scan: u64,
+    /// Value before the change
+    pub old_value: CanonicalValue,
+    /// Value after the change
+    pub new_value: CanonicalValue,
+    /// Position after rewinding (the checkpoint just before the change)
+    pub position: HistoryPosition,
+}
+
+// ============================================================================
+// Scan History
+// ============================================================================
+
+#[derive(Debug)]
+struct HistoryState {
+    checkpoints: VecDeque<ScanCheckpoint>,
+    capacity: usize,
+    /// `Some(i)`이면 checkpoints[i] 시점으로 되감긴 상태
+    cursor: Option<usize>,
+    enabled: bool,
+}
+
+impl HistoryState {
+    fn current_index(&self) -> Option<usize> {
+        self.cursor
+            .or_else(|| self.checkpoints.len().checked_sub(1))
+    }
+
+    fn position(&self) -> Option<HistoryPosition> {
+        let index = self.current_index()?;
+        Some(HistoryPosition {
+            scan_count: self.checkpoints[index].scan_count,
+            index,
+            len: self.checkpoints.len(),
+            at_latest: self.cursor.is_none(),
+        })
+    }
+
+    fn move_to(&mut self, index: usize) -> ScanCheckpoint {
+        self.cursor = if index + 1 == self.checkpoints.len() {
+            None
+        } else {
+            Some(index)
+        };
+        self.checkpoints[index].clone()
+    }
+}
+
+/// Rolling per-scan checkpoint store
+pub struct ScanHistory {
+    state: RwLock<HistoryState>,
+}
+
+impl ScanHistory {
+    /// Create a disabled history keeping at most `capacity` checkpoints once enabled
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            state: RwLock::new(HistoryState {
+                checkpoints: VecDeque::new(),
+                capacity: capacity.max(2),
+                cursor: None,
+                enabled: false,
+            }),
+        }
+    }
+
+    /// Enable or disable recording. Disabling drops retained checkpoints.
+    pub fn set_enabled(&self, enabled: bool) {
+        let mut state = self.state.write();
+        state.enabled = enabled;
+        if !enabled {
+            state.checkpoints.clear();
+            state.cursor = None;
+        }
+    }
+
+    /// Whether checkpoints are recorded
+    pub fn is_enabled(&self) -> bool {
+        self.state.read().enabled
+    }
+
+    /// Change the retention depth, dropping the oldest checkpoints if needed
+    pub fn set_capacity(&self, capacity: usize) {
+        let mut state = self.state.write();
+        state.capacity = capacity.max(2);
+        while state.checkpoints.len() > state.capacity {
+            state.checkpoints.pop_front();
+            state.cursor = state.cursor.and_then(|c| c.checked_sub(1));
+        }
+    }
+
+    /// Retention depth
+    pub fn capacity(&self) -> usize {
+        self.state.read().capacity
+    }
+
+    /// Append a checkpoint. Recording while rewound discards the newer branch.
+    pub fn record(&self, checkpoint: ScanCheckpoint) {
+        let mut state = self.state.write();
+        if !state.enabled {
+            return;
+        }
+        if let Some(cursor) = state.cursor.take() {
+            state.checkpoints.truncate(cursor + 1);
+        }
+        if state.checkpoints.len() == state.capacity {
+            state.checkpoints.pop_front();
+        }
+        state.checkpoints.push_back(checkpoint);
+    }
+
+    /// Newest checkpoint (the end of the last completed scan)
+    pub fn latest(&self) -> Option<ScanCheckpoint> {
+        self.state.read().checkpoints.back().cloned()
+    }
+
+    /// Drop all checkpoints
+    pub fn clear(&self) {
+        let mut state = self.state.write();
+        state.checkpoints.clear();
+        state.cursor = None;
+    }
+
+    /// Whether the debugger is positioned before the newest checkpoint
+    pub fn is_rewound(&self) -> bool {
+        self.state.read().cursor.is_some()
+    }
+
+    /// Current position, or `None` when nothing has been recorded
+    pub fn position(&self) -> Option<HistoryPosition> {
+        self.state.read().position()
+    }
+
+    /// Move one scan back and return the checkpoint to restore
+    pub fn step_back(&self) -> Option<ScanCheckpoint> {
+        let mut state = self.state.write();
+        let target = state.current_index()?.checked_sub(1)?;
+        Some(state.move_to(target))
+    }
+
+    /// Move one scan forward through recorded history.
+    ///
+    /// Returns `None` at the newest checkpoint; the caller then executes a new scan.
+    pub fn step_forward(&self) -> Option<ScanCheckpoint> {
+        let mut state = self.state.write();
+        let target = state.cursor? + 1;
+        Some(state.move_to(target))
+    }
+
+    /// Search backwards from the current position for the last scan in which
+    /// `address` changed, and rewind to the checkpoint just before it so that
+    /// stepping forward replays the change.
+    pub fn rewind_to_last_change(
+        &self,
+        address: CanonicalAddress,
+    ) -> Option<(ScanCheckpoint, ValueChangeLocation)> {
+        let mut state = self.state.write();
+        let current = state.current_index()?;
+
+        for index in (1..=current).rev() {
+            let new_value = state.checkpoints[index].value_at(address)?;
+            let old_value = state.checkpoints[index - 1].value_at(address)?;
+            if new_value != old_value {
+                let changed_in_scan = state.checkpoints[index].scan_count;
+                let checkpoint = state.move_to(index - 1);
+                let position = state.position().expect("history is not empty");
+                return Some((
+                    checkpoint,
+                    ValueChangeLocation {
+                        address,
+                        changed_in_scan,
+                        old_value,
+                        new_value,
+                        position,
+                    },
+                ));
+            }
+        }
+
+        None
+    }
+}
+
+/// Disabled history with [`DEFAULT_HISTORY_DEPTH`] retention once enabled
+impl Default for ScanHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_HISTORY_DEPTH)
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::{
+        compile_program, LadderNetwork, LadderNode, LadderProgram, NodeType, ProgramExecutor,
+    };
+    use crate::memory::CanonicalRuntimeFacade;
+    use crate::types::{SimBitDeviceType, SimTimeBase, SimWordDeviceType};
+    use crate::{CounterManager, TimerManager};
+    use modone_contract::CanonicalAreaKind;
+    use plc_model::{LsProfile, PlcHardwareTopology};
+    use std::sync::Arc;
+
+    struct Fixture {
+        runtime: Arc<CanonicalRuntimeFacade>,
+        timers: Arc<TimerManager>,
+        executor: ProgramExecutor,
+        history: ScanHistory,
+        scans: u64,
+    }
+
+    impl Fixture {
+        fn new(capacity: usize) -> Self {
+            let runtime = Arc::new(CanonicalRuntimeFacade::new());
+            let timers = Arc::new(TimerManager::new());
+            let executor = ProgramExecutor::new(
+                Arc::clone(&runtime),
+                Arc::clone(&timers),
+                Arc::new(CounterManager::new()),
+            );
+            let history = ScanHistory::new(capacity);
+            history.set_enabled(true);
+            Self {
+                runtime,
+                timers,
+                executor,
+                history,
+                scans: 0,
+            }
+        }
+
+        /// D0 = scan number, plus a TON and a rising-edge contact on M0.
+        fn scan(&mut self) {
+            self.scans += 1;
+            self.runtime
+                .write_word(SimWordDeviceType::D, 0, self.scans as u16)
+                .unwrap();
+            let program = LadderProgram {
+                name: "History".to_string(),
+                networks: vec![
+                    LadderNetwork {
+                        id: 0,
+                        nodes: vec![LadderNode::series(vec![
+                            LadderNode::contact(NodeType::ContactNo, "M0"),
+                            LadderNode::timer(NodeType::TimerTon, "T0", 100, SimTimeBase::Ms100),
+                        ])],
+                        comment: None,
+                    },
+                    LadderNetwork {
+                        id: 1,
+                        nodes: vec![LadderNode::series(vec![
+                            LadderNode::contact(NodeType::ContactP, "M0"),
+                            LadderNode::coil(NodeType::CoilSet, "M1"),
+                        ])],
+                        comment: None,
+                    },
+                ],
+            };
+            let profile = LsProfile::new("XGK".to_string(), PlcHardwareTopology::default());
+            let compiled = compile_program(&program, &profile).unwrap();
+            self.timers.tick(10);
+            self.executor.execute_program(&compiled);
+            self.history
+                .record(self.executor.capture_checkpoint(self.scans));
+        }
+
+        fn restore(&self, checkpoint: &ScanCheckpoint) {
+            self.executor.restore_checkpoint(checkpoint).unwrap();
+        }
+
+        fn d0(&self) -> u16 {
+            self.runtime.read_word(SimWordDeviceType::D, 0).unwrap()
+        }
+    }
+
+    #[test]
+    fn test_starts_disabled() {
+        let mut fixture = Fixture::new(4);
+        fixture.history = ScanHistory::new(4);
+        assert!(!fixture.history.is_enabled());
+        assert!(!ScanHistory::default().is_enabled());
+
+        fixture.scan();
+        assert!(fixture.history.position().is_none());
+    }
+
+    #[test]
+    fn test_step_back_and_forward() {
+        let mut fx = Fixture::new(10);
+        for _ in 0..5 {
+            fx.scan();
+        }
+        assert_eq!(fx.history.position().unwrap().scan_count, 5);
+        assert!(fx.history.step_forward().is_none());
+
+        let cp = fx.history.step_back().unwrap();
+        fx.restore(&cp);
+        assert_eq!(fx.d0(), 4);
+        assert!(fx.history.is_rewound());
+
+        let cp = fx.history.step_back().unwrap();
+        fx.restore(&cp);
+        assert_eq!(fx.d0(), 3);
+
+        let cp = fx.history.step_forward().unwrap();
+        fx.restore(&cp);
+        assert_eq!(fx.d0(), 4);
+
+        let cp = fx.history.step_forward().unwrap();
+        fx.restore(&cp);
+        assert_eq!(fx.d0(), 5);
+        assert!(!fx.history.is_rewound());
+        assert!(fx.history.step_forward().is_none());
+    }
+
+    #[test]
+    fn test_capacity_rolls_oldest_out() {
+        let mut fx = Fixture::new(3);
+        for _ in 0..6 {
+            fx.scan();
+        }
+        let mut oldest = None;
+        while let Some(cp) = fx.history.step_back() {
+            oldest = Some(cp.scan_count());
+        }
+        assert_eq!(oldest, Some(4));
+    }
+
+    #[test]
+    fn test_recording_while_rewound_discards_branch() {
+        let mut fx = Fixture::new(10);
+        for _ in 0..5 {
+            fx.scan();
+        }
+        fx.history.step_back();
+        fx.history.step_back();
+        fx.scans = 3;
+        fx.scan();
+
+        let position = fx.history.position().unwrap();
+        assert_eq!(position.len, 4);
+        assert_eq!(position.scan_count, 4);
+        assert!(position.at_latest);
+    }
+
+    #[test]
+    fn test_restore_includes_timer_and_edge_state() {
+        let mut fx = Fixture::new(10);
+        fx.scan();
+        fx.runtime.write_bit(SimBitDeviceType::M, 0, true).unwrap();
+        fx.scan();
+        assert!(fx.timers.get_state(0).unwrap().enabled);
+
+        let cp = fx.history.step_back().unwrap();
+        fx.restore(&cp);
+        assert!(!fx.timers.get_state(0).is_some_and(|t| t.enabled));
+        assert!(!fx.runtime.read_bit(SimBitDeviceType::M, 0).unwrap());
+        assert!(cp.edges.values().all(|v| !*v));
+    }
+
+    #[test]
+    fn test_rewind_to_last_change() {
+        let mut fx = Fixture::new(20);
+        let d100 = CanonicalAddress::new(CanonicalAreaKind::DataWord, 100);
+        for scan in 1..=8 {
+            if scan == 3 || scan == 6 {
+                fx.runtime
+                    .write_word(SimWordDeviceType::D, 100, scan as u16)
+                    .unwrap();
+            }
+            fx.scan();
+        }
+
+        let (cp, location) = fx.history.rewind_to_last_change(d100).unwrap();
+        assert_eq!(location.changed_in_scan, 6);
+        assert_eq!(location.old_value, CanonicalValue::U16(3));
+        assert_eq!(location.new_value, CanonicalValue::U16(6));
+        assert_eq!(cp.scan_count(), 5);
+        assert!(fx.history.is_rewound());
+
+        // 한 번 더 찾으면 그 이전 변경으로 간다
+        let (_, location) = fx.history.rewind_to_last_change(d100).unwrap();
+        assert_eq!(location.changed_in_scan, 3);
+        assert!(fx.history.rewind_to_last_change(d100).is_none());
+
+        // 워드의 비트 지정 주소는 해당 비트만 비교한다 (3 → 6에서 bit 2가 켜짐)
+        while fx.history.step_forward().is_some() {}
+        let bit2 = CanonicalAddress::with_bit_index(CanonicalAreaKind::DataWord, 100, 2);
+        let (_, location) = fx.history.rewind_to_last_change(bit2).unwrap();
+        assert_eq!(location.changed_in_scan, 6);
+        assert_eq!(location.new_value, CanonicalValue::Bool(true));
+    }
+}
@@ -0,0 +1,658 @@
+//! 입력 고장 주입 (sensor fault injection)
+//!
+//! 현장 기기 고장을 흉내 내 HMI 알람과 PLC 디바운스 로직을 검증하기 위한 서비스.
+//! 입력 비트를 0/1에 고정(stuck-at)하거나 채터링시키고, 입력 워드에 가우시안
+//! 노이즈·스파이크를 얹거나, 값 전파를 N ms 지연시킨다.
+//!
+//! 주입기는 주소별로 "현장 값(raw)"을 추적한다. 호스트는 매 스캔 시작 시 프로그램
+//! 실행 직전에 [`InputFaultInjector::apply`]를 호출하므로 스캔 사이에 들어온 쓰기가
+//! 있어도 프로그램은 항상 주입 결과를 읽는다. 호출 시 현재 메모리 값이 주입기가 마지막으로
+//! 쓴 값과 다르면 다른 출처(캔버스, 시나리오, 프로토콜)가 새 현장 값을 쓴 것으로
+//! 본다. 주입기가 쓴 값과 같은 값이 다시 쓰이면 구분할 수 없으므로 그 변화는
+//! 놓친다. 고장이 해제되거나 만료되면 현장 값을 복원한다. 모든 쓰기는 `CanonicalWriteSource::FaultInjection`으로 남는다.
+
+use std::collections::{BTreeMap, VecDeque};
+
+use modone_contract::{CanonicalAddress, CanonicalValue, CanonicalWriteSource};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::memory::{CanonicalRuntimeFacade, SimMemoryError};
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Fault injection error types
+#[derive(Debug, Error)]
+pub enum InjectionError {
+    #[error("Fault '{kind}' requires a bit address: {address:?}")]
+    BitAddressRequired {
+        kind: &'static str,
+        address: CanonicalAddress,
+    },
+    #[error("Fault '{kind}' requires a word address: {address:?}")]
+    WordAddressRequired {
+        kind: &'static str,
+        address: CanonicalAddress,
+    },
+    #[error("Invalid fault parameter: {0}")]
+    InvalidParameter(String),
+    #[error("Address already has an injected fault: {0:?}")]
+    AlreadyInjected(CanonicalAddress),
+    #[error("Injected fault not found: {0}")]
+    NotFound(u64),
+    #[error(transparent)]
+    Memory(#[from] SimMemoryError),
+}
+
+pub type InjectionResult<T> = Result<T, InjectionError>;
+
+// ============================================================================
+// Request Types
+// ============================================================================
+
+/// Kind of injected field-device fault
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum InjectedFaultKind {
+    /// Bit pinned at a fixed value
+    #[serde(rename_all = "camelCase")]
+    StuckAt { value: bool },
+    /// Bit chattering: inverted every half period at the given rate
+    #[serde(rename_all = "camelCase")]
+    Flicker { rate_hz: f64 },
+    /// Gaussian noise added to a word
+    #[serde(rename_all = "camelCase")]
+    Noise {
+        std_dev: f64,
+        /// Interpret the word as i16
+        #[serde(default)]
+        signed: bool,
+    },
+    /// Offset added to a word for a limited time after activation
+    #[serde(rename_all = "camelCase")]
+    Spike {
+        magnitude: i32,
+        duration_ms: u64,
+        #[serde(default)]
+        signed: bool,
+    },
+    /// Field value changes propagate after a delay
+    #[serde(rename_all = "camelCase")]
+    Delay { delay_ms: u64 },
+}
+
+impl InjectedFaultKind {
+    fn name(&self) -> &'static str {
+        match self {
+            InjectedFaultKind::StuckAt { .. } => "stuckAt",
+            InjectedFaultKind::Flicker { .. } => "flicker",
+            InjectedFaultKind::Noise { .. } => "noise",
+            InjectedFaultKind::Spike { .. } => "spike",
+            InjectedFaultKind::Delay { .. } => "delay",
+        }
+    }
+
+    fn validate(&self, address: CanonicalAddress) -> InjectionResult<()> {
+        let is_bit = address.area.is_bit_area() || address.bit_index.is_some();
+        match *self {
+            InjectedFaultKind::StuckAt { .. } | InjectedFaultKind::Flicker { .. } if !is_bit => {
+                return Err(InjectionError::BitAddressRequired {
+                    kind: self.name(),
+                    address,
+                });
+            }
+            InjectedFaultKind::Noise { .. } | InjectedFaultKind::Spike { .. } if is_bit => {
+                return Err(InjectionError::WordAddressRequired {
+                    kind: self.name(),
+                    address,
+                });
+            }
+            _ => {}
+        }
+
+        match *self {
+            InjectedFaultKind::Flicker { rate_hz } if !(rate_hz > 0.0 && rate_hz.is_finite()) => {
+                Err(InjectionError::InvalidParameter(format!(
+                    "flicker rate must be positive: {rate_hz}"
+                )))
+            }
+            InjectedFaultKind::Noise { std_dev, .. }
+                if !(std_dev >= 0.0 && std_dev.is_finite()) =>
+            {
+                Err(InjectionError::InvalidParameter(format!(
+                    "noise standard deviation must not be negative: {std_dev}"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// When an injected fault is active, relative to the time it was injected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectionSchedule {
+    /// Delay before the fault becomes active
+    #[serde(default)]
+    pub start_after_ms: u64,
+    /// Active duration; `None` keeps the fault until it is cleared
+    #[serde(default)]
+    pub duration_ms: Option<u64>,
+}
+
+/// Request to inject a fault on one address
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectionRequest {
+    pub address: CanonicalAddress,
+    pub fault: InjectedFaultKind,
+    #[serde(default)]
+    pub schedule: InjectionSchedule,
+    /// Display address (vendor notation) for the UI
+    #[serde(default)]
+    pub label: Option<String>,
+}
+
+/// Injected fault state reported to the UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InjectedFaultInfo {
+    pub id: u64,
+    pub address: CanonicalAddress,
+    pub label: Option<String>,
+    pub fault: InjectedFaultKind,
+    /// Activation time (epoch ms)
+    pub starts_at_ms: u64,
+    /// Expiry time (epoch ms)
+    pub ends_at_ms: Option<u64>,
+    pub active: bool,
+}
+
+// ============================================================================
+// Injector
+// ============================================================================
+
+struct InjectedFault {
+    request: InjectionRequest,
+    starts_at_ms: u64,
+    ends_at_ms: Option<u64>,
+    /// Latest value written by the field (non-injection sources)
+    raw: Option<CanonicalValue>,
+    /// Value last written by the injector
+    last_written: Option<CanonicalValue>,
+    /// Pending field changes for `Delay`, oldest first
+    pending: VecDeque<(u64, CanonicalValue)>,
+    /// Value currently propagated by `Delay`
+    delayed: Option<CanonicalValue>,
+    rng: XorShift,
+}
+
+impl InjectedFault {
+    fn is_active(&self, now_ms: u64) -> bool {
+        now_ms >= self.starts_at_ms && self.ends_at_ms.map_or(true, |end| now_ms < end)
+    }
+
+    fn info(&self, id: u64, now_ms: u64) -> InjectedFaultInfo {
+        InjectedFaultInfo {
+            id,
+            address: self.request.address,
+            label: self.request.label.clone(),
+            fault: self.request.fault,
+            starts_at_ms: self.starts_at_ms,
+            ends_at_ms: self.ends_at_ms,
+            active: self.is_active(now_ms),
+        }
+    }
+
+    /// 현장 값 갱신을 감지하고 이번 스캔에 쓸 값을 계산한다
+    fn next_value(&mut self, current: CanonicalValue, now_ms: u64) -> CanonicalValue {
+        if self.last_written != Some(current) {
+            self.raw = Some(current);
+            if self.delayed.is_none() {
+                self.delayed = Some(current);
+            } else {
+                self.pending.push_back((now_ms, current));
+            }
+        }
+        let raw = self.raw.unwrap_or(current);
+        let elapsed_ms = now_ms.saturating_sub(self.starts_at_ms);
+
+        match self.request.fault {
+            InjectedFaultKind::StuckAt { value } => CanonicalValue::Bool(value),
+            InjectedFaultKind::Flicker { rate_hz } => {
+                let half_period_ms = (500.0 / rate_hz).max(1.0) as u64;
+                let inverted = (elapsed_ms / half_period_ms) % 2 == 1;
+                CanonicalValue::Bool(as_bool(raw) ^ inverted)
+            }
+            InjectedFaultKind::Noise { std_dev, signed } => {
+                let delta = self.rng.next_gaussian() * std_dev;
+                offset_word(raw, delta, signed)
+            }
+            InjectedFaultKind::Spike {
+                magnitude,
+                duration_ms,
+                signed,
+            } => {
+                if elapsed_ms < duration_ms {
+                    offset_word(raw, magnitude as f64, signed)
+                } else {
+                    raw
+                }
+            }
+            InjectedFaultKind::Delay { delay_ms } => {
+                while let Some(&(changed_at, value)) = self.pending.front() {
+                    if now_ms.saturating_sub(changed_at) < delay_ms {
+                        break;
+                    }
+                    self.delayed = Some(value);
+                    self.pending.pop_front();
+                }
+                self.delayed.unwrap_or(raw)
+            }
+        }
+    }
+}
+
+/// Schedules and applies field-device faults on the canonical runtime
+#[derive(Default)]
+pub struct InputFaultInjector {
+    faults: Mutex<BTreeMap<u64, InjectedFault>>,
+    next_id: Mutex<u64>,
+}
+
+impl InputFaultInjector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inject a fault starting from now
+    pub fn inject(&self, request: InjectionRequest) -> InjectionResult<u64> {
+        self.inject_at(request, modone_contract::clock::now_millis())
+    }
+
+    /// Inject a fault scheduled relative to `now_ms`
+    pub fn inject_at(&self, request: InjectionRequest, now_ms: u64) -> InjectionResult<u64> {
+        request.fault.validate(request.address)?;
+
+        let mut faults = self.faults.lock();
+        if faults
+            .values()
+            .any(|fault| fault.request.address == request.address)
+        {
+            return Err(InjectionError::AlreadyInjected(request.address));
+        }
+
+        let id = {
+            let mut next_id = self.next_id.lock();
+            *next_id += 1;
+            *next_id
+        };
+        let starts_at_ms = now_ms + request.schedule.start_after_ms;
+        let ends_at_ms = request
+            .schedule
+            .duration_ms
+            .map(|duration| starts_at_ms + duration);
+        faults.insert(
+            id,
+            InjectedFault {
+                request,
+                starts_at_ms,
+                ends_at_ms,
+                raw: None,
+                last_written: None,
+                pending: VecDeque::new(),
+                delayed: None,
+                rng: XorShift::new(id ^ now_ms),
+            },
+        );
+        Ok(id)
+    }
+
+    /// Remove a fault and restore the field value
+    pub fn clear(&self, id: u64, runtime: &CanonicalRuntimeFacade) -> InjectionResult<()> {
+        let fault = self
+            .faults
+            .lock()
+            .remove(&id)
+            .ok_or(InjectionError::NotFound(id))?;
+        restore_field_value(&fault, runtime)?;
+        Ok(())
+    }
+
+    /// Remove all faults and restore the field values
+    pub fn clear_all(&self, runtime: &CanonicalRuntimeFacade) -> InjectionResult<()> {
+        let faults = std::mem::take(&mut *self.faults.lock());
+        for fault in faults.values() {
+            restore_field_value(fault, runtime)?;
+        }
+        Ok(())
+    }
+
+    /// Scheduled and active faults
+    pub fn list(&self) -> Vec<InjectedFaultInfo> {
+        self.list_at(modone_contract::clock::now_millis())
+    }
+
+    pub fn list_at(&self, now_ms: u64) -> Vec<InjectedFaultInfo> {
+        self.faults
+            .lock()
+            .iter()
+            .map(|(id, fault)| fault.info(*id, now_ms))
+            .collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.faults.lock().is_empty()
+    }
+
+    /// Apply active faults. The host calls this at the start of each scan,
+    /// before the program runs, so writes between scans cannot bypass a fault.
+    pub fn apply(&self, runtime: &CanonicalRuntimeFacade) -> InjectionResult<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        self.apply_at(runtime, modone_contract::clock::now_millis())
+    }
+
+    pub fn apply_at(&self, runtime: &CanonicalRuntimeFacade, now_ms: u64) -> InjectionResult<()> {
+        let mut faults = self.faults.lock();
+
+        // 만료된 고장은 현장 값을 복원하고 제거한다
+        let expired: Vec<u64> = faults
+            .iter()
+            .filter(|(_, fault)| fault.ends_at_ms.is_some_and(|end| now_ms >= end))
+            .map(|(id, _)| *id)
+            .collect();
+        for id in expired {
+            if let Some(fault) = faults.remove(&id) {
+                restore_field_value(&fault, runtime)?;
+            }
+        }
+
+        for fault in faults.values_mut() {
+            if !fault.is_active(now_ms) {
+                continue;
+            }
+            let address = fault.request.address;
+            let current = runtime.read(address)?;
+            let value = fault.next_value(current, now_ms);
+            runtime.write(address, value, CanonicalWriteSource::FaultInjection)?;
+            fault.last_written = Some(value);
+        }
+        Ok(())
+    }
+}
+
+/// 주입기가 쓴 값이 그대로 남아 있으면 현장 값으로 되돌린다
+fn restore_field_value(
+    fault: &InjectedFault,
+    runtime: &CanonicalRuntimeFacade,
+) -> InjectionResult<()> {
+    let (Some(raw), Some(last_written)) = (fault.raw, fault.last_written) else {
+        return Ok(());
+    };
+    let address = fault.request.address;
+    if runtime.read(address)? == last_written {
+        runtime.write(address, raw, CanonicalWriteSource::FaultInjection)?;
+    }
+    Ok(())
+}
+
+fn as_bool(value: CanonicalValue) -> bool {
+    match value {
+        CanonicalValue::Bool(value) => value,
+        CanonicalValue::U16(value) => value != 0,
+    }
+}
+
+fn offset_word(value: CanonicalValue, delta: f64, signed: bool) -> CanonicalValue {
+    let raw = match value {
+        CanonicalValue::U16(value) => value,
+        CanonicalValue::Bool(value) => value as u16,
+    };
+    let word = if signed {
+        let shifted = (raw as i16 as f64 + delta).round();
+        shifted.clamp(i16::MIN as f64, i16::MAX as f64) as i16 as u16
+    } else {
+        let shifted = (raw as f64 + delta).round();
+        shifted.clamp(0.0, u16::MAX as f64) as u16
+    };
+    CanonicalValue::U16(word)
+}
+
+/// wasm에서도 쓸 수 있는 작은 의사난수 생성기 (xorshift64*)
+struct XorShift(u64);
+
+impl XorShift {
+    fn new(seed: u64) -> Self {
+        Self(seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut x = self.0;
+        x ^= x >> 12;
+        x ^= x << 25;
+        x ^= x >> 27;
+        self.0 = x;
+        x.wrapping_mul(0x2545_F491_4F6C_DD1D)
+    }
+
+    /// (0, 1] 구간 균등 분포
+    fn next_unit(&mut self) -> f64 {
+        ((self.next_u64() >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+
+    /// 표준 정규 분포 (Box-Muller)
+    fn next_gaussian(&mut self) -> f64 {
+        let u1 = self.next_unit();
+        let u2 = self.next_unit();
+        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use modone_contract::CanonicalAreaKind;
+
+    fn input(index: u32) -> CanonicalAddress {
+        CanonicalAddress::new(CanonicalAreaKind::InputBit, index)
+    }
+
+    fn word(index: u32) -> CanonicalAddress {
+        CanonicalAddress::new(CanonicalAreaKind::DataWord, index)
+    }
+
+    fn request(address: CanonicalAddress, fault: InjectedFaultKind) -> InjectionRequest {
+        InjectionRequest {
+            address,
+            fault,
+            schedule: InjectionSchedule::default(),
+            label: None,
+        }
+    }
+
+    #[test]
+    fn test_stuck_at_overrides_field_and_restores_on_clear() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let injector = InputFaultInjector::new();
+        runtime
+            .write_bool(input(0), true, CanonicalWriteSource::Simulation)
+            .unwrap();
+
+        let id = injector
+            .inject_at(
+                request(input(0), InjectedFaultKind::StuckAt { value: false }),
+                0,
+            )
+            .unwrap();
+        injector.apply_at(&runtime, 0).unwrap();
+        assert!(!runtime.read_bool(input(0)).unwrap());
+
+        injector.clear(id, &runtime).unwrap();
+        assert!(runtime.read_bool(input(0)).unwrap());
+        assert!(injector.is_empty());
+    }
+
+    #[test]
+    fn test_flicker_toggles_at_rate() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let injector = InputFaultInjector::new();
+        injector
+            .inject_at(
+                request(input(1), InjectedFaultKind::Flicker { rate_hz: 10.0 }),
+                0,
+            )
+            .unwrap();
+
+        let samples: Vec<bool> = [0, 50, 100, 150]
+            .into_iter()
+            .map(|now| {
+                injector.apply_at(&runtime, now).unwrap();
+                runtime.read_bool(input(1)).unwrap()
+            })
+            .collect();
+        assert_eq!(samples, vec![false, true, false, true]);
+    }
+
+    #[test]
+    fn test_noise_and_spike_on_words() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let injector = InputFaultInjector::new();
+        runtime
+            .write_word_value(word(0), 1000, CanonicalWriteSource::Simulation)
+            .unwrap();
+        runtime
+            .write_word_value(word(1), 1000, CanonicalWriteSource::Simulation)
+            .unwrap();
+        injector
+            .inject_at(
+                request(
+                    word(0),
+                    InjectedFaultKind::Noise {
+                        std_dev: 5.0,
+                        signed: false,
+                    },
+                ),
+                0,
+            )
+            .unwrap();
+        injector
+            .inject_at(
+                request(
+                    word(1),
+                    InjectedFaultKind::Spike {
+                        magnitude: 500,
+                        duration_ms: 100,
+                        signed: false,
+                    },
+                ),
+                0,
+            )
+            .unwrap();
+
+        let mut distinct = std::collections::BTreeSet::new();
+        for now in 0..20 {
+            injector.apply_at(&runtime, now).unwrap();
+            let value = runtime.read_word_value(word(0)).unwrap();
+            assert!((950..=1050).contains(&value), "noise out of range: {value}");
+            distinct.insert(value);
+        }
+        assert!(distinct.len() > 1);
+        assert_eq!(runtime.read_word_value(word(1)).unwrap(), 1500);
+
+        injector.apply_at(&runtime, 100).unwrap();
+        assert_eq!(runtime.read_word_value(word(1)).unwrap(), 1000);
+    }
+
+    #[test]
+    fn test_delay_propagates_field_changes_late() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let injector = InputFaultInjector::new();
+        injector
+            .inject_at(
+                request(input(2), InjectedFaultKind::Delay { delay_ms: 200 }),
+                0,
+            )
+            .unwrap();
+        injector.apply_at(&runtime, 0).unwrap();
+
+        runtime
+            .write_bool(input(2), true, CanonicalWriteSource::Simulation)
+            .unwrap();
+        injector.apply_at(&runtime, 10).unwrap();
+        assert!(!runtime.read_bool(input(2)).unwrap());
+
+        injector.apply_at(&runtime, 150).unwrap();
+        assert!(!runtime.read_bool(input(2)).unwrap());
+
+        injector.apply_at(&runtime, 210).unwrap();
+        assert!(runtime.read_bool(input(2)).unwrap());
+    }
+
+    #[test]
+    fn test_schedule_and_validation() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let injector = InputFaultInjector::new();
+        let mut scheduled = request(input(3), InjectedFaultKind::StuckAt { value: true });
+        scheduled.schedule = InjectionSchedule {
+            start_after_ms: 100,
+            duration_ms: Some(50),
+        };
+        injector.inject_at(scheduled, 0).unwrap();
+
+        injector.apply_at(&runtime, 50).unwrap();
+        assert!(!runtime.read_bool(input(3)).unwrap());
+        assert!(!injector.list_at(50)[0].active);
+
+        injector.apply_at(&runtime, 100).unwrap();
+        assert!(runtime.read_bool(input(3)).unwrap());
+
+        // 만료되면 현장 값으로 복원되고 목록에서 빠진다
+        injector.apply_at(&runtime, 150).unwrap();
+        assert!(!runtime.read_bool(input(3)).unwrap());
+        assert!(injector.is_empty());
+
+        assert!(matches!(
+            injector.inject_at(
+                request(word(0), InjectedFaultKind::StuckAt { value: true }),
+                0
+            ),
+            Err(InjectionError::BitAddressRequired { .. })
+        ));
+        assert!(matches!(
+            injector.inject_at(
+                request(input(0), InjectedFaultKind::Flicker { rate_hz: 0.0 }),
+                0
+            ),
+            Err(InjectionError::InvalidParameter(_))
+        ));
+    }
+
+    #[test]
+    fn test_writes_are_tagged_as_fault_injection() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let injector = InputFaultInjector::new();
+        let mut rx = runtime.handle().read().bus().subscribe();
+        injector
+            .inject_at(
+                request(input(4), InjectedFaultKind::StuckAt { value: true }),
+                0,
+            )
+            .unwrap();
+        injector.apply_at(&runtime, 0).unwrap();
+
+        let event = rx.try_recv().unwrap();
+        let modone_contract::CanonicalMemoryEvent::Single(change) = event else {
+            panic!("expected single change");
+        };
+        assert_eq!(change.source, CanonicalWriteSource::FaultInjection);
+    }
+}
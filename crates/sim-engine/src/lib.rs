@@ -5,20 +5,67 @@
 //! (interval/select)·소켓·Tauri 는 native 셸(src-tauri/sim)에 남는다. wasm·native
 //! 양쪽으로 컴파일된다. 설계: docs/wasm-migration/00-CONTRACT.md, 02-PLC-MODEL.md.
 
+pub mod analog;
 pub mod counter;
 pub mod debugger;
+pub mod diff;
 pub mod executor;
+pub mod expression;
+pub mod fault;
+pub mod history;
+pub mod injection;
+pub mod link;
 pub mod memory;
+pub mod motion;
+pub mod profiler;
+pub mod recording;
+pub mod snapshot;
 pub mod tag_registry;
 pub mod timer;
+pub mod translation;
 pub mod types;
+pub mod watch;
 
+pub use analog::{
+    AnalogChannelConfig, AnalogChannelState, AnalogDirection, AnalogError, AnalogFilter,
+    AnalogModuleConfig, AnalogModuleInfo, AnalogModuleSimulator, AnalogResult, AnalogSignalRange,
+    AnalogSource, AnalogWordLayout,
+};
 pub use counter::CounterManager;
+pub use diff::{diff_memory, AreaDiff, MemoryChange, MemoryDiff};
 pub use executor::{
-    compile_program, CompiledNetwork, CompiledNode, CompiledOperand, CompiledProgram,
-    DeviceAddress, ExecutionError, ExecutionResult, LadderNetwork, LadderNode, LadderProgram,
-    NetworkExecutionResult, NodeType, ProgramExecutionResult, ProgramExecutor,
+    compile_program, unsupported_instructions, CompiledNetwork, CompiledNode, CompiledOperand,
+    CompiledProgram, DeviceAddress, ExecutionError, ExecutionResult, InstructionTiming,
+    LadderNetwork, LadderNode, LadderProgram, NetworkExecutionResult, NodeType,
+    ProgramExecutionResult, ProgramExecutor, UnsupportedInstruction,
+};
+pub use expression::{ExprValue, Expression, ExpressionError, ExpressionResult};
+pub use fault::{FaultAction, FaultHandling, FaultKind, FaultPolicy, ScanFault};
+pub use history::{HistoryPosition, ScanCheckpoint, ScanHistory, ValueChangeLocation};
+pub use injection::{
+    InjectedFaultInfo, InjectedFaultKind, InjectionError, InjectionRequest, InjectionSchedule,
+    InputFaultInjector,
 };
+pub use link::{copy_link, range_contains, validate_links, LinkError};
 pub use memory::{CanonicalRuntimeFacade, SimMemoryError, SimMemoryResult};
+pub use motion::{
+    AxisConfig, AxisInfo, HscChannelConfig, HscChannelInfo, HscCompare, HscCountMode, HscPreset,
+    MotionError, MotionResult, MotionSimulator,
+};
+pub use profiler::{
+    slowest_networks, InstructionProfile, NetworkProfile, ScanProfile, ScanProfiler, SlowNetwork,
+};
+pub use recording::{
+    EventRecorder, EventRecording, EventReplayer, RecordedEvent, RecordedWrite, RecordingError,
+    RecordingHeader, RecordingResult, ReplayProgress, RECORDING_FORMAT_VERSION,
+};
+pub use snapshot::{
+    validate_snapshot_name, EdgeState, PlcStateSnapshot, ScenarioPosition, SnapshotError,
+    SnapshotInfo, SnapshotResult, SNAPSHOT_FORMAT_VERSION,
+};
 pub use timer::TimerManager;
+pub use translation::{translate_program, translate_tags};
 pub use types::*;
+pub use watch::{
+    export_history_csv, DisplayRadix, WatchDataType, WatchFormat, WatchScaling, WordOrder,
+};
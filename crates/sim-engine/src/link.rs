@@ -0,0 +1,304 @@
+//! inter-CPU 메모리 링크 검증·복사
+//!
+//! `MemoryLink`는 src CPU의 구간을 dst CPU의 같은 길이 구간으로 단방향 복사한다.
+//! 충돌은 런타임 셀 소유권이 아니라 설정 시점 정적 검증으로 막는다 — dst 구간이
+//! 겹치는 링크, 복사된 셀이 링크를 따라 다시 자기 src 로 돌아오는 순환(a→b→c→a
+//! 포함)은 거부한다.
+//! 복사 태스크(Eventual/Periodic/OnScan)는 native 셸이 구동한다.
+//! 설계: docs/architecture/multi-cpu/00-design.md §3–§4
+
+use modone_contract::{
+    CanonicalAddress, CanonicalAreaKind, CanonicalRange, CanonicalWriteSource, CpuId, MemoryLink,
+};
+use thiserror::Error;
+
+use super::memory::{CanonicalRuntimeFacade, SimMemoryResult};
+
+/// Link configuration errors
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum LinkError {
+    #[error("Link {index} references unknown CPU '{cpu}'")]
+    UnknownCpu { index: usize, cpu: CpuId },
+    #[error("Link {index} has an empty range")]
+    EmptyRange { index: usize },
+    #[error("Link {index} copies {src} cells into {dst} cells")]
+    LengthMismatch { index: usize, src: u32, dst: u32 },
+    #[error("Link {index} mixes bit and word areas")]
+    AreaMismatch { index: usize },
+    #[error("Link {index} cannot write {area:?} on CPU '{cpu}'")]
+    ReadOnlyDestination {
+        index: usize,
+        cpu: CpuId,
+        area: CanonicalAreaKind,
+    },
+    #[error("Links {first} and {second} write overlapping ranges on CPU '{cpu}'")]
+    OverlappingDestination {
+        first: usize,
+        second: usize,
+        cpu: CpuId,
+    },
+    #[error("Links {links:?} copy the same cells around in a cycle")]
+    CircularLink { links: Vec<usize> },
+}
+
+/// Validate links against the configured CPUs
+pub fn validate_links(links: &[MemoryLink], cpus: &[CpuId]) -> Result<(), LinkError> {
+    for (index, link) in links.iter().enumerate() {
+        for endpoint in [&link.src, &link.dst] {
+            if !cpus.contains(&endpoint.cpu) {
+                return Err(LinkError::UnknownCpu {
+                    index,
+                    cpu: endpoint.cpu.clone(),
+                });
+            }
+            if endpoint.range.is_empty() {
+                return Err(LinkError::EmptyRange { index });
+            }
+        }
+        if link.src.range.len() != link.dst.range.len() {
+            return Err(LinkError::LengthMismatch {
+                index,
+                src: link.src.range.len(),
+                dst: link.dst.range.len(),
+            });
+        }
+        if link.src.range.area.is_bit_area() != link.dst.range.area.is_bit_area() {
+            return Err(LinkError::AreaMismatch { index });
+        }
+        if !link
+            .dst
+            .range
+            .area
+            .default_access()
+            .allows_write(CanonicalWriteSource::CpuLink)
+        {
+            return Err(LinkError::ReadOnlyDestination {
+                index,
+                cpu: link.dst.cpu.clone(),
+                area: link.dst.range.area,
+            });
+        }
+    }
+
+    for (first, a) in links.iter().enumerate() {
+        for (offset, b) in links[first + 1..].iter().enumerate() {
+            let second = first + 1 + offset;
+            if a.dst.cpu == b.dst.cpu && a.dst.range.overlaps(&b.dst.range) {
+                return Err(LinkError::OverlappingDestination {
+                    first,
+                    second,
+                    cpu: a.dst.cpu.clone(),
+                });
+            }
+        }
+    }
+
+    if let Some(links) = find_link_cycle(links) {
+        return Err(LinkError::CircularLink { links });
+    }
+
+    Ok(())
+}
+
+/// Whether cells written by `a` are read again by `b`
+fn feeds(a: &MemoryLink, b: &MemoryLink) -> bool {
+    a.dst.cpu == b.src.cpu && a.dst.range.overlaps(&b.src.range)
+}
+
+/// Indices of the links on the first cycle of the "feeds" graph, if any
+fn find_link_cycle(links: &[MemoryLink]) -> Option<Vec<usize>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum Visit {
+        New,
+        OnPath,
+        Done,
+    }
+
+    fn visit(
+        index: usize,
+        links: &[MemoryLink],
+        state: &mut [Visit],
+        path: &mut Vec<usize>,
+    ) -> Option<Vec<usize>> {
+        state[index] = Visit::OnPath;
+        path.push(index);
+        for next in 0..links.len() {
+            if !feeds(&links[index], &links[next]) {
+                continue;
+            }
+            match state[next] {
+                Visit::OnPath => {
+                    let start = path.iter().position(|&i| i == next)?;
+                    return Some(path[start..].to_vec());
+                }
+                Visit::New => {
+                    if let Some(cycle) = visit(next, links, state, path) {
+                        return Some(cycle);
+                    }
+                }
+                Visit::Done => {}
+            }
+        }
+        path.pop();
+        state[index] = Visit::Done;
+        None
+    }
+
+    let mut state = vec![Visit::New; links.len()];
+    let mut path = Vec::new();
+    (0..links.len()).find_map(|start| {
+        (state[start] == Visit::New)
+            .then(|| visit(start, links, &mut state, &mut path))
+            .flatten()
+    })
+}
+
+/// Whether a write to `address` falls inside the range
+pub fn range_contains(range: &CanonicalRange, address: &CanonicalAddress) -> bool {
+    address.area == range.area && (range.start_index..=range.end_index).contains(&address.index)
+}
+
+/// Copy the link's src range into its dst range. Only changed cells produce
+/// bus events on the destination.
+pub fn copy_link(
+    link: &MemoryLink,
+    src: &CanonicalRuntimeFacade,
+    dst: &CanonicalRuntimeFacade,
+) -> SimMemoryResult<usize> {
+    let count = link.src.range.len() as usize;
+    let values = src.read_range(link.src.range.area, link.src.range.start_index, count)?;
+    let writes = values
+        .into_iter()
+        .enumerate()
+        .map(|(offset, value)| {
+            (
+                CanonicalAddress::new(
+                    link.dst.range.area,
+                    link.dst.range.start_index + offset as u32,
+                ),
+                value,
+            )
+        })
+        .collect();
+    dst.write_batch(writes, CanonicalWriteSource::CpuLink)?;
+    Ok(count)
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::SimWordDeviceType;
+    use modone_contract::{LinkEndpoint, LinkSyncMode};
+
+    fn link(
+        src: (&str, CanonicalAreaKind, u32, u32),
+        dst: (&str, CanonicalAreaKind, u32, u32),
+    ) -> MemoryLink {
+        let endpoint =
+            |(cpu, area, start, end): (&str, CanonicalAreaKind, u32, u32)| LinkEndpoint {
+                cpu: CpuId::from(cpu),
+                range: CanonicalRange {
+                    area,
+                    start_index: start,
+                    end_index: end,
+                },
+            };
+        MemoryLink {
+            src: endpoint(src),
+            dst: endpoint(dst),
+            sync: LinkSyncMode::Eventual,
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_conflicts() {
+        use CanonicalAreaKind::{DataWord, InputBit, OutputBit, TimerDoneBit};
+        let cpus = vec![CpuId::from("a"), CpuId::from("b")];
+
+        let ok = link(("a", OutputBit, 0, 15), ("b", InputBit, 0, 15));
+        assert_eq!(validate_links(std::slice::from_ref(&ok), &cpus), Ok(()));
+
+        let overlapping = link(("a", DataWord, 0, 15), ("b", InputBit, 10, 25));
+        assert!(matches!(
+            validate_links(&[ok.clone(), overlapping], &cpus),
+            Err(LinkError::AreaMismatch { index: 1 })
+        ));
+        let overlapping = link(("a", OutputBit, 100, 115), ("b", InputBit, 10, 25));
+        assert!(matches!(
+            validate_links(&[ok.clone(), overlapping], &cpus),
+            Err(LinkError::OverlappingDestination {
+                first: 0,
+                second: 1,
+                ..
+            })
+        ));
+
+        let back = link(("b", InputBit, 0, 15), ("a", OutputBit, 0, 15));
+        assert!(matches!(
+            validate_links(&[ok.clone(), back], &cpus),
+            Err(LinkError::CircularLink { .. })
+        ));
+
+        // a→b→c→a 도 순환이다. 구간이 어긋나면 순환이 아니다.
+        let three = vec![CpuId::from("a"), CpuId::from("b"), CpuId::from("c")];
+        let chain = vec![
+            link(("a", DataWord, 0, 9), ("b", DataWord, 100, 109)),
+            link(("b", DataWord, 100, 109), ("c", DataWord, 200, 209)),
+            link(("c", DataWord, 200, 209), ("a", DataWord, 5, 14)),
+        ];
+        assert_eq!(
+            validate_links(&chain, &three),
+            Err(LinkError::CircularLink {
+                links: vec![0, 1, 2]
+            })
+        );
+        let mut open = chain.clone();
+        open[2] = link(("c", DataWord, 200, 209), ("a", DataWord, 10, 19));
+        assert_eq!(validate_links(&open, &three), Ok(()));
+
+        let unknown = link(("a", OutputBit, 0, 15), ("c", InputBit, 0, 15));
+        assert!(matches!(
+            validate_links(&[unknown], &cpus),
+            Err(LinkError::UnknownCpu { .. })
+        ));
+        let short = link(("a", OutputBit, 0, 15), ("b", InputBit, 0, 7));
+        assert!(matches!(
+            validate_links(&[short], &cpus),
+            Err(LinkError::LengthMismatch { .. })
+        ));
+        let timer = link(("a", OutputBit, 0, 15), ("b", TimerDoneBit, 0, 15));
+        assert!(matches!(
+            validate_links(&[timer], &cpus),
+            Err(LinkError::ReadOnlyDestination { .. })
+        ));
+    }
+
+    #[test]
+    fn test_copy_link_mirrors_range() {
+        let src = CanonicalRuntimeFacade::new();
+        let dst = CanonicalRuntimeFacade::new();
+        src.write_word(SimWordDeviceType::D, 100, 11).unwrap();
+        src.write_word(SimWordDeviceType::D, 101, 22).unwrap();
+
+        let words = link(
+            ("a", CanonicalAreaKind::DataWord, 100, 101),
+            ("b", CanonicalAreaKind::DataWord, 500, 501),
+        );
+        assert_eq!(copy_link(&words, &src, &dst).unwrap(), 2);
+        assert_eq!(dst.read_word(SimWordDeviceType::D, 500).unwrap(), 11);
+        assert_eq!(dst.read_word(SimWordDeviceType::D, 501).unwrap(), 22);
+
+        assert!(range_contains(
+            &words.src.range,
+            &CanonicalAddress::new(CanonicalAreaKind::DataWord, 101)
+        ));
+        assert!(!range_contains(
+            &words.src.range,
+            &CanonicalAddress::new(CanonicalAreaKind::DataWord, 102)
+        ));
+    }
+}
@@ -12,9 +12,12 @@ use thiserror::Error;
 
 use modone_contract::{
     CanonicalAddress, CanonicalAreaKind, CanonicalMemory, CanonicalMemoryError,
-    CanonicalMemorySnapshot, CanonicalValue, CanonicalWriteSource,
+    CanonicalMemorySnapshot, CanonicalValue, CanonicalWriteSource, TypedDataType, TypedValue,
+    WordOrder,
 };
+use plc_model::PlcMemoryLayout;
 
+use super::diff::{diff_memory, MemoryDiff};
 use super::types::{CounterState, MemorySnapshot, SimBitDeviceType, SimWordDeviceType, TimerState};
 
 #[derive(Debug, Error)]
@@ -47,6 +50,14 @@ impl CanonicalRuntimeFacade {
         Arc::clone(&self.memory)
     }
 
+    /// Reallocate every area to the CPU model's device sizes.
+    pub fn apply_memory_layout(&self, layout: &PlcMemoryLayout) {
+        let mut memory = self.memory.write();
+        for area in CanonicalAreaKind::ALL {
+            memory.resize_area(area, layout.size(area) as usize);
+        }
+    }
+
     pub fn read(&self, address: CanonicalAddress) -> SimMemoryResult<CanonicalValue> {
         Ok(self.memory.read().read(address)?)
     }
@@ -77,6 +88,30 @@ impl CanonicalRuntimeFacade {
         Ok(self.memory.write().write_batch(writes, source)?)
     }
 
+    pub fn read_typed(
+        &self,
+        address: CanonicalAddress,
+        data_type: TypedDataType,
+        order: WordOrder,
+    ) -> SimMemoryResult<TypedValue> {
+        Ok(self.memory.read().read_typed(address, data_type, order)?)
+    }
+
+    /// 다중 워드 값을 하나의 쓰기 잠금·배치로 기록한다 (torn read 방지)
+    pub fn write_typed(
+        &self,
+        address: CanonicalAddress,
+        data_type: TypedDataType,
+        value: &TypedValue,
+        order: WordOrder,
+        source: CanonicalWriteSource,
+    ) -> SimMemoryResult<()> {
+        Ok(self
+            .memory
+            .write()
+            .write_typed(address, data_type, value, order, source)?)
+    }
+
     pub fn clear_all(&self) {
         self.memory.write().clear_all();
     }
@@ -93,6 +128,11 @@ impl CanonicalRuntimeFacade {
         Ok(self.memory.write().restore_snapshot(snapshot)?)
     }
 
+    /// Changes in live memory since `baseline`
+    pub fn diff_against(&self, baseline: &CanonicalMemorySnapshot) -> MemoryDiff {
+        diff_memory(baseline, &self.snapshot())
+    }
+
     pub fn read_bool(&self, address: CanonicalAddress) -> SimMemoryResult<bool> {
         match self.read(address)? {
             CanonicalValue::Bool(value) => Ok(value),
@@ -109,6 +149,36 @@ impl CanonicalRuntimeFacade {
         self.write(address, CanonicalValue::Bool(value), source)
     }
 
+    /// Read sixteen bits of a bit area from `first` as one word, bit 0 first
+    pub fn read_bit_word(&self, first: CanonicalAddress) -> SimMemoryResult<u16> {
+        let bits = self.read_range(first.area, first.index, 16)?;
+        Ok(bits
+            .iter()
+            .enumerate()
+            .fold(0, |word, (bit, value)| match value {
+                CanonicalValue::Bool(true) => word | (1 << bit),
+                _ => word,
+            }))
+    }
+
+    /// Write `value` to sixteen bits of a bit area from `first` in one batch
+    pub fn write_bit_word(
+        &self,
+        first: CanonicalAddress,
+        value: u16,
+        source: CanonicalWriteSource,
+    ) -> SimMemoryResult<()> {
+        let writes = (0..16)
+            .map(|bit| {
+                (
+                    CanonicalAddress::new(first.area, first.index + bit),
+                    CanonicalValue::Bool(value & (1 << bit) != 0),
+                )
+            })
+            .collect();
+        self.write_batch(writes, source)
+    }
+
     pub fn read_word_value(&self, address: CanonicalAddress) -> SimMemoryResult<u16> {
         match self.read(address)? {
             CanonicalValue::U16(value) => Ok(value),
@@ -0,0 +1,1058 @@
+//! 고속 카운터·위치결정 모듈 시뮬레이션
+//!
+//! 고속 카운터(HSC) 채널은 엔코더 주파수(Hz, 부호 = 방향)를 스캔 간격만큼 적분해
+//! 현재값 더블워드에 쓴다. 리니어 모드는 범위 끝에서 멈추고 오버/언더플로 플래그를,
+//! 링 모드는 범위 안에서 순환한다. 프리셋 비트의 상승 에지에 프리셋 값을 싣고,
+//! 비교 출력 비트를 구동한다. 주파수는 직접 주거나 소스 더블워드(캔버스·시나리오가
+//! 쓰는 값)에서 읽는다.
+//!
+//! 위치결정 축은 펄스 출력/위치결정 명령이 모듈에 넘기는 명령 인터페이스를 그대로
+//! canonical 메모리에 둔다. 래더가 목표 위치·속도 더블워드를 쓰고 기동 비트를 올리면
+//! 사다리꼴 속도 프로파일로 현재 위치를 움직이며 busy/done/error 비트와 상태 워드를
+//! 갱신한다. 리밋 스위치 입력 비트가 이동 방향으로 켜지면 즉시 정지하고 에러를 낸다.
+//!
+//! 현재값/현재 위치 워드를 다른 출처(래더의 MOV 등)가 바꾸면 그 값을 새 기준으로
+//! 받아들인다. 모든 쓰기는 `CanonicalWriteSource::Simulation`으로 남고, 이벤트
+//! 재생은 [`MotionSimulator::output_addresses`]로 모듈이 다시 만들 쓰기를 건너뛴다.
+
+use std::collections::BTreeMap;
+
+use modone_contract::{
+    CanonicalAddress, CanonicalValue, CanonicalWriteSource, TypedDataType, TypedValue, WordOrder,
+};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::memory::{CanonicalRuntimeFacade, SimMemoryError};
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// High-speed counter / positioning simulation error types
+#[derive(Debug, Error)]
+pub enum MotionError {
+    #[error("'{field}' requires a bit address: {address:?}")]
+    BitAddressRequired {
+        field: &'static str,
+        address: CanonicalAddress,
+    },
+    #[error("'{field}' requires a word address: {address:?}")]
+    WordAddressRequired {
+        field: &'static str,
+        address: CanonicalAddress,
+    },
+    #[error("Invalid motion parameter: {0}")]
+    InvalidParameter(String),
+    #[error("High-speed counter or axis not found: {0}")]
+    NotFound(String),
+    #[error(transparent)]
+    Memory(#[from] SimMemoryError),
+}
+
+pub type MotionResult<T> = Result<T, MotionError>;
+
+fn require_bit(field: &'static str, address: CanonicalAddress) -> MotionResult<()> {
+    if address.area.is_bit_area() || address.bit_index.is_some() {
+        Ok(())
+    } else {
+        Err(MotionError::BitAddressRequired { field, address })
+    }
+}
+
+fn require_word(field: &'static str, address: CanonicalAddress) -> MotionResult<()> {
+    if address.area.is_bit_area() || address.bit_index.is_some() {
+        Err(MotionError::WordAddressRequired { field, address })
+    } else {
+        Ok(())
+    }
+}
+
+fn require_id(id: &str) -> MotionResult<()> {
+    if id.trim().is_empty() {
+        return Err(MotionError::InvalidParameter(
+            "id must not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// High-Speed Counter
+// ============================================================================
+
+/// Counting range behaviour
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum HscCountMode {
+    /// Stops at the bounds and raises overflow/underflow
+    Linear { min: i32, max: i32 },
+    /// Wraps around within `min..=max`
+    Ring { min: i32, max: i32 },
+}
+
+impl Default for HscCountMode {
+    fn default() -> Self {
+        Self::Linear {
+            min: i32::MIN,
+            max: i32::MAX,
+        }
+    }
+}
+
+impl HscCountMode {
+    fn bounds(&self) -> (i32, i32) {
+        match *self {
+            Self::Linear { min, max } | Self::Ring { min, max } => (min, max),
+        }
+    }
+}
+
+/// Compare output: on while `lower <= count <= upper`
+///
+/// With `upper == lower` the output is a coincidence output that also turns
+/// on in a scan where the count passed the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HscCompare {
+    pub lower: i32,
+    #[serde(default)]
+    pub upper: Option<i32>,
+    pub output: CanonicalAddress,
+}
+
+/// Preset loaded on the rising edge of `trigger`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HscPreset {
+    pub value: i32,
+    pub trigger: CanonicalAddress,
+}
+
+/// High-speed counter channel definition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HscChannelConfig {
+    pub id: String,
+    /// Current count (INT32 double word)
+    pub count: CanonicalAddress,
+    #[serde(default)]
+    pub word_order: WordOrder,
+    #[serde(default)]
+    pub mode: HscCountMode,
+    /// Count-enable bit; counts continuously when omitted
+    #[serde(default)]
+    pub enable: Option<CanonicalAddress>,
+    /// INT32 double word holding the encoder frequency in Hz
+    #[serde(default)]
+    pub frequency_source: Option<CanonicalAddress>,
+    #[serde(default)]
+    pub preset: Option<HscPreset>,
+    #[serde(default)]
+    pub compares: Vec<HscCompare>,
+    /// Status word: bit0 counting, bit1 overflow, bit2 underflow, bit 8+n compare n
+    #[serde(default)]
+    pub status: Option<CanonicalAddress>,
+}
+
+impl HscChannelConfig {
+    fn validate(&self) -> MotionResult<()> {
+        require_id(&self.id)?;
+        require_word("count", self.count)?;
+        let (min, max) = self.mode.bounds();
+        if min >= max {
+            return Err(MotionError::InvalidParameter(format!(
+                "counter '{}': range {min}..{max} must be increasing",
+                self.id
+            )));
+        }
+        if let Some(enable) = self.enable {
+            require_bit("enable", enable)?;
+        }
+        if let Some(source) = self.frequency_source {
+            require_word("frequencySource", source)?;
+        }
+        if let Some(preset) = &self.preset {
+            require_bit("preset.trigger", preset.trigger)?;
+        }
+        for compare in &self.compares {
+            require_bit("compares.output", compare.output)?;
+            if compare.upper.is_some_and(|upper| upper < compare.lower) {
+                return Err(MotionError::InvalidParameter(format!(
+                    "counter '{}': compare upper bound is below {}",
+                    self.id, compare.lower
+                )));
+            }
+        }
+        if let Some(status) = self.status {
+            require_word("status", status)?;
+        }
+        Ok(())
+    }
+}
+
+/// Counter state reported to the UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HscChannelInfo {
+    pub id: String,
+    pub count: i32,
+    pub frequency_hz: f64,
+    pub counting: bool,
+    pub overflow: bool,
+    pub underflow: bool,
+    pub compare_outputs: Vec<bool>,
+}
+
+struct HscChannel {
+    config: HscChannelConfig,
+    frequency_hz: f64,
+    /// Count including the fractional part carried between scans
+    count: f64,
+    last_written: Option<i32>,
+    last_ms: Option<u64>,
+    preset_input: bool,
+    counting: bool,
+    overflow: bool,
+    underflow: bool,
+    compare_outputs: Vec<bool>,
+}
+
+impl HscChannel {
+    fn new(config: HscChannelConfig) -> Self {
+        let compare_outputs = vec![false; config.compares.len()];
+        Self {
+            config,
+            frequency_hz: 0.0,
+            count: 0.0,
+            last_written: None,
+            last_ms: None,
+            preset_input: false,
+            counting: false,
+            overflow: false,
+            underflow: false,
+            compare_outputs,
+        }
+    }
+
+    fn value(&self) -> i32 {
+        self.count.floor() as i32
+    }
+
+    fn info(&self) -> HscChannelInfo {
+        HscChannelInfo {
+            id: self.config.id.clone(),
+            count: self.value(),
+            frequency_hz: self.frequency_hz,
+            counting: self.counting,
+            overflow: self.overflow,
+            underflow: self.underflow,
+            compare_outputs: self.compare_outputs.clone(),
+        }
+    }
+
+    /// 카운트 기준값이 바뀌면 플래그를 풀고 그 값에서 다시 센다
+    fn load(&mut self, value: i32) {
+        self.count = value as f64;
+        self.overflow = false;
+        self.underflow = false;
+    }
+
+    fn apply(&mut self, runtime: &CanonicalRuntimeFacade, now_ms: u64) -> MotionResult<()> {
+        let order = self.config.word_order;
+        let dt = now_ms.saturating_sub(self.last_ms.unwrap_or(now_ms)) as f64 / 1000.0;
+        self.last_ms = Some(now_ms);
+
+        if let Some(source) = self.config.frequency_source {
+            self.frequency_hz = read_i32(runtime, source, order)? as f64;
+        }
+        let current = read_i32(runtime, self.config.count, order)?;
+        if self.last_written.is_some_and(|written| written != current) {
+            self.load(current);
+        }
+        if let Some(preset) = self.config.preset {
+            let input = runtime.read_bool(preset.trigger)?;
+            if input && !self.preset_input {
+                self.load(preset.value);
+            }
+            self.preset_input = input;
+        }
+
+        let enabled = match self.config.enable {
+            Some(enable) => runtime.read_bool(enable)?,
+            None => true,
+        };
+        let previous = self.value();
+        self.counting = enabled && self.frequency_hz != 0.0;
+        if self.counting {
+            self.count += self.frequency_hz * dt;
+            let (min, max) = self.config.mode.bounds();
+            match self.config.mode {
+                HscCountMode::Linear { .. } => {
+                    if self.count >= max as f64 + 1.0 {
+                        self.count = max as f64;
+                        self.overflow = true;
+                    } else if self.count < min as f64 {
+                        self.count = min as f64;
+                        self.underflow = true;
+                    }
+                }
+                HscCountMode::Ring { .. } => {
+                    let span = max as f64 - min as f64 + 1.0;
+                    self.count = min as f64 + (self.count - min as f64).rem_euclid(span);
+                }
+            }
+        }
+
+        let value = self.value();
+        let (low, high) = (previous.min(value), previous.max(value));
+        for (index, compare) in self.config.compares.iter().enumerate() {
+            let in_range =
+                value >= compare.lower && compare.upper.map_or(true, |upper| value <= upper);
+            let passed = compare.upper == Some(compare.lower)
+                && self.counting
+                && (low..=high).contains(&compare.lower);
+            self.compare_outputs[index] = in_range || passed;
+            write_bool_if_changed(runtime, compare.output, self.compare_outputs[index])?;
+        }
+
+        if self.last_written != Some(value) || current != value {
+            write_i32(runtime, self.config.count, value, order)?;
+        }
+        self.last_written = Some(value);
+
+        if let Some(status) = self.config.status {
+            let mut word =
+                self.counting as u16 | (self.overflow as u16) << 1 | (self.underflow as u16) << 2;
+            for (index, output) in self.compare_outputs.iter().take(8).enumerate() {
+                word |= (*output as u16) << (8 + index);
+            }
+            write_word_if_changed(runtime, status, word)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Positioning Axis
+// ============================================================================
+
+/// Positioning axis definition; the command side is written by the ladder
+/// program, the status side by the simulated module
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AxisConfig {
+    pub id: String,
+    #[serde(default)]
+    pub word_order: WordOrder,
+    /// Rising edge starts a move to `target_position`
+    pub start: CanonicalAddress,
+    /// Rising edge decelerates to a stop
+    #[serde(default)]
+    pub stop: Option<CanonicalAddress>,
+    /// Absolute target in pulses (INT32)
+    pub target_position: CanonicalAddress,
+    /// Command speed in pulses/s (INT32)
+    pub target_speed: CanonicalAddress,
+    /// Acceleration and deceleration rate in pulses/s²
+    pub acceleration: f64,
+    /// Current position in pulses (INT32)
+    pub current_position: CanonicalAddress,
+    /// Current speed in pulses/s (INT32)
+    #[serde(default)]
+    pub current_speed: Option<CanonicalAddress>,
+    pub busy: CanonicalAddress,
+    pub done: CanonicalAddress,
+    #[serde(default)]
+    pub error: Option<CanonicalAddress>,
+    #[serde(default)]
+    pub forward_limit: Option<CanonicalAddress>,
+    #[serde(default)]
+    pub reverse_limit: Option<CanonicalAddress>,
+    /// Status word: bit0 busy, bit1 done, bit2 error, bit3 forward limit, bit4 reverse limit
+    #[serde(default)]
+    pub status: Option<CanonicalAddress>,
+}
+
+impl AxisConfig {
+    fn validate(&self) -> MotionResult<()> {
+        require_id(&self.id)?;
+        if !(self.acceleration > 0.0 && self.acceleration.is_finite()) {
+            return Err(MotionError::InvalidParameter(format!(
+                "axis '{}': acceleration must be positive",
+                self.id
+            )));
+        }
+        require_bit("start", self.start)?;
+        require_word("targetPosition", self.target_position)?;
+        require_word("targetSpeed", self.target_speed)?;
+        require_word("currentPosition", self.current_position)?;
+        require_bit("busy", self.busy)?;
+        require_bit("done", self.done)?;
+        for (field, address) in [
+            ("stop", self.stop),
+            ("error", self.error),
+            ("forwardLimit", self.forward_limit),
+            ("reverseLimit", self.reverse_limit),
+        ] {
+            if let Some(address) = address {
+                require_bit(field, address)?;
+            }
+        }
+        for (field, address) in [
+            ("currentSpeed", self.current_speed),
+            ("status", self.status),
+        ] {
+            if let Some(address) = address {
+                require_word(field, address)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Axis state reported to the UI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AxisInfo {
+    pub id: String,
+    pub position: i32,
+    pub speed: f64,
+    pub target: i32,
+    pub busy: bool,
+    pub done: bool,
+    pub error: bool,
+    pub forward_limit: bool,
+    pub reverse_limit: bool,
+}
+
+struct Axis {
+    config: AxisConfig,
+    position: f64,
+    speed: f64,
+    target: f64,
+    max_speed: f64,
+    /// +1 / -1 while moving
+    direction: f64,
+    busy: bool,
+    done: bool,
+    error: bool,
+    stopping: bool,
+    start_input: bool,
+    stop_input: bool,
+    forward_limit: bool,
+    reverse_limit: bool,
+    last_written: Option<i32>,
+    last_ms: Option<u64>,
+}
+
+impl Axis {
+    fn new(config: AxisConfig) -> Self {
+        Self {
+            config,
+            position: 0.0,
+            speed: 0.0,
+            target: 0.0,
+            max_speed: 0.0,
+            direction: 0.0,
+            busy: false,
+            done: false,
+            error: false,
+            stopping: false,
+            start_input: false,
+            stop_input: false,
+            forward_limit: false,
+            reverse_limit: false,
+            last_written: None,
+            last_ms: None,
+        }
+    }
+
+    fn info(&self) -> AxisInfo {
+        AxisInfo {
+            id: self.config.id.clone(),
+            position: self.position.round() as i32,
+            speed: self.speed,
+            target: self.target.round() as i32,
+            busy: self.busy,
+            done: self.done,
+            error: self.error,
+            forward_limit: self.forward_limit,
+            reverse_limit: self.reverse_limit,
+        }
+    }
+
+    fn halt(&mut self, error: bool) {
+        self.speed = 0.0;
+        self.busy = false;
+        self.stopping = false;
+        self.error = error;
+    }
+
+    fn start(&mut self, runtime: &CanonicalRuntimeFacade) -> MotionResult<()> {
+        let order = self.config.word_order;
+        if self.busy {
+            self.error = true;
+            return Ok(());
+        }
+        self.target = read_i32(runtime, self.config.target_position, order)? as f64;
+        self.max_speed = (read_i32(runtime, self.config.target_speed, order)? as f64).abs();
+        self.done = false;
+        self.error = self.max_speed == 0.0;
+        if self.error {
+            return Ok(());
+        }
+        let distance = self.target - self.position;
+        if distance == 0.0 {
+            self.done = true;
+        } else {
+            self.direction = distance.signum();
+            self.busy = true;
+        }
+        Ok(())
+    }
+
+    /// 사다리꼴 프로파일로 한 스캔만큼 움직인다
+    fn step(&mut self, dt: f64) {
+        let blocked = (self.direction > 0.0 && self.forward_limit)
+            || (self.direction < 0.0 && self.reverse_limit);
+        if blocked {
+            self.halt(true);
+            return;
+        }
+
+        let accel = self.config.acceleration;
+        let remaining = (self.target - self.position) * self.direction;
+        if self.stopping {
+            self.speed = (self.speed - accel * dt).max(0.0);
+            if self.speed == 0.0 {
+                self.halt(false);
+                return;
+            }
+        } else if remaining <= self.speed * self.speed / (2.0 * accel) {
+            // 감속 구간. 이산 스텝으로 목표 직전에 멈추지 않게 최소 속도를 남긴다
+            let creep = (accel * dt).min(self.max_speed);
+            self.speed = (self.speed - accel * dt).max(creep);
+        } else {
+            self.speed = (self.speed + accel * dt).min(self.max_speed);
+        }
+
+        let travel = self.speed * dt;
+        if !self.stopping && travel >= remaining {
+            self.position = self.target;
+            self.speed = 0.0;
+            self.busy = false;
+            self.done = true;
+        } else {
+            self.position += self.direction * travel;
+        }
+    }
+
+    fn apply(&mut self, runtime: &CanonicalRuntimeFacade, now_ms: u64) -> MotionResult<()> {
+        let order = self.config.word_order;
+        let dt = now_ms.saturating_sub(self.last_ms.unwrap_or(now_ms)) as f64 / 1000.0;
+        self.last_ms = Some(now_ms);
+
+        let current = read_i32(runtime, self.config.current_position, order)?;
+        if !self.busy && self.last_written.is_some_and(|written| written != current) {
+            self.position = current as f64;
+        }
+        self.forward_limit = read_optional_bool(runtime, self.config.forward_limit)?;
+        self.reverse_limit = read_optional_bool(runtime, self.config.reverse_limit)?;
+
+        let start = runtime.read_bool(self.config.start)?;
+        if start && !self.start_input {
+            self.start(runtime)?;
+        }
+        self.start_input = start;
+        let stop = read_optional_bool(runtime, self.config.stop)?;
+        if stop && !self.stop_input && self.busy {
+            self.stopping = true;
+        }
+        self.stop_input = stop;
+
+        if self.busy {
+            self.step(dt);
+        }
+
+        let position = self.position.round() as i32;
+        if position != current {
+            write_i32(runtime, self.config.current_position, position, order)?;
+        }
+        self.last_written = Some(position);
+        if let Some(address) = self.config.current_speed {
+            let speed = (self.direction * self.speed).round() as i32;
+            if read_i32(runtime, address, order)? != speed {
+                write_i32(runtime, address, speed, order)?;
+            }
+        }
+        write_bool_if_changed(runtime, self.config.busy, self.busy)?;
+        write_bool_if_changed(runtime, self.config.done, self.done)?;
+        if let Some(address) = self.config.error {
+            write_bool_if_changed(runtime, address, self.error)?;
+        }
+        if let Some(address) = self.config.status {
+            let word = self.busy as u16
+                | (self.done as u16) << 1
+                | (self.error as u16) << 2
+                | (self.forward_limit as u16) << 3
+                | (self.reverse_limit as u16) << 4;
+            write_word_if_changed(runtime, address, word)?;
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Simulator
+// ============================================================================
+
+/// Simulates high-speed counter channels and positioning axes
+#[derive(Default)]
+pub struct MotionSimulator {
+    counters: Mutex<BTreeMap<String, HscChannel>>,
+    axes: Mutex<BTreeMap<String, Axis>>,
+}
+
+impl MotionSimulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a counter channel, replacing any channel with the same id
+    pub fn configure_counter(&self, config: HscChannelConfig) -> MotionResult<()> {
+        config.validate()?;
+        self.counters
+            .lock()
+            .insert(config.id.clone(), HscChannel::new(config));
+        Ok(())
+    }
+
+    pub fn remove_counter(&self, id: &str) -> MotionResult<()> {
+        self.counters
+            .lock()
+            .remove(id)
+            .map(|_| ())
+            .ok_or_else(|| MotionError::NotFound(id.to_string()))
+    }
+
+    /// Set the encoder frequency (Hz, negative counts down)
+    pub fn set_frequency(&self, id: &str, frequency_hz: f64) -> MotionResult<()> {
+        if !frequency_hz.is_finite() {
+            return Err(MotionError::InvalidParameter(format!(
+                "frequency must be finite: {frequency_hz}"
+            )));
+        }
+        let mut counters = self.counters.lock();
+        let channel = counters
+            .get_mut(id)
+            .ok_or_else(|| MotionError::NotFound(id.to_string()))?;
+        channel.frequency_hz = frequency_hz;
+        Ok(())
+    }
+
+    pub fn list_counters(&self) -> Vec<HscChannelInfo> {
+        self.counters
+            .lock()
+            .values()
+            .map(HscChannel::info)
+            .collect()
+    }
+
+    /// Add a positioning axis, replacing any axis with the same id
+    pub fn configure_axis(&self, config: AxisConfig) -> MotionResult<()> {
+        config.validate()?;
+        self.axes
+            .lock()
+            .insert(config.id.clone(), Axis::new(config));
+        Ok(())
+    }
+
+    pub fn remove_axis(&self, id: &str) -> MotionResult<()> {
+        self.axes
+            .lock()
+            .remove(id)
+            .map(|_| ())
+            .ok_or_else(|| MotionError::NotFound(id.to_string()))
+    }
+
+    pub fn list_axes(&self) -> Vec<AxisInfo> {
+        self.axes.lock().values().map(Axis::info).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counters.lock().is_empty() && self.axes.lock().is_empty()
+    }
+
+    /// Words and bits the counters and axes write themselves (counts,
+    /// positions, flags, status). Event replay skips recorded writes to them.
+    pub fn output_addresses(&self) -> Vec<CanonicalAddress> {
+        let mut addresses = Vec::new();
+        for channel in self.counters.lock().values() {
+            let config = &channel.config;
+            addresses.extend(double_word(config.count));
+            addresses.extend(config.compares.iter().map(|compare| compare.output));
+            addresses.extend(config.status);
+        }
+        for axis in self.axes.lock().values() {
+            let config = &axis.config;
+            addresses.extend(double_word(config.current_position));
+            addresses.extend(config.current_speed.into_iter().flat_map(double_word));
+            addresses.extend([config.busy, config.done]);
+            addresses.extend(config.error);
+            addresses.extend(config.status);
+        }
+        addresses
+    }
+
+    /// Advance counters and axes. The host calls this after each completed
+    /// scan, so the program reads the new counts on the next scan.
+    pub fn apply(&self, runtime: &CanonicalRuntimeFacade) -> MotionResult<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        self.apply_at(runtime, modone_contract::clock::now_millis())
+    }
+
+    pub fn apply_at(&self, runtime: &CanonicalRuntimeFacade, now_ms: u64) -> MotionResult<()> {
+        for channel in self.counters.lock().values_mut() {
+            channel.apply(runtime, now_ms)?;
+        }
+        for axis in self.axes.lock().values_mut() {
+            axis.apply(runtime, now_ms)?;
+        }
+        Ok(())
+    }
+}
+
+/// 더블워드가 차지하는 두 워드
+fn double_word(address: CanonicalAddress) -> [CanonicalAddress; 2] {
+    [
+        address,
+        CanonicalAddress::new(address.area, address.index + 1),
+    ]
+}
+
+fn read_i32(
+    runtime: &CanonicalRuntimeFacade,
+    address: CanonicalAddress,
+    order: WordOrder,
+) -> MotionResult<i32> {
+    match runtime.read_typed(address, TypedDataType::Int32, order)? {
+        TypedValue::Int32(value) => Ok(value),
+        _ => Ok(0),
+    }
+}
+
+fn write_i32(
+    runtime: &CanonicalRuntimeFacade,
+    address: CanonicalAddress,
+    value: i32,
+    order: WordOrder,
+) -> MotionResult<()> {
+    runtime.write_typed(
+        address,
+        TypedDataType::Int32,
+        &TypedValue::Int32(value),
+        order,
+        CanonicalWriteSource::Simulation,
+    )?;
+    Ok(())
+}
+
+fn read_optional_bool(
+    runtime: &CanonicalRuntimeFacade,
+    address: Option<CanonicalAddress>,
+) -> MotionResult<bool> {
+    match address {
+        Some(address) => Ok(runtime.read_bool(address)?),
+        None => Ok(false),
+    }
+}
+
+fn write_bool_if_changed(
+    runtime: &CanonicalRuntimeFacade,
+    address: CanonicalAddress,
+    value: bool,
+) -> MotionResult<()> {
+    if runtime.read_bool(address)? != value {
+        runtime.write_bool(address, value, CanonicalWriteSource::Simulation)?;
+    }
+    Ok(())
+}
+
+fn write_word_if_changed(
+    runtime: &CanonicalRuntimeFacade,
+    address: CanonicalAddress,
+    word: u16,
+) -> MotionResult<()> {
+    if runtime.read(address)? != CanonicalValue::U16(word) {
+        runtime.write(
+            address,
+            CanonicalValue::U16(word),
+            CanonicalWriteSource::Simulation,
+        )?;
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use modone_contract::CanonicalAreaKind;
+
+    fn word(index: u32) -> CanonicalAddress {
+        CanonicalAddress::new(CanonicalAreaKind::DataWord, index)
+    }
+
+    fn bit(index: u32) -> CanonicalAddress {
+        CanonicalAddress::new(CanonicalAreaKind::InternalBit, index)
+    }
+
+    fn input(index: u32) -> CanonicalAddress {
+        CanonicalAddress::new(CanonicalAreaKind::InputBit, index)
+    }
+
+    fn counter(mode: HscCountMode) -> HscChannelConfig {
+        HscChannelConfig {
+            id: "ch0".to_string(),
+            count: word(0),
+            word_order: WordOrder::LowWordFirst,
+            mode,
+            enable: None,
+            frequency_source: None,
+            preset: Some(HscPreset {
+                value: 5000,
+                trigger: bit(0),
+            }),
+            compares: vec![
+                HscCompare {
+                    lower: 150,
+                    upper: None,
+                    output: bit(10),
+                },
+                HscCompare {
+                    lower: 120,
+                    upper: Some(120),
+                    output: bit(11),
+                },
+            ],
+            status: Some(word(2)),
+        }
+    }
+
+    fn count(runtime: &CanonicalRuntimeFacade) -> i32 {
+        read_i32(runtime, word(0), WordOrder::LowWordFirst).unwrap()
+    }
+
+    #[test]
+    fn test_counter_integrates_frequency_with_compare_and_preset() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let sim = MotionSimulator::new();
+        sim.configure_counter(counter(HscCountMode::default()))
+            .unwrap();
+        sim.set_frequency("ch0", 1000.0).unwrap();
+
+        sim.apply_at(&runtime, 0).unwrap();
+        sim.apply_at(&runtime, 100).unwrap();
+        assert_eq!(count(&runtime), 100);
+        assert!(!runtime.read_bool(bit(10)).unwrap());
+
+        // 100 → 200 사이에 120 을 지나쳤으므로 일치 출력이 켜진다
+        sim.apply_at(&runtime, 200).unwrap();
+        assert_eq!(count(&runtime), 200);
+        assert!(runtime.read_bool(bit(10)).unwrap());
+        assert!(runtime.read_bool(bit(11)).unwrap());
+        assert_eq!(runtime.read_word_value(word(2)).unwrap(), 0b11 << 8 | 0b1);
+
+        sim.apply_at(&runtime, 300).unwrap();
+        assert!(!runtime.read_bool(bit(11)).unwrap());
+
+        runtime
+            .write_bool(bit(0), true, CanonicalWriteSource::Simulation)
+            .unwrap();
+        sim.apply_at(&runtime, 300).unwrap();
+        assert_eq!(count(&runtime), 5000);
+
+        // 래더가 현재값을 0 으로 지우면 거기서부터 다시 센다
+        write_i32(&runtime, word(0), 0, WordOrder::LowWordFirst).unwrap();
+        sim.apply_at(&runtime, 350).unwrap();
+        assert_eq!(count(&runtime), 50);
+    }
+
+    #[test]
+    fn test_counter_ring_and_linear_bounds() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let sim = MotionSimulator::new();
+        let mut ring = counter(HscCountMode::Ring { min: 0, max: 999 });
+        ring.frequency_source = Some(word(10));
+        sim.configure_counter(ring).unwrap();
+        write_i32(&runtime, word(10), 3000, WordOrder::LowWordFirst).unwrap();
+
+        sim.apply_at(&runtime, 0).unwrap();
+        sim.apply_at(&runtime, 500).unwrap();
+        assert_eq!(count(&runtime), 500);
+
+        let mut linear = counter(HscCountMode::Linear {
+            min: -100,
+            max: 100,
+        });
+        linear.id = "ch1".to_string();
+        linear.count = word(20);
+        linear.status = Some(word(22));
+        linear.compares.clear();
+        sim.configure_counter(linear).unwrap();
+        sim.set_frequency("ch1", -1000.0).unwrap();
+        sim.apply_at(&runtime, 500).unwrap();
+        sim.apply_at(&runtime, 1000).unwrap();
+        assert_eq!(
+            read_i32(&runtime, word(20), WordOrder::LowWordFirst).unwrap(),
+            -100
+        );
+        let info = sim
+            .list_counters()
+            .into_iter()
+            .find(|info| info.id == "ch1")
+            .unwrap();
+        assert!(info.underflow);
+        assert_eq!(runtime.read_word_value(word(22)).unwrap(), 0b101);
+    }
+
+    fn axis() -> AxisConfig {
+        AxisConfig {
+            id: "x".to_string(),
+            word_order: WordOrder::LowWordFirst,
+            start: bit(0),
+            stop: Some(bit(1)),
+            target_position: word(0),
+            target_speed: word(2),
+            acceleration: 1000.0,
+            current_position: word(4),
+            current_speed: Some(word(6)),
+            busy: bit(10),
+            done: bit(11),
+            error: Some(bit(12)),
+            forward_limit: Some(input(0)),
+            reverse_limit: Some(input(1)),
+            status: Some(word(8)),
+        }
+    }
+
+    fn command(runtime: &CanonicalRuntimeFacade, target: i32, speed: i32) {
+        write_i32(runtime, word(0), target, WordOrder::LowWordFirst).unwrap();
+        write_i32(runtime, word(2), speed, WordOrder::LowWordFirst).unwrap();
+        runtime
+            .write_bool(bit(0), true, CanonicalWriteSource::Simulation)
+            .unwrap();
+    }
+
+    fn position(runtime: &CanonicalRuntimeFacade) -> i32 {
+        read_i32(runtime, word(4), WordOrder::LowWordFirst).unwrap()
+    }
+
+    #[test]
+    fn test_output_addresses_exclude_command_words() {
+        let sim = MotionSimulator::new();
+        sim.configure_axis(axis()).unwrap();
+
+        let outputs = sim.output_addresses();
+        for address in [
+            word(4),
+            word(5),
+            word(6),
+            word(7),
+            word(8),
+            bit(10),
+            bit(11),
+            bit(12),
+        ] {
+            assert!(outputs.contains(&address), "{address:?}");
+        }
+        // 래더가 쓰는 명령 워드·기동 비트는 재생 대상으로 남는다
+        for address in [word(0), word(2), bit(0), bit(1)] {
+            assert!(!outputs.contains(&address), "{address:?}");
+        }
+    }
+
+    #[test]
+    fn test_axis_runs_trapezoidal_move_to_target() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let sim = MotionSimulator::new();
+        sim.configure_axis(axis()).unwrap();
+        sim.apply_at(&runtime, 0).unwrap();
+
+        command(&runtime, 1000, 500);
+        let mut now = 0;
+        while now < 1000 {
+            now += 10;
+            sim.apply_at(&runtime, now).unwrap();
+        }
+        // 가속 0.5 s(125 펄스) 후 정속 구간
+        assert!(runtime.read_bool(bit(10)).unwrap());
+        assert!((370..=385).contains(&position(&runtime)));
+        assert_eq!(
+            read_i32(&runtime, word(6), WordOrder::LowWordFirst).unwrap(),
+            500
+        );
+
+        while now < 2700 {
+            now += 10;
+            sim.apply_at(&runtime, now).unwrap();
+        }
+        assert_eq!(position(&runtime), 1000);
+        assert!(!runtime.read_bool(bit(10)).unwrap());
+        assert!(runtime.read_bool(bit(11)).unwrap());
+        assert_eq!(runtime.read_word_value(word(8)).unwrap(), 0b10);
+    }
+
+    #[test]
+    fn test_axis_stops_on_limit_switch_and_stop_command() {
+        let runtime = CanonicalRuntimeFacade::new();
+        let sim = MotionSimulator::new();
+        sim.configure_axis(axis()).unwrap();
+        sim.apply_at(&runtime, 0).unwrap();
+
+        command(&runtime, 10_000, 1000);
+        sim.apply_at(&runtime, 100).unwrap();
+        sim.apply_at(&runtime, 200).unwrap();
+        runtime
+            .write_bool(input(0), true, CanonicalWriteSource::Simulation)
+            .unwrap();
+        sim.apply_at(&runtime, 300).unwrap();
+        let info = &sim.list_axes()[0];
+        assert!(!info.busy && info.error && info.forward_limit);
+        assert!(runtime.read_bool(bit(12)).unwrap());
+        assert_eq!(runtime.read_word_value(word(8)).unwrap(), 0b1100);
+
+        // 역방향 이동은 가능하고, 정지 명령으로 감속 정지한다
+        runtime
+            .write_bool(bit(0), false, CanonicalWriteSource::Simulation)
+            .unwrap();
+        sim.apply_at(&runtime, 310).unwrap();
+        command(&runtime, -10_000, 1000);
+        sim.apply_at(&runtime, 400).unwrap();
+        sim.apply_at(&runtime, 500).unwrap();
+        assert!(sim.list_axes()[0].busy);
+        runtime
+            .write_bool(bit(1), true, CanonicalWriteSource::Simulation)
+            .unwrap();
+        for now in (600..=900).step_by(100) {
+            sim.apply_at(&runtime, now).unwrap();
+        }
+        let info = &sim.list_axes()[0];
+        assert!(!info.busy && !info.done && !info.error);
+
+        assert!(matches!(
+            sim.configure_axis(AxisConfig {
+                busy: word(0),
+                ..axis()
+            }),
+            Err(MotionError::BitAddressRequired { .. })
+        ));
+    }
+}
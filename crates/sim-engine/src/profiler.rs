@@ -0,0 +1,396 @@
+//! 스캔 프로파일러
+//!
+//! 스캔마다 `ProgramExecutionResult`를 받아 네트워크별·명령어 타입별 소요시간
+//! 통계(횟수/평균/p95/최대)를 집계한다. `ScanCycleInfo`의 전체 스캔 시간만으로는
+//! 어느 네트워크가 watchdog을 유발했는지 알 수 없어서, 원인 지목(attribution)에
+//! 필요한 분해 정보를 제공한다.
+//!
+//! p95는 최근 `window`개 샘플에 대해서만 계산한다(메모리 상한). 횟수/평균/최대는
+//! 리셋 전까지 누적값이다.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+
+use super::executor::{CompiledProgram, NodeType, ProgramExecutionResult};
+
+/// p95 계산에 보관하는 기본 샘플 수
+pub const DEFAULT_PROFILE_WINDOW: usize = 512;
+
+/// watchdog 이벤트에 실어 보내는 기본 네트워크 수
+pub const DEFAULT_SLOWEST_LIMIT: usize = 5;
+
+// ============================================================================
+// Profile Types
+// ============================================================================
+
+/// 네트워크별 실행시간 통계 (마이크로초)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkProfile {
+    /// Network ID
+    pub network_id: u32,
+    /// Number of executions
+    pub count: u64,
+    /// Mean execution time
+    pub mean_us: f64,
+    /// 95th percentile over the recent window
+    pub p95_us: u64,
+    /// Maximum execution time
+    pub max_us: u64,
+    /// Accumulated execution time
+    pub total_us: u64,
+}
+
+/// 명령어 타입별 실행시간 통계 (나노초, 실행 1회 기준)
+///
+/// p95/최대는 네트워크 단위 샘플(해당 네트워크에서 실행 1회당 평균)로 계산한다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InstructionProfile {
+    /// Instruction type
+    pub node_type: NodeType,
+    /// Number of executions
+    pub count: u64,
+    /// Mean time per execution
+    pub mean_ns: f64,
+    /// 95th percentile over the recent window
+    pub p95_ns: u64,
+    /// Maximum time per execution
+    pub max_ns: u64,
+    /// Accumulated time
+    pub total_ns: u64,
+}
+
+/// 프로파일 스냅샷. 네트워크/명령어 모두 누적 시간이 큰 순서로 정렬된다.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScanProfile {
+    /// Number of scans aggregated
+    pub scans: u64,
+    /// Per-network statistics
+    pub networks: Vec<NetworkProfile>,
+    /// Per-instruction-type statistics (empty unless instruction profiling is on)
+    pub instructions: Vec<InstructionProfile>,
+}
+
+/// 한 스캔에서 오래 걸린 네트워크
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlowNetwork {
+    /// Network ID
+    pub network_id: u32,
+    /// Network comment, if any
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    /// Execution time in microseconds
+    pub execution_time_us: u64,
+    /// Share of the program execution time (0.0 ~ 100.0)
+    pub share_percent: f64,
+}
+
+/// 한 스캔 결과에서 가장 오래 걸린 네트워크를 최대 `limit`개 고른다.
+pub fn slowest_networks(
+    program: &CompiledProgram,
+    result: &ProgramExecutionResult,
+    limit: usize,
+) -> Vec<SlowNetwork> {
+    let total: u64 = result
+        .network_results
+        .iter()
+        .map(|r| r.execution_time_us)
+        .sum();
+
+    let mut ranked: Vec<_> = result.network_results.iter().collect();
+    ranked.sort_by_key(|r| Reverse(r.execution_time_us));
+
+    ranked
+        .into_iter()
+        .take(limit)
+        .map(|r| SlowNetwork {
+            network_id: r.network_id,
+            comment: program
+                .networks
+                .iter()
+                .find(|n| n.id == r.network_id)
+                .and_then(|n| n.comment.clone()),
+            execution_time_us: r.execution_time_us,
+            share_percent: if total == 0 {
+                0.0
+            } else {
+                r.execution_time_us as f64 * 100.0 / total as f64
+            },
+        })
+        .collect()
+}
+
+// ============================================================================
+// Scan Profiler
+// ============================================================================
+
+#[derive(Debug, Default)]
+struct TimingAccumulator {
+    count: u64,
+    total: u64,
+    max: u64,
+    recent: VecDeque<u64>,
+}
+
+impl TimingAccumulator {
+    /// `count`회 실행에 `elapsed`가 걸린 샘플 하나를 더한다.
+    fn add(&mut self, elapsed: u64, count: u64, window: usize) {
+        if count == 0 {
+            return;
+        }
+        let per_execution = elapsed / count;
+        self.count += count;
+        self.total += elapsed;
+        self.max = self.max.max(per_execution);
+        if self.recent.len() == window {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(per_execution);
+    }
+
+    fn mean(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.total as f64 / self.count as f64
+        }
+    }
+
+    fn p95(&self) -> u64 {
+        if self.recent.is_empty() {
+            return 0;
+        }
+        let mut sorted: Vec<u64> = self.recent.iter().copied().collect();
+        sorted.sort_unstable();
+        let rank = (sorted.len() * 95).div_ceil(100);
+        sorted[rank.saturating_sub(1)]
+    }
+}
+
+#[derive(Debug, Default)]
+struct ProfilerState {
+    scans: u64,
+    networks: BTreeMap<u32, TimingAccumulator>,
+    instructions: HashMap<NodeType, TimingAccumulator>,
+}
+
+/// 네트워크/명령어 타입별 스캔 시간 집계기
+pub struct ScanProfiler {
+    state: RwLock<ProfilerState>,
+    window: usize,
+}
+
+impl ScanProfiler {
+    /// Create a profiler keeping `window` recent samples per key for p95
+    pub fn new(window: usize) -> Self {
+        Self {
+            state: RwLock::new(ProfilerState::default()),
+            window: window.max(1),
+        }
+    }
+
+    /// 한 스캔의 실행 결과를 집계에 반영한다.
+    pub fn record(&self, result: &ProgramExecutionResult) {
+        let mut state = self.state.write();
+        state.scans += 1;
+
+        for network in &result.network_results {
+            state.networks.entry(network.network_id).or_default().add(
+                network.execution_time_us,
+                1,
+                self.window,
+            );
+
+            for timing in &network.instruction_timings {
+                state.instructions.entry(timing.node_type).or_default().add(
+                    timing.elapsed_ns,
+                    timing.count as u64,
+                    self.window,
+                );
+            }
+        }
+    }
+
+    /// 현재까지의 통계를 스냅샷으로 반환한다.
+    pub fn snapshot(&self) -> ScanProfile {
+        let state = self.state.read();
+
+        let mut networks: Vec<_> = state
+            .networks
+            .iter()
+            .map(|(id, acc)| NetworkProfile {
+                network_id: *id,
+                count: acc.count,
+                mean_us: acc.mean(),
+                p95_us: acc.p95(),
+                max_us: acc.max,
+                total_us: acc.total,
+            })
+            .collect();
+        networks.sort_by_key(|n| Reverse(n.total_us));
+
+        let mut instructions: Vec<_> = state
+            .instructions
+            .iter()
+            .map(|(node_type, acc)| InstructionProfile {
+                node_type: *node_type,
+                count: acc.count,
+                mean_ns: acc.mean(),
+                p95_ns: acc.p95(),
+                max_ns: acc.max,
+                total_ns: acc.total,
+            })
+            .collect();
+        instructions.sort_by_key(|i| Reverse(i.total_ns));
+
+        ScanProfile {
+            scans: state.scans,
+            networks,
+            instructions,
+        }
+    }
+
+    /// Clear all statistics
+    pub fn reset(&self) {
+        *self.state.write() = ProfilerState::default();
+    }
+}
+
+impl Default for ScanProfiler {
+    fn default() -> Self {
+        Self::new(DEFAULT_PROFILE_WINDOW)
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::{CompiledNetwork, InstructionTiming, NetworkExecutionResult};
+
+    fn network_result(network_id: u32, execution_time_us: u64) -> NetworkExecutionResult {
+        NetworkExecutionResult {
+            network_id,
+            execution_time_us,
+            success: true,
+            error: None,
+            instruction_timings: Vec::new(),
+        }
+    }
+
+    fn program_result(times: &[(u32, u64)]) -> ProgramExecutionResult {
+        ProgramExecutionResult {
+            network_results: times
+                .iter()
+                .map(|(id, us)| network_result(*id, *us))
+                .collect(),
+            total_time_us: times.iter().map(|(_, us)| us).sum(),
+            success: true,
+            error: None,
+            faults: Vec::new(),
+            halted: false,
+        }
+    }
+
+    #[test]
+    fn test_network_statistics() {
+        let profiler = ScanProfiler::default();
+        for us in 1..=100 {
+            profiler.record(&program_result(&[(0, us), (1, 1)]));
+        }
+
+        let profile = profiler.snapshot();
+        assert_eq!(profile.scans, 100);
+        assert_eq!(profile.networks[0].network_id, 0);
+
+        let hot = &profile.networks[0];
+        assert_eq!(hot.count, 100);
+        assert_eq!(hot.max_us, 100);
+        assert_eq!(hot.p95_us, 95);
+        assert!((hot.mean_us - 50.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_p95_uses_recent_window() {
+        let profiler = ScanProfiler::new(10);
+        for _ in 0..100 {
+            profiler.record(&program_result(&[(0, 1000)]));
+        }
+        for _ in 0..10 {
+            profiler.record(&program_result(&[(0, 10)]));
+        }
+
+        let network = &profiler.snapshot().networks[0];
+        assert_eq!(network.p95_us, 10);
+        assert_eq!(network.max_us, 1000);
+    }
+
+    #[test]
+    fn test_instruction_statistics() {
+        let profiler = ScanProfiler::default();
+        let mut result = program_result(&[(0, 5)]);
+        result.network_results[0].instruction_timings = vec![
+            InstructionTiming {
+                node_type: NodeType::ContactNo,
+                count: 4,
+                elapsed_ns: 400,
+            },
+            InstructionTiming {
+                node_type: NodeType::MathDiv,
+                count: 1,
+                elapsed_ns: 900,
+            },
+        ];
+        profiler.record(&result);
+
+        let profile = profiler.snapshot();
+        assert_eq!(profile.instructions[0].node_type, NodeType::MathDiv);
+        let contact = profile
+            .instructions
+            .iter()
+            .find(|i| i.node_type == NodeType::ContactNo)
+            .unwrap();
+        assert_eq!(contact.count, 4);
+        assert_eq!(contact.max_ns, 100);
+        assert!((contact.mean_ns - 100.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_reset() {
+        let profiler = ScanProfiler::default();
+        profiler.record(&program_result(&[(0, 5)]));
+        profiler.reset();
+        assert_eq!(profiler.snapshot(), ScanProfile::default());
+    }
+
+    #[test]
+    fn test_slowest_networks() {
+        let program = CompiledProgram {
+            name: "Test".to_string(),
+            networks: (0..3)
+                .map(|id| CompiledNetwork {
+                    id,
+                    nodes: Vec::new(),
+                    comment: (id == 2).then(|| "conveyor sort".to_string()),
+                })
+                .collect(),
+        };
+        let result = program_result(&[(0, 10), (1, 30), (2, 60)]);
+
+        let slowest = slowest_networks(&program, &result, 2);
+        assert_eq!(slowest.len(), 2);
+        assert_eq!(slowest[0].network_id, 2);
+        assert_eq!(slowest[0].comment.as_deref(), Some("conveyor sort"));
+        assert!((slowest[0].share_percent - 60.0).abs() < 1e-9);
+        assert_eq!(slowest[1].network_id, 1);
+    }
+}
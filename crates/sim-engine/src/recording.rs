@@ -0,0 +1,498 @@
+//! canonical 메모리 이벤트 스트림 기록·재생
+//!
+//! 고객사 HMI 세션에서 관찰된 버그를 개발자 PC에서 그대로 재현하기 위해
+//! `CanonicalMemoryBus`의 모든 이벤트를 수신 시각·쓰기 출처와 함께 기록한다.
+//! 파일은 JSON Lines 형식이다 — 첫 줄은 헤더, 이후 한 줄에 이벤트 하나(키는 한 글자).
+//!
+//! 재생은 외부 프로토콜·시뮬레이션 쓰기만 원래 출처로 새 런타임에 다시 주입한다.
+//! 래더 출력과 아날로그·모션 모듈 갱신도 `Simulation` 출처로 기록되지만 재생하는
+//! 런타임의 프로그램과 모듈이 다시 만들어내므로, 호스트가 그 주소들을
+//! [`EventReplayer::skipping_outputs`]로 넘겨 건너뛴다. 런타임 내부 쓰기
+//! (`InternalRuntime`, 타이머·카운터 등)는 재생하지 않는다.
+//! 버스 구독과 타이밍 루프는 native 셸이 담당하고, 여기는 순수 로직만 둔다.
+
+use std::collections::BTreeSet;
+use std::io::{BufRead, Write};
+
+use modone_contract::{
+    CanonicalAddress, CanonicalMemoryEvent, CanonicalValue, CanonicalWriteSource,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::memory::CanonicalRuntimeFacade;
+
+/// Current recording file format version
+pub const RECORDING_FORMAT_VERSION: u32 = 1;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Recording error types
+#[derive(Debug, Error)]
+pub enum RecordingError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid recording line {line}: {source}")]
+    InvalidLine {
+        line: usize,
+        source: serde_json::Error,
+    },
+    #[error("Recording header is missing")]
+    MissingHeader,
+    #[error("Unsupported recording format version: {0}")]
+    UnsupportedVersion(u32),
+    #[error("Replay speed must be a positive number (got {0})")]
+    InvalidSpeed(f64),
+}
+
+pub type RecordingResult<T> = Result<T, RecordingError>;
+
+// ============================================================================
+// Recording Types
+// ============================================================================
+
+/// First line of a recording file
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingHeader {
+    pub version: u32,
+    /// Recording start (ISO 8601)
+    pub started_at: String,
+    /// Length of the recording in milliseconds
+    pub duration_ms: u64,
+    pub event_count: usize,
+    /// Events lost because the recorder lagged behind the bus
+    #[serde(default)]
+    pub dropped_events: u64,
+}
+
+/// One written cell: `[address, value]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedWrite(pub CanonicalAddress, pub CanonicalValue);
+
+/// One bus event. Batches with mixed sources are split per source.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// Milliseconds since the recording started
+    #[serde(rename = "t")]
+    pub offset_ms: u64,
+    #[serde(rename = "s")]
+    pub source: CanonicalWriteSource,
+    /// Whether the writes were applied as one batch
+    #[serde(rename = "b", default, skip_serializing_if = "std::ops::Not::not")]
+    pub batch: bool,
+    #[serde(rename = "w")]
+    pub writes: Vec<RecordedWrite>,
+}
+
+impl RecordedEvent {
+    /// Whether replay re-injects this event
+    pub fn is_replayable(&self) -> bool {
+        matches!(
+            self.source,
+            CanonicalWriteSource::ExternalProtocol | CanonicalWriteSource::Simulation
+        )
+    }
+}
+
+/// A complete recording
+#[derive(Debug, Clone, PartialEq)]
+pub struct EventRecording {
+    pub header: RecordingHeader,
+    pub events: Vec<RecordedEvent>,
+}
+
+impl EventRecording {
+    /// Write as JSON Lines
+    pub fn write_to(&self, mut writer: impl Write) -> RecordingResult<()> {
+        serde_json::to_writer(&mut writer, &self.header).map_err(std::io::Error::from)?;
+        writer.write_all(b"\n")?;
+        for event in &self.events {
+            serde_json::to_writer(&mut writer, event).map_err(std::io::Error::from)?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+
+    /// Read a JSON Lines recording
+    pub fn read_from(reader: impl BufRead) -> RecordingResult<Self> {
+        let mut lines = reader
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !matches!(line, Ok(text) if text.trim().is_empty()));
+
+        let (_, first) = lines.next().ok_or(RecordingError::MissingHeader)?;
+        let header: RecordingHeader = serde_json::from_str(&first?)
+            .map_err(|source| RecordingError::InvalidLine { line: 1, source })?;
+        if header.version > RECORDING_FORMAT_VERSION {
+            return Err(RecordingError::UnsupportedVersion(header.version));
+        }
+
+        let mut events = Vec::with_capacity(header.event_count);
+        for (index, line) in lines {
+            let event =
+                serde_json::from_str(&line?).map_err(|source| RecordingError::InvalidLine {
+                    line: index + 1,
+                    source,
+                })?;
+            events.push(event);
+        }
+
+        Ok(Self { header, events })
+    }
+}
+
+// ============================================================================
+// Recorder
+// ============================================================================
+
+/// Collects bus events into a recording
+#[derive(Debug)]
+pub struct EventRecorder {
+    started_at: String,
+    started_ms: u64,
+    events: Vec<RecordedEvent>,
+    dropped: u64,
+}
+
+impl Default for EventRecorder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventRecorder {
+    pub fn new() -> Self {
+        Self::starting_at(modone_contract::clock::now_millis())
+    }
+
+    /// Start with an explicit clock origin (milliseconds)
+    pub fn starting_at(started_ms: u64) -> Self {
+        Self {
+            started_at: modone_contract::clock::now_rfc3339(),
+            started_ms,
+            events: Vec::new(),
+            dropped: 0,
+        }
+    }
+
+    /// Record an event received now
+    pub fn record(&mut self, event: &CanonicalMemoryEvent) {
+        self.record_at(event, modone_contract::clock::now_millis());
+    }
+
+    /// Record an event received at `now_ms`
+    pub fn record_at(&mut self, event: &CanonicalMemoryEvent, now_ms: u64) {
+        let offset_ms = now_ms.saturating_sub(self.started_ms);
+        match event {
+            CanonicalMemoryEvent::Single(change) => self.events.push(RecordedEvent {
+                offset_ms,
+                source: change.source,
+                batch: false,
+                writes: vec![RecordedWrite(change.address, change.new_value)],
+            }),
+            CanonicalMemoryEvent::Batch(batch) => {
+                for (position, change) in batch.changes.iter().enumerate() {
+                    let write = RecordedWrite(change.address, change.new_value);
+                    match self.events.last_mut() {
+                        Some(last) if position > 0 && last.source == change.source => {
+                            last.writes.push(write)
+                        }
+                        _ => self.events.push(RecordedEvent {
+                            offset_ms,
+                            source: change.source,
+                            batch: true,
+                            writes: vec![write],
+                        }),
+                    }
+                }
+            }
+        }
+    }
+
+    /// Count events lost to bus lag
+    pub fn note_dropped(&mut self, count: u64) {
+        self.dropped += count;
+    }
+
+    pub fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Finish and return the recording
+    pub fn finish(self) -> EventRecording {
+        let duration_ms = self.events.last().map(|e| e.offset_ms).unwrap_or(0);
+        EventRecording {
+            header: RecordingHeader {
+                version: RECORDING_FORMAT_VERSION,
+                started_at: self.started_at,
+                duration_ms,
+                event_count: self.events.len(),
+                dropped_events: self.dropped,
+            },
+            events: self.events,
+        }
+    }
+}
+
+// ============================================================================
+// Replayer
+// ============================================================================
+
+/// Replay progress
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayProgress {
+    /// Replayable events in the recording
+    pub total: usize,
+    pub applied: usize,
+    /// Events the runtime refused (e.g. address outside a smaller memory layout)
+    pub rejected: usize,
+    pub finished: bool,
+}
+
+/// Re-injects the external/simulation writes of a recording
+#[derive(Debug)]
+pub struct EventReplayer {
+    events: Vec<RecordedEvent>,
+    cursor: usize,
+    speed: f64,
+    applied: usize,
+    rejected: usize,
+}
+
+impl EventReplayer {
+    /// `speed` scales time: 2.0 replays twice as fast
+    pub fn new(recording: EventRecording, speed: f64) -> RecordingResult<Self> {
+        if !(speed.is_finite() && speed > 0.0) {
+            return Err(RecordingError::InvalidSpeed(speed));
+        }
+        Ok(Self {
+            events: recording
+                .events
+                .into_iter()
+                .filter(RecordedEvent::is_replayable)
+                .collect(),
+            cursor: 0,
+            speed,
+            applied: 0,
+            rejected: 0,
+        })
+    }
+
+    /// Leave out simulation writes to `outputs`, the addresses the replaying
+    /// runtime's program and modules write themselves
+    pub fn skipping_outputs(mut self, outputs: impl IntoIterator<Item = CanonicalAddress>) -> Self {
+        let outputs: BTreeSet<_> = outputs.into_iter().collect();
+        if outputs.is_empty() {
+            return self;
+        }
+        for event in &mut self.events {
+            if event.source == CanonicalWriteSource::Simulation {
+                event
+                    .writes
+                    .retain(|RecordedWrite(address, _)| !outputs.contains(address));
+            }
+        }
+        self.events.retain(|event| !event.writes.is_empty());
+        self
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.cursor >= self.events.len()
+    }
+
+    /// Replay time (ms since replay start) at which the next event is due
+    pub fn next_due_ms(&self) -> Option<u64> {
+        self.events
+            .get(self.cursor)
+            .map(|event| (event.offset_ms as f64 / self.speed) as u64)
+    }
+
+    /// Apply every event due at `elapsed_ms` of replay time
+    pub fn apply_due(&mut self, runtime: &CanonicalRuntimeFacade, elapsed_ms: u64) -> usize {
+        let mut count = 0;
+        while self.next_due_ms().is_some_and(|due| due <= elapsed_ms) {
+            let event = &self.events[self.cursor];
+            let writes = event
+                .writes
+                .iter()
+                .map(|RecordedWrite(address, value)| (*address, *value))
+                .collect();
+            match runtime.write_batch(writes, event.source) {
+                Ok(()) => self.applied += 1,
+                Err(_) => self.rejected += 1,
+            }
+            self.cursor += 1;
+            count += 1;
+        }
+        count
+    }
+
+    pub fn progress(&self) -> ReplayProgress {
+        ReplayProgress {
+            total: self.events.len(),
+            applied: self.applied,
+            rejected: self.rejected,
+            finished: self.is_finished(),
+        }
+    }
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{SimBitDeviceType, SimWordDeviceType};
+    use modone_contract::CanonicalAreaKind;
+
+    fn record_session(runtime: &CanonicalRuntimeFacade) -> EventRecording {
+        let mut rx = runtime.handle().read().bus().subscribe();
+        let mut recorder = EventRecorder::starting_at(1_000);
+
+        runtime
+            .write(
+                CanonicalAddress::new(CanonicalAreaKind::DataWord, 10),
+                CanonicalValue::U16(7),
+                CanonicalWriteSource::ExternalProtocol,
+            )
+            .unwrap();
+        recorder.record_at(&rx.try_recv().unwrap(), 1_100);
+
+        runtime
+            .write_bit_internal(SimBitDeviceType::M, 1, true)
+            .unwrap();
+        recorder.record_at(&rx.try_recv().unwrap(), 1_150);
+
+        runtime
+            .write_batch(
+                vec![
+                    (
+                        CanonicalAddress::new(CanonicalAreaKind::DataWord, 20),
+                        CanonicalValue::U16(1),
+                    ),
+                    (
+                        CanonicalAddress::new(CanonicalAreaKind::DataWord, 21),
+                        CanonicalValue::U16(2),
+                    ),
+                ],
+                CanonicalWriteSource::Simulation,
+            )
+            .unwrap();
+        recorder.record_at(&rx.try_recv().unwrap(), 1_400);
+
+        recorder.finish()
+    }
+
+    #[test]
+    fn test_file_round_trip() {
+        let recording = record_session(&CanonicalRuntimeFacade::new());
+        assert_eq!(recording.header.event_count, 3);
+        assert_eq!(recording.header.duration_ms, 400);
+        assert_eq!(recording.events[2].writes.len(), 2);
+
+        let mut buffer = Vec::new();
+        recording.write_to(&mut buffer).unwrap();
+        let text = String::from_utf8(buffer.clone()).unwrap();
+        assert_eq!(text.lines().count(), 4);
+
+        let loaded = EventRecording::read_from(buffer.as_slice()).unwrap();
+        assert_eq!(loaded, recording);
+        assert!(matches!(
+            EventRecording::read_from(&b""[..]),
+            Err(RecordingError::MissingHeader)
+        ));
+    }
+
+    #[test]
+    fn test_replay_skips_internal_writes_and_scales_time() {
+        let recording = record_session(&CanonicalRuntimeFacade::new());
+        let fresh = CanonicalRuntimeFacade::new();
+        let mut replayer = EventReplayer::new(recording, 2.0).unwrap();
+        assert_eq!(replayer.progress().total, 2);
+
+        assert_eq!(replayer.apply_due(&fresh, 49), 0);
+        assert_eq!(replayer.apply_due(&fresh, 50), 1);
+        assert_eq!(fresh.read_word(SimWordDeviceType::D, 10).unwrap(), 7);
+        assert_eq!(replayer.next_due_ms(), Some(200));
+
+        replayer.apply_due(&fresh, 200);
+        assert_eq!(fresh.read_word(SimWordDeviceType::D, 21).unwrap(), 2);
+        assert!(!fresh.read_bit(SimBitDeviceType::M, 1).unwrap());
+        assert_eq!(
+            replayer.progress(),
+            ReplayProgress {
+                total: 2,
+                applied: 2,
+                rejected: 0,
+                finished: true,
+            }
+        );
+
+        assert!(EventReplayer::new(record_session(&CanonicalRuntimeFacade::new()), 0.0).is_err());
+    }
+
+    #[test]
+    fn test_replay_skips_program_outputs() {
+        use crate::counter::CounterManager;
+        use crate::executor::{
+            compile_program, LadderNetwork, LadderNode, LadderProgram, NodeType, ProgramExecutor,
+        };
+        use crate::timer::TimerManager;
+        use plc_model::{LsProfile, PlcHardwareTopology};
+        use std::sync::Arc;
+
+        let runtime = Arc::new(CanonicalRuntimeFacade::new());
+        let executor = ProgramExecutor::new(
+            Arc::clone(&runtime),
+            Arc::new(TimerManager::new()),
+            Arc::new(CounterManager::new()),
+        );
+        let program = LadderProgram {
+            name: "relay".to_string(),
+            networks: vec![LadderNetwork {
+                id: 0,
+                nodes: vec![LadderNode::series(vec![
+                    LadderNode::contact(NodeType::ContactNo, "M0001"),
+                    LadderNode::coil(NodeType::CoilOut, "M0002"),
+                ])],
+                comment: None,
+            }],
+        };
+        let profile = LsProfile::new("XGK".to_string(), PlcHardwareTopology::default());
+        let compiled = compile_program(&program, &profile).unwrap();
+
+        let mut rx = runtime.handle().read().bus().subscribe();
+        let mut recorder = EventRecorder::starting_at(0);
+        runtime.write_bit(SimBitDeviceType::M, 1, true).unwrap();
+        executor.execute_program(&compiled);
+        assert!(runtime.read_bit(SimBitDeviceType::M, 2).unwrap());
+        while let Ok(event) = rx.try_recv() {
+            recorder.record_at(&event, 10);
+        }
+        let recording = recorder.finish();
+        assert!(recording
+            .events
+            .iter()
+            .any(|event| event.writes.contains(&RecordedWrite(
+                CanonicalAddress::new(CanonicalAreaKind::InternalBit, 2),
+                CanonicalValue::Bool(true)
+            ))));
+
+        // 입력 쓰기만 재생되고 코일 출력은 프로그램이 다시 만들 몫이다
+        let fresh = CanonicalRuntimeFacade::new();
+        let mut replayer = EventReplayer::new(recording, 1.0)
+            .unwrap()
+            .skipping_outputs(compiled.output_addresses());
+        assert_eq!(replayer.progress().total, 1);
+        replayer.apply_due(&fresh, 10);
+        assert!(fresh.read_bit(SimBitDeviceType::M, 1).unwrap());
+        assert!(!fresh.read_bit(SimBitDeviceType::M, 2).unwrap());
+    }
+}
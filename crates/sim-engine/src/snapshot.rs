@@ -0,0 +1,372 @@
+//! 이름 있는 전체 상태 스냅샷
+//!
+//! 테스트 시 "충전 단계 끝의 설비 상태"처럼 공정을 매번 수동으로 몰고 가지 않도록
+//! PLC 전체 상태를 이름 붙여 저장·복원한다. 스캔 체크포인트(메모리, 타이머, 카운터,
+//! 에지 접점 상태)에 강제값과 시나리오 위치를 더한 것이 파일 포맷이다.
+//!
+//! 메모리는 0이 아닌 셀만 저장한다(비트는 1). 복원 시에는 현재 런타임의 영역
+//! 크기로 0을 채운 뒤 저장된 셀을 덮어쓴다.
+
+use std::collections::BTreeMap;
+
+use modone_contract::{
+    CanonicalAddress, CanonicalAreaKind, CanonicalMemorySnapshot, CanonicalValue,
+};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use super::counter::CounterSnapshot;
+use super::history::ScanCheckpoint;
+use super::timer::TimerSnapshot;
+use super::types::ForcedDeviceValue;
+
+/// Current snapshot file format version
+pub const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+/// Maximum snapshot name length
+const MAX_NAME_LEN: usize = 100;
+
+// ============================================================================
+// Error Types
+// ============================================================================
+
+/// Snapshot error types
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    #[error("Unsupported snapshot format version: {0}")]
+    UnsupportedVersion(u32),
+    #[error("Invalid snapshot name: {0}")]
+    InvalidName(String),
+    #[error("Snapshot cell {area:?}[{index}] is outside the current memory layout")]
+    AddressOutOfRange { area: CanonicalAreaKind, index: u32 },
+}
+
+pub type SnapshotResult<T> = Result<T, SnapshotError>;
+
+// ============================================================================
+// Snapshot Types
+// ============================================================================
+
+/// Position of the running scenario when the snapshot was taken
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenarioPosition {
+    /// Scenario name (metadata), used to match the loaded scenario on restore
+    pub scenario_name: String,
+    /// Elapsed scenario time in seconds
+    pub elapsed_secs: f64,
+    /// Current loop iteration (1-based)
+    pub current_loop: u32,
+}
+
+/// Previous input state of an edge contact (P/N)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EdgeState {
+    pub address: CanonicalAddress,
+    pub previous: bool,
+}
+
+/// Full saved PLC state
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlcStateSnapshot {
+    pub version: u32,
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Capture timestamp (ISO 8601)
+    pub captured_at: String,
+    /// Scan count at capture
+    pub scan_count: u64,
+    /// Non-zero cells per area (bits stored as 1)
+    pub memory: BTreeMap<CanonicalAreaKind, BTreeMap<u32, u16>>,
+    #[serde(default)]
+    pub timers: TimerSnapshot,
+    #[serde(default)]
+    pub counters: CounterSnapshot,
+    #[serde(default)]
+    pub edges: Vec<EdgeState>,
+    #[serde(default)]
+    pub forced: Vec<ForcedDeviceValue>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scenario: Option<ScenarioPosition>,
+}
+
+/// Snapshot summary for listings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotInfo {
+    pub name: String,
+    pub description: String,
+    pub captured_at: String,
+    pub scan_count: u64,
+    pub forced_count: usize,
+    pub scenario: Option<ScenarioPosition>,
+}
+
+impl PlcStateSnapshot {
+    /// Build a snapshot from a scan checkpoint (without forces and scenario position)
+    pub fn from_checkpoint(
+        name: impl Into<String>,
+        description: impl Into<String>,
+        checkpoint: &ScanCheckpoint,
+    ) -> Self {
+        let memory = checkpoint
+            .memory
+            .areas
+            .iter()
+            .filter_map(|(area, values)| {
+                let cells: BTreeMap<u32, u16> = values
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, value)| {
+                        let raw = match value {
+                            CanonicalValue::Bool(bit) => *bit as u16,
+                            CanonicalValue::U16(word) => *word,
+                        };
+                        (raw != 0).then_some((index as u32, raw))
+                    })
+                    .collect();
+                (!cells.is_empty()).then_some((*area, cells))
+            })
+            .collect();
+
+        let mut edges: Vec<EdgeState> = checkpoint
+            .edges
+            .iter()
+            .map(|(address, previous)| EdgeState {
+                address: *address,
+                previous: *previous,
+            })
+            .collect();
+        edges.sort_by_key(|edge| edge.address);
+
+        Self {
+            version: SNAPSHOT_FORMAT_VERSION,
+            name: name.into(),
+            description: description.into(),
+            captured_at: checkpoint.captured_at.clone(),
+            scan_count: checkpoint.scan_count,
+            memory,
+            timers: checkpoint.timers.clone(),
+            counters: checkpoint.counters.clone(),
+            edges,
+            forced: Vec::new(),
+            scenario: None,
+        }
+    }
+
+    /// Rebuild a checkpoint using the area layout of `template` (the live memory)
+    pub fn to_checkpoint(
+        &self,
+        template: &CanonicalMemorySnapshot,
+    ) -> SnapshotResult<ScanCheckpoint> {
+        Ok(ScanCheckpoint {
+            scan_count: self.scan_count,
+            captured_at: self.captured_at.clone(),
+            memory: self.to_memory(template)?,
+            timers: self.timers.clone(),
+            counters: self.counters.clone(),
+            edges: self
+                .edges
+                .iter()
+                .map(|edge| (edge.address, edge.previous))
+                .collect(),
+        })
+    }
+
+    /// Expand the sparse memory into a full image shaped like `template`
+    pub fn to_memory(
+        &self,
+        template: &CanonicalMemorySnapshot,
+    ) -> SnapshotResult<CanonicalMemorySnapshot> {
+        if self.version > SNAPSHOT_FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(self.version));
+        }
+
+        let mut areas = template.areas.clone();
+        for values in areas.values_mut() {
+            for value in values.iter_mut() {
+                *value = match value {
+                    CanonicalValue::Bool(_) => CanonicalValue::Bool(false),
+                    CanonicalValue::U16(_) => CanonicalValue::U16(0),
+                };
+            }
+        }
+
+        for (area, cells) in &self.memory {
+            for (&index, &raw) in cells {
+                let slot = areas
+                    .get_mut(area)
+                    .and_then(|values| values.get_mut(index as usize))
+                    .ok_or(SnapshotError::AddressOutOfRange { area: *area, index })?;
+                *slot = if area.is_bit_area() {
+                    CanonicalValue::Bool(raw != 0)
+                } else {
+                    CanonicalValue::U16(raw)
+                };
+            }
+        }
+
+        Ok(CanonicalMemorySnapshot {
+            captured_at: self.captured_at.clone(),
+            areas,
+        })
+    }
+
+    /// Value of a cell as stored in the snapshot (absent cells are zero)
+    pub fn value_at(&self, address: CanonicalAddress) -> CanonicalValue {
+        let raw = self
+            .memory
+            .get(&address.area)
+            .and_then(|cells| cells.get(&address.index))
+            .copied()
+            .unwrap_or(0);
+        match address.bit_index {
+            Some(bit) => CanonicalValue::Bool(raw & (1 << bit) != 0),
+            None if address.area.is_bit_area() => CanonicalValue::Bool(raw != 0),
+            None => CanonicalValue::U16(raw),
+        }
+    }
+
+    /// Summary for listings
+    pub fn info(&self) -> SnapshotInfo {
+        SnapshotInfo {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            captured_at: self.captured_at.clone(),
+            scan_count: self.scan_count,
+            forced_count: self.forced.len(),
+            scenario: self.scenario.clone(),
+        }
+    }
+}
+
+/// Check that a snapshot name can be used as a file name
+pub fn validate_snapshot_name(name: &str) -> SnapshotResult<()> {
+    let trimmed = name.trim();
+    let invalid = trimmed.is_empty()
+        || trimmed != name
+        || name.len() > MAX_NAME_LEN
+        || name.starts_with('.')
+        || name.chars().any(|c| {
+            c.is_control() || matches!(c, '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|')
+        });
+    if invalid {
+        return Err(SnapshotError::InvalidName(name.to_string()));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    use crate::counter::CounterManager;
+    use crate::executor::ProgramExecutor;
+    use crate::memory::CanonicalRuntimeFacade;
+    use crate::timer::TimerManager;
+    use crate::types::{SimBitDeviceType, SimTimeBase, SimTimerType, SimWordDeviceType};
+
+    fn create_executor() -> (
+        ProgramExecutor,
+        Arc<CanonicalRuntimeFacade>,
+        Arc<TimerManager>,
+    ) {
+        let runtime = Arc::new(CanonicalRuntimeFacade::new());
+        let timers = Arc::new(TimerManager::new());
+        let executor = ProgramExecutor::new(
+            runtime.clone(),
+            timers.clone(),
+            Arc::new(CounterManager::new()),
+        );
+        (executor, runtime, timers)
+    }
+
+    #[test]
+    fn test_round_trip_through_json() {
+        let (executor, runtime, timers) = create_executor();
+        runtime.write_bit(SimBitDeviceType::M, 5, true).unwrap();
+        runtime.write_word(SimWordDeviceType::D, 100, 1234).unwrap();
+        timers.update(3, SimTimerType::Ton, true, 50, SimTimeBase::Ms100);
+
+        let mut snapshot = PlcStateSnapshot::from_checkpoint(
+            "end of filling",
+            "",
+            &executor.capture_checkpoint(42),
+        );
+        snapshot.scenario = Some(ScenarioPosition {
+            scenario_name: "Filling".to_string(),
+            elapsed_secs: 12.5,
+            current_loop: 2,
+        });
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let loaded: PlcStateSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(loaded.scan_count, 42);
+        assert_eq!(loaded.scenario, snapshot.scenario);
+
+        runtime.write_bit(SimBitDeviceType::M, 5, false).unwrap();
+        runtime.write_word(SimWordDeviceType::D, 7, 9).unwrap();
+        timers.clear();
+
+        let restored = loaded.to_checkpoint(&runtime.snapshot()).unwrap();
+        executor.restore_checkpoint(&restored).unwrap();
+        assert!(runtime.read_bit(SimBitDeviceType::M, 5).unwrap());
+        assert_eq!(runtime.read_word(SimWordDeviceType::D, 100).unwrap(), 1234);
+        assert_eq!(runtime.read_word(SimWordDeviceType::D, 7).unwrap(), 0);
+        assert!(timers.get_state(3).is_some());
+    }
+
+    #[test]
+    fn test_sparse_memory_and_value_at() {
+        let (executor, runtime, _) = create_executor();
+        runtime.write_word(SimWordDeviceType::D, 10, 0b100).unwrap();
+
+        let snapshot = PlcStateSnapshot::from_checkpoint("s", "", &executor.capture_checkpoint(0));
+        assert_eq!(snapshot.memory.len(), 1);
+        assert_eq!(
+            snapshot.value_at(CanonicalAddress::new(CanonicalAreaKind::DataWord, 10)),
+            CanonicalValue::U16(4)
+        );
+        assert_eq!(
+            snapshot.value_at(CanonicalAddress::with_bit_index(
+                CanonicalAreaKind::DataWord,
+                10,
+                2
+            )),
+            CanonicalValue::Bool(true)
+        );
+        assert_eq!(
+            snapshot.value_at(CanonicalAddress::new(CanonicalAreaKind::InputBit, 0)),
+            CanonicalValue::Bool(false)
+        );
+    }
+
+    #[test]
+    fn test_rejects_cells_outside_layout_and_bad_names() {
+        let (executor, runtime, _) = create_executor();
+        let mut snapshot =
+            PlcStateSnapshot::from_checkpoint("s", "", &executor.capture_checkpoint(0));
+        snapshot
+            .memory
+            .entry(CanonicalAreaKind::DataWord)
+            .or_default()
+            .insert(u32::MAX, 1);
+        assert!(matches!(
+            snapshot.to_checkpoint(&runtime.snapshot()),
+            Err(SnapshotError::AddressOutOfRange { .. })
+        ));
+
+        assert!(validate_snapshot_name("End of filling").is_ok());
+        for bad in ["", " padded", "../escape", "a/b", ".hidden"] {
+            assert!(validate_snapshot_name(bad).is_err(), "{bad:?}");
+        }
+    }
+}
@@ -20,6 +20,8 @@ pub enum TagRegistryError {
     ReservedTagPrefix,
     #[error("tag request must provide a canonical address or canonical binding")]
     MissingCanonicalBinding,
+    #[error("expression binding has no canonical address: {0}")]
+    ExpressionBinding(String),
     #[error("tag access cannot be less restrictive than canonical access")]
     AccessEscalation,
     #[error("tagId is immutable after creation and cannot be changed (tag: {0})")]
@@ -28,11 +30,14 @@ pub enum TagRegistryError {
 
 pub type TagRegistryResult<T> = Result<T, TagRegistryError>;
 
+type ChangeHook = Box<dyn Fn() + Send + Sync>;
+
 #[derive(Default)]
 pub struct TagRegistry {
     raw_tags: RwLock<HashMap<String, TagDefinition>>,
     semantic_tags: RwLock<HashMap<String, TagDefinition>>,
     reverse_index: RwLock<HashMap<CanonicalAddress, Vec<String>>>,
+    change_hooks: RwLock<Vec<ChangeHook>>,
 }
 
 impl TagRegistry {
@@ -40,6 +45,19 @@ impl TagRegistry {
         Self::default()
     }
 
+    /// Run `hook` after every tag registration, rebind or removal.
+    ///
+    /// Hooks run with no registry lock held, so they may resolve tags.
+    pub fn on_change(&self, hook: impl Fn() + Send + Sync + 'static) {
+        self.change_hooks.write().push(Box::new(hook));
+    }
+
+    fn notify_changed(&self) {
+        for hook in self.change_hooks.read().iter() {
+            hook();
+        }
+    }
+
     pub fn resolve(&self, tag_id: &str) -> TagRegistryResult<TagDefinition> {
         if let Some(raw) = self.raw_tags.read().get(tag_id).cloned() {
             return Ok(raw);
@@ -60,6 +78,9 @@ impl TagRegistry {
         match binding {
             RuntimeBinding::Canonical { address } => Ok(*address),
             RuntimeBinding::Tag { tag_id } => Ok(self.resolve(tag_id)?.canonical_address),
+            RuntimeBinding::Expression { source } => {
+                Err(TagRegistryError::ExpressionBinding(source.clone()))
+            }
         }
     }
 
@@ -94,7 +115,7 @@ impl TagRegistry {
             .or_else(|| {
                 request.binding.as_ref().and_then(|binding| match binding {
                     RuntimeBinding::Canonical { address } => Some(*address),
-                    RuntimeBinding::Tag { .. } => None,
+                    RuntimeBinding::Tag { .. } | RuntimeBinding::Expression { .. } => None,
                 })
             })
             .ok_or(TagRegistryError::MissingCanonicalBinding)?;
@@ -142,6 +163,8 @@ impl TagRegistry {
             .entry(canonical_address)
             .or_default()
             .push(tag_id);
+        drop(semantic_tags);
+        self.notify_changed();
         Ok(definition)
     }
 
@@ -160,6 +183,7 @@ impl TagRegistry {
             .entry(address)
             .or_default()
             .push(definition.tag_id.clone());
+        self.notify_changed();
         definition
     }
 
@@ -190,6 +214,7 @@ impl TagRegistry {
             definition.display_name = name;
         }
 
+        let mut rebound = false;
         if let Some(new_addr) = canonical_address {
             let old_addr = definition.canonical_address;
             if old_addr != new_addr {
@@ -216,6 +241,7 @@ impl TagRegistry {
                     .push(tag_id.to_string());
 
                 definition.canonical_address = new_addr;
+                rebound = true;
             }
         }
 
@@ -240,7 +266,12 @@ impl TagRegistry {
             definition.folder_path = fp;
         }
 
-        Ok(definition.clone())
+        let updated = definition.clone();
+        drop(semantic_tags);
+        if rebound {
+            self.notify_changed();
+        }
+        Ok(updated)
     }
 
     pub fn remove(&self, tag_id: &str) -> TagRegistryResult<()> {
@@ -262,6 +293,8 @@ impl TagRegistry {
                         reverse.remove(&definition.canonical_address);
                     }
                 }
+                drop(reverse);
+                self.notify_changed();
                 Ok(())
             }
             None => Err(TagRegistryError::TagNotFound(tag_id.to_string())),
@@ -294,7 +327,7 @@ impl TagRegistry {
 
 pub type SharedTagRegistry = Arc<TagRegistry>;
 
-fn raw_tag_id(address: CanonicalAddress) -> String {
+pub(crate) fn raw_tag_id(address: CanonicalAddress) -> String {
     match address.bit_index {
         Some(bit_index) => format!(
             "{RAW_TAG_PREFIX}{:?}:{}:bit:{}",
@@ -348,7 +381,7 @@ fn access_from_canonical(access: CanonicalAccess) -> TagAccessLevel {
     }
 }
 
-fn raw_display_name(address: CanonicalAddress) -> String {
+pub(crate) fn raw_display_name(address: CanonicalAddress) -> String {
     match address.bit_index {
         Some(bit_index) => format!("{:?}:{}.{}", address.area, address.index, bit_index),
         None => format!("{:?}:{}", address.area, address.index),
@@ -476,4 +509,68 @@ mod tests {
             "D5 Raw"
         );
     }
+
+    #[test]
+    fn notifies_hooks_on_registry_changes() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let registry = Arc::new(TagRegistry::new());
+        let resolved = Arc::new(AtomicUsize::new(0));
+        let weak = Arc::downgrade(&registry);
+        let counter = Arc::clone(&resolved);
+        // 훅 안에서 레지스트리를 다시 읽어도 교착되지 않아야 한다
+        registry.on_change(move || {
+            let registry = weak.upgrade().expect("registry");
+            if registry.resolve("level").is_ok() {
+                counter.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        registry
+            .register_semantic(RegisterTagRequest {
+                tag_id: Some("level".to_string()),
+                display_name: "Level".to_string(),
+                binding: None,
+                canonical_address: Some(CanonicalAddress::new(CanonicalAreaKind::DataWord, 1)),
+                vendor_aliases: Vec::new(),
+                description: None,
+                engineering_unit: None,
+                access: None,
+                folder_path: None,
+            })
+            .expect("register");
+        assert_eq!(resolved.load(Ordering::SeqCst), 1);
+
+        // 주소가 그대로면 알리지 않는다
+        registry
+            .update_semantic(
+                "level",
+                Some("Tank Level".to_string()),
+                None,
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("rename");
+        assert_eq!(resolved.load(Ordering::SeqCst), 1);
+
+        registry
+            .update_semantic(
+                "level",
+                None,
+                Some(CanonicalAddress::new(CanonicalAreaKind::DataWord, 2)),
+                None,
+                None,
+                None,
+                None,
+            )
+            .expect("rebind");
+        assert_eq!(resolved.load(Ordering::SeqCst), 2);
+
+        registry.remove("level").expect("remove");
+        // 제거 후 훅은 돌지만 태그는 더 이상 풀리지 않는다
+        assert_eq!(resolved.load(Ordering::SeqCst), 2);
+        assert!(registry.resolve("level").is_err());
+    }
 }
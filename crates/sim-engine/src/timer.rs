@@ -5,6 +5,7 @@
 //! management with proper timing logic.
 
 use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 use super::types::{SimTimeBase, SimTimerType, TimerState};
@@ -14,7 +15,8 @@ use super::types::{SimTimeBase, SimTimerType, TimerState};
 // ============================================================================
 
 /// Internal runtime state for a timer
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct TimerRuntime {
     /// Timer type (TON, TOF, TMR)
     timer_type: SimTimerType,
@@ -61,6 +63,12 @@ impl TimerRuntime {
     }
 }
 
+/// Opaque copy of all timer runtime states, used for scan checkpoints and snapshots
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TimerSnapshot {
+    timers: HashMap<u16, TimerRuntime>,
+}
+
 // ============================================================================
 // Timer Manager
 // ============================================================================
@@ -275,6 +283,18 @@ impl TimerManager {
     pub fn clear(&self) {
         self.timers.write().clear();
     }
+
+    /// Capture all timer runtime states
+    pub fn snapshot(&self) -> TimerSnapshot {
+        TimerSnapshot {
+            timers: self.timers.read().clone(),
+        }
+    }
+
+    /// Replace all timer runtime states with a captured snapshot
+    pub fn restore(&self, snapshot: &TimerSnapshot) {
+        *self.timers.write() = snapshot.timers.clone();
+    }
 }
 
 impl Default for TimerManager {
@@ -0,0 +1,206 @@
+//! 래더 프로그램·태그 목록의 벤더 간 주소 변환
+//!
+//! [`plc_model::AddressTranslator`]로 한 VendorProfile 기준 프로그램/태그 목록의
+//! 모든 주소를 다른 프로파일 표기로 다시 쓴다. 변환할 수 없는 피연산자는 원문을
+//! 그대로 두고 [`TranslationReport`]에 위치와 사유를 남긴다 — 결과 프로그램은
+//! 항상 돌려주므로 사용자가 보고서를 보고 매핑 표를 고쳐 다시 돌리면 된다.
+//! 상수 피연산자(`operand1: "100"`)는 실행기와 같은 규칙으로 건너뛴다.
+
+use plc_model::{AddressTranslator, TranslationReport, UnmappableOperand};
+
+use super::executor::{LadderNode, LadderProgram};
+use super::tag_registry::{raw_display_name, raw_tag_id};
+use super::types::{RuntimeBinding, TagClass, TagDefinition};
+
+/// Copy of `program` with every address rewritten for the translator's target.
+pub fn translate_program(
+    program: &LadderProgram,
+    translator: &AddressTranslator<'_>,
+) -> (LadderProgram, TranslationReport) {
+    fn visit(
+        node: &mut LadderNode,
+        network_id: u32,
+        translator: &AddressTranslator<'_>,
+        report: &mut TranslationReport,
+    ) {
+        let node_type = node.node_type;
+        for (field, value) in [
+            ("address", &mut node.address),
+            ("operand1", &mut node.operand1),
+            ("operand2", &mut node.operand2),
+            ("destination", &mut node.destination),
+        ] {
+            let Some(operand) = value.as_deref() else {
+                continue;
+            };
+            if operand.trim().parse::<i32>().is_ok() {
+                continue;
+            }
+            let location = format!("network {network_id} {node_type:?} {field}");
+            *value = Some(report.translate(translator, location, operand));
+        }
+        for child in &mut node.children {
+            visit(child, network_id, translator, report);
+        }
+    }
+
+    let mut translated = program.clone();
+    let mut report = TranslationReport::default();
+    for network in &mut translated.networks {
+        for node in &mut network.nodes {
+            visit(node, network.id, translator, &mut report);
+        }
+    }
+    (translated, report)
+}
+
+/// Copy of `tags` with canonical addresses and vendor aliases rewritten.
+/// Raw-backed tags keep ids derived from their new address.
+pub fn translate_tags(
+    tags: &[TagDefinition],
+    translator: &AddressTranslator<'_>,
+) -> (Vec<TagDefinition>, TranslationReport) {
+    let mut report = TranslationReport::default();
+    let translated = tags
+        .iter()
+        .map(|tag| {
+            let mut tag = tag.clone();
+            let original = tag.canonical_address;
+            match translator.translate_canonical(&original) {
+                Ok(address) => {
+                    report.translated += 1;
+                    tag.canonical_address = address;
+                    if let RuntimeBinding::Canonical { address: bound } = &mut tag.binding {
+                        if *bound == original {
+                            *bound = address;
+                        }
+                    }
+                    if tag.class == TagClass::RawBacked && tag.tag_id == raw_tag_id(original) {
+                        tag.tag_id = raw_tag_id(address);
+                        tag.binding = RuntimeBinding::tag(tag.tag_id.clone());
+                        if tag.display_name == raw_display_name(original) {
+                            tag.display_name = raw_display_name(address);
+                        }
+                    }
+                }
+                Err(reason) => report.unmappable.push(UnmappableOperand {
+                    location: format!("tag {} canonicalAddress", tag.tag_id),
+                    operand: format!("{:?}:{}", original.area, original.index),
+                    reason,
+                }),
+            }
+            let location = format!("tag {} vendorAliases", tag.tag_id);
+            tag.vendor_aliases = tag
+                .vendor_aliases
+                .iter()
+                .map(|alias| report.translate(translator, location.clone(), alias))
+                .collect();
+            tag
+        })
+        .collect();
+    (translated, report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::executor::{LadderNetwork, NodeType};
+    use crate::tag_registry::TagRegistry;
+    use modone_contract::{CanonicalAddress, CanonicalAreaKind};
+    use plc_model::{
+        AddressMappingRule, AddressMappingTable, LsProfile, MelsecFxQProfile, PlcHardwareTopology,
+    };
+
+    fn profiles() -> (LsProfile, MelsecFxQProfile) {
+        (
+            LsProfile::new("XGK".to_string(), PlcHardwareTopology::default()),
+            MelsecFxQProfile::new("FX5U".to_string(), PlcHardwareTopology::default()),
+        )
+    }
+
+    #[test]
+    fn test_translate_program_rewrites_operands_and_reports_gaps() {
+        let (ls, melsec) = profiles();
+        let table = AddressMappingTable::default();
+        let translator = AddressTranslator::new(&ls, &melsec, &table);
+        let program = LadderProgram {
+            name: "port".to_string(),
+            networks: vec![LadderNetwork {
+                id: 3,
+                nodes: vec![
+                    LadderNode::series(vec![
+                        LadderNode::contact(NodeType::ContactNo, "M0010"),
+                        LadderNode::compare(NodeType::CompareGt, "TD0005", "100"),
+                    ]),
+                    LadderNode::coil(NodeType::CoilOut, "P00020"),
+                    LadderNode::math(NodeType::MathAdd, "D0100", "5", "D0101"),
+                ],
+                comment: None,
+            }],
+        };
+
+        let (translated, report) = translate_program(&program, &translator);
+        let nodes = &translated.networks[0].nodes;
+        assert_eq!(nodes[0].children[0].address.as_deref(), Some("M10"));
+        assert_eq!(nodes[1].address.as_deref(), Some("Y24"));
+        assert_eq!(nodes[2].operand1.as_deref(), Some("D100"));
+        assert_eq!(nodes[2].operand2.as_deref(), Some("5"));
+        assert_eq!(nodes[2].destination.as_deref(), Some("D101"));
+
+        // TD 는 MELSEC 별칭이 없어 원문 그대로 남고 보고서에 기록된다
+        let compare = &nodes[0].children[1];
+        assert_eq!(compare.operand1.as_deref(), Some("TD0005"));
+        assert_eq!(report.translated, 4);
+        assert_eq!(report.unmappable.len(), 1);
+        assert_eq!(
+            report.unmappable[0].location,
+            "network 3 CompareGt operand1"
+        );
+
+        // 매핑 규칙을 주면 다시 돌려 해결된다
+        let table = AddressMappingTable {
+            rules: vec![AddressMappingRule::family("TD", "D")],
+        };
+        let translator = AddressTranslator::new(&ls, &melsec, &table);
+        let (translated, report) = translate_program(&program, &translator);
+        assert!(report.is_complete());
+        assert_eq!(
+            translated.networks[0].nodes[0].children[1]
+                .operand1
+                .as_deref(),
+            Some("D5")
+        );
+    }
+
+    #[test]
+    fn test_translate_tags_updates_aliases_and_raw_ids() {
+        let (ls, melsec) = profiles();
+        let table = AddressMappingTable {
+            rules: vec![AddressMappingRule::family("TD", "D")],
+        };
+        let translator = AddressTranslator::new(&ls, &melsec, &table);
+
+        let registry = TagRegistry::new();
+        let timer = CanonicalAddress::new(CanonicalAreaKind::TimerValueWord, 2);
+        let raw = registry.raw_tag_for_address(timer, None, vec!["TD0002".to_string()]);
+        let output = registry.raw_tag_for_address(
+            CanonicalAddress::new(CanonicalAreaKind::OutputBit, 20),
+            Some("Lamp".to_string()),
+            vec!["P00020".to_string(), "Q1".to_string()],
+        );
+
+        let (translated, report) = translate_tags(&[raw, output], &translator);
+        let data = CanonicalAddress::new(CanonicalAreaKind::DataWord, 2);
+        assert_eq!(translated[0].canonical_address, data);
+        assert_eq!(translated[0].tag_id, raw_tag_id(data));
+        assert_eq!(translated[0].display_name, raw_display_name(data));
+        assert_eq!(translated[0].vendor_aliases, vec!["D2".to_string()]);
+
+        assert_eq!(translated[1].display_name, "Lamp");
+        assert_eq!(translated[1].vendor_aliases[0], "Y24");
+        assert_eq!(translated[1].vendor_aliases[1], "Q1");
+        assert_eq!(report.translated, 4);
+        assert_eq!(report.unmappable.len(), 1);
+        assert_eq!(report.unmappable[0].operand, "Q1");
+    }
+}
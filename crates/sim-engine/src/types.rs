@@ -8,6 +8,8 @@ use modone_contract::CanonicalAddress;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use super::watch::WatchFormat;
+
 // ============================================================================
 // Device Memory Configuration Types
 // ============================================================================
@@ -395,8 +397,16 @@ pub struct ScanCycleInfo {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", tag = "kind")]
 pub enum RuntimeBinding {
-    Canonical { address: CanonicalAddress },
-    Tag { tag_id: String },
+    Canonical {
+        address: CanonicalAddress,
+    },
+    Tag {
+        tag_id: String,
+    },
+    /// Debugger watch on an expression, keyed by its source text
+    Expression {
+        source: String,
+    },
 }
 
 impl RuntimeBinding {
@@ -410,10 +420,16 @@ impl RuntimeBinding {
         }
     }
 
+    pub fn expression(source: impl Into<String>) -> Self {
+        Self::Expression {
+            source: source.into(),
+        }
+    }
+
     pub fn canonical_address(&self) -> Option<CanonicalAddress> {
         match self {
             Self::Canonical { address } => Some(*address),
-            Self::Tag { .. } => None,
+            Self::Tag { .. } | Self::Expression { .. } => None,
         }
     }
 }
@@ -558,11 +574,15 @@ impl Breakpoint {
 
 /// Value history entry
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct ValueHistoryEntry {
     /// Value (number or boolean serialized)
     pub value: serde_json::Value,
     /// Timestamp (epoch ms)
     pub timestamp: u64,
+    /// Scan in which the value was observed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scan_count: Option<u64>,
 }
 
 /// Watch variable for monitoring device values
@@ -585,6 +605,15 @@ pub struct WatchVariable {
     pub history: Vec<ValueHistoryEntry>,
     /// Maximum history length
     pub max_history: usize,
+    /// Source text for expression watches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expression: Option<String>,
+    /// Data type, word order, scaling and radix (raw cell value when absent)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<WatchFormat>,
+    /// Current value rendered with the format's radix
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_value: Option<String>,
 }
 
 impl WatchVariable {
@@ -606,19 +635,38 @@ impl WatchVariable {
             history: vec![ValueHistoryEntry {
                 value: initial_value,
                 timestamp: now,
+                scan_count: None,
             }],
             max_history,
+            expression: None,
+            format: None,
+            display_value: None,
         }
     }
 
+    /// Attach a typed format and render the current value with it
+    pub fn with_format(mut self, format: WatchFormat) -> Self {
+        self.display_value = Some(format.display(&self.current_value));
+        self.format = Some(format);
+        self
+    }
+
     /// Update with a new value
     pub fn update(&mut self, new_value: serde_json::Value) {
+        self.update_at_scan(new_value, None);
+    }
+
+    /// Update with a new value observed in the given scan
+    pub fn update_at_scan(&mut self, new_value: serde_json::Value, scan_count: Option<u64>) {
         if self.current_value != new_value {
             let now = modone_contract::clock::now_millis();
             self.previous_value = self.current_value.clone();
             self.current_value = new_value.clone();
             self.change_count += 1;
             self.last_change_time = now;
+            if let Some(format) = &self.format {
+                self.display_value = Some(format.display(&new_value));
+            }
 
             // Add to history (front)
             self.history.insert(
@@ -626,6 +674,7 @@ impl WatchVariable {
                 ValueHistoryEntry {
                     value: new_value,
                     timestamp: now,
+                    scan_count,
                 },
             );
 
@@ -0,0 +1,466 @@
+//! 타입 지정 watch 포맷과 이력 내보내기
+//!
+//! canonical 메모리는 Bool/U16 셀만 갖기 때문에, 두 D 레지스터에 걸친 REAL
+//! 설정값 같은 값은 그대로 보면 의미 없는 u16 두 개가 된다. `WatchFormat`은
+//! 시작 주소부터 연속 워드를 읽어 데이터 타입·워드 순서로 디코딩하고, 스케일링과
+//! 표시 진법을 적용한다.
+//!
+//! watch 이력은 타임스탬프·스캔 번호와 함께 CSV로 내보낼 수 있다.
+
+use serde::{Deserialize, Serialize};
+
+use modone_contract::typed::decode_words;
+use modone_contract::{TypedDataType, TypedValue};
+pub use modone_contract::WordOrder;
+
+use super::types::WatchVariable;
+
+// ============================================================================
+// Format Types
+// ============================================================================
+
+/// Data type used to decode a watch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WatchDataType {
+    /// Single bit
+    Bool,
+    /// Signed 16-bit word
+    #[default]
+    Int16,
+    /// Unsigned 16-bit word
+    Uint16,
+    /// Signed 32-bit over two words
+    Int32,
+    /// Unsigned 32-bit over two words
+    Uint32,
+    /// IEEE 754 single precision over two words
+    Real,
+    /// ASCII string, two characters per word (first character in the low
+    /// byte, or the high byte with `HighWordFirst`)
+    String,
+    /// Packed BCD, four digits per word
+    Bcd,
+}
+
+/// Display radix for integer values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DisplayRadix {
+    #[default]
+    Decimal,
+    Hexadecimal,
+    Binary,
+    Octal,
+}
+
+/// Linear scaling: `value * factor + offset`
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchScaling {
+    pub factor: f64,
+    #[serde(default)]
+    pub offset: f64,
+}
+
+/// How a watch reads and presents its value
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WatchFormat {
+    /// Data type
+    #[serde(default)]
+    pub data_type: WatchDataType,
+    /// Word order for 32-bit types, multi-word BCD and string bytes
+    #[serde(default)]
+    pub word_order: WordOrder,
+    /// Character count for STRING, word count for BCD (default: 1 word)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub length: Option<u16>,
+    /// Optional linear scaling (numeric types only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scaling: Option<WatchScaling>,
+    /// Display radix (unscaled integer types only)
+    #[serde(default)]
+    pub radix: DisplayRadix,
+}
+
+impl WatchFormat {
+    /// Create a format for the given data type with default options
+    pub fn new(data_type: WatchDataType) -> Self {
+        Self {
+            data_type,
+            ..Self::default()
+        }
+    }
+
+    /// Number of consecutive words the format reads (0 for BOOL)
+    pub fn word_count(&self) -> usize {
+        match self.data_type {
+            WatchDataType::Bool => 0,
+            WatchDataType::Int16 | WatchDataType::Uint16 => 1,
+            WatchDataType::Int32 | WatchDataType::Uint32 | WatchDataType::Real => 2,
+            WatchDataType::String => (self.length.unwrap_or(2).max(1) as usize).div_ceil(2),
+            WatchDataType::Bcd => self.length.unwrap_or(1).clamp(1, 2) as usize,
+        }
+    }
+
+    /// Decode consecutive words (lowest address first) into a JSON value.
+    ///
+    /// Invalid BCD digits and non-finite scaled results decode to `null`.
+    pub fn decode_words(&self, words: &[u16]) -> serde_json::Value {
+        if words.len() < self.word_count() {
+            return serde_json::Value::Null;
+        }
+
+        let raw = match self.data_type {
+            WatchDataType::Bool => return serde_json::Value::Null,
+            WatchDataType::Int16 => RawValue::Int(words[0] as i16 as i64),
+            WatchDataType::Uint16 => RawValue::Int(words[0] as i64),
+            WatchDataType::String => return serde_json::json!(self.decode_string(words)),
+            WatchDataType::Bcd => match self.decode_bcd(words) {
+                Some(value) => RawValue::Int(value),
+                None => return serde_json::Value::Null,
+            },
+            WatchDataType::Int32 => match self.decode_typed(TypedDataType::Int32, words) {
+                Some(TypedValue::Int32(value)) => RawValue::Int(value as i64),
+                _ => return serde_json::Value::Null,
+            },
+            WatchDataType::Uint32 => match self.decode_typed(TypedDataType::UInt32, words) {
+                Some(TypedValue::UInt32(value)) => RawValue::Int(value as i64),
+                _ => return serde_json::Value::Null,
+            },
+            WatchDataType::Real => match self.decode_typed(TypedDataType::Real, words) {
+                Some(TypedValue::Real(value)) => RawValue::Float(value as f64),
+                _ => return serde_json::Value::Null,
+            },
+        };
+
+        match (raw, self.scaling) {
+            (RawValue::Int(value), None) => serde_json::json!(value),
+            (RawValue::Int(value), Some(scaling)) => float_json(scaling.apply(value as f64)),
+            (RawValue::Float(value), None) => float_json(value),
+            (RawValue::Float(value), Some(scaling)) => float_json(scaling.apply(value)),
+        }
+    }
+
+    /// Render a decoded value for display using the radix
+    pub fn display(&self, value: &serde_json::Value) -> String {
+        match value {
+            serde_json::Value::Null => String::new(),
+            serde_json::Value::String(text) => text.clone(),
+            serde_json::Value::Bool(flag) => if *flag { "1" } else { "0" }.to_string(),
+            serde_json::Value::Number(number) => match number.as_i64() {
+                Some(int) if self.scaling.is_none() => self.format_int(int),
+                _ => number.to_string(),
+            },
+            other => other.to_string(),
+        }
+    }
+
+    fn format_int(&self, value: i64) -> String {
+        // 음수는 타입 폭의 2의 보수로 표시한다 (-1 → 16#FFFF)
+        let bits = match self.data_type {
+            WatchDataType::Int16 => value as u16 as u64,
+            WatchDataType::Int32 => value as u32 as u64,
+            _ => value as u64,
+        };
+        match self.radix {
+            DisplayRadix::Decimal => value.to_string(),
+            DisplayRadix::Hexadecimal => format!("16#{bits:X}"),
+            DisplayRadix::Binary => format!("2#{bits:b}"),
+            DisplayRadix::Octal => format!("8#{bits:o}"),
+        }
+    }
+
+    fn decode_typed(&self, data_type: TypedDataType, words: &[u16]) -> Option<TypedValue> {
+        decode_words(data_type, self.word_order, words).ok()
+    }
+
+    fn decode_bcd(&self, words: &[u16]) -> Option<i64> {
+        let (raw, digits) = if self.word_count() == 2 {
+            match self.decode_typed(TypedDataType::UInt32, words)? {
+                TypedValue::UInt32(raw) => (raw, 8),
+                _ => return None,
+            }
+        } else {
+            (words[0] as u32, 4)
+        };
+        let mut value = 0i64;
+        for shift in (0..digits).rev().map(|digit| digit * 4) {
+            let digit = (raw >> shift) & 0xF;
+            if digit > 9 {
+                return None;
+            }
+            value = value * 10 + digit as i64;
+        }
+        Some(value)
+    }
+
+    fn decode_string(&self, words: &[u16]) -> String {
+        let length = self.length.unwrap_or(2).max(1);
+        match self.decode_typed(TypedDataType::String { length }, words) {
+            Some(TypedValue::String(text)) => text
+                .chars()
+                .map(|c| {
+                    if c.is_ascii() && !c.is_ascii_control() {
+                        c
+                    } else {
+                        '?'
+                    }
+                })
+                .collect(),
+            _ => String::new(),
+        }
+    }
+}
+
+impl WatchScaling {
+    fn apply(&self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+}
+
+#[derive(Clone, Copy)]
+enum RawValue {
+    Int(i64),
+    Float(f64),
+}
+
+fn float_json(value: f64) -> serde_json::Value {
+    serde_json::Number::from_f64(value)
+        .map(serde_json::Value::Number)
+        .unwrap_or(serde_json::Value::Null)
+}
+
+// ============================================================================
+// CSV Export
+// ============================================================================
+
+/// Export the history of all watches as CSV, oldest entry first.
+///
+/// Columns: `timestamp` (ISO 8601 UTC), `epochMs`, `scan`, `address`, `dataType`,
+/// `value`, `display`. `scan` is empty for entries recorded outside a scan.
+pub fn export_history_csv(watches: &[WatchVariable]) -> String {
+    let mut rows: Vec<_> = watches
+        .iter()
+        .flat_map(|watch| watch.history.iter().map(move |entry| (watch, entry)))
+        .collect();
+    rows.sort_by_key(|(_, entry)| (entry.timestamp, entry.scan_count));
+
+    let mut csv = String::from("timestamp,epochMs,scan,address,dataType,value,display\n");
+    for (watch, entry) in rows {
+        let format = watch.format.clone().unwrap_or_default();
+        let data_type = match &watch.format {
+            Some(format) => serde_json::to_value(format.data_type)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default(),
+            None => String::new(),
+        };
+        let value = match &entry.value {
+            serde_json::Value::String(text) => text.clone(),
+            serde_json::Value::Null => String::new(),
+            other => other.to_string(),
+        };
+        let display = if watch.format.is_some() {
+            format.display(&entry.value)
+        } else {
+            value.clone()
+        };
+        let fields = [
+            format_epoch_millis(entry.timestamp),
+            entry.timestamp.to_string(),
+            entry.scan_count.map(|s| s.to_string()).unwrap_or_default(),
+            watch.address.clone(),
+            data_type,
+            value,
+            display,
+        ];
+        let line: Vec<_> = fields.iter().map(|f| csv_field(f)).collect();
+        csv.push_str(&line.join(","));
+        csv.push('\n');
+    }
+    csv
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// epoch ms → `YYYY-MM-DDTHH:MM:SS.mmmZ` (chrono 없이 계산, wasm 호환)
+fn format_epoch_millis(millis: u64) -> String {
+    let days = (millis / 86_400_000) as i64;
+    let ms_of_day = millis % 86_400_000;
+
+    // civil_from_days (H. Hinnant)
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        ms_of_day / 3_600_000,
+        ms_of_day / 60_000 % 60,
+        ms_of_day / 1000 % 60,
+        ms_of_day % 1000
+    )
+}
+
+// ============================================================================
+// Unit Tests
+// ============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{RuntimeBinding, ValueHistoryEntry};
+
+    #[test]
+    fn test_decode_real_word_orders() {
+        let bits = 12.5f32.to_bits();
+        let (low, high) = (bits as u16, (bits >> 16) as u16);
+
+        let format = WatchFormat::new(WatchDataType::Real);
+        assert_eq!(format.decode_words(&[low, high]), serde_json::json!(12.5));
+
+        let swapped = WatchFormat {
+            word_order: WordOrder::HighWordFirst,
+            ..WatchFormat::new(WatchDataType::Real)
+        };
+        assert_eq!(swapped.decode_words(&[high, low]), serde_json::json!(12.5));
+    }
+
+    #[test]
+    fn test_decode_integers() {
+        let int16 = WatchFormat::new(WatchDataType::Int16);
+        assert_eq!(int16.decode_words(&[0xFFFF]), serde_json::json!(-1));
+
+        let uint32 = WatchFormat::new(WatchDataType::Uint32);
+        assert_eq!(
+            uint32.decode_words(&[0x0000, 0x0001]),
+            serde_json::json!(65536)
+        );
+
+        let int32 = WatchFormat::new(WatchDataType::Int32);
+        assert_eq!(int32.decode_words(&[0xFFFE, 0xFFFF]), serde_json::json!(-2));
+        assert_eq!(int32.decode_words(&[1]), serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_decode_bcd_and_string() {
+        let bcd = WatchFormat::new(WatchDataType::Bcd);
+        assert_eq!(bcd.decode_words(&[0x1234]), serde_json::json!(1234));
+        assert_eq!(bcd.decode_words(&[0x12A4]), serde_json::Value::Null);
+
+        let bcd32 = WatchFormat {
+            length: Some(2),
+            ..WatchFormat::new(WatchDataType::Bcd)
+        };
+        assert_eq!(
+            bcd32.decode_words(&[0x5678, 0x1234]),
+            serde_json::json!(12345678)
+        );
+
+        let string = WatchFormat {
+            length: Some(5),
+            ..WatchFormat::new(WatchDataType::String)
+        };
+        assert_eq!(string.word_count(), 3);
+        let words = [
+            u16::from_le_bytes(*b"HE"),
+            u16::from_le_bytes(*b"LL"),
+            0x004F,
+        ];
+        assert_eq!(string.decode_words(&words), serde_json::json!("HELLO"));
+    }
+
+    #[test]
+    fn test_scaling_and_radix() {
+        let scaled = WatchFormat {
+            scaling: Some(WatchScaling {
+                factor: 0.1,
+                offset: -10.0,
+            }),
+            ..WatchFormat::new(WatchDataType::Int16)
+        };
+        let value = scaled.decode_words(&[250]);
+        assert!((value.as_f64().unwrap() - 15.0).abs() < 1e-9);
+
+        let hex = WatchFormat {
+            radix: DisplayRadix::Hexadecimal,
+            ..WatchFormat::new(WatchDataType::Int16)
+        };
+        assert_eq!(hex.display(&hex.decode_words(&[0xFFFF])), "16#FFFF");
+
+        let bin = WatchFormat {
+            radix: DisplayRadix::Binary,
+            ..WatchFormat::new(WatchDataType::Uint16)
+        };
+        assert_eq!(bin.display(&serde_json::json!(5)), "2#101");
+    }
+
+    #[test]
+    fn test_data_type_serialization() {
+        let json = serde_json::to_string(&WatchDataType::Uint32).unwrap();
+        assert_eq!(json, "\"UINT32\"");
+        let format: WatchFormat =
+            serde_json::from_str(r#"{"dataType":"REAL","wordOrder":"highWordFirst"}"#).unwrap();
+        assert_eq!(format.data_type, WatchDataType::Real);
+        assert_eq!(format.word_order, WordOrder::HighWordFirst);
+    }
+
+    #[test]
+    fn test_export_history_csv() {
+        let mut watch = WatchVariable::new(
+            RuntimeBinding::tag("setpoint"),
+            "D100".to_string(),
+            serde_json::json!(1.5),
+            10,
+        );
+        watch.format = Some(WatchFormat::new(WatchDataType::Real));
+        watch.history = vec![
+            ValueHistoryEntry {
+                value: serde_json::json!(2.5),
+                timestamp: 1_700_000_001_000,
+                scan_count: Some(42),
+            },
+            ValueHistoryEntry {
+                value: serde_json::json!(1.5),
+                timestamp: 1_700_000_000_000,
+                scan_count: None,
+            },
+        ];
+
+        let csv = export_history_csv(&[watch]);
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "timestamp,epochMs,scan,address,dataType,value,display"
+        );
+        assert_eq!(
+            lines[1],
+            "2023-11-14T22:13:20.000Z,1700000000000,,D100,REAL,1.5,1.5"
+        );
+        assert_eq!(
+            lines[2],
+            "2023-11-14T22:13:21.000Z,1700000001000,42,D100,REAL,2.5,2.5"
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("plain"), "plain");
+    }
+}
@@ -8,7 +8,7 @@
 use std::cell::RefCell;
 use std::sync::Arc;
 
-use plc_model::{LsProfile, PlcHardwareTopology};
+use plc_model::{LsProfile, PlcHardwareTopology, VendorProfile};
 use sim_engine::{
     compile_program, CanonicalRuntimeFacade, CompiledProgram, CounterManager, LadderNetwork,
     LadderNode, LadderProgram, NodeType, ProgramExecutor, SimBitDeviceType, TimerManager,
@@ -60,6 +60,7 @@ pub extern "C" fn sim_init() {
     let counter = Arc::new(CounterManager::new());
     let executor = ProgramExecutor::new(Arc::clone(&memory), timer, counter);
     let profile = LsProfile::new("XGK".to_string(), PlcHardwareTopology::default());
+    memory.apply_memory_layout(profile.memory_layout());
     let program =
         compile_program(&build_demo_program(), &profile).expect("demo program should compile");
 
@@ -38,6 +38,7 @@ pub use project::{
     get_available_backups,
     get_project_status,
     get_recent_projects,
+    list_plc_cpu_models,
     mark_project_modified,
     open_project,
     recover_project_from_backup,
@@ -153,12 +154,27 @@ pub use canvas_sync::{
 pub use sim::{
     ladder_force_device, ladder_release_force, runtime_query_audit_log,
     ladder_start_monitoring, ladder_stop_monitoring,
-    sim_add_breakpoint, sim_add_watch, sim_continue, sim_create_raw_tag, sim_get_breakpoints,
-    sim_get_debugger_state, sim_get_memory_snapshot, sim_get_scan_info, sim_get_status,
-    sim_get_tag, sim_get_watches, sim_list_tags, sim_load_program, sim_pause, sim_read_binding,
-    sim_register_tag, sim_remove_breakpoint, sim_remove_tag, sim_remove_watch, sim_reset,
-    sim_resolve_binding, sim_resolve_binding_parts, sim_resume, sim_run,
-    sim_set_breakpoint_enabled, sim_step, sim_stop, sim_write_binding, SimState,
+    sim_add_breakpoint, sim_add_cpu, sim_add_modbus_cpu, sim_add_opcua_cpu, sim_add_watch,
+    sim_clear_input_fault, sim_configure_analog_module, sim_configure_axis,
+    sim_configure_history, sim_configure_hsc_channel, sim_continue,
+    sim_create_raw_tag, sim_delete_snapshot, sim_diff_memory_baseline, sim_diff_snapshot,
+    sim_export_watch_history, sim_get_breakpoints, sim_get_debugger_state,
+    sim_get_history_position, sim_get_links, sim_get_memory_snapshot, sim_get_profile,
+    sim_get_scan_info, sim_get_status, sim_get_tag, sim_get_watches, sim_inject_input_fault,
+    sim_list_analog_modules, sim_list_axes, sim_list_cpus, sim_list_hsc_channels,
+    sim_list_input_faults, sim_list_snapshots,
+    sim_list_tags, sim_load_cpu_program, sim_load_program, sim_mark_memory_baseline, sim_pause,
+    sim_read_binding, sim_read_typed, sim_register_tag, sim_remove_analog_module, sim_remove_axis,
+    sim_remove_breakpoint, sim_remove_cpu, sim_remove_hsc_channel, sim_remove_tag, sim_remove_watch,
+    sim_replay_recording,
+    sim_reset, sim_reset_profile, sim_resolve_binding, sim_resolve_binding_parts,
+    sim_restore_snapshot, sim_resume, sim_run, sim_run_back_to_change, sim_save_snapshot,
+    sim_set_analog_value, sim_set_analog_wire_break, sim_set_breakpoint_enabled,
+    sim_set_hsc_frequency,
+    sim_set_instruction_profiling, sim_set_links, sim_start_recording,
+    sim_step, sim_stop, sim_stop_recording, sim_stop_replay, sim_translate_program,
+    sim_translate_tags, sim_write_binding, sim_write_typed,
+    SimState,
 };
 
 // Re-export explorer commands
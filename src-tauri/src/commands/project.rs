@@ -5,6 +5,7 @@
 
 use std::path::PathBuf;
 
+use plc_model::{cpu_models, PlcCpuModel, PlcCpuModelInfo, VendorProfileId};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use tauri::State;
@@ -20,7 +21,7 @@ use crate::project::{
     CanvasData,
     MemorySnapshot,
     MopIntegrityResult,
-    PlcHardwareTopology,
+    PlcManufacturer,
     PlcSettings,
     ProjectConfig,
     ProjectError,
@@ -107,7 +108,8 @@ fn format_error(error: ProjectError) -> String {
 /// * `project_dir` - Path to the project directory (e.g., Documents/ModOne/MyProject)
 /// * `plc_manufacturer` - PLC manufacturer (e.g., "LS", "Mitsubishi", "Siemens")
 /// * `plc_model` - PLC model name
-/// * `scan_time_ms` - PLC scan time in milliseconds (optional, defaults to 10)
+/// * `scan_time_ms` - PLC scan time in milliseconds (optional, defaults to the
+///   CPU model's catalogue value, or 10 for unknown models)
 ///
 /// # Project Structure Created
 /// ```text
@@ -131,13 +133,11 @@ pub async fn create_project(
         .parse()
         .map_err(|e: String| format!("Invalid PLC manufacturer: {}", e))?;
 
-    // Create PLC settings
-    let plc_settings = PlcSettings {
-        manufacturer,
-        model: plc_model,
-        scan_time_ms: scan_time_ms.unwrap_or(10),
-        hardware_topology: PlcHardwareTopology::default(),
-    };
+    // Create PLC settings; defaults come from the CPU model catalogue
+    let mut plc_settings = PlcSettings::for_model(manufacturer, plc_model);
+    if let Some(scan_time_ms) = scan_time_ms {
+        plc_settings.scan_time_ms = scan_time_ms;
+    }
 
     // Acquire lock and create project
     let mut manager = state
@@ -155,6 +155,17 @@ pub async fn create_project(
     })
 }
 
+/// List the catalogued CPU models of a manufacturer with their capabilities
+#[tauri::command]
+pub fn list_plc_cpu_models(plc_manufacturer: String) -> Result<Vec<PlcCpuModelInfo>, String> {
+    let manufacturer: PlcManufacturer = plc_manufacturer
+        .parse()
+        .map_err(|e: String| format!("Invalid PLC manufacturer: {}", e))?;
+    Ok(cpu_models(VendorProfileId::for_manufacturer(manufacturer))
+        .map(PlcCpuModel::info)
+        .collect())
+}
+
 /// Open an existing project from a .mop file
 ///
 /// # Arguments
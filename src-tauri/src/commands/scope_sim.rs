@@ -124,6 +124,9 @@ fn read_device_voltage(
                 CanonicalValue::U16(value) => Ok(value as f32 * mapping.scale + mapping.offset),
             }
         }
+        RuntimeBinding::Expression { source } => Err(format!(
+            "Expression watches cannot drive a scope channel: {source}"
+        )),
     }
 }
 
@@ -157,7 +160,7 @@ pub async fn scope_read_device_voltage(
             5.0
         }
         RuntimeBinding::Canonical { .. } => 0.001,
-        RuntimeBinding::Tag { .. } => 1.0,
+        RuntimeBinding::Tag { .. } | RuntimeBinding::Expression { .. } => 1.0,
     });
 
     // Create temporary mapping for reading
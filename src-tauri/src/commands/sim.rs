@@ -3,7 +3,10 @@
 //! Tauri command handlers for PLC simulation control, memory access,
 //! and debugging operations.
 
+use modone_contract::{CpuId, FieldLink, MemoryLink, TypedDataType, TypedValue, WordOrder};
+use plc_model::{AddressMappingTable, AddressTranslator, TranslationReport};
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use std::sync::Arc;
 use tauri::{AppHandle, Emitter, Manager, State};
 
@@ -16,24 +19,46 @@ use crate::commands::opcua::{
     opcua_start_project_simulation, opcua_stop_project_simulation, CredentialCacheState,
     OpcUaState, UserAccountStoreState,
 };
-use crate::opcua::AuditLoggerState;
+use crate::commands::scenario::ScenarioExecutorState;
+use crate::opcua::{AuditLoggerState, OpcUaClientConfig, OpcUaClientLink};
 use modone_audit::{AuditLogQuery, AuditLogResult};
-use crate::modbus::ModbusMemory;
+use crate::modbus::{ModbusMasterConfig, ModbusMemory, ModbusTcpMaster};
 use crate::plc_runtime::{
     resolve_vendor_profile, CanonicalAddress, CanonicalValue, CanonicalWriteSource, VendorAddress,
+    VendorProfile,
+};
+use crate::project::{
+    PlcManufacturer, PlcSettings, ProjectConfig, ProjectStorage, SharedProjectManager,
 };
-use crate::project::{PlcSettings, ProjectConfig, SharedProjectManager};
 use crate::sim::{
+    analog::{
+        AnalogChannelConfig, AnalogDirection, AnalogModuleConfig, AnalogModuleInfo, AnalogSource,
+        AnalogWordLayout,
+    },
     audit::RuntimeAuditState,
-    debugger::{SimDebugger, StepResult, StepType},
-    executor::{compile_program, LadderProgram},
+    cpu_manager::CpuInfo,
+    debugger::{SimDebugger, StepDirection, StepResult, StepType},
+    diff::MemoryDiff,
+    executor::{compile_program, unsupported_instructions, LadderProgram, UnsupportedInstruction},
+    history::{HistoryPosition, ValueChangeLocation},
+    injection::{InjectedFaultInfo, InjectedFaultKind, InjectionRequest, InjectionSchedule},
     memory::CanonicalRuntimeFacade,
+    motion::{
+        AxisConfig, AxisInfo, HscChannelConfig, HscChannelInfo, HscCompare, HscCountMode,
+        HscPreset,
+    },
+    profiler::ScanProfile,
+    recording::{EventRecording, RecordingHeader, ReplayProgress},
     runtime_host::SimulationRuntimeHost,
+    snapshot::SnapshotInfo,
+    snapshot_store,
     tag_registry::SharedTagRegistry,
+    translation::{translate_program, translate_tags},
     types::{
         Breakpoint, ForcedDeviceValue, MemorySnapshot, RegisterTagRequest, RuntimeBinding,
         ScanCycleInfo, SimulationConfig, SimulationStatus, TagDefinition, WatchVariable,
     },
+    watch::WatchFormat,
 };
 
 // ============================================================================
@@ -129,6 +154,13 @@ pub struct WatchBindingRequest {
     pub address: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub display_address: Option<String>,
+    /// Watch expression (e.g. `int32(D100) / 10`), evaluated instead of a single binding
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expression: Option<String>,
+    /// Data type/word order/scaling/radix for typed watches; expression
+    /// watches use only its word order
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<WatchFormat>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -313,18 +345,224 @@ pub fn sim_get_scan_info(state: State<'_, SimState>) -> Result<ScanCycleInfo, St
     Ok(state.host().scan_info())
 }
 
-/// Load a ladder program for simulation
+/// Get per-network / per-instruction scan time profile
+#[tauri::command]
+pub fn sim_get_profile(state: State<'_, SimState>) -> Result<ScanProfile, String> {
+    Ok(state.host().scan_profile())
+}
+
+/// Reset accumulated profile statistics
+#[tauri::command]
+pub fn sim_reset_profile(state: State<'_, SimState>) -> Result<(), String> {
+    state.host().reset_scan_profile();
+    Ok(())
+}
+
+/// Enable or disable per-instruction timing
+#[tauri::command]
+pub fn sim_set_instruction_profiling(
+    state: State<'_, SimState>,
+    enabled: bool,
+) -> Result<(), String> {
+    state.host().set_instruction_profiling(enabled)
+}
+
+/// Load a ladder program for simulation. Returns the instructions the
+/// project's CPU model does not support (the program still loads).
 #[tauri::command]
 pub fn sim_load_program(
     state: State<'_, SimState>,
     project_state: State<'_, SharedProjectManager>,
     program: LadderProgram,
-) -> Result<(), String> {
+) -> Result<Vec<UnsupportedInstruction>, String> {
     let plc_settings = active_plc_settings(Some(&project_state))?;
     let profile = resolve_vendor_profile(&plc_settings).map_err(|e| e.to_string())?;
     let compiled = compile_program(&program, profile.as_ref()).map_err(|e| e.to_string())?;
+    // 모델에 없는 명령도 시뮬레이션은 하되, 경고로 돌려준다
+    let warnings = match plc_model::resolve_cpu_model(&plc_settings) {
+        Ok(Some(model)) => unsupported_instructions(&program, model),
+        _ => Vec::new(),
+    };
+    for warning in &warnings {
+        log::warn!("{}", warning.message);
+    }
     state.host().load_program(compiled);
-    Ok(())
+    Ok(warnings)
+}
+
+/// Target vendor for address translation, with optional user mapping rules
+/// tried before the canonical mapping
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslationTarget {
+    pub plc_manufacturer: String,
+    #[serde(default)]
+    pub plc_model: String,
+    #[serde(default)]
+    pub mapping_table: AddressMappingTable,
+}
+
+/// Translated ladder program and the operands that could not be mapped
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslatedProgram {
+    pub program: LadderProgram,
+    pub report: TranslationReport,
+}
+
+/// Translated tag list and the addresses that could not be mapped
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranslatedTags {
+    pub tags: Vec<TagDefinition>,
+    pub report: TranslationReport,
+}
+
+/// Rewrite a ladder program written for the project's PLC into another
+/// vendor's addresses. Unmappable operands are kept and reported.
+#[tauri::command]
+pub fn sim_translate_program(
+    project_state: State<'_, SharedProjectManager>,
+    program: LadderProgram,
+    target: TranslationTarget,
+) -> Result<TranslatedProgram, String> {
+    let (source, target_profile) = translation_profiles(&project_state, &target)?;
+    let translator = AddressTranslator::new(
+        source.as_ref(),
+        target_profile.as_ref(),
+        &target.mapping_table,
+    );
+    let (program, report) = translate_program(&program, &translator);
+    Ok(TranslatedProgram { program, report })
+}
+
+/// Rewrite the registered tags' addresses and vendor aliases for another
+/// vendor. The registry itself is left unchanged.
+#[tauri::command]
+pub fn sim_translate_tags(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    target: TranslationTarget,
+    include_raw: Option<bool>,
+) -> Result<TranslatedTags, String> {
+    let (source, target_profile) = translation_profiles(&project_state, &target)?;
+    let translator = AddressTranslator::new(
+        source.as_ref(),
+        target_profile.as_ref(),
+        &target.mapping_table,
+    );
+    let tags = state.tag_registry().list(include_raw.unwrap_or(false));
+    let (tags, report) = translate_tags(&tags, &translator);
+    Ok(TranslatedTags { tags, report })
+}
+
+fn translation_profiles(
+    project_state: &State<'_, SharedProjectManager>,
+    target: &TranslationTarget,
+) -> Result<(Box<dyn VendorProfile>, Box<dyn VendorProfile>), String> {
+    let source = resolve_vendor_profile(&active_plc_settings(Some(project_state))?)
+        .map_err(|e| e.to_string())?;
+    let manufacturer: PlcManufacturer = target
+        .plc_manufacturer
+        .parse()
+        .map_err(|e: String| format!("Invalid PLC manufacturer: {}", e))?;
+    let target = resolve_vendor_profile(&PlcSettings::for_model(
+        manufacturer,
+        target.plc_model.clone(),
+    ))
+    .map_err(|e| e.to_string())?;
+    Ok((source, target))
+}
+
+/// List the primary CPU and every additional virtual CPU
+#[tauri::command]
+pub fn sim_list_cpus(state: State<'_, SimState>) -> Vec<CpuInfo> {
+    state.host().cpus().list()
+}
+
+/// Add a virtual CPU with its own memory. It starts with the next run.
+#[tauri::command]
+pub fn sim_add_cpu(state: State<'_, SimState>, cpu_id: String) -> Result<Vec<CpuInfo>, String> {
+    let cpus = state.host().cpus();
+    cpus.add_virtual_cpu(CpuId::from(cpu_id))?;
+    Ok(cpus.list())
+}
+
+/// Add a real CPU mirrored over Modbus TCP. It connects with the next run.
+#[tauri::command]
+pub fn sim_add_modbus_cpu(
+    state: State<'_, SimState>,
+    cpu_id: String,
+    config: ModbusMasterConfig,
+) -> Result<Vec<CpuInfo>, String> {
+    let cpus = state.host().cpus();
+    cpus.add_real_cpu(CpuId::from(cpu_id), |runtime| {
+        let master = ModbusTcpMaster::new(config, runtime.handle()).map_err(|e| e.to_string())?;
+        Ok(Arc::new(master) as Arc<dyn FieldLink>)
+    })?;
+    Ok(cpus.list())
+}
+
+/// Add a real CPU mirrored from an OPC UA server. It connects with the next run.
+#[tauri::command]
+pub fn sim_add_opcua_cpu(
+    state: State<'_, SimState>,
+    cpu_id: String,
+    config: OpcUaClientConfig,
+) -> Result<Vec<CpuInfo>, String> {
+    let cpus = state.host().cpus();
+    cpus.add_real_cpu(CpuId::from(cpu_id), |runtime| {
+        let link = OpcUaClientLink::new(config, runtime.handle()).map_err(|e| e.to_string())?;
+        Ok(Arc::new(link) as Arc<dyn FieldLink>)
+    })?;
+    Ok(cpus.list())
+}
+
+/// Remove an additional CPU
+#[tauri::command]
+pub fn sim_remove_cpu(state: State<'_, SimState>, cpu_id: String) -> Result<Vec<CpuInfo>, String> {
+    let cpus = state.host().cpus();
+    cpus.remove_cpu(&CpuId::from(cpu_id))?;
+    Ok(cpus.list())
+}
+
+/// Compile and load a ladder program into a specific CPU
+#[tauri::command]
+pub fn sim_load_cpu_program(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    cpu_id: String,
+    program: LadderProgram,
+) -> Result<(), String> {
+    let plc_settings = active_plc_settings(Some(&project_state))?;
+    let profile = resolve_vendor_profile(&plc_settings).map_err(|e| e.to_string())?;
+    let compiled = compile_program(&program, profile.as_ref()).map_err(|e| e.to_string())?;
+
+    let host = state.host();
+    let cpu_id = CpuId::from(cpu_id);
+    if &cpu_id == host.cpus().primary_id() {
+        host.load_program(compiled);
+        Ok(())
+    } else {
+        host.cpus().load_program(&cpu_id, compiled)
+    }
+}
+
+/// Get the inter-CPU memory links
+#[tauri::command]
+pub fn sim_get_links(state: State<'_, SimState>) -> Vec<MemoryLink> {
+    state.host().cpus().links()
+}
+
+/// Replace the inter-CPU memory links. Overlapping destinations and
+/// circular links are rejected.
+/// Async so that restarting the link tasks runs on the tokio runtime.
+#[tauri::command]
+pub async fn sim_set_links(
+    state: State<'_, SimState>,
+    links: Vec<MemoryLink>,
+) -> Result<(), String> {
+    state.host().cpus().set_links(links)
 }
 
 // ============================================================================
@@ -360,6 +598,26 @@ fn current_project_config(
         .map(|project| project.config.clone()))
 }
 
+/// 스냅샷은 폴더 기반 프로젝트의 `snapshots/`에만 저장한다(레거시 ZIP 미지원).
+fn current_snapshots_dir(
+    project_state: &State<'_, SharedProjectManager>,
+) -> Result<PathBuf, String> {
+    let manager = project_state
+        .lock()
+        .map_err(|e| format!("Failed to acquire project manager lock: {}", e))?;
+
+    match manager
+        .get_current_project()
+        .map(|project| &project.storage)
+    {
+        Some(ProjectStorage::Folder(folder)) => Ok(folder.snapshots_dir()),
+        Some(ProjectStorage::LegacyZip(_)) => {
+            Err("Snapshots require a folder-based project".to_string())
+        }
+        None => Err("No project is open".to_string()),
+    }
+}
+
 fn resolve_sim_address(
     project_state: Option<&State<'_, SharedProjectManager>>,
     address: &str,
@@ -545,6 +803,63 @@ pub fn sim_write_binding(
     Ok(())
 }
 
+/// Read a multi-word value (INT32/UINT32/REAL/LREAL/STRING) starting at a binding
+#[tauri::command]
+pub fn sim_read_typed(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    request: WatchBindingRequest,
+    data_type: TypedDataType,
+    word_order: Option<WordOrder>,
+) -> Result<TypedValue, String> {
+    let (binding, _) = resolve_runtime_binding(Some(&project_state), &request)?;
+    let canonical = resolve_binding_to_canonical(&state.tag_registry(), &binding)?;
+    state
+        .runtime()
+        .read_typed(canonical, data_type, word_order.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+/// Write a multi-word value atomically so clients never observe a torn value
+#[tauri::command]
+pub fn sim_write_typed(
+    app: AppHandle,
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    request: WatchBindingRequest,
+    data_type: TypedDataType,
+    value: TypedValue,
+    word_order: Option<WordOrder>,
+) -> Result<(), String> {
+    let (binding, display_address) = resolve_runtime_binding(Some(&project_state), &request)?;
+    let canonical = resolve_binding_to_canonical(&state.tag_registry(), &binding)?;
+    let order = word_order.unwrap_or_default();
+    let runtime = state.runtime();
+    let old_value = runtime
+        .read_typed(canonical, data_type, order)
+        .map_err(|e| e.to_string())?;
+    runtime
+        .write_typed(
+            canonical,
+            data_type,
+            &value,
+            order,
+            CanonicalWriteSource::Simulation,
+        )
+        .map_err(|e| e.to_string())?;
+
+    let _ = app.emit(
+        SIM_DEVICE_CHANGE_EVENT,
+        serde_json::json!({
+            "address": display_address,
+            "binding": binding,
+            "oldValue": old_value,
+            "newValue": value
+        }),
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -564,6 +879,8 @@ mod tests {
             model: "XGK".to_string(),
             scan_time_ms: 10,
             hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: Default::default(),
+            memory_sections: Default::default(),
         };
 
         let (normalized, resolved) =
@@ -583,6 +900,8 @@ mod tests {
             model: "FX5U".to_string(),
             scan_time_ms: 10,
             hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: Default::default(),
+            memory_sections: Default::default(),
         };
 
         let (_, x) = resolve_sim_address_for_settings(&settings, "X10").expect("X should map");
@@ -605,6 +924,8 @@ mod tests {
             model: "XBC-DN32H".to_string(),
             scan_time_ms: 10,
             hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: Default::default(),
+            memory_sections: Default::default(),
         };
 
         let (_, input) = resolve_sim_address_for_settings(&settings, "P0019").expect("P19");
@@ -620,6 +941,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn removes_expression_watch_added_through_command_path() {
+        let settings = PlcSettings {
+            manufacturer: PlcManufacturer::LS,
+            model: "XGK".to_string(),
+            scan_time_ms: 10,
+            hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: Default::default(),
+            memory_sections: Default::default(),
+        };
+        let profile = resolve_vendor_profile(&settings).unwrap();
+        let debugger = SimDebugger::new(10);
+        let runtime = CanonicalRuntimeFacade::new();
+
+        add_expression_watch(&debugger, "D0100 > 10", None, profile.as_ref(), &runtime)
+            .expect("expression watch");
+        assert_eq!(debugger.get_watches().len(), 1);
+
+        remove_expression_watch(&debugger, "  D0100 > 10 ").expect("remove expression watch");
+        assert!(debugger.get_watches().is_empty());
+        assert!(remove_expression_watch(&debugger, "D0100 > 10").is_err());
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn opcua_running_session_freezes_tag_namespace_mutations() {
         let settings = PlcSettings {
@@ -627,6 +971,8 @@ mod tests {
             model: "XGK".to_string(),
             scan_time_ms: 10,
             hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: Default::default(),
+            memory_sections: Default::default(),
         };
         let profile = resolve_vendor_profile(&settings).unwrap();
         let canonical_memory = Arc::new(RwLock::new(CanonicalMemory::new()));
@@ -815,8 +1161,12 @@ pub fn sim_add_breakpoint(
         }
     }
 
-    let id = state.debugger().add_breakpoint(breakpoint);
-    Ok(id)
+    let plc_settings = active_plc_settings(Some(&project_state))?;
+    let profile = resolve_vendor_profile(&plc_settings).map_err(|e| e.to_string())?;
+    state
+        .debugger()
+        .add_breakpoint(breakpoint, profile.as_ref())
+        .map_err(|e| e.to_string())
 }
 
 /// Remove a breakpoint
@@ -855,15 +1205,36 @@ pub fn sim_add_watch(
     project_state: State<'_, SharedProjectManager>,
     request: WatchBindingRequest,
 ) -> Result<(), String> {
+    if let Some(expression) = request.expression.as_deref() {
+        let plc_settings = active_plc_settings(Some(&project_state))?;
+        let profile = resolve_vendor_profile(&plc_settings).map_err(|e| e.to_string())?;
+        let engine_arc = state.engine();
+        let engine_guard = engine_arc.lock();
+        let runtime = engine_guard
+            .as_ref()
+            .map(|engine| Arc::clone(engine.runtime()))
+            .unwrap_or_else(|| Arc::new(CanonicalRuntimeFacade::new()));
+        return add_expression_watch(
+            &state.debugger(),
+            expression,
+            request.format.as_ref(),
+            profile.as_ref(),
+            &runtime,
+        );
+    }
+
     let (binding, display_address) = resolve_runtime_binding(Some(&project_state), &request)?;
     let tracked_display_address = display_address.clone();
     let engine_arc = state.engine();
     let engine_guard = engine_arc.lock();
 
     if let Some(ref engine) = *engine_guard {
-        state
-            .debugger()
-            .add_watch_binding(binding.clone(), display_address, engine.runtime());
+        state.debugger().add_typed_watch_binding(
+            binding.clone(),
+            display_address,
+            request.format,
+            engine.runtime(),
+        );
         state
             .host()
             .monitoring()
@@ -872,9 +1243,12 @@ pub fn sim_add_watch(
     } else {
         // Add watch without initial value
         let runtime = CanonicalRuntimeFacade::new();
-        state
-            .debugger()
-            .add_watch_binding(binding.clone(), display_address, &runtime);
+        state.debugger().add_typed_watch_binding(
+            binding.clone(),
+            display_address,
+            request.format,
+            &runtime,
+        );
         state
             .host()
             .monitoring()
@@ -890,6 +1264,10 @@ pub fn sim_remove_watch(
     project_state: State<'_, SharedProjectManager>,
     request: WatchBindingRequest,
 ) -> Result<(), String> {
+    if let Some(expression) = request.expression.as_deref() {
+        return remove_expression_watch(&state.debugger(), expression);
+    }
+
     let (binding, _) = resolve_runtime_binding(Some(&project_state), &request)?;
     state.host().monitoring().unregister_binding(&binding);
     state
@@ -898,32 +1276,75 @@ pub fn sim_remove_watch(
         .map_err(|e| e.to_string())
 }
 
+fn add_expression_watch(
+    debugger: &SimDebugger,
+    expression: &str,
+    format: Option<&WatchFormat>,
+    profile: &dyn VendorProfile,
+    runtime: &CanonicalRuntimeFacade,
+) -> Result<(), String> {
+    debugger
+        .add_watch_expression(
+            expression,
+            format.map(|format| format.word_order),
+            profile,
+            runtime,
+        )
+        .map_err(|e| e.to_string())
+}
+
+// 식 감시는 컴파일된 식의 원문(trim)으로 저장되므로 같은 키의 식 바인딩으로 제거한다.
+fn remove_expression_watch(debugger: &SimDebugger, expression: &str) -> Result<(), String> {
+    debugger
+        .remove_watch_binding(&RuntimeBinding::expression(expression.trim()))
+        .map_err(|e| e.to_string())
+}
+
 /// Get all watch variables
 #[tauri::command]
 pub fn sim_get_watches(state: State<'_, SimState>) -> Result<Vec<WatchVariable>, String> {
     Ok(state.debugger().get_watches())
 }
 
-/// Step execution (network or scan)
+/// Export watch history as CSV (timestamps and scan numbers)
+#[tauri::command]
+pub fn sim_export_watch_history(state: State<'_, SimState>) -> Result<String, String> {
+    Ok(state.debugger().export_watch_history_csv())
+}
+
+/// Step execution (network or scan), forwards or backwards through the scan history
 #[tauri::command]
 pub fn sim_step(
     app: AppHandle,
     state: State<'_, SimState>,
     step_type: StepType,
+    direction: Option<StepDirection>,
 ) -> Result<StepResult, String> {
-    let engine_arc = state.engine();
-    let engine_guard = engine_arc.lock();
-
-    let engine = engine_guard.as_ref().ok_or("Simulation is not running")?;
+    let direction = direction.unwrap_or_default();
 
     // Enable step mode
     state.debugger().enable_step_mode(step_type);
 
-    // Execute single scan (step type determines granularity in future)
-    engine.single_scan().map_err(|e| e.to_string())?;
+    let rewound = state
+        .host()
+        .history_position()
+        .is_some_and(|position| !position.at_latest);
+    let history_position = match direction {
+        StepDirection::Backward => Some(state.host().step_back()?),
+        StepDirection::Forward if rewound => Some(state.host().step_forward()?),
+        StepDirection::Forward => {
+            let engine_arc = state.engine();
+            let engine_guard = engine_arc.lock();
+            let engine = engine_guard.as_ref().ok_or("Simulation is not running")?;
+
+            // Execute single scan (step type determines granularity in future)
+            engine.single_scan().map_err(|e| e.to_string())?;
+            engine.history().position()
+        }
+    };
 
     // Get status
-    let status = engine.get_status();
+    let status = state.host().status();
 
     // Build step result
     let result = StepResult {
@@ -932,6 +1353,7 @@ pub fn sim_step(
         network_id: None, // Would need to track current network
         scan_count: status.scan_count,
         breakpoint_hit: state.debugger().get_pause_state(),
+        history_position,
     };
 
     // Emit status update
@@ -956,6 +1378,535 @@ pub fn sim_step(
     Ok(result)
 }
 
+/// Rewind to the scan just before the address last changed
+#[tauri::command]
+pub fn sim_run_back_to_change(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    address: String,
+) -> Result<ValueChangeLocation, String> {
+    let (_, canonical) = resolve_sim_address(Some(&project_state), &address)?;
+    state.host().run_back_to_change(canonical)
+}
+
+/// Get the current position in the scan history
+#[tauri::command]
+pub fn sim_get_history_position(state: State<'_, SimState>) -> Option<HistoryPosition> {
+    state.host().history_position()
+}
+
+/// Enable/disable scan checkpoints and set how many scans are kept
+#[tauri::command]
+pub fn sim_configure_history(
+    state: State<'_, SimState>,
+    enabled: bool,
+    depth: Option<usize>,
+) -> Result<(), String> {
+    state.host().configure_history(enabled, depth)
+}
+
+/// Inject a field-device fault (stuck-at, flicker, noise, spike, delay) on an input
+#[tauri::command]
+pub fn sim_inject_input_fault(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    address: String,
+    fault: InjectedFaultKind,
+    schedule: Option<InjectionSchedule>,
+) -> Result<u64, String> {
+    let (label, canonical) = resolve_sim_address(Some(&project_state), &address)?;
+    state.host().inject_input_fault(InjectionRequest {
+        address: canonical,
+        fault,
+        schedule: schedule.unwrap_or_default(),
+        label: Some(label),
+    })
+}
+
+/// Clear one injected fault (or all when `id` is omitted)
+#[tauri::command]
+pub fn sim_clear_input_fault(state: State<'_, SimState>, id: Option<u64>) -> Result<(), String> {
+    state.host().clear_input_faults(id)
+}
+
+/// List scheduled and active injected faults
+#[tauri::command]
+pub fn sim_list_input_faults(state: State<'_, SimState>) -> Vec<InjectedFaultInfo> {
+    state.host().list_input_faults()
+}
+
+/// Analog module definition with vendor-notation addresses
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalogModuleRequest {
+    pub id: String,
+    pub direction: AnalogDirection,
+    /// Channel 0 value word (e.g. `U1\G11`, `D100`); channel n follows at +n
+    pub values: String,
+    /// Status words; when both are omitted they follow the channel values
+    #[serde(default)]
+    pub range_status: Option<String>,
+    #[serde(default)]
+    pub wire_break_status: Option<String>,
+    pub channels: Vec<AnalogChannelRequest>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalogChannelRequest {
+    #[serde(flatten)]
+    pub config: AnalogChannelConfig,
+    /// Word written by the canvas or scenarios that drives this input channel
+    #[serde(default)]
+    pub source_address: Option<String>,
+    /// Engineering units per count of the source word
+    #[serde(default)]
+    pub source_scale: Option<f64>,
+    #[serde(default)]
+    pub source_signed: bool,
+}
+
+/// Add or replace a simulated analog I/O module
+#[tauri::command]
+pub fn sim_configure_analog_module(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    request: AnalogModuleRequest,
+) -> Result<(), String> {
+    let resolve = |address: &str| {
+        resolve_sim_address(Some(&project_state), address).map(|(_, canonical)| canonical)
+    };
+
+    let values = resolve(&request.values)?;
+    let layout = if request.range_status.is_none() && request.wire_break_status.is_none() {
+        AnalogWordLayout::contiguous(values, request.channels.len())
+    } else {
+        AnalogWordLayout {
+            values,
+            range_status: request.range_status.as_deref().map(resolve).transpose()?,
+            wire_break_status: request.wire_break_status.as_deref().map(resolve).transpose()?,
+        }
+    };
+
+    let mut channels = Vec::with_capacity(request.channels.len());
+    for channel in request.channels {
+        let mut config = channel.config;
+        if let Some(address) = channel.source_address.as_deref() {
+            config.source = Some(AnalogSource {
+                address: resolve(address)?,
+                scale: channel.source_scale.unwrap_or(1.0),
+                signed: channel.source_signed,
+            });
+        }
+        channels.push(config);
+    }
+
+    state.host().configure_analog_module(AnalogModuleConfig {
+        id: request.id,
+        direction: request.direction,
+        layout,
+        channels,
+    })
+}
+
+/// Remove a simulated analog module
+#[tauri::command]
+pub fn sim_remove_analog_module(state: State<'_, SimState>, id: String) -> Result<(), String> {
+    state.host().remove_analog_module(&id)
+}
+
+/// Set the engineering value (mA/V) of an analog input channel
+#[tauri::command]
+pub fn sim_set_analog_value(
+    state: State<'_, SimState>,
+    id: String,
+    channel: usize,
+    value: f64,
+) -> Result<(), String> {
+    state.host().set_analog_value(&id, channel, value)
+}
+
+/// Simulate a cut (or repaired) cable on an analog channel
+#[tauri::command]
+pub fn sim_set_analog_wire_break(
+    state: State<'_, SimState>,
+    id: String,
+    channel: usize,
+    broken: bool,
+) -> Result<(), String> {
+    state.host().set_analog_wire_break(&id, channel, broken)
+}
+
+/// List simulated analog modules with their channel values and diagnostics
+#[tauri::command]
+pub fn sim_list_analog_modules(state: State<'_, SimState>) -> Vec<AnalogModuleInfo> {
+    state.host().list_analog_modules()
+}
+
+/// High-speed counter channel definition with vendor-notation addresses
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HscChannelRequest {
+    pub id: String,
+    /// Current value double word (e.g. `D1000`, `U1\G24`)
+    pub count: String,
+    #[serde(default)]
+    pub word_order: WordOrder,
+    #[serde(default)]
+    pub mode: HscCountMode,
+    #[serde(default)]
+    pub enable: Option<String>,
+    /// Double word holding the encoder frequency in Hz
+    #[serde(default)]
+    pub frequency_source: Option<String>,
+    #[serde(default)]
+    pub preset_value: i32,
+    #[serde(default)]
+    pub preset_trigger: Option<String>,
+    #[serde(default)]
+    pub compares: Vec<HscCompareRequest>,
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HscCompareRequest {
+    pub lower: i32,
+    #[serde(default)]
+    pub upper: Option<i32>,
+    pub output: String,
+}
+
+/// Add or replace a simulated high-speed counter channel
+#[tauri::command]
+pub fn sim_configure_hsc_channel(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    request: HscChannelRequest,
+) -> Result<(), String> {
+    let resolve = |address: &str| {
+        resolve_sim_address(Some(&project_state), address).map(|(_, canonical)| canonical)
+    };
+
+    let preset = match request.preset_trigger.as_deref() {
+        Some(trigger) => Some(HscPreset {
+            value: request.preset_value,
+            trigger: resolve(trigger)?,
+        }),
+        None => None,
+    };
+    let compares = request
+        .compares
+        .iter()
+        .map(|compare| {
+            Ok(HscCompare {
+                lower: compare.lower,
+                upper: compare.upper,
+                output: resolve(&compare.output)?,
+            })
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+
+    state.host().configure_hsc_channel(HscChannelConfig {
+        id: request.id,
+        count: resolve(&request.count)?,
+        word_order: request.word_order,
+        mode: request.mode,
+        enable: request.enable.as_deref().map(resolve).transpose()?,
+        frequency_source: request.frequency_source.as_deref().map(resolve).transpose()?,
+        preset,
+        compares,
+        status: request.status.as_deref().map(resolve).transpose()?,
+    })
+}
+
+/// Remove a simulated high-speed counter channel
+#[tauri::command]
+pub fn sim_remove_hsc_channel(state: State<'_, SimState>, id: String) -> Result<(), String> {
+    state.host().remove_hsc_channel(&id)
+}
+
+/// Set the encoder frequency (Hz) driving a counter channel
+#[tauri::command]
+pub fn sim_set_hsc_frequency(
+    state: State<'_, SimState>,
+    id: String,
+    frequency_hz: f64,
+) -> Result<(), String> {
+    state.host().set_hsc_frequency(&id, frequency_hz)
+}
+
+/// List simulated counter channels with their counts and flags
+#[tauri::command]
+pub fn sim_list_hsc_channels(state: State<'_, SimState>) -> Vec<HscChannelInfo> {
+    state.host().list_hsc_channels()
+}
+
+/// Positioning axis definition with vendor-notation addresses
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AxisRequest {
+    pub id: String,
+    #[serde(default)]
+    pub word_order: WordOrder,
+    pub start: String,
+    #[serde(default)]
+    pub stop: Option<String>,
+    pub target_position: String,
+    pub target_speed: String,
+    /// Pulses/s²
+    pub acceleration: f64,
+    pub current_position: String,
+    #[serde(default)]
+    pub current_speed: Option<String>,
+    pub busy: String,
+    pub done: String,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub forward_limit: Option<String>,
+    #[serde(default)]
+    pub reverse_limit: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+}
+
+/// Add or replace a simulated positioning axis
+#[tauri::command]
+pub fn sim_configure_axis(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    request: AxisRequest,
+) -> Result<(), String> {
+    let resolve = |address: &str| {
+        resolve_sim_address(Some(&project_state), address).map(|(_, canonical)| canonical)
+    };
+    let resolve_optional =
+        |address: &Option<String>| address.as_deref().map(resolve).transpose();
+
+    state.host().configure_axis(AxisConfig {
+        id: request.id.clone(),
+        word_order: request.word_order,
+        start: resolve(&request.start)?,
+        stop: resolve_optional(&request.stop)?,
+        target_position: resolve(&request.target_position)?,
+        target_speed: resolve(&request.target_speed)?,
+        acceleration: request.acceleration,
+        current_position: resolve(&request.current_position)?,
+        current_speed: resolve_optional(&request.current_speed)?,
+        busy: resolve(&request.busy)?,
+        done: resolve(&request.done)?,
+        error: resolve_optional(&request.error)?,
+        forward_limit: resolve_optional(&request.forward_limit)?,
+        reverse_limit: resolve_optional(&request.reverse_limit)?,
+        status: resolve_optional(&request.status)?,
+    })
+}
+
+/// Remove a simulated positioning axis
+#[tauri::command]
+pub fn sim_remove_axis(state: State<'_, SimState>, id: String) -> Result<(), String> {
+    state.host().remove_axis(&id)
+}
+
+/// List simulated positioning axes with position, speed and status flags
+#[tauri::command]
+pub fn sim_list_axes(state: State<'_, SimState>) -> Vec<AxisInfo> {
+    state.host().list_axes()
+}
+
+/// Result of restoring a snapshot
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotRestoreResult {
+    pub snapshot: SnapshotInfo,
+    /// Whether the saved scenario position was applied to the loaded scenario
+    pub scenario_restored: bool,
+}
+
+/// Save the full PLC state (memory, timers, counters, edges, forces and
+/// scenario position) as a named snapshot in the project folder
+#[tauri::command]
+pub fn sim_save_snapshot(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    scenario_state: State<'_, ScenarioExecutorState>,
+    name: String,
+    description: Option<String>,
+) -> Result<SnapshotInfo, String> {
+    let dir = current_snapshots_dir(&project_state)?;
+    let mut snapshot = state
+        .host()
+        .capture_snapshot(name, description.unwrap_or_default())?;
+    // 시나리오 실행 태스크가 락을 쥐고 있으면 위치 없이 저장한다
+    match scenario_state.executor.try_lock() {
+        Ok(guard) => snapshot.scenario = guard.as_ref().and_then(|executor| executor.position()),
+        Err(_) => log::warn!("Scenario executor is busy; saving without scenario position"),
+    }
+
+    snapshot_store::save_snapshot(&dir, &snapshot).map_err(|e| e.to_string())?;
+    Ok(snapshot.info())
+}
+
+/// Restore a named snapshot. The simulation must be stopped or paused.
+#[tauri::command]
+pub fn sim_restore_snapshot(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    scenario_state: State<'_, ScenarioExecutorState>,
+    runtime_audit: State<'_, RuntimeAuditState>,
+    name: String,
+) -> Result<SnapshotRestoreResult, String> {
+    let dir = current_snapshots_dir(&project_state)?;
+    let snapshot = snapshot_store::load_snapshot(&dir, &name).map_err(|e| e.to_string())?;
+    state.host().restore_snapshot(&snapshot)?;
+    runtime_audit.sim_event(
+        "sim_restore_snapshot",
+        &format!("Snapshot '{}' restored", snapshot.name),
+    );
+
+    let mut scenario_restored = false;
+    if let Some(position) = snapshot.scenario.as_ref() {
+        let result = match scenario_state.executor.try_lock() {
+            Ok(mut guard) => guard
+                .as_mut()
+                .ok_or_else(|| "no scenario loaded".to_string())
+                .and_then(|executor| executor.restore_position(position)),
+            Err(_) => Err("scenario executor is busy".to_string()),
+        };
+        match result {
+            Ok(()) => scenario_restored = true,
+            Err(err) => log::warn!("Scenario position not restored: {}", err),
+        }
+    }
+
+    Ok(SnapshotRestoreResult {
+        snapshot: snapshot.info(),
+        scenario_restored,
+    })
+}
+
+/// List the snapshots saved in the current project, newest first
+#[tauri::command]
+pub fn sim_list_snapshots(
+    project_state: State<'_, SharedProjectManager>,
+) -> Result<Vec<SnapshotInfo>, String> {
+    let dir = current_snapshots_dir(&project_state)?;
+    snapshot_store::list_snapshots(&dir).map_err(|e| e.to_string())
+}
+
+/// Delete a named snapshot
+#[tauri::command]
+pub fn sim_delete_snapshot(
+    project_state: State<'_, SharedProjectManager>,
+    name: String,
+) -> Result<(), String> {
+    let dir = current_snapshots_dir(&project_state)?;
+    snapshot_store::delete_snapshot(&dir, &name).map_err(|e| e.to_string())
+}
+
+/// Compare a named snapshot with live memory, or with another named snapshot
+#[tauri::command]
+pub fn sim_diff_snapshot(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+    name: String,
+    against: Option<String>,
+) -> Result<MemoryDiff, String> {
+    let dir = current_snapshots_dir(&project_state)?;
+    let before = snapshot_store::load_snapshot(&dir, &name).map_err(|e| e.to_string())?;
+    let after = against
+        .map(|other| snapshot_store::load_snapshot(&dir, &other))
+        .transpose()
+        .map_err(|e| e.to_string())?;
+
+    let plc_settings = active_plc_settings(Some(&project_state))?;
+    let profile = resolve_vendor_profile(&plc_settings).map_err(|e| e.to_string())?;
+    state
+        .host()
+        .diff_snapshots(&before, after.as_ref(), profile.as_ref())
+}
+
+/// Remember live memory as the baseline for `sim_diff_memory_baseline`
+#[tauri::command]
+pub fn sim_mark_memory_baseline(state: State<'_, SimState>) -> String {
+    state.host().mark_memory_baseline()
+}
+
+/// Changes in live memory since the marked baseline (e.g. after an HMI action)
+#[tauri::command]
+pub fn sim_diff_memory_baseline(
+    state: State<'_, SimState>,
+    project_state: State<'_, SharedProjectManager>,
+) -> Result<MemoryDiff, String> {
+    let plc_settings = active_plc_settings(Some(&project_state))?;
+    let profile = resolve_vendor_profile(&plc_settings).map_err(|e| e.to_string())?;
+    state.host().diff_memory_baseline(profile.as_ref())
+}
+
+/// Start recording every canonical memory event
+#[tauri::command]
+pub async fn sim_start_recording(state: State<'_, SimState>) -> Result<(), String> {
+    let host = state.host();
+    host.recording().start_recording(host.runtime())
+}
+
+/// Stop recording and write the event stream to `path` (JSON Lines)
+#[tauri::command]
+pub async fn sim_stop_recording(
+    state: State<'_, SimState>,
+    path: String,
+) -> Result<RecordingHeader, String> {
+    let host = state.host();
+    let recording = host.recording().stop_recording().await?;
+    let file = std::fs::File::create(&path).map_err(|e| e.to_string())?;
+    recording
+        .write_to(std::io::BufWriter::new(file))
+        .map_err(|e| e.to_string())?;
+    Ok(recording.header)
+}
+
+/// Replay the external/simulation writes of a recording. `fresh` clears
+/// memory first; `speed` scales time (default 1.0).
+#[tauri::command]
+pub async fn sim_replay_recording(
+    app: AppHandle,
+    state: State<'_, SimState>,
+    runtime_audit: State<'_, RuntimeAuditState>,
+    path: String,
+    speed: Option<f64>,
+    fresh: Option<bool>,
+) -> Result<ReplayProgress, String> {
+    let file = std::fs::File::open(&path).map_err(|e| e.to_string())?;
+    let recording =
+        EventRecording::read_from(std::io::BufReader::new(file)).map_err(|e| e.to_string())?;
+
+    let host = state.host();
+    if fresh.unwrap_or(false) {
+        host.runtime().clear_all();
+    }
+    let progress = host.recording().start_replay(
+        app,
+        Arc::clone(host.runtime()),
+        recording,
+        speed.unwrap_or(1.0),
+        host.runtime_output_addresses(),
+    )?;
+    runtime_audit.sim_event(
+        "sim_replay_recording",
+        &format!("Replaying {} events from '{}'", progress.total, path),
+    );
+    Ok(progress)
+}
+
+/// Abort a running replay
+#[tauri::command]
+pub fn sim_stop_replay(state: State<'_, SimState>) -> Result<(), String> {
+    state.host().recording().stop_replay();
+    Ok(())
+}
+
 /// Continue execution after pause
 #[tauri::command]
 pub fn sim_continue(
@@ -97,6 +97,7 @@ use commands::{
     ladder_start_monitoring,
     ladder_stop_monitoring,
     list_layouts,
+    list_plc_cpu_models,
     // Explorer commands
     list_project_files,
     load_layout,
@@ -247,33 +248,78 @@ use commands::{
     set_last_active_layout,
     set_restore_last_session,
     sim_add_breakpoint,
+    sim_add_cpu,
+    sim_add_modbus_cpu,
+    sim_add_opcua_cpu,
     sim_add_watch,
+    sim_clear_input_fault,
+    sim_configure_analog_module,
+    sim_configure_axis,
+    sim_configure_history,
+    sim_configure_hsc_channel,
     sim_continue,
     sim_create_raw_tag,
+    sim_delete_snapshot,
+    sim_diff_memory_baseline,
+    sim_diff_snapshot,
+    sim_export_watch_history,
     sim_get_breakpoints,
     sim_get_debugger_state,
+    sim_get_history_position,
+    sim_get_links,
     sim_get_memory_snapshot,
+    sim_get_profile,
     sim_get_scan_info,
     sim_get_status,
     sim_get_tag,
     sim_get_watches,
+    sim_inject_input_fault,
+    sim_list_analog_modules,
+    sim_list_axes,
+    sim_list_cpus,
+    sim_list_hsc_channels,
+    sim_list_input_faults,
+    sim_list_snapshots,
     sim_list_tags,
+    sim_load_cpu_program,
     sim_load_program,
+    sim_mark_memory_baseline,
     sim_pause,
     sim_read_binding,
+    sim_read_typed,
     sim_register_tag,
+    sim_remove_analog_module,
+    sim_remove_axis,
     sim_remove_breakpoint,
+    sim_remove_cpu,
+    sim_remove_hsc_channel,
     sim_remove_tag,
     sim_remove_watch,
+    sim_replay_recording,
     sim_reset,
+    sim_reset_profile,
     sim_resolve_binding,
     sim_resolve_binding_parts,
+    sim_restore_snapshot,
     sim_resume,
     sim_run,
+    sim_run_back_to_change,
+    sim_save_snapshot,
+    sim_set_analog_value,
+    sim_set_analog_wire_break,
     sim_set_breakpoint_enabled,
+    sim_set_hsc_frequency,
+    sim_set_instruction_profiling,
+    sim_set_links,
+    sim_start_recording,
     sim_step,
     sim_stop,
+    sim_stop_recording,
+    sim_stop_replay,
+    sim_translate_program,
+    sim_translate_tags,
     sim_write_binding,
+    sim_write_typed,
     start_auto_save,
     stop_auto_save,
     symbol_delete,
@@ -582,6 +628,7 @@ pub fn run() {
             get_cli_project_path,
             // Project management commands
             create_project,
+            list_plc_cpu_models,
             open_project,
             save_project,
             close_project,
@@ -725,7 +772,12 @@ pub fn run() {
             sim_reset,
             sim_get_status,
             sim_get_scan_info,
+            sim_get_profile,
+            sim_reset_profile,
+            sim_set_instruction_profiling,
             sim_load_program,
+            sim_translate_program,
+            sim_translate_tags,
             sim_resolve_binding,
             sim_resolve_binding_parts,
             sim_register_tag,
@@ -735,6 +787,8 @@ pub fn run() {
             sim_create_raw_tag,
             sim_read_binding,
             sim_write_binding,
+            sim_read_typed,
+            sim_write_typed,
             sim_get_memory_snapshot,
             sim_add_breakpoint,
             sim_remove_breakpoint,
@@ -743,7 +797,45 @@ pub fn run() {
             sim_add_watch,
             sim_remove_watch,
             sim_get_watches,
+            sim_export_watch_history,
             sim_step,
+            sim_run_back_to_change,
+            sim_get_history_position,
+            sim_configure_history,
+            sim_inject_input_fault,
+            sim_clear_input_fault,
+            sim_list_input_faults,
+            sim_configure_analog_module,
+            sim_remove_analog_module,
+            sim_set_analog_value,
+            sim_set_analog_wire_break,
+            sim_list_analog_modules,
+            sim_configure_hsc_channel,
+            sim_remove_hsc_channel,
+            sim_set_hsc_frequency,
+            sim_list_hsc_channels,
+            sim_configure_axis,
+            sim_remove_axis,
+            sim_list_axes,
+            sim_save_snapshot,
+            sim_restore_snapshot,
+            sim_list_snapshots,
+            sim_delete_snapshot,
+            sim_diff_snapshot,
+            sim_mark_memory_baseline,
+            sim_diff_memory_baseline,
+            sim_start_recording,
+            sim_stop_recording,
+            sim_replay_recording,
+            sim_stop_replay,
+            sim_list_cpus,
+            sim_add_cpu,
+            sim_add_modbus_cpu,
+            sim_add_opcua_cpu,
+            sim_remove_cpu,
+            sim_load_cpu_program,
+            sim_get_links,
+            sim_set_links,
             sim_continue,
             sim_get_debugger_state,
             ladder_start_monitoring,
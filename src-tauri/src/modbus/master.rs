@@ -0,0 +1,294 @@
+//! Modbus TCP master `FieldLink`
+//!
+//! Mirrors a real (or remote ModOne) PLC into a CPU's canonical memory.
+//! Poll groups are read on their own interval; write groups are pushed back
+//! when the mirrored outputs change. `FieldLink` is synchronous, so this uses
+//! a blocking socket with timeouts and is driven from a blocking task.
+//!
+//! Polled values are written with source `ExternalProtocol`; a write
+//! protection rule denying that source on a polled range stops the mirroring
+//! and marks the link `Degraded` with the rejected write as its error.
+
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::{Mutex, RwLock};
+
+use modbus_codec::client::{
+    check_write_response, decode_mbap_header, encode_mbap, ClientError, ModbusMasterConfig,
+    ModbusMirror, MBAP_HEADER_SIZE,
+};
+use modone_contract::memory::CanonicalMemory;
+use modone_contract::{CpuHealth, FieldLink};
+
+type LinkError = Box<dyn std::error::Error + Send + Sync>;
+
+pub struct ModbusTcpMaster {
+    mirror: Mutex<ModbusMirror>,
+    stream: Mutex<Option<TcpStream>>,
+    transaction_id: AtomicU16,
+    last_poll: Mutex<Vec<Option<Instant>>>,
+    health: RwLock<CpuHealth>,
+}
+
+impl ModbusTcpMaster {
+    /// Create a master for `config`, mirroring into `memory`. Connects lazily.
+    pub fn new(
+        config: ModbusMasterConfig,
+        memory: Arc<RwLock<CanonicalMemory>>,
+    ) -> Result<Self, ClientError> {
+        let poll_groups = config.poll_groups.len();
+        Ok(Self {
+            mirror: Mutex::new(ModbusMirror::new(config, memory)?),
+            stream: Mutex::new(None),
+            transaction_id: AtomicU16::new(0),
+            last_poll: Mutex::new(vec![None; poll_groups]),
+            health: RwLock::new(CpuHealth::Disconnected),
+        })
+    }
+
+    pub fn config(&self) -> ModbusMasterConfig {
+        self.mirror.lock().config().clone()
+    }
+
+    fn connect(config: &ModbusMasterConfig) -> std::io::Result<TcpStream> {
+        let timeout = Duration::from_millis(config.timeout_ms.max(1) as u64);
+        let address = (config.host.as_str(), config.port)
+            .to_socket_addrs()?
+            .next()
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("Cannot resolve {}", config.host),
+                )
+            })?;
+        let stream = TcpStream::connect_timeout(&address, timeout)?;
+        stream.set_read_timeout(Some(timeout))?;
+        stream.set_write_timeout(Some(timeout))?;
+        stream.set_nodelay(true)?;
+        Ok(stream)
+    }
+
+    /// Send one request PDU and return the response PDU. Transport errors
+    /// drop the connection so the next call reconnects.
+    fn transact(&self, config: &ModbusMasterConfig, request: &[u8]) -> Result<Vec<u8>, LinkError> {
+        let mut stream = self.stream.lock();
+        let result = match stream.take() {
+            Some(socket) => Ok(socket),
+            None => Self::connect(config),
+        }
+        .map_err(LinkError::from)
+        .and_then(|mut socket| {
+            let response = self.exchange(&mut socket, config.unit_id, request)?;
+            *stream = Some(socket);
+            Ok(response)
+        });
+
+        if result.is_err() {
+            *self.health.write() = CpuHealth::Disconnected;
+        }
+        result
+    }
+
+    fn exchange(
+        &self,
+        socket: &mut TcpStream,
+        unit_id: u8,
+        request: &[u8],
+    ) -> Result<Vec<u8>, LinkError> {
+        let transaction_id = self.transaction_id.fetch_add(1, Ordering::Relaxed);
+        socket.write_all(&encode_mbap(transaction_id, unit_id, request))?;
+
+        let mut header = [0u8; MBAP_HEADER_SIZE];
+        socket.read_exact(&mut header)?;
+        let (response_id, _, pdu_len) = decode_mbap_header(&header)?;
+        let mut pdu = vec![0u8; pdu_len];
+        socket.read_exact(&mut pdu)?;
+
+        if response_id != transaction_id {
+            return Err(Box::new(ClientError::InvalidResponse(format!(
+                "transaction id {} does not match request {}",
+                response_id, transaction_id
+            ))));
+        }
+        Ok(pdu)
+    }
+}
+
+impl FieldLink for ModbusTcpMaster {
+    fn poll_device(&self) -> Result<(), LinkError> {
+        let config = self.config();
+        let now = Instant::now();
+        let mut polled = false;
+        let mut degraded = None;
+
+        for (index, group) in config.poll_groups.iter().enumerate() {
+            let interval = Duration::from_millis(group.interval_ms as u64);
+            let due = !matches!(
+                self.last_poll.lock()[index],
+                Some(last) if now.duration_since(last) < interval
+            );
+            if !due {
+                continue;
+            }
+            let request = self.mirror.lock().poll_request(index);
+            let response = self.transact(&config, &request)?;
+            // 통신이 실패한 그룹은 주기를 기다리지 않고 다음 폴에서 바로 다시 읽는다
+            self.last_poll.lock()[index] = Some(now);
+            polled = true;
+            // 예외 응답은 연결은 살아 있으므로 Degraded 로 두고 다른 그룹은 계속 읽는다
+            if let Err(err) = self.mirror.lock().apply_poll(index, &response) {
+                degraded = Some(err);
+            }
+        }
+
+        if let Some(err) = degraded {
+            *self.health.write() = CpuHealth::Degraded;
+            return Err(Box::new(err));
+        }
+        if polled {
+            *self.health.write() = CpuHealth::Good;
+        }
+        Ok(())
+    }
+
+    fn write_device(&self) -> Result<(), LinkError> {
+        let config = self.config();
+        let pending = self.mirror.lock().pending_writes()?;
+        for write in pending {
+            for request in &write.requests {
+                let response = self.transact(&config, request)?;
+                check_write_response(request, &response)?;
+            }
+            self.mirror.lock().commit(write);
+        }
+        Ok(())
+    }
+
+    fn health(&self) -> CpuHealth {
+        *self.health.read()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use modbus_codec::client::{PollGroup, ReadFunction, WriteFunction, WriteGroup};
+    use modbus_codec::{pdu, MemoryMapSettings, ModbusMemory};
+    use modone_contract::{CanonicalAddress, CanonicalAreaKind, CanonicalValue};
+    use std::net::TcpListener;
+
+    /// Minimal blocking Modbus TCP device backed by `ModbusMemory`
+    fn spawn_device(device: Arc<ModbusMemory>) -> u16 {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        std::thread::spawn(move || {
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut header = [0u8; MBAP_HEADER_SIZE];
+            while socket.read_exact(&mut header).is_ok() {
+                let (transaction_id, unit_id, pdu_len) = decode_mbap_header(&header).unwrap();
+                let mut request = vec![0u8; pdu_len];
+                socket.read_exact(&mut request).unwrap();
+                let response = pdu::process_request(&device, request[0], &request);
+                socket
+                    .write_all(&encode_mbap(transaction_id, unit_id, &response))
+                    .unwrap();
+            }
+        });
+        port
+    }
+
+    #[test]
+    fn test_master_mirrors_device() {
+        let device = Arc::new(ModbusMemory::new(&MemoryMapSettings::default()));
+        device.write_holding_register(5, 4321).unwrap();
+        let port = spawn_device(Arc::clone(&device));
+
+        let memory = Arc::new(RwLock::new(CanonicalMemory::new()));
+        let master = ModbusTcpMaster::new(
+            ModbusMasterConfig {
+                host: "127.0.0.1".to_string(),
+                port,
+                unit_id: 1,
+                timeout_ms: 1000,
+                poll_groups: vec![PollGroup {
+                    function: ReadFunction::HoldingRegisters,
+                    address: 5,
+                    count: 1,
+                    area: CanonicalAreaKind::DataWord,
+                    start_index: 0,
+                    interval_ms: 0,
+                }],
+                write_groups: vec![WriteGroup {
+                    function: WriteFunction::MultipleCoils,
+                    address: 0,
+                    count: 4,
+                    area: CanonicalAreaKind::OutputBit,
+                    start_index: 0,
+                }],
+            },
+            Arc::clone(&memory),
+        )
+        .unwrap();
+        assert_eq!(master.health(), CpuHealth::Disconnected);
+
+        master.poll_device().unwrap();
+        assert_eq!(master.health(), CpuHealth::Good);
+        assert_eq!(
+            memory
+                .read()
+                .read(CanonicalAddress::new(CanonicalAreaKind::DataWord, 0))
+                .unwrap(),
+            CanonicalValue::U16(4321)
+        );
+
+        master.write_device().unwrap();
+        memory
+            .write()
+            .write(
+                CanonicalAddress::new(CanonicalAreaKind::OutputBit, 1),
+                CanonicalValue::Bool(true),
+                modone_contract::CanonicalWriteSource::Simulation,
+            )
+            .unwrap();
+        master.write_device().unwrap();
+        assert_eq!(
+            device.read_coils(0, 4).unwrap(),
+            vec![false, true, false, false]
+        );
+    }
+
+    #[test]
+    fn test_master_reports_disconnected() {
+        let port = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let master = ModbusTcpMaster::new(
+            ModbusMasterConfig {
+                host: "127.0.0.1".to_string(),
+                port,
+                unit_id: 1,
+                timeout_ms: 200,
+                poll_groups: vec![PollGroup {
+                    function: ReadFunction::Coils,
+                    address: 0,
+                    count: 8,
+                    area: CanonicalAreaKind::InputBit,
+                    start_index: 0,
+                    interval_ms: 0,
+                }],
+                write_groups: Vec::new(),
+            },
+            Arc::new(RwLock::new(CanonicalMemory::new())),
+        )
+        .unwrap();
+
+        assert!(master.poll_device().is_err());
+        assert_eq!(master.health(), CpuHealth::Disconnected);
+    }
+}
@@ -5,6 +5,7 @@
 //! 공개 표면을 `crate::modbus::X` 경로로 재노출한다.
 
 pub mod generator;
+pub mod master;
 pub mod rtu;
 pub mod tauri_sink;
 pub mod tcp;
@@ -13,13 +14,14 @@ pub mod types;
 
 // codec 데이터 모델/코덱/어댑터/정책 재노출 (기존 `crate::modbus::X` 경로 호환).
 pub use modbus_codec::{
-    pdu, ChangeSource, DirtyPublishWindow, MemoryBatchChangeEvent, MemoryChangeEvent, MemoryError,
-    MemoryEventSink, MemoryMapSettings, MemoryType, ModbusAdapter, ModbusAdapterError,
+    client, pdu, ChangeSource, DirtyPublishWindow, MemoryBatchChangeEvent, MemoryChangeEvent,
+    MemoryError, MemoryEventSink, MemoryMapSettings, MemoryType, ModbusAdapter, ModbusAdapterError,
     ModbusAdapterResult, ModbusAddressSpace, ModbusMappingPolicy, ModbusMappingRule,
-    ModbusMappingSource, ModbusMemory, ProtocolAdapter,
+    ModbusMappingSource, ModbusMasterConfig, ModbusMemory, ProtocolAdapter,
 };
 
 pub use generator::{GeneratorConfig, GeneratorManager, GeneratorTarget, Waveform};
+pub use master::ModbusTcpMaster;
 pub use rtu::{
     list_available_ports, ModbusRtuServer, PortInfo, RtuConfig, RtuDataBits, RtuParity, RtuStopBits,
 };
@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 
 use crate::plc_runtime::{
-    CanonicalAddress, CanonicalAreaKind, CanonicalMemory, VendorProfile,
+    resolve_memory_layout, CanonicalAddress, CanonicalAreaKind, CanonicalMemory, VendorProfile,
 };
 use crate::project::{PlcAddressWindow, PlcHardwareTopology, PlcIoDirection, PlcSettings};
 use crate::sim::tag_registry::SharedTagRegistry;
@@ -57,10 +57,14 @@ fn window_matches_area(area: CanonicalAreaKind, window: &PlcAddressWindow) -> bo
     let family = window.family.trim().to_uppercase();
     match area {
         CanonicalAreaKind::InputBit => {
-            family == "X" || (family == "P" && window.io_direction != Some(PlcIoDirection::Output))
+            family == "X"
+                || family == "%IX"
+                || (family == "P" && window.io_direction != Some(PlcIoDirection::Output))
         }
         CanonicalAreaKind::OutputBit => {
-            family == "Y" || (family == "P" && window.io_direction != Some(PlcIoDirection::Input))
+            family == "Y"
+                || family == "%QX"
+                || (family == "P" && window.io_direction != Some(PlcIoDirection::Input))
         }
         CanonicalAreaKind::InternalBit => family == "M",
         CanonicalAreaKind::RetentiveBit => family == "K" || family == "L",
@@ -83,7 +87,7 @@ fn raw_exposure_limit(area: CanonicalAreaKind, plc_settings: &PlcSettings) -> u3
     let topology_limit = topology_exposure_limit(area, &plc_settings.hardware_topology);
     topology_limit
         .unwrap_or_else(|| documented_default_exposure(area))
-        .min(area.default_size() as u32)
+        .min(resolve_memory_layout(plc_settings).size(area))
 }
 
 /// Build the OPC UA Address Space path segments for a tag definition.
@@ -294,6 +298,8 @@ mod tests {
             model: "XGK".to_string(),
             scan_time_ms: 10,
             hardware_topology: PlcHardwareTopology::default(),
+            memory_sizes: Default::default(),
+            memory_sections: Default::default(),
         }
     }
 
@@ -0,0 +1,792 @@
+// OPC UA client `FieldLink` — 외부 OPC UA 서버(실 컨트롤러 또는 로컬 대역)를
+// CPU 의 canonical 메모리로 미러링한다.
+//
+// 설정된 노드들을 구독(subscription)하고, 수신 값은 기존 `OpcUaMappingConfig`
+// 변환(node_values)으로 canonical 주소에 기록한다. ReadWrite 매핑 항목은 메모리
+// 쪽 변경을 원격 노드에 되써넣는다. 항목 품질이 Bad 거나 항목 값을 반영하지
+// 못하면 `CpuHealth::Degraded`. 보안 정책·모드·사용자 인증은 설정에서 고른다.
+// 세션 관련 코드만 `opcua-server` feature 에 의존하고 미러 로직은 순수하다.
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use modone_contract::{CpuHealth, FieldLink};
+
+use crate::plc_runtime::{
+    CanonicalAddress, CanonicalMemory, CanonicalMemoryError, CanonicalWriteSource,
+};
+
+use super::node_values::{mapped_to_register_writes, read_node_mapped, NodeValueError};
+use super::types::OpcUaSecurityPolicy;
+use super::{MappedValue, MappingAccessLevel, OpcUaMappingConfig};
+
+type LinkError = Box<dyn std::error::Error + Send + Sync>;
+
+fn default_publishing_interval_ms() -> u32 {
+    100
+}
+
+fn default_security_policy() -> OpcUaSecurityPolicy {
+    OpcUaSecurityPolicy::None
+}
+
+/// Message security mode requested from the server
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OpcUaClientSecurityMode {
+    #[default]
+    None,
+    Sign,
+    SignAndEncrypt,
+}
+
+/// User identity presented when the session is activated
+#[derive(Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum OpcUaClientIdentity {
+    #[default]
+    Anonymous,
+    UserName {
+        username: String,
+        password: String,
+    },
+}
+
+// 비밀번호가 로그에 남지 않도록 직접 구현
+impl fmt::Debug for OpcUaClientIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Anonymous => f.write_str("Anonymous"),
+            Self::UserName { username, .. } => f
+                .debug_struct("UserName")
+                .field("username", username)
+                .field("password", &"***")
+                .finish(),
+        }
+    }
+}
+
+/// One remote node mirrored into canonical memory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpcUaClientItem {
+    /// Remote node id, e.g. `ns=2;s=Line1.Speed`
+    pub node_id: String,
+    /// First canonical address the value is written to
+    pub address: CanonicalAddress,
+    /// Type interpretation; `ReadWrite` items are written back to the server
+    #[serde(default)]
+    pub mapping: OpcUaMappingConfig,
+}
+
+impl OpcUaClientItem {
+    pub fn is_writable(&self) -> bool {
+        self.mapping.access_level == MappingAccessLevel::ReadWrite
+    }
+}
+
+/// Connection and item list for an OPC UA client CPU
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpcUaClientConfig {
+    /// Endpoint URL, e.g. `opc.tcp://192.168.0.10:4840`
+    pub endpoint_url: String,
+    #[serde(default = "default_publishing_interval_ms")]
+    pub publishing_interval_ms: u32,
+    #[serde(default = "default_security_policy")]
+    pub security_policy: OpcUaSecurityPolicy,
+    /// Must be `None` exactly when `security_policy` is `None`
+    #[serde(default)]
+    pub security_mode: OpcUaClientSecurityMode,
+    #[serde(default)]
+    pub identity: OpcUaClientIdentity,
+    /// Accept any server certificate instead of only those trusted in `pki_dir`
+    #[serde(default)]
+    pub trust_server_certs: bool,
+    /// Client PKI directory (own certificate, trusted servers). Required for
+    /// secure policies; the client certificate is created there if missing.
+    #[serde(default)]
+    pub pki_dir: Option<PathBuf>,
+    #[serde(default)]
+    pub items: Vec<OpcUaClientItem>,
+}
+
+impl OpcUaClientConfig {
+    pub fn validate(&self) -> Result<(), OpcUaClientError> {
+        if self.endpoint_url.trim().is_empty() {
+            return Err(OpcUaClientError::Config(
+                "endpoint URL is required".to_string(),
+            ));
+        }
+        if self.publishing_interval_ms == 0 {
+            return Err(OpcUaClientError::Config(
+                "publishing interval must be positive".to_string(),
+            ));
+        }
+        let secure = self.security_policy.requires_encryption();
+        if secure == (self.security_mode == OpcUaClientSecurityMode::None) {
+            return Err(OpcUaClientError::Config(format!(
+                "security mode {:?} does not match policy {}",
+                self.security_mode,
+                self.security_policy.display_name()
+            )));
+        }
+        if secure && self.pki_dir.is_none() {
+            return Err(OpcUaClientError::Config(
+                "a PKI directory is required for secure policies".to_string(),
+            ));
+        }
+        if let OpcUaClientIdentity::UserName { username, .. } = &self.identity {
+            if username.trim().is_empty() {
+                return Err(OpcUaClientError::Config(
+                    "user name is required".to_string(),
+                ));
+            }
+        }
+        for (index, item) in self.items.iter().enumerate() {
+            let invalid = |message: String| OpcUaClientError::InvalidItem { index, message };
+            if item.node_id.trim().is_empty() {
+                return Err(invalid("node id is required".to_string()));
+            }
+            item.mapping
+                .validated_register_range(item.address)
+                .map_err(invalid)?;
+            if !item
+                .address
+                .area
+                .default_access()
+                .allows_write(CanonicalWriteSource::ExternalProtocol)
+            {
+                return Err(invalid(format!("{:?} is read-only", item.address.area)));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum OpcUaClientError {
+    #[error("OPC UA client configuration error: {0}")]
+    Config(String),
+    #[error("Invalid OPC UA client item {index}: {message}")]
+    InvalidItem { index: usize, message: String },
+    #[error("OPC UA client item {index} cannot be converted: {message}")]
+    Mapping { index: usize, message: String },
+    #[error("OPC UA client item {index} cannot be written: {source}")]
+    ItemWrite {
+        index: usize,
+        #[source]
+        source: CanonicalMemoryError,
+    },
+    #[error("OPC UA service failed: {0}")]
+    Service(String),
+    #[error("OPC UA item {0} has bad quality")]
+    BadQuality(String),
+    #[error(transparent)]
+    Memory(#[from] CanonicalMemoryError),
+}
+
+/// Result of applying the pending samples
+#[derive(Debug, Default)]
+pub struct SampleReport {
+    /// Node ids of the items that are currently Bad
+    pub bad: Vec<String>,
+    /// Items whose sample could not be converted or written
+    pub errors: Vec<OpcUaClientError>,
+}
+
+/// Latest value reported by the subscription for one item
+#[derive(Debug, Clone, PartialEq)]
+pub enum ItemSample {
+    Value(MappedValue),
+    Bad,
+}
+
+/// Transport-independent mirror state: incoming samples, per-item quality and
+/// the last value written back for each `ReadWrite` item.
+pub struct OpcUaMirror {
+    config: OpcUaClientConfig,
+    memory: Arc<RwLock<CanonicalMemory>>,
+    samples: Vec<Option<ItemSample>>,
+    bad: Vec<bool>,
+    last_written: Vec<Option<MappedValue>>,
+}
+
+impl OpcUaMirror {
+    pub fn new(
+        config: OpcUaClientConfig,
+        memory: Arc<RwLock<CanonicalMemory>>,
+    ) -> Result<Self, OpcUaClientError> {
+        config.validate()?;
+        let items = config.items.len();
+        Ok(Self {
+            config,
+            memory,
+            samples: vec![None; items],
+            // 첫 샘플이 오기 전에는 품질을 알 수 없으므로 Good 으로 본다
+            bad: vec![false; items],
+            last_written: vec![None; items],
+        })
+    }
+
+    pub fn config(&self) -> &OpcUaClientConfig {
+        &self.config
+    }
+
+    /// Record a sample; only the latest one per item is applied
+    pub fn push_sample(&mut self, index: usize, sample: ItemSample) {
+        if let Some(slot) = self.samples.get_mut(index) {
+            *slot = Some(sample);
+        }
+    }
+
+    /// Write pending samples into memory item by item. A sample that cannot
+    /// be converted or written is reported and the other items still apply.
+    pub fn apply_samples(&mut self) -> SampleReport {
+        let mut errors = Vec::new();
+        for (index, sample) in self.samples.iter_mut().enumerate() {
+            let item = &self.config.items[index];
+            match sample.take() {
+                Some(ItemSample::Value(value)) => {
+                    let written = mapped_to_register_writes(&value, item.address, &item.mapping)
+                        .map_err(|e| OpcUaClientError::Mapping {
+                            index,
+                            message: format!("{:?}", e),
+                        })
+                        .and_then(|writes| {
+                            self.memory
+                                .write()
+                                .write_batch(writes, CanonicalWriteSource::ExternalProtocol)
+                                .map_err(|source| OpcUaClientError::ItemWrite { index, source })
+                        });
+                    match written {
+                        Ok(()) => {
+                            self.bad[index] = false;
+                            // 장비에서 온 값은 되써넣지 않는다
+                            if item.is_writable() {
+                                self.last_written[index] = Some(value);
+                            }
+                        }
+                        Err(err) => errors.push(err),
+                    }
+                }
+                Some(ItemSample::Bad) => self.bad[index] = true,
+                None => {}
+            }
+        }
+
+        SampleReport {
+            bad: self
+                .config
+                .items
+                .iter()
+                .zip(&self.bad)
+                .filter(|(_, bad)| **bad)
+                .map(|(item, _)| item.node_id.clone())
+                .collect(),
+            errors,
+        }
+    }
+
+    /// `ReadWrite` items whose memory value changed since the last write. The
+    /// first call only records the current values so the device is never
+    /// overwritten with the mirror's initial zeros.
+    pub fn pending_writes(&mut self) -> Result<Vec<(usize, MappedValue)>, OpcUaClientError> {
+        let memory = self.memory.read();
+        let mut pending = Vec::new();
+        for (index, item) in self.config.items.iter().enumerate() {
+            if !item.is_writable() {
+                continue;
+            }
+            let value =
+                read_node_mapped(&memory, item.address, &item.mapping).map_err(|e| match e {
+                    NodeValueError::Memory(e) => OpcUaClientError::Memory(e),
+                    NodeValueError::Mapping(e) => OpcUaClientError::Mapping {
+                        index,
+                        message: format!("{:?}", e),
+                    },
+                })?;
+            match &self.last_written[index] {
+                None => self.last_written[index] = Some(value),
+                Some(last) if *last == value => {}
+                Some(_) => pending.push((index, value)),
+            }
+        }
+        Ok(pending)
+    }
+
+    pub fn commit(&mut self, index: usize, value: MappedValue) {
+        self.last_written[index] = Some(value);
+    }
+}
+
+#[cfg(feature = "opcua-server")]
+struct ClientSession {
+    session: Arc<RwLock<opcua::client::prelude::Session>>,
+    stop: Option<tokio::sync::oneshot::Sender<opcua::client::prelude::SessionCommand>>,
+    // 세션이 살아 있는 동안 클라이언트(설정/인증서 저장소)를 유지한다
+    _client: opcua::client::prelude::Client,
+}
+
+/// OPC UA client driver for a real CPU. Connects lazily on the first poll and
+/// reconnects on the next poll after the session drops.
+pub struct OpcUaClientLink {
+    mirror: Arc<Mutex<OpcUaMirror>>,
+    #[cfg(feature = "opcua-server")]
+    node_ids: Vec<opcua::client::prelude::NodeId>,
+    #[cfg(feature = "opcua-server")]
+    session: Mutex<Option<ClientSession>>,
+    health: RwLock<CpuHealth>,
+}
+
+impl OpcUaClientLink {
+    pub fn new(
+        config: OpcUaClientConfig,
+        memory: Arc<RwLock<CanonicalMemory>>,
+    ) -> Result<Self, OpcUaClientError> {
+        #[cfg(feature = "opcua-server")]
+        let node_ids = config
+            .items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                item.node_id
+                    .parse::<opcua::client::prelude::NodeId>()
+                    .map_err(|_| OpcUaClientError::InvalidItem {
+                        index,
+                        message: format!("'{}' is not a node id", item.node_id),
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            mirror: Arc::new(Mutex::new(OpcUaMirror::new(config, memory)?)),
+            #[cfg(feature = "opcua-server")]
+            node_ids,
+            #[cfg(feature = "opcua-server")]
+            session: Mutex::new(None),
+            health: RwLock::new(CpuHealth::Disconnected),
+        })
+    }
+
+    pub fn config(&self) -> OpcUaClientConfig {
+        self.mirror.lock().config().clone()
+    }
+
+    #[cfg(feature = "opcua-server")]
+    fn connect(&self) -> Result<ClientSession, OpcUaClientError> {
+        use opcua::client::prelude::*;
+        use std::collections::HashMap;
+
+        let config = self.config();
+        let service = |status: StatusCode| OpcUaClientError::Service(status.to_string());
+
+        let mut builder = ClientBuilder::new()
+            .application_name("ModOne")
+            .application_uri("urn:modone:client")
+            .product_uri("urn:modone:client")
+            .trust_server_certs(config.trust_server_certs)
+            .create_sample_keypair(config.security_policy.requires_encryption())
+            .session_retry_limit(0);
+        if let Some(pki_dir) = &config.pki_dir {
+            builder = builder.pki_dir(pki_dir.clone());
+        }
+        let mut client = builder
+            .client()
+            .ok_or_else(|| OpcUaClientError::Config("invalid client configuration".to_string()))?;
+
+        let security_mode = match config.security_mode {
+            OpcUaClientSecurityMode::None => MessageSecurityMode::None,
+            OpcUaClientSecurityMode::Sign => MessageSecurityMode::Sign,
+            OpcUaClientSecurityMode::SignAndEncrypt => MessageSecurityMode::SignAndEncrypt,
+        };
+        let identity = match &config.identity {
+            OpcUaClientIdentity::Anonymous => IdentityToken::Anonymous,
+            OpcUaClientIdentity::UserName { username, password } => {
+                IdentityToken::UserName(username.clone(), password.clone())
+            }
+        };
+        // 토큰 정책은 서버가 광고한 엔드포인트에서 고르므로 여기 값은 쓰이지 않는다
+        let session = client
+            .connect_to_endpoint(
+                (
+                    config.endpoint_url.as_str(),
+                    SecurityPolicy::from_uri(config.security_policy.policy_uri()).to_str(),
+                    security_mode,
+                    UserTokenPolicy::anonymous(),
+                ),
+                identity,
+            )
+            .map_err(service)?;
+
+        let lookup: HashMap<NodeId, usize> = self
+            .node_ids
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(index, node_id)| (node_id, index))
+            .collect();
+        let targets: Vec<_> = config
+            .items
+            .iter()
+            .map(|item| item.mapping.effective_opcua_data_type())
+            .collect();
+        let mirror = Arc::clone(&self.mirror);
+        let callback = DataChangeCallback::new(move |items| {
+            let mut mirror = mirror.lock();
+            for item in items.iter() {
+                let Some(&index) = lookup.get(&item.item_to_monitor().node_id) else {
+                    continue;
+                };
+                let value = item.last_value();
+                let good = !matches!(value.status, Some(status) if status.is_bad());
+                let sample = match value.value.as_ref() {
+                    Some(variant) if good => {
+                        match super::node_values::variant_to_mapped(variant, targets[index]) {
+                            Ok(mapped) => ItemSample::Value(mapped),
+                            Err(_) => ItemSample::Bad,
+                        }
+                    }
+                    _ => ItemSample::Bad,
+                };
+                mirror.push_sample(index, sample);
+            }
+        });
+
+        {
+            let session = session.read();
+            let subscription_id = session
+                .create_subscription(
+                    config.publishing_interval_ms as f64,
+                    30,
+                    10,
+                    0,
+                    0,
+                    true,
+                    callback,
+                )
+                .map_err(service)?;
+            let requests: Vec<MonitoredItemCreateRequest> =
+                self.node_ids.iter().cloned().map(Into::into).collect();
+            let results = session
+                .create_monitored_items(subscription_id, TimestampsToReturn::Both, &requests)
+                .map_err(service)?;
+            let mut mirror = self.mirror.lock();
+            for (index, result) in results.iter().enumerate() {
+                if !result.status_code.is_good() {
+                    log::warn!(
+                        "OPC UA client: cannot monitor {}: {}",
+                        config.items[index].node_id,
+                        result.status_code
+                    );
+                    mirror.push_sample(index, ItemSample::Bad);
+                }
+            }
+        }
+
+        let stop = Session::run_async(Arc::clone(&session));
+        Ok(ClientSession {
+            session,
+            stop: Some(stop),
+            _client: client,
+        })
+    }
+
+    #[cfg(feature = "opcua-server")]
+    fn ensure_connected(&self) -> Result<(), OpcUaClientError> {
+        let mut slot = self.session.lock();
+        if let Some(current) = slot.as_mut() {
+            if current.session.read().is_connected() {
+                return Ok(());
+            }
+            Self::close(current);
+            *slot = None;
+        }
+        *slot = Some(self.connect()?);
+        Ok(())
+    }
+
+    #[cfg(not(feature = "opcua-server"))]
+    fn ensure_connected(&self) -> Result<(), OpcUaClientError> {
+        Err(OpcUaClientError::Config(
+            "OPC UA support is not enabled in this build".to_string(),
+        ))
+    }
+
+    #[cfg(feature = "opcua-server")]
+    fn close(current: &mut ClientSession) {
+        if let Some(stop) = current.stop.take() {
+            let _ = stop.send(opcua::client::prelude::SessionCommand::Stop);
+        }
+        current.session.read().disconnect();
+    }
+
+    #[cfg(feature = "opcua-server")]
+    fn send_writes(&self, pending: &[(usize, MappedValue)]) -> Result<Vec<bool>, OpcUaClientError> {
+        use opcua::client::prelude::*;
+
+        let slot = self.session.lock();
+        let Some(current) = slot.as_ref() else {
+            return Err(OpcUaClientError::Service("not connected".to_string()));
+        };
+        let writes: Vec<WriteValue> = pending
+            .iter()
+            .map(|(index, value)| WriteValue {
+                node_id: self.node_ids[*index].clone(),
+                attribute_id: AttributeId::Value as u32,
+                index_range: UAString::null(),
+                value: DataValue::value_only(super::node_values::mapped_value_to_variant(value)),
+            })
+            .collect();
+        let results = current
+            .session
+            .read()
+            .write(&writes)
+            .map_err(|status| OpcUaClientError::Service(status.to_string()))?;
+        Ok(results.iter().map(|status| status.is_good()).collect())
+    }
+
+    #[cfg(not(feature = "opcua-server"))]
+    fn send_writes(
+        &self,
+        _pending: &[(usize, MappedValue)],
+    ) -> Result<Vec<bool>, OpcUaClientError> {
+        Err(OpcUaClientError::Service("not connected".to_string()))
+    }
+}
+
+impl FieldLink for OpcUaClientLink {
+    fn poll_device(&self) -> Result<(), LinkError> {
+        if let Err(err) = self.ensure_connected() {
+            *self.health.write() = CpuHealth::Disconnected;
+            return Err(Box::new(err));
+        }
+
+        let report = self.mirror.lock().apply_samples();
+        for err in report.errors.iter().skip(1) {
+            log::warn!("OPC UA client: {}", err);
+        }
+        if let Some(err) = report.errors.into_iter().next() {
+            *self.health.write() = CpuHealth::Degraded;
+            return Err(Box::new(err));
+        }
+        if let Some(first) = report.bad.first() {
+            *self.health.write() = CpuHealth::Degraded;
+            return Err(Box::new(OpcUaClientError::BadQuality(first.clone())));
+        }
+        *self.health.write() = CpuHealth::Good;
+        Ok(())
+    }
+
+    fn write_device(&self) -> Result<(), LinkError> {
+        let pending = self.mirror.lock().pending_writes()?;
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let results = self.send_writes(&pending)?;
+        let mut mirror = self.mirror.lock();
+        let mut rejected = None;
+        for ((index, value), good) in pending.into_iter().zip(results) {
+            if good {
+                mirror.commit(index, value);
+            } else {
+                rejected = Some(mirror.config().items[index].node_id.clone());
+            }
+        }
+        match rejected {
+            Some(node_id) => Err(Box::new(OpcUaClientError::Service(format!(
+                "write to {} was rejected",
+                node_id
+            )))),
+            None => Ok(()),
+        }
+    }
+
+    fn health(&self) -> CpuHealth {
+        *self.health.read()
+    }
+}
+
+#[cfg(feature = "opcua-server")]
+impl Drop for OpcUaClientLink {
+    fn drop(&mut self) {
+        if let Some(mut current) = self.session.lock().take() {
+            Self::close(&mut current);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::opcua::OpcUaDataType;
+    use crate::plc_runtime::{CanonicalAreaKind, CanonicalValue};
+
+    fn item(
+        node_id: &str,
+        index: u32,
+        data_type: OpcUaDataType,
+        word_count: u16,
+    ) -> OpcUaClientItem {
+        OpcUaClientItem {
+            node_id: node_id.to_string(),
+            address: CanonicalAddress::new(CanonicalAreaKind::DataWord, index),
+            mapping: OpcUaMappingConfig {
+                opcua_data_type: data_type,
+                word_count,
+                access_level: MappingAccessLevel::ReadWrite,
+                ..OpcUaMappingConfig::default()
+            },
+        }
+    }
+
+    fn client_config() -> OpcUaClientConfig {
+        OpcUaClientConfig {
+            endpoint_url: "opc.tcp://localhost:4840".to_string(),
+            publishing_interval_ms: 100,
+            security_policy: OpcUaSecurityPolicy::None,
+            security_mode: OpcUaClientSecurityMode::None,
+            identity: OpcUaClientIdentity::Anonymous,
+            trust_server_certs: false,
+            pki_dir: None,
+            items: Vec::new(),
+        }
+    }
+
+    fn mirror(items: Vec<OpcUaClientItem>) -> (OpcUaMirror, Arc<RwLock<CanonicalMemory>>) {
+        let memory = Arc::new(RwLock::new(CanonicalMemory::new()));
+        let config = OpcUaClientConfig {
+            items,
+            ..client_config()
+        };
+        (
+            OpcUaMirror::new(config, Arc::clone(&memory)).unwrap(),
+            memory,
+        )
+    }
+
+    #[test]
+    fn test_mirror_applies_samples_and_quality() {
+        let (mut mirror, memory) = mirror(vec![
+            item("ns=2;s=Speed", 10, OpcUaDataType::Int32, 2),
+            item("ns=2;s=Level", 20, OpcUaDataType::UInt16, 1),
+        ]);
+
+        mirror.push_sample(0, ItemSample::Value(MappedValue::Int32(70000)));
+        mirror.push_sample(1, ItemSample::Bad);
+        assert_eq!(mirror.apply_samples().bad, vec!["ns=2;s=Level"]);
+        let read = |index| {
+            memory
+                .read()
+                .read(CanonicalAddress::new(CanonicalAreaKind::DataWord, index))
+                .unwrap()
+        };
+        assert_eq!(read(10), CanonicalValue::U16(1));
+        assert_eq!(read(11), CanonicalValue::U16(4464));
+
+        // 장비에서 온 값은 되써넣기 대상이 아니다
+        assert!(mirror.pending_writes().unwrap().is_empty());
+
+        mirror.push_sample(1, ItemSample::Value(MappedValue::UInt16(5)));
+        assert!(mirror.apply_samples().bad.is_empty());
+    }
+
+    #[test]
+    fn test_mirror_keeps_applying_after_item_errors() {
+        let (mut mirror, memory) = mirror(vec![
+            item("ns=2;s=Speed", 10, OpcUaDataType::UInt16, 1),
+            item("ns=2;s=Level", 20, OpcUaDataType::UInt16, 1),
+        ]);
+
+        // 첫 항목은 타입이 맞지 않아 실패해도 둘째 항목은 반영된다
+        mirror.push_sample(0, ItemSample::Value(MappedValue::Int32(1)));
+        mirror.push_sample(1, ItemSample::Value(MappedValue::UInt16(9)));
+        let report = mirror.apply_samples();
+        assert!(matches!(
+            report.errors.as_slice(),
+            [OpcUaClientError::Mapping { index: 0, .. }]
+        ));
+        assert_eq!(
+            memory
+                .read()
+                .read(CanonicalAddress::new(CanonicalAreaKind::DataWord, 20))
+                .unwrap(),
+            CanonicalValue::U16(9)
+        );
+    }
+
+    #[test]
+    fn test_mirror_writes_back_changed_outputs() {
+        let (mut mirror, memory) =
+            mirror(vec![item("ns=2;s=Setpoint", 30, OpcUaDataType::UInt16, 1)]);
+        assert!(mirror.pending_writes().unwrap().is_empty());
+
+        memory
+            .write()
+            .write(
+                CanonicalAddress::new(CanonicalAreaKind::DataWord, 30),
+                CanonicalValue::U16(42),
+                CanonicalWriteSource::Simulation,
+            )
+            .unwrap();
+        let pending = mirror.pending_writes().unwrap();
+        assert_eq!(pending, vec![(0, MappedValue::UInt16(42))]);
+        mirror.commit(0, MappedValue::UInt16(42));
+        assert!(mirror.pending_writes().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_config_rejects_invalid_items() {
+        let config = OpcUaClientConfig {
+            items: vec![item("", 0, OpcUaDataType::UInt16, 1)],
+            ..client_config()
+        };
+        assert!(matches!(
+            config.validate(),
+            Err(OpcUaClientError::InvalidItem { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_config_checks_security_settings() {
+        let secure = OpcUaClientConfig {
+            security_policy: OpcUaSecurityPolicy::Basic256Sha256,
+            security_mode: OpcUaClientSecurityMode::SignAndEncrypt,
+            identity: OpcUaClientIdentity::UserName {
+                username: "operator".to_string(),
+                password: "secret".to_string(),
+            },
+            pki_dir: Some(PathBuf::from("pki")),
+            ..client_config()
+        };
+        assert!(secure.validate().is_ok());
+        assert!(!format!("{:?}", secure.identity).contains("secret"));
+
+        let no_pki = OpcUaClientConfig {
+            pki_dir: None,
+            ..secure.clone()
+        };
+        assert!(no_pki.validate().is_err());
+
+        let mismatched = OpcUaClientConfig {
+            security_mode: OpcUaClientSecurityMode::None,
+            ..secure.clone()
+        };
+        assert!(mismatched.validate().is_err());
+
+        let insecure_signed = OpcUaClientConfig {
+            security_mode: OpcUaClientSecurityMode::Sign,
+            ..client_config()
+        };
+        assert!(insecure_signed.validate().is_err());
+
+        let defaults: OpcUaClientConfig =
+            serde_json::from_str(r#"{"endpointUrl":"opc.tcp://localhost:4840"}"#).unwrap();
+        assert_eq!(defaults, client_config());
+    }
+}
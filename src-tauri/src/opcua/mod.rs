@@ -8,6 +8,7 @@ pub mod address_space;
 pub mod audit;
 pub mod auth;
 pub mod backend_impl;
+pub mod client;
 pub mod control;
 pub mod node_values;
 pub mod server;
@@ -35,6 +36,10 @@ pub use auth::{
     VerifiedCredential, resolve_verified_credentials, resolve_verified_credentials_audited,
 };
 pub use address_space::is_bool_address;
+pub use client::{
+    OpcUaClientConfig, OpcUaClientError, OpcUaClientIdentity, OpcUaClientItem, OpcUaClientLink,
+    OpcUaClientSecurityMode,
+};
 pub use opcua_codec::dirty_tracker::{DirtyTracker, SharedDirtyTracker};
 pub use opcua_codec::mapping::{
     ByteOrder, DeadbandConfig, DeadbandKind, MappedValue, MappingAccessLevel, MappingError,
@@ -258,6 +258,29 @@ pub fn read_node_data_value(
     }
 }
 
+/// Rejects a client write up front when canonical memory would refuse any of
+/// its register writes: project write protection maps to
+/// `BadUserAccessDenied`, a non-writable area to `BadNotWritable`.
+#[cfg(feature = "opcua-server")]
+pub fn check_node_writes(
+    memory: &CanonicalMemory,
+    writes: &[(CanonicalAddress, CanonicalValue)],
+) -> Result<(), opcua::server::prelude::StatusCode> {
+    use crate::plc_runtime::CanonicalWriteSource;
+    use opcua::server::prelude::StatusCode;
+
+    for (address, _) in writes {
+        memory
+            .check_write(*address, CanonicalWriteSource::ExternalProtocol)
+            .map_err(|err| match err {
+                CanonicalMemoryError::WriteProtected { .. } => StatusCode::BadUserAccessDenied,
+                CanonicalMemoryError::WriteNotAllowed { .. } => StatusCode::BadNotWritable,
+                _ => StatusCode::BadOutOfRange,
+            })?;
+    }
+    Ok(())
+}
+
 /// Converts a client-written Variant into a [`MappedValue`] of the node's target
 /// type, with lenient numeric coercion and range checking. Returns
 /// `BadOutOfRange` when the value does not fit the target integer type, or
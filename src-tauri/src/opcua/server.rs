@@ -705,6 +705,7 @@ impl OpcUaServer {
                 };
                 let vb = if node_spec.access_level == OpcUaAccessLevel::ReadWrite {
                     let opcua_memory = Arc::clone(&self.opcua_memory);
+                    let canonical_memory = Arc::clone(&canonical_memory);
                     let base = node_spec.canonical_address;
                     let mapping = node_spec.mapping.clone();
                     let setter = AttrFnSetter::new_boxed(
@@ -721,6 +722,10 @@ impl OpcUaServer {
                                 &mapped, base, &mapping,
                             )
                             .map_err(|_| StatusCode::BadTypeMismatch)?;
+                            super::node_values::check_node_writes(
+                                &canonical_memory.read(),
+                                &writes,
+                            )?;
                             for (addr, canonical_value) in writes {
                                 opcua_memory.record_external_write(addr, canonical_value);
                             }
@@ -11,12 +11,16 @@ mod modbus_policy;
 pub use modone_contract::{event_bus, memory, types};
 pub use plc_model::profiles;
 
-pub use event_bus::CanonicalMemoryBus;
+pub use event_bus::{
+    BusFilter, CanonicalMemoryBus, FilteredSubscription, SubscriptionFilterHandle,
+    SubscriptionOptions,
+};
 pub use memory::{CanonicalMemory, CanonicalMemoryError, CanonicalMemorySnapshot};
 pub use modbus_policy::resolve_modbus_mapping_policy;
 pub use plc_model::{
-    resolve_vendor_profile, ModbusAddressSpace, ModbusMappingPolicy, ModbusMappingRule,
-    ModbusMappingSource, OpcUaAliasPolicy, VendorAddress, VendorAddressMetadata,
+    resolve_memory_layout, resolve_vendor_profile, ModbusAddressSpace, ModbusMappingPolicy,
+    ModbusMappingRule, ModbusMappingSource, OpcUaAliasPolicy, PlcMemoryLayout, VendorAddress,
+    VendorAddressMetadata,
     VendorAddressNumberBase, VendorDataKind, VendorProfile, VendorProfileError, VendorProfileId,
 };
 pub use types::{
@@ -73,6 +73,8 @@ mod tests {
                 model: "XBC-DN32H".to_string(),
                 scan_time_ms: 10,
                 hardware_topology: PlcHardwareTopology::default(),
+                memory_sizes: Default::default(),
+                memory_sections: Default::default(),
             },
             None,
         )
@@ -92,6 +94,8 @@ mod tests {
                 model: "XGK".to_string(),
                 scan_time_ms: 10,
                 hardware_topology: PlcHardwareTopology::default(),
+                memory_sizes: Default::default(),
+                memory_sections: Default::default(),
             },
             Some(&ModbusExposureSettings {
                 mode: ModbusExposureMode::Custom,
@@ -5,9 +5,11 @@
 use std::collections::HashMap;
 
 use chrono::{DateTime, Utc};
+use modone_contract::WriteProtectionPolicy;
 use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::opcua::{OpcUaMappingConfig, OpcUaSecurityPolicy, UserAccount};
+use crate::sim::fault::FaultPolicy;
 
 /// Main project configuration structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +54,14 @@ pub struct ProjectConfig {
     /// Persisted so that custom OPC UA type mappings survive project close/reopen.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub opcua_mappings: HashMap<String, OpcUaMappingConfig>,
+
+    /// Runtime error (fault) policy for the simulated CPU
+    #[serde(default)]
+    pub fault_policy: FaultPolicy,
+
+    /// Per-source write protection for canonical memory
+    #[serde(default, skip_serializing_if = "WriteProtectionPolicy::is_empty")]
+    pub write_protection: WriteProtectionPolicy,
 }
 
 impl Default for ProjectConfig {
@@ -68,6 +78,8 @@ impl Default for ProjectConfig {
             opcua: OpcUaSettings::default(),
             watched_tag_ids: Vec::new(),
             opcua_mappings: HashMap::new(),
+            fault_policy: FaultPolicy::default(),
+            write_protection: WriteProtectionPolicy::default(),
         }
     }
 }
@@ -337,8 +349,8 @@ impl Default for ProjectSettings {
 // 기존 `crate::project::Plc*` 경로 호환을 위해 재노출한다. 설계:
 // docs/wasm-migration/02-PLC-MODEL.md
 pub use plc_model::{
-    PlcAddressWindow, PlcHardwareModule, PlcHardwareTopology, PlcIoDirection, PlcManufacturer,
-    PlcModuleKind, PlcRackKind, PlcRackTopology, PlcSettings,
+    PlcAddressWindow, PlcHardwareModule, PlcHardwareTopology, PlcIoAllocationMode, PlcIoDirection,
+    PlcManufacturer, PlcModuleKind, PlcRackKind, PlcRackTopology, PlcSettings,
 };
 
 /// Modbus communication settings
@@ -774,6 +786,10 @@ password: legacy-pass
             "SIEMENS".parse::<PlcManufacturer>().unwrap(),
             PlcManufacturer::Siemens
         );
+        assert_eq!(
+            "omron".parse::<PlcManufacturer>().unwrap(),
+            PlcManufacturer::Omron
+        );
         assert!("Unknown".parse::<PlcManufacturer>().is_err());
     }
 
@@ -782,5 +798,6 @@ password: legacy-pass
         assert_eq!(PlcManufacturer::LS.to_string(), "LS");
         assert_eq!(PlcManufacturer::Mitsubishi.to_string(), "Mitsubishi");
         assert_eq!(PlcManufacturer::Siemens.to_string(), "Siemens");
+        assert_eq!(PlcManufacturer::Omron.to_string(), "Omron");
     }
 }
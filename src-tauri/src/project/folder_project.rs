@@ -13,6 +13,9 @@ use thiserror::Error;
 use super::config::PlcSettings;
 use super::manifest::ProjectManifest;
 
+/// Directory holding named PLC state snapshots
+pub const SNAPSHOTS_DIR: &str = "snapshots";
+
 /// Errors specific to folder-based project operations
 #[derive(Error, Debug)]
 pub enum FolderProjectError {
@@ -211,6 +214,7 @@ impl FolderProject {
             &self.scenario_dir(),
             &new_project_dir.join(&self.manifest.directories.scenario),
         )?;
+        self.copy_directory_if_exists(&self.snapshots_dir(), &new_project_dir.join(SNAPSHOTS_DIR))?;
 
         // Update manifest
         if let Some(name) = new_name {
@@ -278,6 +282,11 @@ impl FolderProject {
         self.project_root.join(&self.manifest.directories.sheets)
     }
 
+    /// Get the PLC state snapshots directory path (created on first save)
+    pub fn snapshots_dir(&self) -> PathBuf {
+        self.project_root.join(SNAPSHOTS_DIR)
+    }
+
     // ==========================================================================
     // Helper Methods
     // ==========================================================================
@@ -6,9 +6,11 @@
 use std::collections::HashMap;
 
 use chrono::Utc;
+use modone_contract::WriteProtectionPolicy;
 use serde::{Deserialize, Serialize};
 
 use crate::opcua::OpcUaMappingConfig;
+use crate::sim::fault::FaultPolicy;
 
 use super::config::{
     AutoSaveSettings, MemoryMapSettings, ModbusSettings, NetworkSettings, OpcUaSettings,
@@ -76,6 +78,14 @@ pub struct ProjectManifest {
     /// Per-tag OPC UA mapping configurations, keyed by tag ID.
     #[serde(default, skip_serializing_if = "HashMap::is_empty")]
     pub opcua_mappings: HashMap<String, OpcUaMappingConfig>,
+
+    /// Runtime error (fault) policy for the simulated CPU
+    #[serde(default)]
+    pub fault_policy: FaultPolicy,
+
+    /// Per-source write protection for canonical memory
+    #[serde(default, skip_serializing_if = "WriteProtectionPolicy::is_empty")]
+    pub write_protection: WriteProtectionPolicy,
 }
 
 impl ProjectManifest {
@@ -101,6 +111,8 @@ impl ProjectManifest {
             sheet: String::new(),
             watched_tag_ids: Vec::new(),
             opcua_mappings: HashMap::new(),
+            fault_policy: FaultPolicy::default(),
+            write_protection: WriteProtectionPolicy::default(),
         }
     }
 
@@ -120,6 +132,8 @@ impl ProjectManifest {
             sheet: String::new(),
             watched_tag_ids: config.watched_tag_ids.clone(),
             opcua_mappings: config.opcua_mappings.clone(),
+            fault_policy: config.fault_policy.clone(),
+            write_protection: config.write_protection.clone(),
         }
     }
 
@@ -137,6 +151,8 @@ impl ProjectManifest {
             opcua: self.opcua.clone(),
             watched_tag_ids: self.watched_tag_ids.clone(),
             opcua_mappings: self.opcua_mappings.clone(),
+            fault_policy: self.fault_policy.clone(),
+            write_protection: self.write_protection.clone(),
         }
     }
 
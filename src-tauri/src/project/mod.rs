@@ -57,8 +57,8 @@ pub use config::{
     ModbusExposureAddressSpace, ModbusExposureMode, ModbusExposureRule, ModbusExposureSettings,
     ModbusRtuSettings, ModbusServerSimulationSettings, ModbusSettings, ModbusSimulationTransport,
     ModbusTcpSettings, OpcUaSecurityPolicySetting, OpcUaSettings, Parity, PlcAddressWindow,
-    PlcHardwareModule, PlcHardwareTopology, PlcIoDirection, PlcManufacturer, PlcModuleKind,
-    PlcRackKind, PlcRackTopology, PlcSettings, ProjectConfig, ProjectSettings,
+    PlcHardwareModule, PlcHardwareTopology, PlcIoAllocationMode, PlcIoDirection, PlcManufacturer,
+    PlcModuleKind, PlcRackKind, PlcRackTopology, PlcSettings, ProjectConfig, ProjectSettings,
 };
 pub use folder_project::{is_folder_project, is_legacy_project, FolderProject, FolderProjectError};
 pub use manifest::{DirectoryConfig, ProjectManifest, MANIFEST_VERSION};
@@ -346,6 +346,10 @@ impl ProjectManager {
 
         let mut updated_config: ProjectConfig = serde_json::from_value(current_value)?;
         updated_config.project.updated_at = Utc::now();
+        // 자동 할당 모드면 모듈 변경에 맞춰 주소창을 다시 만든다(충돌은 검증에서 보고).
+        if let Ok(allocation) = plc_model::allocate_io_windows(&updated_config.plc) {
+            updated_config.plc.hardware_topology = allocation.topology;
+        }
         validate_project_config(&updated_config)?;
 
         project.config = updated_config.clone();
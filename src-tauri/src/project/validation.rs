@@ -5,7 +5,7 @@
 use super::config::{
     AutoSaveSettings, MemoryMapSettings, ModbusExposureMode, ModbusExposureSettings,
     ModbusRtuSettings, ModbusServerSimulationSettings, ModbusSimulationTransport,
-    ModbusTcpSettings, NetworkSettings, OpcUaSettings, PlcHardwareTopology, ProjectConfig,
+    ModbusTcpSettings, NetworkSettings, OpcUaSettings, PlcSettings, ProjectConfig,
 };
 use crate::error::ModOneError;
 use std::net::IpAddr;
@@ -35,6 +35,8 @@ const MAX_BACKUP_COUNT: u32 = 100;
 pub struct ValidationResult {
     /// List of (field, message) pairs
     errors: Vec<(String, String)>,
+    /// Problems worth reporting that do not block loading or saving
+    warnings: Vec<(String, String)>,
 }
 
 impl ValidationResult {
@@ -58,6 +60,16 @@ impl ValidationResult {
         &self.errors
     }
 
+    /// Add a non-blocking validation warning
+    pub fn add_warning(&mut self, field: impl Into<String>, message: impl Into<String>) {
+        self.warnings.push((field.into(), message.into()));
+    }
+
+    /// Get all warnings
+    pub fn warnings(&self) -> &[(String, String)] {
+        &self.warnings
+    }
+
     /// Convert to ModOneError if there are validation errors
     pub fn to_error(&self) -> Option<ModOneError> {
         if self.errors.is_empty() {
@@ -333,8 +345,24 @@ pub fn validate_auto_save(settings: &AutoSaveSettings, result: &mut ValidationRe
     }
 }
 
-/// Validate PLC hardware topology settings
-pub fn validate_hardware_topology(settings: &PlcHardwareTopology, result: &mut ValidationResult) {
+/// Validate the PLC model against the CPU catalogue of its manufacturer.
+/// An empty model is allowed and means "unspecified". Unknown models are
+/// only warned about so projects with free-text models keep opening.
+pub fn validate_plc_model(plc: &PlcSettings, result: &mut ValidationResult) {
+    if let Err(e) = plc_model::resolve_cpu_model(plc) {
+        let profile_id = plc_model::VendorProfileId::for_manufacturer(plc.manufacturer);
+        let known = plc_model::cpu_models(profile_id)
+            .flat_map(|model| model.model_prefixes.iter().copied())
+            .collect::<Vec<_>>()
+            .join(", ");
+        result.add_warning("plc.model", format!("{e} (known series: {known})"));
+    }
+}
+
+/// Validate PLC hardware topology settings, including I/O allocation conflicts
+/// reported by the vendor profile.
+pub fn validate_hardware_topology(plc: &PlcSettings, result: &mut ValidationResult) {
+    let settings = &plc.hardware_topology;
     for (rack_idx, rack) in settings.racks.iter().enumerate() {
         if rack.rack_id.trim().is_empty() {
             result.add_error(
@@ -385,6 +413,22 @@ pub fn validate_hardware_topology(settings: &PlcHardwareTopology, result: &mut V
             }
         }
     }
+
+    match plc_model::allocate_io_windows(plc) {
+        Ok(allocation) => {
+            for conflict in allocation.conflicts {
+                let field = match (conflict.rack_index, conflict.module_index) {
+                    (Some(rack_idx), Some(module_idx)) => {
+                        format!("plc.hardware_topology.racks[{rack_idx}].modules[{module_idx}]")
+                    }
+                    (Some(rack_idx), None) => format!("plc.hardware_topology.racks[{rack_idx}]"),
+                    _ => "plc.hardware_topology.io_allocation".to_string(),
+                };
+                result.add_error(field, conflict.message);
+            }
+        }
+        Err(e) => result.add_error("plc.manufacturer", e.to_string()),
+    }
 }
 
 /// Validate Modbus exposure settings
@@ -571,8 +615,9 @@ pub fn validate_project_config(config: &ProjectConfig) -> Result<(), ModOneError
     // Validate Modbus RTU settings
     validate_rtu_settings(&config.modbus.rtu, &mut result);
 
-    // Validate PLC topology
-    validate_hardware_topology(&config.plc.hardware_topology, &mut result);
+    // Validate PLC model and topology
+    validate_plc_model(&config.plc, &mut result);
+    validate_hardware_topology(&config.plc, &mut result);
 
     // Validate Modbus exposure
     validate_modbus_exposure(&config.modbus.exposure, &mut result);
@@ -589,6 +634,9 @@ pub fn validate_project_config(config: &ProjectConfig) -> Result<(), ModOneError
     // Validate auto-save settings
     validate_auto_save(&config.auto_save, &mut result);
 
+    for (field, message) in result.warnings() {
+        log::warn!("{field}: {message}");
+    }
     result.into_result()
 }
 
@@ -601,9 +649,17 @@ mod tests {
     use super::*;
     use crate::project::config::{
         ModbusExposureAddressSpace, ModbusExposureRule, Parity, PlcAddressWindow,
-        PlcHardwareModule, PlcIoDirection, PlcModuleKind, PlcRackKind, PlcRackTopology,
+        PlcHardwareModule, PlcHardwareTopology, PlcIoAllocationMode, PlcIoDirection,
+        PlcManufacturer, PlcModuleKind, PlcRackKind, PlcRackTopology,
     };
 
+    fn plc_with_topology(hardware_topology: PlcHardwareTopology) -> PlcSettings {
+        PlcSettings {
+            hardware_topology,
+            ..PlcSettings::default()
+        }
+    }
+
     #[test]
     fn test_validate_port() {
         assert!(validate_port(502, "tcp.port").is_none());
@@ -821,10 +877,11 @@ mod tests {
                     }],
                 }],
             }],
+            ..PlcHardwareTopology::default()
         };
 
         let mut result = ValidationResult::new();
-        validate_hardware_topology(&topology, &mut result);
+        validate_hardware_topology(&plc_with_topology(topology), &mut result);
         assert!(!result.is_valid());
     }
 
@@ -847,13 +904,79 @@ mod tests {
                     }],
                 }],
             }],
+            ..PlcHardwareTopology::default()
         };
 
         let mut result = ValidationResult::new();
-        validate_hardware_topology(&topology, &mut result);
+        validate_hardware_topology(&plc_with_topology(topology), &mut result);
         assert!(result.is_valid());
     }
 
+    #[test]
+    fn test_validate_hardware_topology_reports_allocation_conflicts() {
+        let topology = PlcHardwareTopology {
+            racks: vec![PlcRackTopology {
+                rack_id: "main".to_string(),
+                rack_kind: PlcRackKind::MainBase,
+                modules: vec![PlcHardwareModule {
+                    slot: 0,
+                    module_kind: PlcModuleKind::DigitalInput,
+                    model: "QX40".to_string(),
+                    point_count: None,
+                    address_windows: vec![],
+                }],
+            }],
+            io_allocation: PlcIoAllocationMode::Sequential,
+            empty_slot_points: None,
+        };
+        let plc = PlcSettings {
+            manufacturer: PlcManufacturer::Mitsubishi,
+            model: "Q03UDV".to_string(),
+            ..plc_with_topology(topology)
+        };
+
+        let mut result = ValidationResult::new();
+        validate_hardware_topology(&plc, &mut result);
+        assert!(!result.is_valid());
+        assert_eq!(
+            result.errors()[0].0,
+            "plc.hardware_topology.racks[0].modules[0]"
+        );
+    }
+
+    #[test]
+    fn test_validate_plc_model_against_catalogue() {
+        let plc = |manufacturer, model: &str| PlcSettings {
+            manufacturer,
+            model: model.to_string(),
+            ..PlcSettings::default()
+        };
+
+        for valid in [
+            plc(PlcManufacturer::LS, ""),
+            plc(PlcManufacturer::LS, "XBC-DN32H"),
+            plc(PlcManufacturer::Mitsubishi, "Q03UDV"),
+        ] {
+            let mut result = ValidationResult::new();
+            validate_plc_model(&valid, &mut result);
+            assert!(result.is_valid(), "{}", valid.model);
+        }
+
+        for unknown in [
+            plc(PlcManufacturer::Siemens, "FX5U"),
+            plc(PlcManufacturer::Mitsubishi, "FX3G"),
+        ] {
+            let mut result = ValidationResult::new();
+            validate_plc_model(&unknown, &mut result);
+            assert!(result.is_valid(), "{}", unknown.model);
+            assert_eq!(result.warnings()[0].0, "plc.model");
+        }
+
+        let mut result = ValidationResult::new();
+        validate_plc_model(&plc(PlcManufacturer::Siemens, "FX5U"), &mut result);
+        assert!(result.warnings()[0].1.contains("S7-15"));
+    }
+
     #[test]
     fn test_validate_custom_modbus_exposure_requires_rules() {
         let mut result = ValidationResult::new();
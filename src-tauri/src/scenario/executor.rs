@@ -15,6 +15,7 @@ use tokio::sync::mpsc;
 use super::types::{Scenario, ScenarioEvent};
 use crate::modbus::types::{ChangeSource, MemoryType};
 use crate::modbus::ModbusMemory;
+use crate::sim::ScenarioPosition;
 
 // ============================================================================
 // Execution State Types
@@ -412,6 +413,38 @@ impl ScenarioExecutor {
         Ok(())
     }
 
+    /// Current position for state snapshots (`None` when idle)
+    pub fn position(&self) -> Option<ScenarioPosition> {
+        if self.state == ScenarioState::Idle {
+            return None;
+        }
+        let scenario = self.scenario.as_ref()?;
+        Some(ScenarioPosition {
+            scenario_name: scenario.metadata.name.clone(),
+            elapsed_secs: self.calculate_elapsed().as_secs_f64(),
+            current_loop: self.current_loop,
+        })
+    }
+
+    /// Return to a saved position. The saved scenario must be the one loaded.
+    pub fn restore_position(&mut self, position: &ScenarioPosition) -> Result<(), String> {
+        let loaded = self
+            .scenario
+            .as_ref()
+            .map(|s| s.metadata.name.as_str())
+            .unwrap_or_default();
+        if loaded != position.scenario_name {
+            return Err(format!(
+                "Cannot restore position: scenario '{}' is not loaded",
+                position.scenario_name
+            ));
+        }
+
+        self.seek(position.elapsed_secs)?;
+        self.current_loop = position.current_loop;
+        Ok(())
+    }
+
     /// Seek to a specific time position in the scenario
     ///
     /// This adjusts the execution timeline to the specified time:
@@ -1158,6 +1191,37 @@ mod tests {
         assert_eq!(executor.executed_count, 1);
     }
 
+    #[test]
+    fn test_executor_restore_position() {
+        let memory = create_test_memory();
+        let mut executor = ScenarioExecutor::new(memory);
+
+        let mut scenario = Scenario::default();
+        scenario.metadata.name = "Filling".to_string();
+        executor.load(scenario).unwrap();
+        assert!(executor.position().is_none());
+
+        executor.state = ScenarioState::Paused;
+        executor.start_time = Some(std::time::Instant::now());
+        executor.pause_time = executor.start_time;
+
+        let position = ScenarioPosition {
+            scenario_name: "Filling".to_string(),
+            elapsed_secs: 12.0,
+            current_loop: 3,
+        };
+        executor.restore_position(&position).unwrap();
+        let restored = executor.position().unwrap();
+        assert_eq!(restored.current_loop, 3);
+        assert!((restored.elapsed_secs - 12.0).abs() < 0.01);
+
+        let other = ScenarioPosition {
+            scenario_name: "Draining".to_string(),
+            ..position
+        };
+        assert!(executor.restore_position(&other).is_err());
+    }
+
     // ====================================================================
     // PLC Address Format Tests
     // ====================================================================
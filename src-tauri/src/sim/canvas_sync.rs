@@ -348,6 +348,9 @@ impl CanvasSync {
                     .read_bool(tag.canonical_address)
                     .map_err(|e| CanvasSyncError::RuntimeMemoryError(e.to_string()))
             }
+            RuntimeBinding::Expression { source } => {
+                Err(CanvasSyncError::InvalidAddress(source.clone()))
+            }
         }
     }
 
@@ -470,6 +473,9 @@ impl CanvasSync {
                     )
                     .map_err(|e| CanvasSyncError::RuntimeMemoryError(e.to_string()))
             }
+            RuntimeBinding::Expression { source } => {
+                Err(CanvasSyncError::InvalidAddress(source.clone()))
+            }
         }
     }
 
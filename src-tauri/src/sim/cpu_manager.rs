@@ -0,0 +1,420 @@
+// 멀티-CPU 매니저 — 가상 CPU N개 + inter-CPU 메모리 링크 (단계 4–5)
+//
+// primary CPU 는 기존처럼 `SimulationRuntimeHost` 가 구동(모니터링·프로토콜·디버거
+// 포함)하고, 매니저는 추가 가상 CPU 의 스캔 태스크, 실 CPU 의 폴 태스크, 선언된
+// `MemoryLink` 복사 태스크를 소유한다. 링크 충돌은 `set_links` 시점에 정적으로 거부한다.
+// 설계: docs/architecture/multi-cpu/00-design.md
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::{Mutex, RwLock};
+use serde::{Deserialize, Serialize};
+
+use modone_contract::{
+    CanonicalMemoryEvent, CanonicalRange, CpuHealth, CpuId, CpuKind, FieldLink, LinkSyncMode,
+    MemoryLink,
+};
+use plc_model::PlcMemoryLayout;
+
+use super::counter::CounterManager;
+use super::cpu_node::CpuNode;
+use super::engine::{EngineEvent, OneSimEngine};
+use super::executor::CompiledProgram;
+use super::link::{copy_link, range_contains, validate_links};
+use super::memory::CanonicalRuntimeFacade;
+use super::timer::TimerManager;
+
+/// Real CPUs poll on this tick; poll groups with shorter intervals are
+/// effectively rounded up to it.
+const REAL_CPU_POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// CPU summary for the UI
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuInfo {
+    pub id: CpuId,
+    pub kind: CpuKind,
+    pub health: CpuHealth,
+    pub primary: bool,
+    pub running: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub program_name: Option<String>,
+}
+
+struct SecondaryCpu {
+    node: Arc<CpuNode>,
+    program: Option<CompiledProgram>,
+    /// Scan loop (virtual) or poll loop (real)
+    task: Option<tokio::task::JoinHandle<()>>,
+}
+
+pub struct CpuManager {
+    primary: Arc<CpuNode>,
+    secondaries: RwLock<BTreeMap<CpuId, SecondaryCpu>>,
+    links: RwLock<Vec<MemoryLink>>,
+    link_tasks: Mutex<Vec<tokio::task::JoinHandle<()>>>,
+    running: AtomicBool,
+}
+
+impl CpuManager {
+    pub fn new(primary: Arc<CpuNode>) -> Self {
+        Self {
+            primary,
+            secondaries: RwLock::new(BTreeMap::new()),
+            links: RwLock::new(Vec::new()),
+            link_tasks: Mutex::new(Vec::new()),
+            running: AtomicBool::new(false),
+        }
+    }
+
+    pub fn primary_id(&self) -> &CpuId {
+        self.primary.id()
+    }
+
+    /// All CPU ids, primary first
+    pub fn cpu_ids(&self) -> Vec<CpuId> {
+        std::iter::once(self.primary.id().clone())
+            .chain(self.secondaries.read().keys().cloned())
+            .collect()
+    }
+
+    pub fn node(&self, id: &CpuId) -> Option<Arc<CpuNode>> {
+        if id == self.primary.id() {
+            return Some(Arc::clone(&self.primary));
+        }
+        self.secondaries
+            .read()
+            .get(id)
+            .map(|cpu| Arc::clone(&cpu.node))
+    }
+
+    pub fn list(&self) -> Vec<CpuInfo> {
+        let info = |node: &CpuNode, program_name: Option<String>| CpuInfo {
+            id: node.id().clone(),
+            kind: node.kind(),
+            health: node.health(),
+            primary: node.id() == self.primary.id(),
+            running: node
+                .engine_slot()
+                .is_some_and(|slot| slot.lock().as_ref().is_some_and(|e| e.is_running())),
+            program_name,
+        };
+
+        let mut cpus = vec![info(&self.primary, None)];
+        cpus.extend(self.secondaries.read().values().map(|cpu| {
+            let mut entry = info(
+                &cpu.node,
+                cpu.program.as_ref().map(|program| program.name.clone()),
+            );
+            if cpu.node.field_link().is_some() {
+                entry.running = cpu.task.as_ref().is_some_and(|task| !task.is_finished());
+            }
+            entry
+        }));
+        cpus
+    }
+
+    /// Add a virtual CPU with its own empty memory
+    pub fn add_virtual_cpu(&self, id: CpuId) -> Result<(), String> {
+        let runtime = Arc::new(CanonicalRuntimeFacade::new());
+        self.insert(CpuNode::new_virtual(id, runtime))
+    }
+
+    /// Add a real CPU whose memory mirrors a device. `make_link` builds the
+    /// driver for the CPU's fresh memory.
+    pub fn add_real_cpu(
+        &self,
+        id: CpuId,
+        make_link: impl FnOnce(&CanonicalRuntimeFacade) -> Result<Arc<dyn FieldLink>, String>,
+    ) -> Result<(), String> {
+        let runtime = Arc::new(CanonicalRuntimeFacade::new());
+        let link = make_link(&runtime)?;
+        self.insert(CpuNode::new_real(id, runtime, link))
+    }
+
+    fn insert(&self, node: CpuNode) -> Result<(), String> {
+        let mut secondaries = self.secondaries.write();
+        if node.id() == self.primary.id() || secondaries.contains_key(node.id()) {
+            return Err(format!("CPU '{}' already exists", node.id()));
+        }
+        secondaries.insert(
+            node.id().clone(),
+            SecondaryCpu {
+                node: Arc::new(node),
+                program: None,
+                task: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Remove a secondary CPU. CPUs referenced by a link cannot be removed.
+    pub fn remove_cpu(&self, id: &CpuId) -> Result<(), String> {
+        if id == self.primary.id() {
+            return Err("The primary CPU cannot be removed".to_string());
+        }
+        if self
+            .links
+            .read()
+            .iter()
+            .any(|link| &link.src.cpu == id || &link.dst.cpu == id)
+        {
+            return Err(format!("CPU '{}' is used by a memory link", id));
+        }
+        let mut cpu = self
+            .secondaries
+            .write()
+            .remove(id)
+            .ok_or_else(|| format!("Unknown CPU '{}'", id))?;
+        stop_secondary(&mut cpu);
+        Ok(())
+    }
+
+    /// Set the program of a secondary CPU (takes effect on the next run)
+    pub fn load_program(&self, id: &CpuId, program: CompiledProgram) -> Result<(), String> {
+        let mut secondaries = self.secondaries.write();
+        let cpu = secondaries
+            .get_mut(id)
+            .ok_or_else(|| format!("Unknown CPU '{}'", id))?;
+        if cpu.node.field_link().is_some() {
+            return Err(format!("CPU '{}' is a real device and has no program", id));
+        }
+        cpu.program = Some(program);
+        Ok(())
+    }
+
+    pub fn links(&self) -> Vec<MemoryLink> {
+        self.links.read().clone()
+    }
+
+    /// Replace the link table. Conflicting links are rejected as a whole.
+    pub fn set_links(&self, links: Vec<MemoryLink>) -> Result<(), String> {
+        validate_links(&links, &self.cpu_ids()).map_err(|e| e.to_string())?;
+        *self.links.write() = links;
+
+        // 실행 중이면 새 링크 표로 복사 태스크를 갈아끼운다
+        if self.running.load(Ordering::SeqCst) {
+            self.start_links();
+        }
+        Ok(())
+    }
+
+    /// Size virtual CPU memory for the project's CPU model. Real CPUs keep
+    /// the layout their FieldLink mirrors.
+    pub fn apply_memory_layout(&self, layout: &PlcMemoryLayout) {
+        for cpu in self.secondaries.read().values() {
+            if cpu.node.engine_slot().is_some() {
+                cpu.node.runtime().apply_memory_layout(layout);
+            }
+        }
+    }
+
+    /// Start every secondary CPU and the link tasks. The primary CPU is
+    /// started by the host beforehand so OnScan links can subscribe to it.
+    pub fn start(&self) -> Result<(), String> {
+        let mut secondaries = self.secondaries.write();
+        if let Err(err) = secondaries.values_mut().try_for_each(start_secondary) {
+            // 일부 CPU만 떠 있는 상태로 남기지 않는다
+            for cpu in secondaries.values_mut() {
+                stop_secondary(cpu);
+            }
+            return Err(err);
+        }
+        drop(secondaries);
+
+        self.start_links();
+        self.running.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+
+    pub fn stop(&self) {
+        self.running.store(false, Ordering::SeqCst);
+        self.stop_links();
+        for cpu in self.secondaries.write().values_mut() {
+            stop_secondary(cpu);
+        }
+    }
+
+    /// Stop and drop the secondary engines (timers/counters start fresh)
+    pub fn reset(&self) {
+        self.stop();
+        for cpu in self.secondaries.read().values() {
+            if let Some(slot) = cpu.node.engine_slot() {
+                *slot.lock() = None;
+            }
+        }
+    }
+
+    fn start_links(&self) {
+        self.stop_links();
+        let tasks = self
+            .links
+            .read()
+            .iter()
+            .filter_map(|link| {
+                let src = self.node(&link.src.cpu)?;
+                let dst = self.node(&link.dst.cpu)?;
+                Some(spawn_link_task(link.clone(), src, dst))
+            })
+            .collect();
+        *self.link_tasks.lock() = tasks;
+    }
+
+    fn stop_links(&self) {
+        for task in self.link_tasks.lock().drain(..) {
+            task.abort();
+        }
+    }
+}
+
+fn start_secondary(cpu: &mut SecondaryCpu) -> Result<(), String> {
+    stop_secondary(cpu);
+    let Some(slot) = cpu.node.engine_slot() else {
+        cpu.task = cpu
+            .node
+            .field_link()
+            .map(|link| spawn_poll_task(cpu.node.id().clone(), Arc::clone(link)));
+        return Ok(());
+    };
+    let engine = Arc::clone(slot.lock().get_or_insert_with(|| {
+        Arc::new(OneSimEngine::with_components(
+            Arc::clone(cpu.node.runtime()),
+            Arc::new(TimerManager::new()),
+            Arc::new(CounterManager::new()),
+        ))
+    }));
+    let program = cpu.program.clone().unwrap_or_else(|| CompiledProgram {
+        name: "Default Program".to_string(),
+        networks: vec![],
+    });
+    engine
+        .start(program)
+        .map_err(|e| format!("CPU '{}': {}", cpu.node.id(), e))?;
+    cpu.task = Some(tokio::spawn(async move {
+        engine.run_scan_loop().await;
+    }));
+    Ok(())
+}
+
+fn stop_secondary(cpu: &mut SecondaryCpu) {
+    if let Some(engine) = cpu.node.engine_slot().and_then(|slot| slot.lock().clone()) {
+        engine.stop();
+    }
+    if let Some(task) = cpu.task.take() {
+        task.abort();
+    }
+}
+
+/// Drive a real CPU: poll the device, then push changed outputs back. Errors
+/// are logged once per health transition rather than on every tick.
+fn spawn_poll_task(id: CpuId, link: Arc<dyn FieldLink>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(REAL_CPU_POLL_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        let mut last_health = link.health();
+        loop {
+            interval.tick().await;
+            let cycle_link = Arc::clone(&link);
+            let result = tokio::task::spawn_blocking(move || {
+                cycle_link.poll_device()?;
+                cycle_link.write_device()
+            })
+            .await;
+
+            let health = link.health();
+            if health != last_health {
+                match result {
+                    Ok(Err(err)) => log::warn!("CPU '{}' is {:?}: {}", id, health, err),
+                    _ => log::info!("CPU '{}' is {:?}", id, health),
+                }
+                last_health = health;
+            }
+        }
+    })
+}
+
+fn spawn_link_task(
+    link: MemoryLink,
+    src: Arc<CpuNode>,
+    dst: Arc<CpuNode>,
+) -> tokio::task::JoinHandle<()> {
+    // OnScan 은 src 가 가상 CPU 이고 엔진이 떠 있을 때만 의미가 있다
+    let scan_events = match link.sync {
+        LinkSyncMode::OnScan => {
+            let events = src
+                .engine_slot()
+                .and_then(|slot| slot.lock().as_ref().map(|engine| engine.subscribe_events()));
+            if events.is_none() {
+                log::warn!(
+                    "Link {} -> {} is OnScan but CPU '{}' is not running; using Eventual",
+                    link.src.cpu,
+                    link.dst.cpu,
+                    link.src.cpu
+                );
+            }
+            events
+        }
+        _ => None,
+    };
+    let bus_events = (scan_events.is_none() && !matches!(link.sync, LinkSyncMode::Periodic(_)))
+        .then(|| src.runtime().handle().read().bus().subscribe());
+
+    tokio::spawn(async move {
+        let copy = || {
+            if let Err(err) = copy_link(&link, src.runtime(), dst.runtime()) {
+                log::warn!(
+                    "Memory link {} -> {} failed: {}",
+                    link.src.cpu,
+                    link.dst.cpu,
+                    err
+                );
+            }
+        };
+        copy();
+
+        if let Some(mut rx) = scan_events {
+            loop {
+                match rx.recv().await {
+                    Ok(EngineEvent::ScanComplete(_)) => copy(),
+                    Ok(_) => {}
+                    Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => copy(),
+                    Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+                }
+            }
+            return;
+        }
+
+        if let LinkSyncMode::Periodic(ms) = link.sync {
+            let mut interval = tokio::time::interval(Duration::from_millis(ms.max(1) as u64));
+            loop {
+                interval.tick().await;
+                copy();
+            }
+        }
+
+        let Some(mut rx) = bus_events else {
+            return;
+        };
+        loop {
+            match rx.recv().await {
+                Ok(event) if event_touches(&event, &link.src.range) => copy(),
+                Ok(_) => {}
+                Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => copy(),
+                Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    })
+}
+
+fn event_touches(event: &CanonicalMemoryEvent, range: &CanonicalRange) -> bool {
+    match event {
+        CanonicalMemoryEvent::Single(change) => range_contains(range, &change.address),
+        CanonicalMemoryEvent::Batch(batch) => batch
+            .changes
+            .iter()
+            .any(|change| range_contains(range, &change.address)),
+    }
+}
@@ -1,14 +1,14 @@
 // 단일 CPU를 메모리·드라이버·헬스로 묶는 노드 — 멀티-CPU 비파괴 래핑(단계 2)
 //
-// 현재는 단일 가상 CPU 를 감싸는 용도다. 멀티-CPU/실 CPU 는 후속 단계에서
-// `CpuManager` 가 이 노드를 N 개 보유하도록 확장한다.
+// primary CPU 는 `SimulationRuntimeHost` 가, 추가 가상/실 CPU 는 `CpuManager` 가
+// 이 노드로 보유한다.
 // 설계: docs/architecture/multi-cpu/00-design.md
 
 use std::sync::Arc;
 
 use parking_lot::Mutex;
 
-use modone_contract::{CpuHealth, CpuId, CpuKind};
+use modone_contract::{CpuHealth, CpuId, CpuKind, FieldLink};
 
 use super::engine::OneSimEngine;
 use super::memory::CanonicalRuntimeFacade;
@@ -16,16 +16,19 @@ use super::memory::CanonicalRuntimeFacade;
 /// 단일 CPU 프로젝트의 기본 primary CPU 식별자.
 pub const PRIMARY_CPU_ID: &str = "cpu-0";
 
-/// CPU 메모리를 전진시키는 드라이버. 가상은 래더 엔진, 실은 FieldLink(후속 단계).
+/// CPU 메모리를 전진시키는 드라이버. 가상은 래더 엔진, 실은 FieldLink.
 pub enum CpuDriver {
     /// 래더 엔진이 권위자. 엔진은 run() 시점에 지연 생성되므로 슬롯으로 보관한다.
     Virtual(Arc<Mutex<Option<Arc<OneSimEngine>>>>),
+    /// 실 장비가 권위자, 메모리는 미러. 엔진/타이머/디버거 없는 경량 노드.
+    Real(Arc<dyn FieldLink>),
 }
 
 impl CpuDriver {
     pub fn kind(&self) -> CpuKind {
         match self {
             CpuDriver::Virtual(_) => CpuKind::Virtual,
+            CpuDriver::Real(_) => CpuKind::Real,
         }
     }
 }
@@ -47,6 +50,19 @@ impl CpuNode {
         }
     }
 
+    /// 실 CPU 노드 생성. `link` 는 `runtime` 을 미러 대상으로 만들어져 있어야 한다.
+    pub fn new_real(
+        id: CpuId,
+        runtime: Arc<CanonicalRuntimeFacade>,
+        link: Arc<dyn FieldLink>,
+    ) -> Self {
+        Self {
+            id,
+            runtime,
+            driver: CpuDriver::Real(link),
+        }
+    }
+
     pub fn id(&self) -> &CpuId {
         &self.id
     }
@@ -63,13 +79,23 @@ impl CpuNode {
     pub fn health(&self) -> CpuHealth {
         match &self.driver {
             CpuDriver::Virtual(_) => CpuHealth::Good,
+            CpuDriver::Real(link) => link.health(),
         }
     }
 
     /// 가상 드라이버의 엔진 슬롯 핸들(지연 생성/교체를 위해 슬롯을 노출).
-    pub fn engine_slot(&self) -> &Arc<Mutex<Option<Arc<OneSimEngine>>>> {
+    /// 실 CPU 는 엔진이 없으므로 `None`.
+    pub fn engine_slot(&self) -> Option<&Arc<Mutex<Option<Arc<OneSimEngine>>>>> {
+        match &self.driver {
+            CpuDriver::Virtual(slot) => Some(slot),
+            CpuDriver::Real(_) => None,
+        }
+    }
+
+    pub fn field_link(&self) -> Option<&Arc<dyn FieldLink>> {
         match &self.driver {
-            CpuDriver::Virtual(slot) => slot,
+            CpuDriver::Virtual(_) => None,
+            CpuDriver::Real(link) => Some(link),
         }
     }
 }
@@ -13,7 +13,10 @@ use tokio::sync::{broadcast, oneshot};
 
 use super::counter::CounterManager;
 use super::executor::{CompiledProgram, ProgramExecutor};
+use super::fault::{FaultKind, ScanFault};
+use super::history::{HistoryPosition, ScanCheckpoint, ScanHistory, ValueChangeLocation};
 use super::memory::CanonicalRuntimeFacade;
+use super::profiler::{slowest_networks, ScanProfiler, SlowNetwork, DEFAULT_SLOWEST_LIMIT};
 use super::timer::TimerManager;
 use super::types::{ScanCycleInfo, SimulationConfig, SimulationState, SimulationStatus};
 
@@ -36,6 +39,14 @@ pub enum EngineError {
     #[error("No program loaded")]
     NoProgramLoaded,
 
+    /// Time travel requested while the scan loop is running
+    #[error("Time travel requires the simulation to be paused or stopped")]
+    NotPaused,
+
+    /// No checkpoint available in the requested direction
+    #[error("No scan history available: {0}")]
+    HistoryUnavailable(String),
+
     /// Watchdog timeout
     #[error("Watchdog timeout: scan took {elapsed_ms}ms, limit is {limit_ms}ms")]
     WatchdogTimeout { elapsed_ms: u64, limit_ms: u64 },
@@ -85,6 +96,8 @@ pub enum EngineEvent {
     StateChange(StateChangeEvent),
     #[serde(rename = "watchdog")]
     Watchdog(WatchdogEvent),
+    #[serde(rename = "fault")]
+    Fault(FaultEvent),
 }
 
 /// Event emitted on state change
@@ -109,6 +122,23 @@ pub struct WatchdogEvent {
     pub limit_ms: u64,
     /// Scan count when timeout occurred
     pub scan_count: u64,
+    /// Slowest networks of the offending scan, slowest first
+    #[serde(default)]
+    pub slowest_networks: Vec<SlowNetwork>,
+    /// Timestamp (ISO 8601)
+    pub timestamp: String,
+}
+
+/// Event emitted when a scan raised runtime faults
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaultEvent {
+    /// Scan count when the faults occurred
+    pub scan_count: u64,
+    /// Faults raised during the scan, in execution order
+    pub faults: Vec<ScanFault>,
+    /// Whether the CPU was halted (ERROR state)
+    pub halted: bool,
     /// Timestamp (ISO 8601)
     pub timestamp: String,
 }
@@ -117,6 +147,9 @@ pub struct WatchdogEvent {
 // Scan Cycle Engine
 // ============================================================================
 
+/// Input overlay run at the start of each scan, before program execution
+pub type PreScanHook = Box<dyn Fn(&CanonicalRuntimeFacade) + Send + Sync>;
+
 /// PLC Scan Cycle Engine
 ///
 /// Implements the three-phase scan cycle:
@@ -159,10 +192,19 @@ pub struct OneSimEngine {
     min_scan_time_us: AtomicU64,
     /// Total scan time for averaging
     total_scan_time_us: AtomicU64,
+    /// Per-network / per-instruction profiler
+    profiler: Arc<ScanProfiler>,
+    /// Rolling per-scan checkpoints for stepping backwards
+    history: Arc<ScanHistory>,
 
     // Error state
     /// Last error message
     last_error: RwLock<Option<String>>,
+    /// Faults of the last reported scan; a persisting fault is emitted once
+    reported_faults: RwLock<Vec<(u32, FaultKind)>>,
+
+    /// Input overlay (e.g. injected field faults) applied before each scan
+    pre_scan_hook: RwLock<Option<PreScanHook>>,
 
     // Shutdown signal
     shutdown_tx: RwLock<Option<oneshot::Sender<()>>>,
@@ -198,7 +240,11 @@ impl OneSimEngine {
             max_scan_time_us: AtomicU64::new(0),
             min_scan_time_us: AtomicU64::new(u64::MAX),
             total_scan_time_us: AtomicU64::new(0),
+            profiler: Arc::new(ScanProfiler::default()),
+            history: Arc::new(ScanHistory::default()),
             last_error: RwLock::new(None),
+            reported_faults: RwLock::new(Vec::new()),
+            pre_scan_hook: RwLock::new(None),
             shutdown_tx: RwLock::new(None),
             event_tx,
         }
@@ -232,7 +278,11 @@ impl OneSimEngine {
             max_scan_time_us: AtomicU64::new(0),
             min_scan_time_us: AtomicU64::new(u64::MAX),
             total_scan_time_us: AtomicU64::new(0),
+            profiler: Arc::new(ScanProfiler::default()),
+            history: Arc::new(ScanHistory::default()),
             last_error: RwLock::new(None),
+            reported_faults: RwLock::new(Vec::new()),
+            pre_scan_hook: RwLock::new(None),
             shutdown_tx: RwLock::new(None),
             event_tx,
         }
@@ -257,11 +307,37 @@ impl OneSimEngine {
         &self.counter_mgr
     }
 
+    /// Install the input overlay run at the start of every scan.
+    ///
+    /// The hook sees the inputs after all writes since the previous scan, so
+    /// its values are the ones the program reads.
+    pub fn set_pre_scan_hook(
+        &self,
+        hook: impl Fn(&CanonicalRuntimeFacade) + Send + Sync + 'static,
+    ) {
+        *self.pre_scan_hook.write() = Some(Box::new(hook));
+    }
+
+    /// Remove the input overlay
+    pub fn clear_pre_scan_hook(&self) {
+        *self.pre_scan_hook.write() = None;
+    }
+
     /// Get program executor
     pub fn executor(&self) -> &Arc<ProgramExecutor> {
         &self.executor
     }
 
+    /// Get scan profiler
+    pub fn profiler(&self) -> &Arc<ScanProfiler> {
+        &self.profiler
+    }
+
+    /// Get scan history
+    pub fn history(&self) -> &Arc<ScanHistory> {
+        &self.history
+    }
+
     /// Subscribe to internal engine lifecycle/scan events.
     pub fn subscribe_events(&self) -> broadcast::Receiver<EngineEvent> {
         self.event_tx.subscribe()
@@ -299,6 +375,7 @@ impl OneSimEngine {
         self.should_stop.store(false, Ordering::Relaxed);
         self.reset_statistics();
         *self.last_error.write() = None;
+        self.reported_faults.write().clear();
 
         // Set state to running
         let prev_state = self.state.swap(STATE_RUNNING, Ordering::SeqCst);
@@ -360,6 +437,101 @@ impl OneSimEngine {
         Ok(())
     }
 
+    // ========================================================================
+    // Time Travel
+    // ========================================================================
+
+    /// Step one scan backwards through the recorded history
+    pub fn step_back(&self) -> EngineResult<HistoryPosition> {
+        self.ensure_not_running()?;
+        let checkpoint = self.history.step_back().ok_or_else(|| {
+            EngineError::HistoryUnavailable("already at the oldest checkpoint".to_string())
+        })?;
+        self.restore_checkpoint(&checkpoint)
+    }
+
+    /// Step one scan forwards: replays a recorded checkpoint while rewound,
+    /// otherwise executes a new scan.
+    pub fn step_forward(&self) -> EngineResult<HistoryPosition> {
+        self.ensure_not_running()?;
+        match self.history.step_forward() {
+            Some(checkpoint) => self.restore_checkpoint(&checkpoint),
+            None => {
+                self.single_scan()?;
+                self.history_position()
+            }
+        }
+    }
+
+    /// Rewind to the checkpoint just before `address` last changed
+    pub fn run_back_to_change(
+        &self,
+        address: modone_contract::CanonicalAddress,
+    ) -> EngineResult<ValueChangeLocation> {
+        self.ensure_not_running()?;
+        let (checkpoint, location) =
+            self.history.rewind_to_last_change(address).ok_or_else(|| {
+                EngineError::HistoryUnavailable(
+                    "no change of the address in the recorded scans".to_string(),
+                )
+            })?;
+        self.restore_checkpoint(&checkpoint)?;
+        Ok(location)
+    }
+
+    // ========================================================================
+    // Snapshots
+    // ========================================================================
+
+    /// Capture the full PLC state. While running, the end of the last completed
+    /// scan is used so the state is never taken mid-scan.
+    pub fn capture_state(&self) -> ScanCheckpoint {
+        if self.is_running() {
+            if let Some(checkpoint) = self.history.latest() {
+                return checkpoint;
+            }
+        }
+        // 히스토리가 꺼져 있으면 프로그램 잠금으로 진행 중인 스캔이 끝나길 기다린다
+        let _program = self.program.write();
+        self.executor
+            .capture_checkpoint(self.scan_count.load(Ordering::Relaxed))
+    }
+
+    /// Restore a saved state. The scan history restarts from the restored checkpoint.
+    pub fn restore_state(&self, checkpoint: &ScanCheckpoint) -> EngineResult<()> {
+        self.ensure_not_running()?;
+        self.executor
+            .restore_checkpoint(checkpoint)
+            .map_err(|e| EngineError::Internal(e.to_string()))?;
+        self.scan_count
+            .store(checkpoint.scan_count(), Ordering::Relaxed);
+        self.history.clear();
+        self.history.record(checkpoint.clone());
+        Ok(())
+    }
+
+    fn ensure_not_running(&self) -> EngineResult<()> {
+        if self.is_running() {
+            return Err(EngineError::NotPaused);
+        }
+        Ok(())
+    }
+
+    fn restore_checkpoint(&self, checkpoint: &ScanCheckpoint) -> EngineResult<HistoryPosition> {
+        self.executor
+            .restore_checkpoint(checkpoint)
+            .map_err(|e| EngineError::Internal(e.to_string()))?;
+        self.scan_count
+            .store(checkpoint.scan_count(), Ordering::Relaxed);
+        self.history_position()
+    }
+
+    fn history_position(&self) -> EngineResult<HistoryPosition> {
+        self.history
+            .position()
+            .ok_or_else(|| EngineError::HistoryUnavailable("history is empty".to_string()))
+    }
+
     /// Check if simulation is running
     pub fn is_running(&self) -> bool {
         self.state.load(Ordering::Relaxed) == STATE_RUNNING
@@ -453,30 +625,67 @@ impl OneSimEngine {
     /// Execute a single scan cycle
     fn execute_scan_cycle(&self) {
         let start = Instant::now();
+        let mut faults = None;
+        let mut result = None;
+
+        // Phase 0: Input overlay. 스캔 사이에 들어온 쓰기 뒤에 적용해야 프로그램이
+        // 고장 주입 같은 덮어쓰기 값을 읽는다.
+        if let Some(ref hook) = *self.pre_scan_hook.read() {
+            hook(&self.runtime);
+        }
 
         // Phase 1: Program Execution
-        if let Some(ref program) = *self.program.read() {
+        let program = self.program.read();
+        if let Some(ref compiled) = *program {
             // Update timer tick
             let delta_ms = self.config.read().scan_time_ms;
             self.timer_mgr.tick(delta_ms);
 
             // Execute program
-            let result = self.executor.execute_program(program);
-
-            if !result.success {
-                if let Some(err) = result.error {
-                    self.handle_error(&err);
-                }
+            let mut executed = self.executor.execute_program(compiled);
+            self.profiler.record(&executed);
+
+            // 네트워크 단위 오류는 fault 로만 보고해 매 스캔 중복 로그를 막는다
+            if !executed.faults.is_empty() {
+                faults = Some((std::mem::take(&mut executed.faults), executed.halted));
+            } else if let Some(ref err) = executed.error {
+                self.handle_error(err);
             }
+            result = Some(executed);
         }
 
         // Update statistics
         let elapsed = start.elapsed();
         self.update_statistics(elapsed);
 
+        // 감시 시간은 스캔 전체 기준이므로 초과했을 때만 느린 네트워크를 고른다
+        let watchdog_tripped = !self.check_watchdog(elapsed);
+        let slowest = match (&*program, &result) {
+            (Some(compiled), Some(result)) if watchdog_tripped => {
+                slowest_networks(compiled, result, DEFAULT_SLOWEST_LIMIT)
+            }
+            _ => Vec::new(),
+        };
+        drop(program);
+
+        match faults {
+            Some((faults, halted)) => self.handle_faults(faults, halted),
+            // 오류가 사라진 뒤 다시 나면 새로 알린다
+            None => self.reported_faults.write().clear(),
+        }
+
+        // 체크포인트 캡처는 스캔 시간에 포함하지 않는다. 메모리 전체를 복사하므로
+        // 히스토리를 켠 경우에만 캡처한다.
+        if self.history.is_enabled() {
+            self.history.record(
+                self.executor
+                    .capture_checkpoint(self.scan_count.load(Ordering::Relaxed)),
+            );
+        }
+
         // Check watchdog
-        if !self.check_watchdog(elapsed) {
-            self.handle_watchdog_timeout(elapsed);
+        if watchdog_tripped {
+            self.handle_watchdog_timeout(elapsed, slowest);
         }
 
         self.emit_scan_complete();
@@ -494,6 +703,8 @@ impl OneSimEngine {
         self.max_scan_time_us.store(0, Ordering::Relaxed);
         self.min_scan_time_us.store(u64::MAX, Ordering::Relaxed);
         self.total_scan_time_us.store(0, Ordering::Relaxed);
+        self.profiler.reset();
+        self.history.clear();
     }
 
     /// Update scan statistics
@@ -535,19 +746,25 @@ impl OneSimEngine {
     }
 
     /// Handle watchdog timeout
-    fn handle_watchdog_timeout(&self, elapsed: Duration) {
+    fn handle_watchdog_timeout(&self, elapsed: Duration, slowest_networks: Vec<SlowNetwork>) {
         let limit_ms = self.config.read().watchdog_timeout_ms as u64;
         let elapsed_ms = elapsed.as_millis() as u64;
 
+        let culprits = slowest_networks
+            .iter()
+            .map(|n| format!("#{} {}us", n.network_id, n.execution_time_us))
+            .collect::<Vec<_>>()
+            .join(", ");
         let error_msg = format!(
-            "Watchdog timeout: scan took {}ms, limit is {}ms",
-            elapsed_ms, limit_ms
+            "Watchdog timeout: scan took {}ms, limit is {}ms (slowest networks: {})",
+            elapsed_ms, limit_ms, culprits
         );
         log::warn!("{}", error_msg);
         let event = WatchdogEvent {
             elapsed_ms,
             limit_ms,
             scan_count: self.scan_count.load(Ordering::Relaxed),
+            slowest_networks,
             timestamp: chrono::Utc::now().to_rfc3339(),
         };
         let _ = self.event_tx.send(EngineEvent::Watchdog(event));
@@ -566,6 +783,53 @@ impl OneSimEngine {
         // For now, we just log and continue
     }
 
+    /// Report scan faults; a halting fault moves the CPU to the ERROR state.
+    /// The same faults recurring on consecutive scans are reported once.
+    fn handle_faults(&self, faults: Vec<ScanFault>, halted: bool) {
+        let signature: Vec<_> = faults
+            .iter()
+            .map(|fault| (fault.network_id, fault.kind))
+            .collect();
+        {
+            let mut reported = self.reported_faults.write();
+            if *reported == signature && !halted {
+                return;
+            }
+            *reported = signature;
+        }
+
+        if let Some(fault) = faults.first() {
+            log::warn!(
+                "Scan fault 0x{:04X} in network {}: {}",
+                fault.code,
+                fault.network_id,
+                fault.message
+            );
+            *self.last_error.write() = Some(fault.message.clone());
+        }
+
+        if halted {
+            if let Some(fault) = faults.last() {
+                *self.last_error.write() = Some(format!(
+                    "CPU halted by fault 0x{:04X} in network {}: {}",
+                    fault.code, fault.network_id, fault.message
+                ));
+            }
+            let prev_state = self.state.swap(STATE_ERROR, Ordering::SeqCst);
+            if prev_state != STATE_ERROR {
+                self.emit_state_change(prev_state, STATE_ERROR);
+            }
+        }
+
+        let event = FaultEvent {
+            scan_count: self.scan_count.load(Ordering::Relaxed),
+            faults,
+            halted,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        };
+        let _ = self.event_tx.send(EngineEvent::Fault(event));
+    }
+
     fn emit_state_change(&self, prev_state: u8, new_state: u8) {
         let prev = match prev_state {
             STATE_RUNNING => SimulationState::Running,
@@ -626,7 +890,8 @@ mod tests {
     use crate::sim::executor::{
         compile_program, CompiledProgram, LadderNetwork, LadderNode, LadderProgram, NodeType,
     };
-    use crate::sim::types::SimBitDeviceType;
+    use crate::sim::fault::{FaultAction, FaultPolicy};
+    use crate::sim::types::{SimBitDeviceType, SimWordDeviceType};
 
     fn create_test_program() -> LadderProgram {
         LadderProgram {
@@ -715,6 +980,202 @@ mod tests {
         assert_eq!(engine.scan_count.load(Ordering::Relaxed), 2);
     }
 
+    #[test]
+    fn test_pre_scan_hook_overrides_writes_between_scans() {
+        let engine = OneSimEngine::new();
+        *engine.program.write() = Some(compile_test_program());
+        // M0 을 0 에 고정하는 입력 고장
+        engine.set_pre_scan_hook(|runtime| {
+            runtime.write_bit(SimBitDeviceType::M, 0, false).unwrap();
+        });
+
+        engine
+            .runtime
+            .write_bit(SimBitDeviceType::M, 0, true)
+            .unwrap();
+        engine.single_scan().unwrap();
+        assert!(!engine.runtime.read_bit(SimBitDeviceType::P, 0).unwrap());
+
+        engine.clear_pre_scan_hook();
+        engine
+            .runtime
+            .write_bit(SimBitDeviceType::M, 0, true)
+            .unwrap();
+        engine.single_scan().unwrap();
+        assert!(engine.runtime.read_bit(SimBitDeviceType::P, 0).unwrap());
+    }
+
+    #[test]
+    fn test_step_back_restores_previous_scan() {
+        let engine = OneSimEngine::new();
+        engine.history.set_enabled(true);
+        *engine.program.write() = Some(compile_test_program());
+
+        engine.single_scan().unwrap();
+        engine
+            .runtime
+            .write_bit(SimBitDeviceType::M, 0, true)
+            .unwrap();
+        engine.single_scan().unwrap();
+        assert!(engine.runtime.read_bit(SimBitDeviceType::P, 0).unwrap());
+
+        let position = engine.step_back().unwrap();
+        assert_eq!(position.scan_count, 1);
+        assert_eq!(engine.scan_count.load(Ordering::Relaxed), 1);
+        assert!(!engine.runtime.read_bit(SimBitDeviceType::P, 0).unwrap());
+
+        let position = engine.step_forward().unwrap();
+        assert!(position.at_latest);
+        assert!(engine.runtime.read_bit(SimBitDeviceType::P, 0).unwrap());
+
+        // 최신 시점에서는 새 스캔을 실행한다
+        let position = engine.step_forward().unwrap();
+        assert_eq!(position.scan_count, 3);
+    }
+
+    #[test]
+    fn test_restore_state_restarts_history() {
+        let engine = OneSimEngine::new();
+        engine.history.set_enabled(true);
+        *engine.program.write() = Some(compile_test_program());
+
+        engine
+            .runtime
+            .write_bit(SimBitDeviceType::M, 0, true)
+            .unwrap();
+        engine.single_scan().unwrap();
+        let saved = engine.capture_state();
+
+        engine
+            .runtime
+            .write_bit(SimBitDeviceType::M, 0, false)
+            .unwrap();
+        engine.single_scan().unwrap();
+        engine.single_scan().unwrap();
+        assert!(!engine.runtime.read_bit(SimBitDeviceType::P, 0).unwrap());
+
+        engine.restore_state(&saved).unwrap();
+        assert_eq!(engine.scan_count.load(Ordering::Relaxed), 1);
+        assert!(engine.runtime.read_bit(SimBitDeviceType::P, 0).unwrap());
+        assert_eq!(engine.history.position().unwrap().len, 1);
+    }
+
+    #[test]
+    fn test_halt_fault_moves_to_error_state() {
+        let profile = LsProfile::new("XGK".to_string(), PlcHardwareTopology::default());
+        let program = LadderProgram {
+            name: "Fault".to_string(),
+            networks: vec![LadderNetwork {
+                id: 0,
+                nodes: vec![LadderNode::math(NodeType::MathDiv, "D0", "D1", "D2")],
+                comment: None,
+            }],
+        };
+        let policy = FaultPolicy {
+            action: FaultAction::Halt,
+            diagnostic_word: Some("D9000".to_string()),
+            ..FaultPolicy::default()
+        };
+
+        let engine = OneSimEngine::new();
+        engine
+            .executor
+            .set_fault_handling(policy.resolve(&profile).unwrap());
+        *engine.program.write() = Some(compile_program(&program, &profile).unwrap());
+        engine.state.store(STATE_RUNNING, Ordering::SeqCst);
+        let mut rx = engine.subscribe_events();
+
+        engine.single_scan().unwrap();
+
+        let status = engine.get_status();
+        assert_eq!(status.state, SimulationState::Error);
+        assert!(status.error.is_some());
+        let fault = std::iter::from_fn(|| rx.try_recv().ok())
+            .find_map(|event| match event {
+                EngineEvent::Fault(event) => Some(event),
+                _ => None,
+            })
+            .expect("fault event");
+        assert!(fault.halted);
+        assert_eq!(fault.faults[0].code, 0x0201);
+    }
+
+    #[test]
+    fn test_persisting_fault_is_reported_once() {
+        let profile = LsProfile::new("XGK".to_string(), PlcHardwareTopology::default());
+        let program = LadderProgram {
+            name: "Fault".to_string(),
+            networks: vec![LadderNetwork {
+                id: 0,
+                nodes: vec![LadderNode::math(NodeType::MathDiv, "D0", "D1", "D2")],
+                comment: None,
+            }],
+        };
+
+        let policy = FaultPolicy {
+            action: FaultAction::SkipNetwork,
+            diagnostic_word: Some("D9000".to_string()),
+            ..FaultPolicy::default()
+        };
+
+        let engine = OneSimEngine::new();
+        engine
+            .executor
+            .set_fault_handling(policy.resolve(&profile).unwrap());
+        *engine.program.write() = Some(compile_program(&program, &profile).unwrap());
+        engine.state.store(STATE_RUNNING, Ordering::SeqCst);
+        let mut rx = engine.subscribe_events();
+        let mut fault_events = || {
+            std::iter::from_fn(|| rx.try_recv().ok())
+                .filter(|event| matches!(event, EngineEvent::Fault(_)))
+                .count()
+        };
+
+        engine.single_scan().unwrap();
+        engine.single_scan().unwrap();
+        assert_eq!(fault_events(), 1);
+        assert!(engine.get_status().error.is_some());
+
+        // 오류가 사라졌다가 다시 나면 한 번 더 알린다
+        engine
+            .runtime
+            .write_word(SimWordDeviceType::D, 1, 1)
+            .unwrap();
+        engine.single_scan().unwrap();
+        engine
+            .runtime
+            .write_word(SimWordDeviceType::D, 1, 0)
+            .unwrap();
+        engine.single_scan().unwrap();
+        assert_eq!(fault_events(), 1);
+    }
+
+    #[test]
+    fn test_division_by_zero_without_policy_raises_no_fault() {
+        let profile = LsProfile::new("XGK".to_string(), PlcHardwareTopology::default());
+        let program = LadderProgram {
+            name: "Fault".to_string(),
+            networks: vec![LadderNetwork {
+                id: 0,
+                nodes: vec![LadderNode::math(NodeType::MathDiv, "D0", "D1", "D2")],
+                comment: None,
+            }],
+        };
+
+        let engine = OneSimEngine::new();
+        *engine.program.write() = Some(compile_program(&program, &profile).unwrap());
+        engine.state.store(STATE_RUNNING, Ordering::SeqCst);
+        let mut rx = engine.subscribe_events();
+
+        engine.single_scan().unwrap();
+
+        assert!(std::iter::from_fn(|| rx.try_recv().ok())
+            .all(|event| !matches!(event, EngineEvent::Fault(_))));
+        let status = engine.get_status();
+        assert_eq!(status.state, SimulationState::Running);
+        assert!(status.error.is_none());
+    }
+
     #[test]
     fn test_statistics_update() {
         let engine = OneSimEngine::new();
@@ -0,0 +1,163 @@
+//! canonical 메모리 버스 기록·재생 태스크
+//!
+//! `sim_engine::recording`의 순수 로직을 tokio 태스크로 구동한다. 기록 태스크는
+//! 버스를 구독해 이벤트를 모으고, 재생 태스크는 원래(또는 배속된) 간격으로
+//! 외부/시뮬레이션 쓰기를 런타임에 다시 주입한다.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use parking_lot::Mutex;
+use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+use tokio::sync::oneshot;
+
+use super::memory::CanonicalRuntimeFacade;
+use super::recording::{EventRecorder, EventRecording, EventReplayer, ReplayProgress};
+use crate::plc_runtime::{CanonicalAddress, CanonicalMemoryEvent};
+
+const SIM_REPLAY_COMPLETE_EVENT: &str = "sim:replay-complete";
+
+pub struct EventRecordingService {
+    recorder: Arc<Mutex<Option<EventRecorder>>>,
+    record_task: Mutex<Option<(oneshot::Sender<()>, tokio::task::JoinHandle<()>)>>,
+    replay_task: Mutex<Option<tokio::task::JoinHandle<()>>>,
+}
+
+impl Default for EventRecordingService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EventRecordingService {
+    pub fn new() -> Self {
+        Self {
+            recorder: Arc::new(Mutex::new(None)),
+            record_task: Mutex::new(None),
+            replay_task: Mutex::new(None),
+        }
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.recorder.lock().is_some()
+    }
+
+    pub fn start_recording(&self, runtime: &CanonicalRuntimeFacade) -> Result<(), String> {
+        let mut recorder_guard = self.recorder.lock();
+        if recorder_guard.is_some() {
+            return Err("A recording is already in progress".to_string());
+        }
+
+        let mut rx = runtime.handle().read().bus().subscribe();
+        *recorder_guard = Some(EventRecorder::new());
+        let recorder = Arc::clone(&self.recorder);
+        let (stop_tx, mut stop_rx) = oneshot::channel::<()>();
+
+        let task = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = &mut stop_rx => {
+                        // 중지 전에 버스에 올라온 이벤트는 버리지 않고 모두 기록한다
+                        loop {
+                            match rx.try_recv() {
+                                Ok(event) => record_event(&recorder, &event),
+                                Err(TryRecvError::Lagged(count)) => note_lag(&recorder, count),
+                                Err(_) => break,
+                            }
+                        }
+                        break;
+                    }
+                    received = rx.recv() => match received {
+                        Ok(event) => record_event(&recorder, &event),
+                        Err(RecvError::Lagged(count)) => note_lag(&recorder, count),
+                        Err(RecvError::Closed) => break,
+                    },
+                }
+            }
+        });
+        *self.record_task.lock() = Some((stop_tx, task));
+        Ok(())
+    }
+
+    /// Stop recording and return everything captured so far, including
+    /// events still queued on the bus
+    pub async fn stop_recording(&self) -> Result<EventRecording, String> {
+        let running = self.record_task.lock().take();
+        if let Some((stop, task)) = running {
+            let _ = stop.send(());
+            if let Err(e) = task.await {
+                log::warn!("Event recorder task ended abnormally: {}", e);
+            }
+        }
+        self.recorder
+            .lock()
+            .take()
+            .map(EventRecorder::finish)
+            .ok_or_else(|| "No recording is in progress".to_string())
+    }
+
+    /// Replay a recording into `runtime`. `speed` 2.0 plays twice as fast.
+    /// Simulation writes to `outputs` are skipped because the running program
+    /// and modules write them again.
+    pub fn start_replay(
+        &self,
+        app: AppHandle,
+        runtime: Arc<CanonicalRuntimeFacade>,
+        recording: EventRecording,
+        speed: f64,
+        outputs: Vec<CanonicalAddress>,
+    ) -> Result<ReplayProgress, String> {
+        let mut replayer = EventReplayer::new(recording, speed)
+            .map_err(|e| e.to_string())?
+            .skipping_outputs(outputs);
+        let progress = replayer.progress();
+        self.stop_replay();
+
+        let task = tokio::spawn(async move {
+            let started = tokio::time::Instant::now();
+            while let Some(due_ms) = replayer.next_due_ms() {
+                tokio::time::sleep_until(started + Duration::from_millis(due_ms)).await;
+                replayer.apply_due(&runtime, started.elapsed().as_millis() as u64);
+            }
+
+            let progress = replayer.progress();
+            if progress.rejected > 0 {
+                log::warn!(
+                    "Replay finished with {} rejected events out of {}",
+                    progress.rejected,
+                    progress.total
+                );
+            }
+            let _ = app.emit(SIM_REPLAY_COMPLETE_EVENT, progress);
+        });
+        *self.replay_task.lock() = Some(task);
+        Ok(progress)
+    }
+
+    pub fn is_replaying(&self) -> bool {
+        self.replay_task
+            .lock()
+            .as_ref()
+            .is_some_and(|task| !task.is_finished())
+    }
+
+    pub fn stop_replay(&self) {
+        if let Some(task) = self.replay_task.lock().take() {
+            task.abort();
+        }
+    }
+}
+
+fn record_event(recorder: &Mutex<Option<EventRecorder>>, event: &CanonicalMemoryEvent) {
+    if let Some(recorder) = recorder.lock().as_mut() {
+        recorder.record(event);
+    }
+}
+
+fn note_lag(recorder: &Mutex<Option<EventRecorder>>, count: u64) {
+    log::warn!("Event recorder lagged; {} events dropped", count);
+    if let Some(recorder) = recorder.lock().as_mut() {
+        recorder.note_dropped(count);
+    }
+}
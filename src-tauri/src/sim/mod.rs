@@ -4,32 +4,60 @@
 
 // 순수 코어(메모리/타이머/카운터/태그/디버거/래더 실행기)는 sim-engine 크레이트로
 // 이전됨. 기존 `crate::sim::<module>::...` 경로 호환을 위해 모듈째 재노출한다.
-pub use sim_engine::{counter, debugger, executor, memory, tag_registry, timer, types};
+pub use sim_engine::{
+    analog, counter, debugger, diff, executor, expression, fault, history, injection, link, memory,
+    motion, profiler, recording, snapshot, tag_registry, timer, translation, types, watch,
+};
 
 // native 셸 — 전송/Tauri/tokio 비동기 드라이버는 여기 잔류.
 pub mod audit;
 pub mod canvas_sync;
+pub mod cpu_manager;
 pub mod cpu_node;
 pub mod engine;
+pub mod event_recording;
 pub mod monitoring;
 pub mod protocol_runtime;
 pub mod runtime_host;
+pub mod snapshot_store;
 pub mod tag_events;
 
+pub use analog::{
+    AnalogChannelConfig, AnalogChannelState, AnalogDirection, AnalogFilter, AnalogModuleConfig,
+    AnalogModuleInfo, AnalogModuleSimulator, AnalogSignalRange, AnalogSource, AnalogWordLayout,
+};
 pub use audit::RuntimeAuditState;
 pub use canvas_sync::{
     CanvasSync, CanvasSyncError, CanvasSyncResult, PlcBlockMapping, PlcBlockType, PlcInputChange,
     PlcOutputUpdate, PlcOutputsEvent,
 };
 pub use counter::CounterManager;
+pub use cpu_manager::{CpuInfo, CpuManager};
+pub use diff::{AreaDiff, MemoryChange, MemoryDiff};
 pub use engine::{
-    EngineError, EngineResult, OneSimEngine, ScanCompleteEvent, StateChangeEvent, WatchdogEvent,
+    EngineError, EngineResult, FaultEvent, OneSimEngine, ScanCompleteEvent, StateChangeEvent,
+    WatchdogEvent,
 };
+pub use event_recording::EventRecordingService;
 pub use executor::{
     compile_program, CompiledNetwork, CompiledNode, CompiledOperand, CompiledProgram,
     DeviceAddress, ExecutionError, ExecutionResult, LadderNetwork, LadderNode, LadderProgram,
     NetworkExecutionResult, NodeType, ProgramExecutionResult, ProgramExecutor,
 };
+pub use fault::{FaultAction, FaultKind, FaultPolicy, ScanFault};
+pub use history::{HistoryPosition, ScanHistory, ValueChangeLocation};
+pub use injection::{
+    InjectedFaultInfo, InjectedFaultKind, InjectionRequest, InjectionSchedule, InputFaultInjector,
+};
+pub use link::LinkError;
 pub use memory::{CanonicalRuntimeFacade, SimMemoryError, SimMemoryResult};
+pub use motion::{
+    AxisConfig, AxisInfo, HscChannelConfig, HscChannelInfo, HscCompare, HscCountMode, HscPreset,
+    MotionError, MotionSimulator,
+};
+pub use profiler::{InstructionProfile, NetworkProfile, ScanProfile, ScanProfiler, SlowNetwork};
+pub use recording::{EventRecording, RecordingError, RecordingHeader, ReplayProgress};
+pub use snapshot::{PlcStateSnapshot, ScenarioPosition, SnapshotError, SnapshotInfo};
+pub use snapshot_store::SnapshotStoreError;
 pub use timer::TimerManager;
 pub use types::*;
@@ -7,7 +7,8 @@ use parking_lot::{Mutex, RwLock};
 use tauri::{AppHandle, Emitter};
 
 use crate::plc_runtime::{
-    CanonicalAddress, CanonicalMemoryEvent, CanonicalValue, CanonicalWriteSource,
+    BusFilter, CanonicalAddress, CanonicalMemoryChange, CanonicalValue, CanonicalWriteSource,
+    SubscriptionOptions,
 };
 
 use super::counter::CounterManager;
@@ -62,7 +63,15 @@ impl MonitoringService {
         let refresh_requested = Arc::clone(&self.refresh_requested);
         let forced_devices = Arc::clone(&self.forced_devices);
         let tracked_bindings = Arc::clone(&self.tracked_bindings);
-        let mut rx = runtime.handle().read().bus().subscribe();
+        let mut subscription =
+            runtime
+                .handle()
+                .read()
+                .bus()
+                .subscribe_filtered(SubscriptionOptions {
+                    filter: BusFilter::All,
+                    coalesce: Some(MONITORING_COALESCE_WINDOW),
+                });
         let task = tokio::spawn(async move {
             let mut tracked_values: HashMap<CanonicalAddress, CanonicalValue> = HashMap::new();
             let mut dirty = true;
@@ -70,17 +79,10 @@ impl MonitoringService {
 
             loop {
                 tokio::select! {
-                    recv = rx.recv() => {
-                        match recv {
-                            Ok(event) => {
-                                track_event(&mut tracked_values, event);
-                                dirty = true;
-                            }
-                            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
-                                dirty = true;
-                            }
-                            Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
-                        }
+                    changes = subscription.recv() => {
+                        let Some(changes) = changes else { break };
+                        track_changes(&mut tracked_values, changes);
+                        dirty = true;
                     }
                     _ = interval.tick() => {
                         let requested = refresh_requested.swap(false, Ordering::SeqCst);
@@ -128,6 +130,12 @@ impl MonitoringService {
         }
     }
 
+    /// Push every registered binding on the next tick, e.g. after memory was
+    /// restored without bus events.
+    pub fn request_refresh(&self) {
+        self.refresh_requested.store(true, Ordering::SeqCst);
+    }
+
     pub fn is_active(&self) -> bool {
         self.active.load(Ordering::Relaxed)
     }
@@ -203,26 +211,14 @@ impl MonitoringService {
     }
 }
 
-fn track_event(
+fn track_changes(
     tracked_values: &mut HashMap<CanonicalAddress, CanonicalValue>,
-    event: CanonicalMemoryEvent,
+    changes: Vec<CanonicalMemoryChange>,
 ) {
-    match event {
-        CanonicalMemoryEvent::Single(change) => {
-            if tracked_values.len() < MAX_TRACKED_VALUES
-                || tracked_values.contains_key(&change.address)
-            {
-                tracked_values.insert(change.address, change.new_value);
-            }
-        }
-        CanonicalMemoryEvent::Batch(batch) => {
-            for change in batch.changes {
-                if tracked_values.len() < MAX_TRACKED_VALUES
-                    || tracked_values.contains_key(&change.address)
-                {
-                    tracked_values.insert(change.address, change.new_value);
-                }
-            }
+    for change in changes {
+        if tracked_values.len() < MAX_TRACKED_VALUES || tracked_values.contains_key(&change.address)
+        {
+            tracked_values.insert(change.address, change.new_value);
         }
     }
 }
@@ -6,7 +6,7 @@ use parking_lot::Mutex;
 use tokio::sync::Notify;
 
 use crate::modbus::{DirtyPublishWindow, ProtocolAdapter};
-use crate::plc_runtime::{CanonicalAreaKind, CanonicalMemoryEvent};
+use crate::plc_runtime::{CanonicalAreaKind, CanonicalMemoryChange, SubscriptionOptions};
 
 use super::memory::CanonicalRuntimeFacade;
 
@@ -98,6 +98,16 @@ impl ProtocolRuntime {
         self.flush_notify.notify_one();
     }
 
+    /// Re-publish the whole canonical memory to every adapter. Needed after a
+    /// snapshot restore, which bypasses the memory bus.
+    pub fn resync_all(&self) {
+        for a in collect_adapters(&self.adapters) {
+            if let Err(err) = a.full_sync() {
+                log::warn!("[ProtocolRuntime] full sync failed: {}", err);
+            }
+        }
+    }
+
     pub fn has_adapter(&self) -> bool {
         !self.adapters.lock().is_empty()
     }
@@ -112,7 +122,12 @@ impl ProtocolRuntime {
     }
 
     fn spawn_task(&self, runtime: Arc<CanonicalRuntimeFacade>) {
-        let mut rx = runtime.handle().read().bus().subscribe();
+        // 메일박스 구독: 스캔당 변경이 많아도 lag 없이 주소별 최신값만 쌓인다
+        let mut subscription = runtime
+            .handle()
+            .read()
+            .bus()
+            .subscribe_filtered(SubscriptionOptions::default());
         let flush_notify = Arc::clone(&self.flush_notify);
         let shared_adapters = Arc::clone(&self.adapters);
 
@@ -125,22 +140,10 @@ impl ProtocolRuntime {
 
             loop {
                 tokio::select! {
-                    recv = rx.recv() => {
-                        match recv {
-                            Ok(event) => {
-                                merge_event(&mut dirty_windows, event);
-                                dirty_since.get_or_insert_with(Instant::now);
-                            }
-                            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
-                                dirty_windows.clear();
-                                let snapshot = collect_adapters(&adapters);
-                                for a in &snapshot {
-                                    let _ = a.full_sync();
-                                }
-                                last_flush = Instant::now();
-                            }
-                            Err(tokio::sync::broadcast::error::RecvError::Closed) => break,
-                        }
+                    changes = subscription.recv() => {
+                        let Some(changes) = changes else { break };
+                        merge_changes(&mut dirty_windows, changes);
+                        dirty_since.get_or_insert_with(Instant::now);
                     }
                     _ = interval.tick() => {
                         let snapshot = collect_adapters(&adapters);
@@ -190,17 +193,12 @@ fn collect_adapters(adapters: &SharedAdapterList) -> Vec<Arc<dyn ProtocolAdapter
         .collect()
 }
 
-fn merge_event(
+fn merge_changes(
     dirty_windows: &mut HashMap<CanonicalAreaKind, DirtyPublishWindow>,
-    event: CanonicalMemoryEvent,
+    changes: Vec<CanonicalMemoryChange>,
 ) {
-    match event {
-        CanonicalMemoryEvent::Single(change) => merge_address(dirty_windows, change.address),
-        CanonicalMemoryEvent::Batch(batch) => {
-            for change in batch.changes {
-                merge_address(dirty_windows, change.address);
-            }
-        }
+    for change in changes {
+        merge_address(dirty_windows, change.address);
     }
 }
 
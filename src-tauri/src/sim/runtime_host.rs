@@ -2,33 +2,49 @@ use std::sync::Arc;
 
 use parking_lot::{Mutex, RwLock};
 use tauri::{AppHandle, Emitter};
+use tokio::sync::broadcast;
 
 use crate::modbus::{ModbusAdapter, ModbusMemory};
-use crate::plc_runtime::resolve_modbus_mapping_policy;
+use crate::plc_runtime::{
+    resolve_modbus_mapping_policy, resolve_vendor_profile, CanonicalMemorySnapshot, VendorProfile,
+};
 use crate::project::{PlcSettings, ProjectConfig};
 
-use modone_contract::CpuId;
+use modone_contract::{CanonicalAddress, CpuId};
 
+use super::analog::{AnalogModuleConfig, AnalogModuleInfo, AnalogModuleSimulator};
 use super::canvas_sync::CanvasSync;
 use super::counter::CounterManager;
+use super::cpu_manager::CpuManager;
 use super::cpu_node::{CpuNode, PRIMARY_CPU_ID};
 use super::debugger::SimDebugger;
+use super::diff::{diff_memory, MemoryDiff};
 use super::engine::{EngineEvent, OneSimEngine};
+use super::event_recording::EventRecordingService;
 use super::executor::CompiledProgram;
+use super::history::{HistoryPosition, ValueChangeLocation};
+use super::injection::{InjectedFaultInfo, InjectionRequest, InputFaultInjector};
 use super::memory::CanonicalRuntimeFacade;
 use super::monitoring::MonitoringService;
+use super::motion::{AxisConfig, AxisInfo, HscChannelConfig, HscChannelInfo, MotionSimulator};
+use super::profiler::ScanProfile;
 use super::protocol_runtime::ProtocolRuntime;
+use super::snapshot::{validate_snapshot_name, PlcStateSnapshot};
 use super::tag_registry::SharedTagRegistry;
 use super::timer::TimerManager;
-use super::types::{ScanCycleInfo, SimulationConfig, SimulationStatus};
+use super::types::{ForcedDeviceValue, ScanCycleInfo, SimulationConfig, SimulationStatus};
 
 const SIM_STATUS_UPDATE_EVENT: &str = "sim:status-update";
 const SIM_SCAN_COMPLETE_EVENT: &str = "sim:scan-complete";
 const SIM_STATE_CHANGE_EVENT: &str = "sim:state-change";
 const SIM_WATCHDOG_EVENT: &str = "sim:watchdog";
+const SIM_FAULT_EVENT: &str = "sim:fault";
 
 pub struct SimulationRuntimeHost {
-    cpu: CpuNode,
+    cpu: Arc<CpuNode>,
+    /// primary CPU 는 항상 가상이므로 엔진 슬롯을 직접 들고 있는다
+    engine_slot: Arc<Mutex<Option<Arc<OneSimEngine>>>>,
+    cpus: Arc<CpuManager>,
     debugger: Arc<SimDebugger>,
     modbus_memory: Option<Arc<ModbusMemory>>,
     program: Arc<Mutex<Option<CompiledProgram>>>,
@@ -38,6 +54,11 @@ pub struct SimulationRuntimeHost {
     protocol_runtime: Arc<ProtocolRuntime>,
     canvas_sync: Arc<RwLock<Option<Arc<CanvasSync>>>>,
     tag_registry: SharedTagRegistry,
+    injector: Arc<InputFaultInjector>,
+    analog: Arc<AnalogModuleSimulator>,
+    motion: Arc<MotionSimulator>,
+    memory_baseline: Mutex<Option<CanonicalMemorySnapshot>>,
+    recording: Arc<EventRecordingService>,
 }
 
 impl SimulationRuntimeHost {
@@ -45,8 +66,12 @@ impl SimulationRuntimeHost {
         runtime: Arc<CanonicalRuntimeFacade>,
         tag_registry: SharedTagRegistry,
     ) -> Self {
+        let cpu = Arc::new(CpuNode::new_virtual(CpuId::from(PRIMARY_CPU_ID), runtime));
+        let engine_slot = Arc::clone(cpu.engine_slot().expect("virtual CPU has an engine slot"));
         Self {
-            cpu: CpuNode::new_virtual(CpuId::from(PRIMARY_CPU_ID), runtime),
+            engine_slot,
+            cpus: Arc::new(CpuManager::new(Arc::clone(&cpu))),
+            cpu,
             debugger: Arc::new(SimDebugger::with_tag_registry(
                 100,
                 Arc::clone(&tag_registry),
@@ -59,6 +84,11 @@ impl SimulationRuntimeHost {
             protocol_runtime: Arc::new(ProtocolRuntime::new()),
             canvas_sync: Arc::new(RwLock::new(None)),
             tag_registry,
+            injector: Arc::new(InputFaultInjector::new()),
+            analog: Arc::new(AnalogModuleSimulator::new()),
+            motion: Arc::new(MotionSimulator::new()),
+            memory_baseline: Mutex::new(None),
+            recording: Arc::new(EventRecordingService::new()),
         }
     }
 
@@ -73,7 +103,7 @@ impl SimulationRuntimeHost {
     }
 
     pub fn engine(&self) -> Arc<Mutex<Option<Arc<OneSimEngine>>>> {
-        Arc::clone(self.cpu.engine_slot())
+        Arc::clone(&self.engine_slot)
     }
 
     pub fn runtime(&self) -> &Arc<CanonicalRuntimeFacade> {
@@ -96,10 +126,32 @@ impl SimulationRuntimeHost {
         Arc::clone(&self.tag_registry)
     }
 
+    pub fn cpus(&self) -> Arc<CpuManager> {
+        Arc::clone(&self.cpus)
+    }
+
+    pub fn recording(&self) -> Arc<EventRecordingService> {
+        Arc::clone(&self.recording)
+    }
+
     pub fn load_program(&self, program: CompiledProgram) {
         *self.program.lock() = Some(program);
     }
 
+    /// Addresses the loaded program and the analog/motion modules write
+    /// themselves; replay leaves recorded simulation writes to them out
+    pub fn runtime_output_addresses(&self) -> Vec<CanonicalAddress> {
+        let mut addresses = self
+            .program
+            .lock()
+            .as_ref()
+            .map(CompiledProgram::output_addresses)
+            .unwrap_or_default();
+        addresses.extend(self.analog.output_addresses());
+        addresses.extend(self.motion.output_addresses());
+        addresses
+    }
+
     pub fn run(
         &self,
         app: AppHandle,
@@ -108,7 +160,7 @@ impl SimulationRuntimeHost {
         canvas_sync: Option<Arc<CanvasSync>>,
         config_override: Option<SimulationConfig>,
     ) -> Result<(), String> {
-        let mut engine_guard = self.cpu.engine_slot().lock();
+        let mut engine_guard = self.engine_slot.lock();
 
         if let Some(ref engine) = *engine_guard {
             if engine.is_running() {
@@ -116,24 +168,42 @@ impl SimulationRuntimeHost {
             }
         }
 
-        if engine_guard.is_none() {
-            let engine = Arc::new(OneSimEngine::with_components(
-                Arc::clone(self.cpu.runtime()),
-                Arc::new(TimerManager::new()),
-                Arc::new(CounterManager::new()),
-            ));
-            *engine_guard = Some(engine);
-        }
-
-        let engine = engine_guard
-            .as_ref()
-            .cloned()
-            .ok_or_else(|| "Failed to create engine".to_string())?;
+        let engine = self.ensure_engine(&mut engine_guard);
 
         if let Some(config) = config_override {
             engine.set_config(config);
+        } else if let Ok(Some(model)) = plc_model::resolve_cpu_model(&plc_settings) {
+            // 명시적 설정이 없으면 프로젝트 스캔 주기와 CPU 모델의 기본 워치독을 쓴다
+            engine.set_config(SimulationConfig {
+                scan_time_ms: plc_settings.scan_time_ms,
+                watchdog_timeout_ms: model.default_watchdog_ms,
+                ..engine.get_config()
+            });
         }
 
+        let fault_policy = project_config
+            .as_ref()
+            .map(|config| config.fault_policy.clone())
+            .unwrap_or_default();
+        let profile = resolve_vendor_profile(&plc_settings).map_err(|e| e.to_string())?;
+        engine
+            .executor()
+            .set_fault_handling(fault_policy.resolve(profile.as_ref())?);
+        let write_protection = project_config
+            .as_ref()
+            .map(|config| config.write_protection.clone())
+            .unwrap_or_default();
+        self.cpu
+            .runtime()
+            .handle()
+            .write()
+            .set_write_protection(write_protection);
+        // CPU 모델별 디바이스 크기로 메모리를 할당한다(가상 CPU 는 같은 모델로 간주)
+        self.cpu
+            .runtime()
+            .apply_memory_layout(profile.memory_layout());
+        self.cpus.apply_memory_layout(profile.memory_layout());
+
         *self.canvas_sync.write() = canvas_sync;
         self.monitoring.start(
             app.clone(),
@@ -144,6 +214,7 @@ impl SimulationRuntimeHost {
             Arc::clone(engine.counter_mgr()),
         );
         self.attach_modbus(project_config.as_ref(), &plc_settings)?;
+        self.install_input_overlay(&engine);
         self.spawn_event_forwarder(app.clone(), Arc::clone(&engine));
 
         let program = self
@@ -168,13 +239,16 @@ impl SimulationRuntimeHost {
         });
         *self.scan_task.lock() = Some(scan_task);
 
+        // 추가 CPU와 링크는 primary 엔진이 뜬 뒤 시작해야 OnScan 링크가 구독할 수 있다
+        self.cpus.start()?;
+
         emit_status_update(&app, &engine.get_status());
         Ok(())
     }
 
     pub fn stop(&self, app: &AppHandle) -> Result<(), String> {
         let stopped = {
-            let engine_guard = self.cpu.engine_slot().lock();
+            let engine_guard = self.engine_slot.lock();
             if let Some(ref engine) = *engine_guard {
                 engine.stop();
                 true
@@ -191,6 +265,7 @@ impl SimulationRuntimeHost {
             handle.abort();
         }
 
+        self.cpus.stop();
         self.protocol_runtime.detach_all();
         self.monitoring.stop();
         emit_stopped(app);
@@ -198,7 +273,7 @@ impl SimulationRuntimeHost {
     }
 
     pub fn pause(&self, app: &AppHandle) -> Result<(), String> {
-        let engine_guard = self.cpu.engine_slot().lock();
+        let engine_guard = self.engine_slot.lock();
         let engine = engine_guard
             .as_ref()
             .ok_or_else(|| "Simulation is not running".to_string())?;
@@ -208,7 +283,7 @@ impl SimulationRuntimeHost {
     }
 
     pub fn resume(&self, app: &AppHandle) -> Result<(), String> {
-        let engine_guard = self.cpu.engine_slot().lock();
+        let engine_guard = self.engine_slot.lock();
         let engine = engine_guard
             .as_ref()
             .ok_or_else(|| "Simulation is not running".to_string())?;
@@ -219,7 +294,7 @@ impl SimulationRuntimeHost {
 
     pub fn reset(&self, app: &AppHandle) {
         {
-            let mut engine_guard = self.cpu.engine_slot().lock();
+            let mut engine_guard = self.engine_slot.lock();
             if let Some(ref engine) = *engine_guard {
                 engine.stop();
             }
@@ -233,6 +308,7 @@ impl SimulationRuntimeHost {
             handle.abort();
         }
 
+        self.cpus.reset();
         self.protocol_runtime.detach_all();
         self.monitoring.stop();
         self.monitoring.clear_forces();
@@ -242,8 +318,7 @@ impl SimulationRuntimeHost {
     }
 
     pub fn status(&self) -> SimulationStatus {
-        self.cpu
-            .engine_slot()
+        self.engine_slot
             .lock()
             .as_ref()
             .map(|engine| engine.get_status())
@@ -251,8 +326,7 @@ impl SimulationRuntimeHost {
     }
 
     pub fn scan_info(&self) -> ScanCycleInfo {
-        self.cpu
-            .engine_slot()
+        self.engine_slot
             .lock()
             .as_ref()
             .map(|engine| engine.get_scan_info())
@@ -265,6 +339,272 @@ impl SimulationRuntimeHost {
             })
     }
 
+    pub fn scan_profile(&self) -> ScanProfile {
+        self.engine_slot
+            .lock()
+            .as_ref()
+            .map(|engine| engine.profiler().snapshot())
+            .unwrap_or_default()
+    }
+
+    pub fn reset_scan_profile(&self) {
+        if let Some(engine) = self.engine_slot.lock().as_ref() {
+            engine.profiler().reset();
+        }
+    }
+
+    pub fn set_instruction_profiling(&self, enabled: bool) -> Result<(), String> {
+        let guard = self.engine_slot.lock();
+        let engine = guard
+            .as_ref()
+            .ok_or_else(|| "Simulation is not running".to_string())?;
+        engine.executor().set_instruction_profiling(enabled);
+        Ok(())
+    }
+
+    /// Schedule a field-device fault on an input
+    pub fn inject_input_fault(&self, request: InjectionRequest) -> Result<u64, String> {
+        self.injector.inject(request).map_err(|e| e.to_string())
+    }
+
+    /// Clear one injected fault, or all of them, restoring the field values
+    pub fn clear_input_faults(&self, id: Option<u64>) -> Result<(), String> {
+        let runtime = self.cpu.runtime();
+        match id {
+            Some(id) => self.injector.clear(id, runtime),
+            None => self.injector.clear_all(runtime),
+        }
+        .map_err(|e| e.to_string())?;
+        self.monitoring.request_refresh();
+        Ok(())
+    }
+
+    pub fn list_input_faults(&self) -> Vec<InjectedFaultInfo> {
+        self.injector.list()
+    }
+
+    /// Add or replace a simulated analog I/O module
+    pub fn configure_analog_module(&self, config: AnalogModuleConfig) -> Result<(), String> {
+        self.analog.configure(config).map_err(|e| e.to_string())
+    }
+
+    pub fn remove_analog_module(&self, id: &str) -> Result<(), String> {
+        self.analog.remove(id).map_err(|e| e.to_string())
+    }
+
+    /// Drive an analog input channel with an engineering value (mA/V)
+    pub fn set_analog_value(&self, id: &str, channel: usize, value: f64) -> Result<(), String> {
+        self.analog
+            .set_engineering_value(id, channel, value)
+            .map_err(|e| e.to_string())
+    }
+
+    /// Simulate a cut or repaired cable on an analog channel
+    pub fn set_analog_wire_break(
+        &self,
+        id: &str,
+        channel: usize,
+        broken: bool,
+    ) -> Result<(), String> {
+        self.analog
+            .set_wire_break(id, channel, broken)
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn list_analog_modules(&self) -> Vec<AnalogModuleInfo> {
+        self.analog.list()
+    }
+
+    /// Add or replace a simulated high-speed counter channel
+    pub fn configure_hsc_channel(&self, config: HscChannelConfig) -> Result<(), String> {
+        self.motion
+            .configure_counter(config)
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn remove_hsc_channel(&self, id: &str) -> Result<(), String> {
+        self.motion.remove_counter(id).map_err(|e| e.to_string())
+    }
+
+    /// Drive a counter channel with an encoder frequency (Hz, negative counts down)
+    pub fn set_hsc_frequency(&self, id: &str, frequency_hz: f64) -> Result<(), String> {
+        self.motion
+            .set_frequency(id, frequency_hz)
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn list_hsc_channels(&self) -> Vec<HscChannelInfo> {
+        self.motion.list_counters()
+    }
+
+    /// Add or replace a simulated positioning axis
+    pub fn configure_axis(&self, config: AxisConfig) -> Result<(), String> {
+        self.motion
+            .configure_axis(config)
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn remove_axis(&self, id: &str) -> Result<(), String> {
+        self.motion.remove_axis(id).map_err(|e| e.to_string())
+    }
+
+    pub fn list_axes(&self) -> Vec<AxisInfo> {
+        self.motion.list_axes()
+    }
+
+    pub fn history_position(&self) -> Option<HistoryPosition> {
+        self.engine_slot
+            .lock()
+            .as_ref()
+            .and_then(|engine| engine.history().position())
+    }
+
+    /// Enable/disable scan checkpoints and change the retained depth
+    pub fn configure_history(&self, enabled: bool, depth: Option<usize>) -> Result<(), String> {
+        self.with_engine(|engine| {
+            if let Some(depth) = depth {
+                engine.history().set_capacity(depth);
+            }
+            engine.history().set_enabled(enabled);
+        })
+    }
+
+    /// Restore the previous scan checkpoint
+    pub fn step_back(&self) -> Result<HistoryPosition, String> {
+        let position = self
+            .with_engine(|engine| engine.step_back())?
+            .map_err(|e| e.to_string())?;
+        self.notify_state_restored();
+        Ok(position)
+    }
+
+    /// Replay the next recorded checkpoint, or execute a new scan at the latest one
+    pub fn step_forward(&self) -> Result<HistoryPosition, String> {
+        let position = self
+            .with_engine(|engine| engine.step_forward())?
+            .map_err(|e| e.to_string())?;
+        self.notify_state_restored();
+        Ok(position)
+    }
+
+    /// Rewind to the scan just before `address` last changed
+    pub fn run_back_to_change(
+        &self,
+        address: CanonicalAddress,
+    ) -> Result<ValueChangeLocation, String> {
+        let location = self
+            .with_engine(|engine| engine.run_back_to_change(address))?
+            .map_err(|e| e.to_string())?;
+        self.notify_state_restored();
+        Ok(location)
+    }
+
+    /// Capture the full PLC state together with the active forces
+    pub fn capture_snapshot(
+        &self,
+        name: String,
+        description: String,
+    ) -> Result<PlcStateSnapshot, String> {
+        validate_snapshot_name(&name).map_err(|e| e.to_string())?;
+        let checkpoint = self
+            .ensure_engine(&mut self.engine_slot.lock())
+            .capture_state();
+
+        let mut snapshot = PlcStateSnapshot::from_checkpoint(name, description, &checkpoint);
+        let mut forced: Vec<ForcedDeviceValue> = self
+            .monitoring
+            .forced_devices()
+            .read()
+            .values()
+            .cloned()
+            .collect();
+        forced.sort_by(|a, b| a.display_address.cmp(&b.display_address));
+        snapshot.forced = forced;
+        Ok(snapshot)
+    }
+
+    /// Restore a saved state and its forces. Only allowed while stopped or paused.
+    pub fn restore_snapshot(&self, snapshot: &PlcStateSnapshot) -> Result<(), String> {
+        let engine = self.ensure_engine(&mut self.engine_slot.lock());
+        let checkpoint = snapshot
+            .to_checkpoint(&self.cpu.runtime().snapshot())
+            .map_err(|e| e.to_string())?;
+        engine
+            .restore_state(&checkpoint)
+            .map_err(|e| e.to_string())?;
+
+        self.monitoring.clear_forces();
+        for forced in &snapshot.forced {
+            self.monitoring.force_device(forced.clone());
+        }
+        self.monitoring
+            .apply_forced_values(self.cpu.runtime(), &self.tag_registry)?;
+        self.notify_state_restored();
+        Ok(())
+    }
+
+    /// Remember live memory as the baseline for [`Self::diff_memory_baseline`]
+    pub fn mark_memory_baseline(&self) -> String {
+        let snapshot = self.cpu.runtime().snapshot();
+        let captured_at = snapshot.captured_at.clone();
+        *self.memory_baseline.lock() = Some(snapshot);
+        captured_at
+    }
+
+    /// Changes in live memory since the marked baseline
+    pub fn diff_memory_baseline(&self, profile: &dyn VendorProfile) -> Result<MemoryDiff, String> {
+        let guard = self.memory_baseline.lock();
+        let baseline = guard
+            .as_ref()
+            .ok_or_else(|| "No memory baseline has been marked".to_string())?;
+        let mut diff = self.cpu.runtime().diff_against(baseline);
+        diff.annotate(profile, &self.tag_registry);
+        Ok(diff)
+    }
+
+    /// Compare a saved snapshot with another one, or with live memory
+    pub fn diff_snapshots(
+        &self,
+        before: &PlcStateSnapshot,
+        after: Option<&PlcStateSnapshot>,
+        profile: &dyn VendorProfile,
+    ) -> Result<MemoryDiff, String> {
+        let live = self.cpu.runtime().snapshot();
+        let before = before.to_memory(&live).map_err(|e| e.to_string())?;
+        let after = match after {
+            Some(snapshot) => snapshot.to_memory(&live).map_err(|e| e.to_string())?,
+            None => live,
+        };
+        let mut diff = diff_memory(&before, &after);
+        diff.annotate(profile, &self.tag_registry);
+        Ok(diff)
+    }
+
+    /// 엔진은 첫 실행 시 생성되지만, 스냅샷은 실행 전에도 캡처·복원할 수 있어야 한다.
+    fn ensure_engine(&self, slot: &mut Option<Arc<OneSimEngine>>) -> Arc<OneSimEngine> {
+        Arc::clone(slot.get_or_insert_with(|| {
+            Arc::new(OneSimEngine::with_components(
+                Arc::clone(self.cpu.runtime()),
+                Arc::new(TimerManager::new()),
+                Arc::new(CounterManager::new()),
+            ))
+        }))
+    }
+
+    fn with_engine<T>(&self, f: impl FnOnce(&OneSimEngine) -> T) -> Result<T, String> {
+        let guard = self.engine_slot.lock();
+        let engine = guard
+            .as_ref()
+            .ok_or_else(|| "Simulation is not running".to_string())?;
+        Ok(f(engine))
+    }
+
+    /// 스냅샷 복원은 bus 이벤트를 내지 않으므로 모니터링/프로토콜에 전체 갱신을 요청한다.
+    fn notify_state_restored(&self) {
+        self.monitoring.request_refresh();
+        self.protocol_runtime.resync_all();
+    }
+
     fn attach_modbus(
         &self,
         project_config: Option<&ProjectConfig>,
@@ -294,6 +634,23 @@ impl SimulationRuntimeHost {
             .attach_adapter("modbus", Arc::clone(self.cpu.runtime()), adapter)
     }
 
+    /// 고장 주입과 강제값은 스캔 시작 시 프로그램 실행 직전에 적용한다. 스캔 사이에
+    /// 캔버스·시나리오·프로토콜이 쓴 값이 stuck-at 같은 고장을 이기지 못하게 하기 위함이다.
+    fn install_input_overlay(&self, engine: &OneSimEngine) {
+        let injector = Arc::clone(&self.injector);
+        let monitoring = Arc::clone(&self.monitoring);
+        let tag_registry = Arc::clone(&self.tag_registry);
+        engine.set_pre_scan_hook(move |runtime| {
+            if let Err(err) = injector.apply(runtime) {
+                log::warn!("Input fault injection failed: {}", err);
+            }
+            // 강제값이 고장보다 우선한다
+            if let Err(err) = monitoring.apply_forced_values(runtime, &tag_registry) {
+                log::warn!("Applying forced values failed: {}", err);
+            }
+        });
+    }
+
     fn spawn_event_forwarder(&self, app: AppHandle, engine: Arc<OneSimEngine>) {
         if let Some(handle) = self.event_task.lock().take() {
             handle.abort();
@@ -305,14 +662,36 @@ impl SimulationRuntimeHost {
         let runtime = Arc::clone(self.cpu.runtime());
         let canvas_sync = Arc::clone(&self.canvas_sync);
         let tag_registry = Arc::clone(&self.tag_registry);
+        let debugger = Arc::clone(&self.debugger);
+        let analog = Arc::clone(&self.analog);
+        let motion = Arc::clone(&self.motion);
         let event_task = tokio::spawn(async move {
-            while let Ok(event) = rx.recv().await {
+            loop {
+                let event = match rx.recv().await {
+                    Ok(event) => event,
+                    // 느린 소비로 밀린 이벤트는 건너뛰고 계속 전달한다
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        log::debug!("Engine event forwarder skipped {} events", skipped);
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => break,
+                };
                 match event {
                     EngineEvent::StateChange(event) => {
                         let _ = app.emit(SIM_STATE_CHANGE_EVENT, &event);
                         emit_status_update(&app, &engine.get_status());
                     }
                     EngineEvent::ScanComplete(event) => {
+                        debugger.update_all_watches_at_scan(&runtime, event.scan_count);
+                        // 아날로그·모션 모듈 → 강제값 순으로 적용해 뒤쪽이 우선하게 한다.
+                        // 스캔이 끝난 뒤 적용하므로 프로그램은 다음 스캔부터 결과를 읽는다.
+                        // 고장 주입은 다음 스캔 시작 시 입력 오버레이에서 그 위에 적용된다.
+                        if let Err(err) = analog.apply(&runtime) {
+                            log::warn!("Analog module simulation failed: {}", err);
+                        }
+                        if let Err(err) = motion.apply(&runtime) {
+                            log::warn!("Motion module simulation failed: {}", err);
+                        }
                         if let Err(err) = monitoring.apply_forced_values(&runtime, &tag_registry) {
                             let _ = app.emit(
                                 "ladder:monitoring-error",
@@ -335,6 +714,9 @@ impl SimulationRuntimeHost {
                     EngineEvent::Watchdog(event) => {
                         let _ = app.emit(SIM_WATCHDOG_EVENT, &event);
                     }
+                    EngineEvent::Fault(event) => {
+                        let _ = app.emit(SIM_FAULT_EVENT, &event);
+                    }
                 }
             }
         });
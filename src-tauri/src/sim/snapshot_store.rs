@@ -0,0 +1,127 @@
+//! 이름 있는 PLC 상태 스냅샷의 파일 저장소 — 프로젝트 폴더의 `snapshots/<name>.json`.
+//!
+//! 포맷/변환은 `sim_engine::snapshot`이 담당하고, 여기서는 파일 입출력만 한다.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+use sim_engine::snapshot::{validate_snapshot_name, PlcStateSnapshot, SnapshotError, SnapshotInfo};
+
+/// Snapshot store error types
+#[derive(Debug, Error)]
+pub enum SnapshotStoreError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("JSON error: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error(transparent)]
+    Snapshot(#[from] SnapshotError),
+    #[error("Snapshot not found: {0}")]
+    NotFound(String),
+}
+
+fn snapshot_path(dir: &Path, name: &str) -> Result<PathBuf, SnapshotStoreError> {
+    validate_snapshot_name(name)?;
+    Ok(dir.join(format!("{name}.json")))
+}
+
+/// Write a snapshot, replacing an existing one with the same name
+pub fn save_snapshot(dir: &Path, snapshot: &PlcStateSnapshot) -> Result<(), SnapshotStoreError> {
+    let path = snapshot_path(dir, &snapshot.name)?;
+    fs::create_dir_all(dir)?;
+    fs::write(path, serde_json::to_vec_pretty(snapshot)?)?;
+    Ok(())
+}
+
+/// Read a snapshot by name
+pub fn load_snapshot(dir: &Path, name: &str) -> Result<PlcStateSnapshot, SnapshotStoreError> {
+    let path = snapshot_path(dir, name)?;
+    if !path.exists() {
+        return Err(SnapshotStoreError::NotFound(name.to_string()));
+    }
+    Ok(serde_json::from_slice(&fs::read(path)?)?)
+}
+
+/// List the snapshots in `dir`, newest first. Unreadable files are skipped.
+pub fn list_snapshots(dir: &Path) -> Result<Vec<SnapshotInfo>, SnapshotStoreError> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut infos = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+        let parsed = fs::read(&path)
+            .map_err(SnapshotStoreError::from)
+            .and_then(|bytes| Ok(serde_json::from_slice::<PlcStateSnapshot>(&bytes)?));
+        match parsed {
+            Ok(snapshot) => infos.push(snapshot.info()),
+            Err(err) => log::warn!("Skipping snapshot {}: {}", path.display(), err),
+        }
+    }
+    infos.sort_by(|a, b| b.captured_at.cmp(&a.captured_at));
+    Ok(infos)
+}
+
+/// Delete a snapshot by name
+pub fn delete_snapshot(dir: &Path, name: &str) -> Result<(), SnapshotStoreError> {
+    let path = snapshot_path(dir, name)?;
+    if !path.exists() {
+        return Err(SnapshotStoreError::NotFound(name.to_string()));
+    }
+    fs::remove_file(path)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use tempfile::TempDir;
+
+    fn snapshot(name: &str, captured_at: &str) -> PlcStateSnapshot {
+        PlcStateSnapshot {
+            version: sim_engine::SNAPSHOT_FORMAT_VERSION,
+            name: name.to_string(),
+            description: String::new(),
+            captured_at: captured_at.to_string(),
+            scan_count: 0,
+            memory: BTreeMap::new(),
+            timers: Default::default(),
+            counters: Default::default(),
+            edges: Vec::new(),
+            forced: Vec::new(),
+            scenario: None,
+        }
+    }
+
+    #[test]
+    fn test_save_list_load_delete() {
+        let temp = TempDir::new().unwrap();
+        let dir = temp.path().join("snapshots");
+
+        save_snapshot(&dir, &snapshot("filled", "2026-01-01T00:00:00Z")).unwrap();
+        save_snapshot(&dir, &snapshot("drained", "2026-01-02T00:00:00Z")).unwrap();
+        fs::write(dir.join("broken.json"), b"{").unwrap();
+
+        let names: Vec<String> = list_snapshots(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|info| info.name)
+            .collect();
+        assert_eq!(names, vec!["drained", "filled"]);
+
+        assert_eq!(load_snapshot(&dir, "filled").unwrap().name, "filled");
+        delete_snapshot(&dir, "filled").unwrap();
+        assert!(matches!(
+            load_snapshot(&dir, "filled"),
+            Err(SnapshotStoreError::NotFound(_))
+        ));
+        assert!(load_snapshot(&dir, "../filled").is_err());
+    }
+}
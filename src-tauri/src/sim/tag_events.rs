@@ -1,8 +1,8 @@
 //! Tag Event Bridge
 //!
-//! Subscribes to `CanonicalMemoryBus` events, resolves changed addresses to
-//! tag IDs via the reverse index, filters against the watched set, and emits
-//! per-tag Tauri events to the frontend.
+//! Subscribes to `CanonicalMemoryBus` with an address filter built from the
+//! watched tags, resolves changed addresses to tag IDs via the reverse index,
+//! and emits per-tag Tauri events to the frontend.
 
 use std::collections::HashSet;
 use std::sync::Arc;
@@ -10,13 +10,13 @@ use std::sync::Arc;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Emitter};
-use tokio::sync::broadcast;
 
 use crate::plc_runtime::{
-    CanonicalMemoryBus, CanonicalMemoryChange, CanonicalMemoryEvent, CanonicalValue,
+    BusFilter, CanonicalMemoryBus, CanonicalValue, FilteredSubscription, SubscriptionFilterHandle,
+    SubscriptionOptions,
 };
 
-use super::tag_registry::SharedTagRegistry;
+use super::tag_registry::{SharedTagRegistry, TagRegistry};
 
 const TAG_VALUE_CHANGED_EVENT: &str = "tags:value-changed";
 
@@ -85,16 +85,33 @@ pub struct TagEventBridge {
     watched_tags: Arc<RwLock<HashSet<String>>>,
     app_handle: Arc<RwLock<Option<AppHandle>>>,
     bus: CanonicalMemoryBus,
+    /// Filter of the running subscription; `None` until `start()`.
+    filter_handle: Arc<RwLock<Option<SubscriptionFilterHandle>>>,
 }
 
 impl TagEventBridge {
     pub fn new(tag_registry: SharedTagRegistry, bus: CanonicalMemoryBus) -> Self {
-        Self {
+        let bridge = Self {
             tag_registry,
             watched_tags: Arc::new(RwLock::new(HashSet::new())),
             app_handle: Arc::new(RwLock::new(None)),
             bus,
-        }
+            filter_handle: Arc::new(RwLock::new(None)),
+        };
+
+        // 태그 생성/재바인딩/삭제마다 감시 주소를 다시 풀어 필터에 반영
+        let registry = Arc::downgrade(&bridge.tag_registry);
+        let watched_tags = Arc::clone(&bridge.watched_tags);
+        let filter_handle = Arc::clone(&bridge.filter_handle);
+        bridge.tag_registry.on_change(move || {
+            let Some(registry) = registry.upgrade() else {
+                return;
+            };
+            if let Some(handle) = filter_handle.read().as_ref() {
+                handle.set_filter(Self::build_filter(&registry, &watched_tags.read()));
+            }
+        });
+        bridge
     }
 
     pub fn set_app_handle(&self, handle: AppHandle) {
@@ -103,65 +120,75 @@ impl TagEventBridge {
 
     pub fn set_watched_tags(&self, tags: Vec<String>) {
         *self.watched_tags.write() = tags.into_iter().collect();
+        self.refresh_filter();
+    }
+
+    /// Re-resolve the watched tags' addresses into the bus filter.
+    fn refresh_filter(&self) {
+        if let Some(handle) = self.filter_handle.read().as_ref() {
+            handle.set_filter(Self::build_filter(
+                &self.tag_registry,
+                &self.watched_tags.read(),
+            ));
+        }
     }
 
     pub fn start(&self) {
-        let rx = self.bus.subscribe();
+        let subscription = self.bus.subscribe_filtered(SubscriptionOptions {
+            filter: Self::build_filter(&self.tag_registry, &self.watched_tags.read()),
+            coalesce: None,
+        });
+        *self.filter_handle.write() = Some(subscription.filter_handle());
         let tag_registry = Arc::clone(&self.tag_registry);
         let watched_tags = Arc::clone(&self.watched_tags);
         let app_handle = Arc::clone(&self.app_handle);
 
         tauri::async_runtime::spawn(async move {
-            Self::subscriber_loop(rx, tag_registry, watched_tags, app_handle).await;
+            Self::subscriber_loop(subscription, tag_registry, watched_tags, app_handle).await;
         });
     }
 
+    fn build_filter(tag_registry: &TagRegistry, watched: &HashSet<String>) -> BusFilter {
+        BusFilter::addresses(
+            watched
+                .iter()
+                .filter_map(|tag_id| tag_registry.resolve(tag_id).ok())
+                .map(|tag| tag.canonical_address),
+        )
+    }
+
     async fn subscriber_loop(
-        mut rx: broadcast::Receiver<CanonicalMemoryEvent>,
+        mut subscription: FilteredSubscription,
         tag_registry: SharedTagRegistry,
         watched_tags: Arc<RwLock<HashSet<String>>>,
         app_handle: Arc<RwLock<Option<AppHandle>>>,
     ) {
-        loop {
-            match rx.recv().await {
-                Ok(event) => {
-                    let changes: Vec<&CanonicalMemoryChange> = match &event {
-                        CanonicalMemoryEvent::Single(change) => vec![change],
-                        CanonicalMemoryEvent::Batch(batch) => batch.changes.iter().collect(),
-                    };
-
-                    let handle_guard = app_handle.read();
-                    let Some(handle) = handle_guard.as_ref() else {
-                        continue;
-                    };
-
-                    let watched = watched_tags.read();
-                    if watched.is_empty() {
-                        continue;
-                    }
+        while let Some(changes) = subscription.recv().await {
+            let handle_guard = app_handle.read();
+            let Some(handle) = handle_guard.as_ref() else {
+                continue;
+            };
+
+            // 필터는 주소 단위라 같은 주소에 묶인 비감시 태그를 여기서 거른다
+            let watched = watched_tags.read();
+            if watched.is_empty() {
+                continue;
+            }
 
-                    for change in changes {
-                        let tag_ids = tag_registry.tags_for_address(&change.address);
-                        for tag_id in tag_ids {
-                            if watched.contains(&tag_id) {
-                                let event = TagValueChangedEvent {
-                                    tag_id,
-                                    value: TagTypedValue::from_canonical(change.new_value),
-                                    timestamp: change.timestamp.clone(),
-                                };
-                                let _ = handle.emit(TAG_VALUE_CHANGED_EVENT, event);
-                            }
-                        }
+            for change in changes {
+                let tag_ids = tag_registry.tags_for_address(&change.address);
+                for tag_id in tag_ids {
+                    if watched.contains(&tag_id) {
+                        let event = TagValueChangedEvent {
+                            tag_id,
+                            value: TagTypedValue::from_canonical(change.new_value),
+                            timestamp: change.timestamp.clone(),
+                        };
+                        let _ = handle.emit(TAG_VALUE_CHANGED_EVENT, event);
                     }
                 }
-                Err(broadcast::error::RecvError::Lagged(n)) => {
-                    log::warn!("TagEventBridge lagged by {} events", n);
-                }
-                Err(broadcast::error::RecvError::Closed) => {
-                    log::info!("TagEventBridge: bus closed, stopping subscriber");
-                    break;
-                }
             }
         }
+        log::info!("TagEventBridge: bus closed, stopping subscriber");
     }
 }
@@ -344,6 +344,8 @@ pub fn start_secure_test_server(trace_path: &Path) -> TestServerFixture {
         model: "XGK".to_string(),
         scan_time_ms: 10,
         hardware_topology: PlcHardwareTopology::default(),
+        memory_sizes: Default::default(),
+        memory_sections: Default::default(),
     };
     let profile = resolve_vendor_profile(&settings).expect("vendor profile should resolve");
     let canonical_memory = Arc::new(RwLock::new(CanonicalMemory::new()));
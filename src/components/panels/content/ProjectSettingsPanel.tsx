@@ -230,6 +230,7 @@ export const ProjectSettingsPanel = memo(function ProjectSettingsPanel() {
                   <option value="LS">LS Electric</option>
                   <option value="Mitsubishi">Mitsubishi</option>
                   <option value="Siemens">Siemens</option>
+                  <option value="Omron">Omron</option>
                 </PanelSelect>
               </PanelField>
               <PanelField label="Model">
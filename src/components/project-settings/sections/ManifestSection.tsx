@@ -65,6 +65,7 @@ export const ManifestSection = memo(function ManifestSection({
               <option value="LS">LS Electric</option>
               <option value="Mitsubishi">Mitsubishi</option>
               <option value="Siemens">Siemens</option>
+              <option value="Omron">Omron</option>
             </PanelSelect>
           </PanelField>
           <PanelField label="Model">
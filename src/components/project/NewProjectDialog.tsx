@@ -93,6 +93,7 @@ const PLC_MANUFACTURERS: { value: PlcManufacturer; label: string }[] = [
   { value: 'LS', label: 'LS Electric' },
   { value: 'Mitsubishi', label: 'Mitsubishi Electric' },
   { value: 'Siemens', label: 'Siemens' },
+  { value: 'Omron', label: 'Omron' },
 ];
 
 export function NewProjectDialog({ isOpen, onClose, onCreated }: NewProjectDialogProps) {
@@ -91,6 +91,7 @@ export const ManifestCategory = memo(function ManifestCategory({
               <option value="LS">LS Electric</option>
               <option value="Mitsubishi">Mitsubishi</option>
               <option value="Siemens">Siemens</option>
+              <option value="Omron">Omron</option>
             </PanelSelect>
           </PanelField>
           <PanelField label="Model">
@@ -191,6 +191,21 @@ describe('simulationService', () => {
       expect(mockInvoke).toHaveBeenCalledWith('sim_load_program', { program });
     });
 
+    it('warns about instructions the CPU model lacks', async () => {
+      mockInvoke.mockResolvedValueOnce([
+        {
+          networkId: 1,
+          nodeType: 'timer_ton',
+          family: 'timer',
+          message: 'Network 1: CPU 1214C does not support timer instructions (TimerTon)',
+        },
+      ]);
+
+      await simulationService.loadProgram({});
+
+      expect(vi.mocked(toast.warning)).toHaveBeenCalled();
+    });
+
     it('calls toast.error and re-throws on failure', async () => {
       const error = new Error('Load program failed');
       mockInvoke.mockRejectedValueOnce(error);
@@ -39,7 +39,7 @@ export const projectService = {
    *
    * @param name - Project name
    * @param projectDir - Path to the project directory (e.g., Documents/ModOne/MyProject)
-   * @param plcManufacturer - PLC manufacturer (LS, Mitsubishi, Siemens)
+   * @param plcManufacturer - PLC manufacturer (LS, Mitsubishi, Siemens, Omron)
    * @param plcModel - PLC model name
    * @param scanTimeMs - Optional scan time in milliseconds (defaults to 10)
    */
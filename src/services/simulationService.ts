@@ -10,6 +10,14 @@ import { invoke } from '@tauri-apps/api/core';
 import { toast } from 'sonner';
 import { useLayoutStore } from '../stores/layoutStore';
 
+/** Ladder instruction outside the selected CPU model's instruction families */
+export interface UnsupportedInstruction {
+  networkId: number;
+  nodeType: string;
+  family: string;
+  message: string;
+}
+
 /**
  * Simulation service for interacting with the Tauri backend
  * and keeping UI state synchronized.
@@ -114,11 +122,19 @@ export const simulationService = {
 
   /**
    * Load a ladder program for simulation
-   * Must be called before start() to provide the program to execute
+   * Must be called before start() to provide the program to execute.
+   * Instructions the project's CPU model lacks are reported as a warning toast.
    */
   async loadProgram(program: unknown): Promise<void> {
     try {
-      await invoke('sim_load_program', { program });
+      const unsupported = await invoke<UnsupportedInstruction[] | undefined>('sim_load_program', {
+        program,
+      });
+      if (unsupported?.length) {
+        toast.warning('CPU 모델이 지원하지 않는 명령이 있습니다', {
+          description: unsupported.map((item) => item.message).join('\n'),
+        });
+      }
     } catch (error) {
       toast.error('Failed to load simulation program', {
         description: error instanceof Error ? error.message : String(error),
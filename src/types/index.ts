@@ -17,6 +17,7 @@ export type {
   PlcRackKind,
   PlcModuleKind,
   PlcIoDirection,
+  PlcIoAllocationMode,
   ProjectSettings,
   PlcSettings,
   ModbusTcpSettings,
@@ -41,7 +41,8 @@ export interface CanonicalAddress {
 
 export type RuntimeBinding =
   | { kind: 'canonical'; address: CanonicalAddress }
-  | { kind: 'tag'; tagId: string };
+  | { kind: 'tag'; tagId: string }
+  | { kind: 'expression'; source: string };
 
 export type TagClass = 'rawBacked' | 'semantic';
 export type TagAccessLevel = 'readOnly' | 'readWrite';
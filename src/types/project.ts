@@ -1,5 +1,5 @@
 // PLC Manufacturer types
-export type PlcManufacturer = 'LS' | 'Mitsubishi' | 'Siemens';
+export type PlcManufacturer = 'LS' | 'Mitsubishi' | 'Siemens' | 'Omron';
 
 // Serial parity settings
 export type Parity = 'None' | 'Even' | 'Odd';
@@ -31,8 +31,10 @@ export type PlcModuleKind =
   | 'AnalogOutput'
   | 'AnalogIo'
   | 'Communication'
-  | 'Special';
+  | 'Special'
+  | 'Empty';
 export type PlcIoDirection = 'Input' | 'Output' | 'Bidirectional';
+export type PlcIoAllocationMode = 'Manual' | 'FixedSlot' | 'Sequential';
 
 export interface PlcAddressWindow {
   family: string;
@@ -57,6 +59,8 @@ export interface PlcRackTopology {
 
 export interface PlcHardwareTopology {
   racks: PlcRackTopology[];
+  io_allocation?: PlcIoAllocationMode;
+  empty_slot_points?: number;
 }
 
 // Modbus TCP server settings
@@ -168,6 +172,26 @@ export interface ProjectConfig {
   directories?: DirectoryConfig;
   /** IDs of tags pinned to the watch list in the Tag Browser */
   watched_tag_ids?: string[];
+  /** Reaction to runtime errors (division by zero, invalid address, ...) */
+  fault_policy?: FaultPolicy;
+}
+
+export type FaultAction = 'continue' | 'skipNetwork' | 'halt';
+
+export type FaultKind =
+  | 'divisionByZero'
+  | 'invalidAddress'
+  | 'memoryAccess'
+  | 'unsupportedInstruction';
+
+export interface FaultPolicy {
+  /** Action for faults without a per-kind override */
+  action: FaultAction;
+  overrides?: Partial<Record<FaultKind, FaultAction>>;
+  /** Bit set on any fault, in vendor notation (e.g. "F110") */
+  errorFlag?: string;
+  /** Word receiving the diagnostic code of the latest fault (e.g. "D9000") */
+  diagnosticWord?: string;
 }
 
 export interface NetworkSettings {